@@ -0,0 +1,102 @@
+; Deeply nested lists, vectors and quotations, for benchmarking the lexer
+; and reader.
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
+(a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 (a `(x ,'(#(1 leaf) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)) . tail)) b)
@@ -0,0 +1,1252 @@
+; A large flat list of numbers, for benchmarking the lexer and reader.
+(
+  200451 #xf60168 406483 #xf4e902 -8939.290862 -585955 -6348.545118 6511.087978
+  260007 671.085105 773/608 -469340 -507834 620/103 #x1cd35b 697/227
+  896.934747 759/448 -7425.773127 -964 -206175 456397 -516909 -580154
+  -882108 -5345.350224 383/506 158/499 398007 -5408.316056 9919.457600 7436.325432
+  154/788 41/520 #x65a6f 645.299879 -4525.323340 215766 556465 -732909
+  #x3a0b69 663174 567/414 -338631 #x201590 604/926 #x8a3733 -751436
+  -6574.577411 855399 -9695.086276 714/558 #x1ee1e8 135/353 -565084 -427.148344
+  -455768 7031.184193 -523683 359/34 981080 621199 -537110 152961
+  296312 204131 -3848.115877 121007 9726.174399 #x861e11 4956.565827 -2903.102991
+  751956 741/935 371293 57946 -1243.440015 865666 -705852 -987263
+  -342779 1514 #xf9fc7c -47159 -475557 200710 -606083 801/200
+  #x25d84e -18660 78/290 #xb245e7 #x476f38 -7028.660582 -356531 245/800
+  111575 993223 -245665 -8291.073303 462045 -975058 #x7de5c5 105595
+  -619761 #xe2d730 -621223 9708.359143 336304 -6822.341392 -857306 #x197142
+  255262 #x6c17a8 -674932 7573.735611 -9774.748017 87/63 187/754 -818363
+  -5541.667700 -881779 -524366 335497 741156 871/90 #xc54138 822593
+  #x31760a 598420 864/236 -264473 #x23e20e 612/509 -745718 4293.118513
+  8889.033772 -391387 #x188f88 -140170 70/352 171/75 971762 211/732
+  92/870 -5150.769295 961886 813/855 311/573 662424 6066.983639 -7783.874231
+  131307 #x481f4a 19/397 -921912 949291 -2954.108494 -490588 -7010.378842
+  4737.406025 -269088 529/293 703882 482122 #xd2826 5787.346523 -3909.879621
+  928/568 366/487 #x38c373 -242316 152135 -506663 348707 612032
+  -500297 -853272 -340298 -925195 379758 #x424bb6 -562193 883141
+  823745 -820951 -505534 185/903 3797.420110 -3696.426292 3683.339754 -725624
+  #xf8f6e3 -339033 3681.065389 -476881 -487325 754438 968722 -863248
+  109380 290836 #xf04d76 230265 #x80cb9c -778932 -842522 1673.895545
+  961/774 -2467.344099 937/395 163/319 -348140 589892 #xf029f7 958382
+  -6996.295049 636807 #x98ef03 847462 -4920.290696 653111 -3480.367706 814231
+  #xd57b27 #x7618c7 622937 -6588.894087 640/95 #x7a9b81 #x37b2d7 #xd9425c
+  -930336 365/168 #xdf9174 7318.694125 729/896 769036 799/466 7286.967089
+  -458653 -350421 #xf31e46 929/481 -559018 857523 -609027 #xa85161
+  795/804 #xf94857 -70248 374755 590841 200580 -407742 #x351be8
+  -2571.975514 #x79a216 232486 -9774.639265 734830 -620266 228409 #x990ead
+  95/659 -498093 244165 -994636 1953.599642 440/523 612080 -520303
+  9152.430413 771074 -29860 -220586 879731 -109178 -167285 -844228
+  -4408.877553 #x4f27d1 3790.618202 7443.377559 570869 701355 837/493 137/889
+  -8214.672297 345415 751277 -7399.733116 9396.457121 810/336 806818 16173
+  239/999 607572 424315 335827 #xdfb6e4 191073 -440801 -711461
+  -534006 #x6d47fd 71/300 #x3dd96e 9458.356074 889915 144581 -995043
+  515052 -386935 251749 817/693 994111 -119135 9385.022036 749191
+  959/526 915/999 6060.238267 -471890 186/948 -815894 #xdcd794 149/767
+  #xfee063 144/906 17/216 #x7f96f4 229/456 651242 710593 -679016
+  757901 696004 38667 888998 972620 -509044 -830323 -655241
+  -229558 928733 -665851 7922.059678 264/383 -7059.449270 870833 1787.349740
+  -517655 #xb4d89b #x3b83f9 304132 949533 #x6fc66d -758060 91/842
+  443630 836843 -952618 -396499 #xcf1c37 #x1c754c -102944 -427057
+  -659042 74034 887799 612736 -376874 639916 -575176 724/867
+  -462.084079 5710.348144 778740 #xd1bbfd 2225.928639 #xfb53a5 -7939.671692 472991
+  -605226 305/77 142/703 #xb5a3d1 -166645 914170 173771 #x44df5f
+  301162 -674156 692528 139938 -851794 -3759.560659 249/868 -477214
+  9572.704523 #x8851ed #x207a26 933283 -162997 47/357 #xd99dac 7955.996580
+  818993 994829 167/85 179/796 980861 448153 -476696 383207
+  -5478.503313 -8107.215714 #x277b9c -662.230022 #x4085c3 -884670 916/94 #x8d2845
+  213201 -793999 -352958 800381 -24612 797394 827388 780775
+  #x3671d -691585 #x6fdd88 -655555 779786 #xb74bc 707363 -539758
+  -226813 -48061 464/70 110.972014 -202683 854189 -243839 933/461
+  593319 -464416 410/803 86013 -8129.370995 460114 -101824 398108
+  -33252 1046.451010 862/709 455/452 836246 #x2beb0 347069 39/445
+  #x96fb4c 447/229 #x54f128 #x5a1e41 -342123 833691 184278 200/868
+  241836 616416 -826587 114717 -29082 #x6c70f7 -644341 -664727
+  120/45 #xb4e0c0 -258474 2839.780583 #xd4d7f9 #x4145ae 812280 #xebbf72
+  -25648 #xc0a57c 193/700 #xc1998 -8446.628628 #xd7c502 #xdd039e 143/968
+  #xc101d -299694 -7988 188/809 9016.941877 -199450 266554 565/330
+  363/741 -541621 632964 #x692af2 -851323 729/907 -811203 681/792
+  -24414 -74482 693/709 #xfaf975 6828.775186 -5069.312537 -516765 -409602
+  -345901 437/472 555/8 -356711 976/639 -107194 832/425 839565
+  2064.346869 2171.120508 832222 -393601 #xc04818 -381500 1741 -958423
+  26/516 561143 #x7b12f8 927630 573/351 #x3b8e06 #x8a898d 874758
+  -562823 -6376.356902 602/985 904403 -7210.424671 930679 4487.310983 965/242
+  6826.561765 613/99 #xed6c93 -504964 -81086 -713121 985/145 563/848
+  253/967 965384 171912 603384 -275223 -273311 841/488 -75257
+  66378 459463 -9719 #x9f8b76 -184775 -931998 -328271 540/950
+  -982078 202/698 284092 -844667 4248.373249 5881.326248 674/592 140/18
+  #xe169c4 889530 -881482 918585 240393 674762 182/467 -3635.402130
+  #x230e9b 878/59 #x992f6a -6307.941498 #xfd9259 #x345cc -9849.980681 5974.504255
+  -420776 366/448 49756 -961.812482 311/246 -874172 #x7de279 38718
+  2684.023214 -443278 -2597 -145904 180247 78/526 -78562 -262939
+  9131.895042 -9753.329758 -605566 #xc1fbba #x9eda2d -55420 #x5ca673 -353818
+  #xcef8d3 16/213 661545 -344708 -808351 #xb0d9d8 #xf740ce 978/375
+  193/586 -7840.853960 #x57626b 370243 -618990 #x76dc60 410353 -2534.285305
+  -610719 326/60 543/979 269/38 #xb3e56b #x45253e 865/601 #xcfde47
+  5517.875781 243466 174739 225/572 358988 1160.226227 726043 -9874.329659
+  #xe7bd9e 183/852 -142600 -1064.727144 -242638 878/734 -600732 828578
+  #xe7313b 376911 104950 -7853.357746 #x1bb850 -9999.151156 7074.896697 485759
+  -823158 -18979 545845 477/600 #xf3b93f 182554 433061 768/544
+  694325 8361.891746 #x72462b -909232 481/495 58912 20206 #xb1d1d1
+  594/618 #xc7df55 -449114 #x94cad4 -742797 345001 -7043.967780 -289623
+  -1151.335215 #x3a484a -636866 -2306.667562 8763.596622 823/435 -977426 -907477
+  818389 -822007 218/20 -494847 719720 -336585 -159541 91.888937
+  #xa0ef8c -5712.483670 #x653665 825261 313/911 -899445 #xce2fd3 -57470
+  38887 722/287 -498398 -251466 -8831.741662 -798457 -370148 131986
+  -5311.762831 -677497 871/233 -796598 -4381.917926 -370323 -858526 #xf19983
+  235047 381989 -2374.396655 -214256 862/312 #xef6d4c -2172.050321 423360
+  767 -535561 -969011 -85.346749 458384 -6927.049784 197445 912/954
+  #x6a8d13 #x96ae50 #x46082c 12/555 -1112.475514 #xda360 400929 -227042
+  -3779.175464 619/616 #x3d9090 -4437.692527 624/380 725056 902082 736266
+  -989233 952785 #xa7c1df #x214509 316/208 475678 99/75 -985939
+  2107.027100 268654 358072 114202 -986153 509/174 -693378 -80873
+  172505 645/529 712/961 -480365 #x774f42 75119 978291 906505
+  1769.031414 -510413 -318113 473260 #x36b2f6 738781 655/12 #x2cd5ff
+  847479 #x620453 902709 #x933e5d -869525 760791 399369 532154
+  827/551 534157 #x106067 219510 #xedd187 -214673 -1814.029551 216823
+  577413 -912059 -638099 1863.262102 776012 973/15 23257 90019
+  -350379 #xb0c06c #x6b4c37 #xb5813b #xaa934d 739831 1102.701646 -395867
+  -87667 886/692 -207.486968 #xd8bf0 245327 106/785 -9677.612113 585094
+  -3312.851049 546/941 501027 796247 -6899.678630 7532.284462 -915692 183/139
+  -510329 174/154 #x85fd86 -2676.606258 361474 235/642 7.630140 -341072
+  174/341 351/827 392279 -912577 163100 9/365 71/67 6227.695296
+  -944439 359/718 #x4714e2 5889.315665 -920914 274/385 613136 42678
+  442777 550973 4794.568549 -258480 -949560 -559894 818084 -739548
+  #x7a9817 -117687 9451.098854 #x5918bd -717162 -5336.914765 #x3cf25b 437045
+  #x75fbde 702288 -630136 -907895 370/479 #xbf3b69 -99589 708100
+  #xad23d9 6346.035813 -163132 917/919 9241.592374 8216.759399 939290 -828647
+  49607 -256121 132606 -751974 -9064.776720 620700 276.346775 -5424
+  -750253 -86953 -1001.499014 -7691.499441 417663 #x9f870b -379690 #x955a24
+  5336.338406 -257782 3438.524739 #x8af128 846479 363/488 -5030.645192 #x80a645
+  328543 -206649 112/577 197/717 727811 263085 125.306863 553825
+  -415252 384/664 #xc20b17 -887558 859632 515/305 262675 979/578
+  459046 #x284f03 489979 402/830 #xcdd5a3 #x99eb4c -578013 54279
+  #xcf3469 428/600 7341.395196 -1813 70155 -925015 405162 991897
+  -870110 #xb43a17 -385789 -941697 5762.672976 -384125 779/175 -138797
+  -446275 5283.692631 186119 3986.852260 #x4b8844 -575661 -602822 -368116
+  -3138.071743 566597 -75061 397/93 -1006.179462 -2154.249813 358649 489574
+  #xf89530 50407 -239131 -894.950639 -294893 511/668 129/448 415987
+  930007 604207 -164449 #x4369be 56648 250823 #xedd3ad 807/56
+  806000 195/929 -389784 358/495 161/304 -1040.953196 953316 -705109
+  7430.997768 #x7fda33 -3563.772624 -942.326479 #x612fde -921113 #x33d4a1 567250
+  -6502.157011 71/887 1872.256386 -940246 162111 233887 #x79db0f #x1211b7
+  -8291.133521 841217 #x4f5a8c 781752 674/383 #x8adbd2 658290 657551
+  762/183 8533.675953 565/290 295/226 680/60 382/473 #x6cb7f0 207/187
+  -18784 438011 #x2eaac3 -2237.970299 -181024 -279567 471901 -117817
+  720678 735/851 -4067.954099 -1616.213111 -3222.499506 -1076.538096 248204 -193499
+  -653321 427985 371214 #xa159e4 998198 -828121 5032.296063 -8269.712260
+  -646.165406 #xbc7dc4 543917 #x1a9eda #x843092 662/197 #xaaf6ef -7834.493444
+  799/823 #x17aeb7 -335503 613272 426523 827/161 794/756 41822
+  658376 -227005 -426713 -399284 -271365 344486 568513 462490
+  5512.606271 741/370 #xe3e799 #xb2e675 -8445.557638 701/325 -395149 -754238
+  -342687 923999 519012 #x5fab9d -7980.691080 -254366 259285 #x21819
+  -483717 #xfe2bbd -808003 #x803ccc 777862 -240790 -6447.284385 #xe257ae
+  #xd9569 269812 -957221 -6202.128810 561607 #xf02298 #xc6a927 243880
+  -568.799231 -695272 448888 826781 -287797 -959630 868905 -752271
+  -8738.213520 -926886 119/86 95440 -925422 10/749 102808 -797660
+  #xc3cac4 #x684d6d #x4243a5 -479034 209/194 #xa2aa21 649882 -681824
+  582/405 #xd414a #xd57319 545259 9403.218382 #x12a75 809764 -250497
+  4410.539543 -982427 #xd3410a 152425 25312 287/710 922846 572465
+  -7415.329872 -384537 -173487 #x1d2e55 -830684 -260644 -640683 -891152
+  555565 594366 #x5abfb7 -330529 648475 2040.020740 -9837.991545 166646
+  979307 -600915 -943940 236934 782/20 -9208.588898 9981.132784 3100.365373
+  72/558 344/98 495/979 #x492ae5 964/832 7201.966431 616876 -9998.921412
+  -552368 858480 576/682 949128 -774407 -323871 -915.273168 132913
+  727409 652/964 #x9d286 -298702 -350922 3253.936482 #x991623 398/210
+  -2953.106747 -508053 642775 241/641 -61232 -890984 8251.829230 -45830
+  407261 -8917.435021 7257.764973 931558 #xfe0359 4441.693596 #x49dbaa 275/381
+  100119 765/581 -218804 -397183 943942 #xc86f42 -779854 740/533
+  1113.467898 405595 -5185.069210 343723 #xb1be8e 844/237 9273.156784 993108
+  #x2cf5f8 9874.157420 582/632 3865.229934 9260.040652 41/818 -200647 #xea733a
+  474781 -1431.031510 73579 194757 770/176 #x749d39 835027 -942.606862
+  -224399 -747455 -302511 680835 3147.103946 79666 -357546 3997.625625
+  -62377 514333 #x153ea6 -371488 244428 289/168 808/656 #xee1a4
+  #x27e210 896/550 40965 #x37d9e 741/730 -258679 988194 79/324
+  890461 233610 -181950 707/884 881264 922/948 1590.299597 897660
+  -7861.024781 684.154717 -489934 -775638 -182795 4181.644968 118904 #x8830c2
+  #xcfd84b 376804 226656 437695 125/994 259/333 -802272 710274
+  39365 #x7b9b5b -1008.034593 206024 842/278 -807475 710/662 244609
+  -296195 5444.717098 248055 -144566 947/188 #xbcbbb 354/550 #x964457
+  121842 -732941 987/901 479/291 711/505 48302 880421 252255
+  -850150 #x6f716 217654 166790 73921 #x6dc588 #xe46f0f #xaf0671
+  269680 -7709.959550 459113 #xae54c2 2681.662404 -311625 #x7a6749 362065
+  #xefcc35 291306 373835 -188859 -969943 #xe03bf -857711 #x6c5e7
+  #x762c94 -251324 10307 44010 877297 #xc20620 #xb7e96d -562250
+  900/539 #xc9bd4d 2379.209918 286/90 542939 -6771.684156 801985 -903254
+  -92317 -716604 595/468 372/787 548/804 #x12f016 715407 #xb154a7
+  #x2ca026 #x87da36 919385 472785 667/394 -770532 768/950 #xb3195f
+  3220.502813 405/625 -7746.958625 -38082 564443 684775 #x4f9fc4 803845
+  86731 -186.573361 #x9e01a4 -2989 -711074 3538.032624 -737133 -739365
+  -803895 429/16 237577 -10581 #x2e79e2 #x9bdd43 -56814 850/597
+  #xe64340 #xe5f4ba -958766 -441452 238547 372/914 574/547 4583.654797
+  -3045.252698 -893344 692508 #x8c495a -408866 -494999 #xbb631b 973685
+  -5944.599014 -534117 409181 -660736 9334.144259 27/996 -686955 796633
+  -559394 913457 263841 811137 838951 882214 921/559 -8883.486216
+  86835 #x93c0b1 282.613066 -3149.112966 283394 -559892 589/174 9741.951175
+  -187897 62/258 7255.037332 -159649 364402 850859 302484 -520010
+  955785 -809265 -1622.426042 -861321 492520 #x2b7a98 716/419 678/479
+  219277 822/395 -480505 1754.022726 #x8796fb -4934.028980 311424 694826
+  493.609337 314/343 -366230 -791987 151/498 -954149 195506 -5349.729738
+  247178 741/393 -861858 911394 995506 -570583 524/255 178.873396
+  5226.688408 -22042 -411816 9996.006820 -456790 5594.246897 2298.106373 -4993.476036
+  98/982 -481.773315 #x9cee2a #xa76c0b #x229b16 -431383 #xf17508 802255
+  #x7ea4aa 452/76 529/643 -881287 -82092 8433.567285 40465 -640303
+  418/832 -492252 -560332 978/547 #xda0cdf 195346 860/684 #x66b467
+  108/662 767/376 #x4a9044 848324 -215110 213946 -68665 -466542
+  -263320 232/489 -735858 #xcb79cd 288/946 952105 885249 -8009.247546
+  -162720 816381 436723 #xbc0f8e 320765 -323139 #xcdf057 442625
+  -678650 793.662005 728583 -851024 #xd4235a -269333 54470 848329
+  699/152 961/461 3193.347147 580/295 -6932.470707 -7176.032744 -636892 787/846
+  602026 #x76dcf5 -4025.208698 264/759 946950 #x243627 286/634 -69851
+  -253767 -559201 237/539 -138725 #x987f55 907062 -200314 638140
+  765675 #xe17f79 407809 -5995.239859 470302 702688 -754920 331928
+  -1283.337495 #x368681 4937.550316 -2925.997612 761/176 17725 946.681371 -982617
+  -9209.693253 #xb494db -242674 557.615059 920/553 719448 2493.515783 -460234
+  #x1b6ffd -611858 437533 #x1f8a14 701/923 #xc843f0 -926224 856/490
+  -654772 -548538 189940 #xbe66cb 2206.890008 -24839 2737.219769 #xe7d208
+  129/531 -591223 438/606 17/172 321014 #x1e518f -355026 #x67ad6b
+  #xb79c8b 8986.664934 #x587c7e 78/650 #x948986 #x746506 981908 -25281
+  896169 -6344.136460 -542512 473152 362222 449057 -3328.971484 17439
+  780/719 #x6b8083 197/809 163/669 6791.258859 #xb23d37 192402 -46876
+  4654.851135 -515980 -6567.904480 620021 325022 -527776 509365 -634088
+  -949819 -2847.690497 #x3ca749 299/45 -7477.522637 -274192 #xf7cb75 709659
+  92094 -82050 635/459 #xa7df3a 729/679 -831116 -217773 220/87
+  -399173 328766 #x9feb70 434054 9360.318111 599/21 -9286.455049 -6931.371274
+  #x9810b4 #xa878e0 -837749 -591817 #xc5f772 1779.124730 521433 585/335
+  839288 -116068 5443.536710 925558 405/974 2678.328781 230807 220716
+  682/28 -250343 304501 #xe0763f 840868 993598 879.142972 -1282.546435
+  677/167 835530 251157 288226 -422792 -45777 35/50 -450145
+  494/982 830064 -303850 971151 461/135 #x13caa1 -380163 977/354
+  #x2749fd 651/612 7890.845123 -204814 62692 770979 -445771 -888456
+  -628685 -351491 721401 -696267 #xc30e78 680716 -450805 509948
+  596/59 862283 174823 826714 725/831 -878693 -577099 924/637
+  359/915 993/851 726952 -13875 #x3b91da -881894 902933 #xf105bd
+  -407166 #xd08193 260694 -741956 #xcdfbb8 -737710 -819486 505687
+  823/357 263/717 266966 377/399 -2509.576020 #xbb1547 821/252 673/10
+  #x9e01e6 -930800 945945 -1835.461828 568452 #x98c1f1 205/273 861/761
+  -117205 #xe9a157 752/171 #x9a68e8 73461 2294.417746 24928 -796062
+  698968 352767 970/178 703800 -476.457785 134503 309/88 39201
+  402168 -679796 852/548 -552777 814/129 -62919 778638 7041.578818
+  690285 860245 393/241 -8436.496570 2648.643517 692562 985304 #xd58aba
+  -934149 -191559 #x3f68ce #xeb8c2b 81/344 -384596 222/150 #xd5c398
+  -876301 201119 12106 639/67 -779138 #x76d302 807286 206/142
+  -437463 -341290 89794 -568712 77/657 762999 -2189.887480 #x68063
+  #x7e9360 859154 #xd06f10 4937.465337 7481.843607 689425 -4651.332272 #x1b9526
+  -342791 909281 689087 -411987 -991169 -4316.014162 29497 -32976
+  4925.847143 313699 566398 880742 96417 #xd842d5 -57683 #xe1b56b
+  -90593 123/893 574759 #xb07794 402/170 763833 -643808 769890
+  -439757 -613344 -23982 327/398 574395 5295.797305 -859950 -139801
+  #x621683 382/849 762484 -697465 -232135 #x94038a -657998 2491.562427
+  589113 -520758 #xf18e60 968993 -1081.932536 108197 -58813 916/335
+  217/237 722327 284761 -919502 #x7c0e32 -427605 -5932.903520 -9313.508058
+  332513 #x2f8273 -790725 -798682 #xe621c 833539 #x60f791 2246.792240
+  #x74e155 869/272 -4961.717999 #xb67a28 -958658 -8398.107319 -2857.236666 -224.414891
+  462033 708/521 871/136 #x96a322 970/39 794838 533/449 630817
+  -642536 9185.976131 #xc61774 #x19fc3c #x71e0a9 -839292 -140363 #xc0d83c
+  #x123977 #xa3dd6a 154/974 -794428 -292794 186338 4815.891932 511/420
+  -719670 779/955 -373788 459958 -284583 885/325 7838.618595 -613857
+  -583793 -7650.401694 3281 -994949 -878781 #x816373 766869 355/340
+  941/186 634403 973/596 923282 #x288da3 963/300 #x51e857 293/208
+  -894719 -47725 293396 108496 604573 -7704.442306 -98843 -626641
+  6232.270023 -369298 -5517.554732 -7207.586984 276810 388459 #xf3d2f1 798677
+  #x682791 #x772ad5 9149.007304 845/92 509091 917933 8607.551184 -610889
+  #x2e21d3 509679 5468.260885 2433.360592 -596092 -82622 -481886 #xde1ba
+  179411 709707 -971043 #xd2383d 868/798 #xc48d28 #x2f6f01 228316
+  -465.182510 51/437 39872 381/227 -6161.362298 -893606 715/310 -5547.236041
+  -491.577972 #xb53818 908153 #xe2c026 -100682 -852235 #xa9bb0e -9349.954842
+  128251 -4457.430296 #xf16d53 559/917 -77501 #x74d071 -3401.976659 -692820
+  -249894 25993 8876.121531 6276.999390 494143 #xcd9c7d 174/124 #x5878f1
+  215695 #xbb1834 -145190 -554617 -264670 110/584 -3022.570982 -330353
+  947/607 420/76 #x47efc7 #xbd717f -894791 -442581 484498 #xf591fd
+  #x578850 3516.026498 342828 8485.001732 -650209 #xc8b48d 451/944 -44454
+  -777709 2093.731999 #x9e58e6 417731 601924 799924 -2830.237487 #x2bf165
+  -789285 -331069 6050.875992 688146 230154 -887974 623041 124065
+  879105 556467 #x3d4c57 -591760 375/534 208/887 #xb56d6c -459451
+  3703.144170 141986 929594 426390 -887895 792.689525 #xf70acc 383577
+  -626860 47/659 628570 -941820 257/936 -29650 -137957 4304.165985
+  14418 #x98ea9c 864/569 877368 -7090.482540 -752284 #xec353b 284358
+  -990630 #xca725c 646/350 137/65 26/821 #xfaca4 -317144 -218204
+  618587 -931.134471 411175 -233888 591/151 -385141 #x2cb2fe 390592
+  #xed2ef 620366 -3533.401961 -59628 356/861 -1310.388755 870414 #xc37110
+  215225 252/493 -599135 53988 -755039 #xb2d96b -112881 -438397
+  436650 -993372 403820 -9326.119775 971864 -352606 995.633125 397910
+  #xcebd75 -625550 -166597 6380.477420 #xc40a32 74/334 5246 #x195570
+  -751124 -5118.545774 806/407 -386266 -7344.759358 #xa324e1 741/265 8922.017565
+  440011 #xe9ff28 81181 86762 140389 -3508.570766 #x4686fb -736882
+  -469746 841838 #xfbd224 -137377 259.151011 -20813 531/984 #xf61d85
+  880085 -849652 -385418 700527 #xb23532 #xdfafd9 536398 -985729
+  164/812 768/428 #xe4932c #xb39407 459/917 -202266 5945.497512 485/748
+  -9805.166935 #xd656d9 -6482.237078 458/814 #x2ea70b -85976 -1982.591625 5213.312101
+  238635 #xfd001a -174127 -872835 #x3e5008 #xdbde0a 461643 966/15
+  871172 993202 -333838 206483 -176440 571544 866230 314099
+  -600748 826357 -605112 431/781 -490960 639/428 #xe41040 269171
+  278/128 -899194 120168 414/825 -4757.228484 -580903 227/711 879275
+  5/412 #x1fed27 -41689 697/354 5865.726467 #x9dd8a #x3514cb -4473.850719
+  #xc1838b -491857 461.443546 8738.616122 #x3b98a9 84061 23175 912/113
+  -7469.066222 703/78 804714 #xa734dd -751261 -131399 -717411 #xfbad6
+  -858023 -365318 17694 1991.691597 420674 #x3371e4 416.286987 515158
+  995.895668 -259645 973434 #xe353b7 9036.672494 #x8caafa 228.758984 574721
+  -6776.633274 609505 -994132 -736981 17/700 #x8df3c4 2738.920412 699/760
+  813/621 -925465 177046 -779708 -891843 468899 #x914133 373931
+  7061.734163 517062 -552442 -562453 1559.268692 -153305 -4073.527098 -414543
+  -321336 #xbe92e 5623.621726 511799 #x45dad0 -7852.098574 285/843 836/629
+  -710748 661975 -272491 #xc8929f 70/308 240/209 -469226 -975385
+  224250 #xcbd2f9 8607.201834 #xdab8c8 981929 -154510 #xb4310c 534806
+  795152 767890 -733861 -293392 #xac97ef #x33a747 106699 #xdba1b4
+  872/352 54979 -2565.737082 #x769ec7 -204096 415857 5603.782290 206/461
+  937126 575/223 101078 11/876 1268.247584 -351892 252952 #xba5b59
+  -537926 -395805 555222 -885458 -9611.469210 812/229 -121938 -918197
+  #x7f86e9 #x972222 857738 204/865 228666 -812550 #x527b0f 458819
+  -22303 -369.610625 191/157 #xab2fef 510583 698767 -7233.640769 -914263
+  8620 4330.027283 887335 666773 -285549 #xc4db42 -383852 -326803
+  #x22e4f2 -556618 123872 -5706.961557 2479.005297 144/793 344142 -582363
+  -8318.825796 #xe26435 #xc3963b 263/100 472647 -220711 -902893 686015
+  502952 -861802 -4545.968107 -795982 783208 -938160 #x2d632e 3972.711154
+  414817 1716.397854 2437.141775 967830 7740.978135 963923 791965 906022
+  833452 -9638.420309 172152 756919 851.972652 951607 -329731 -6584.448760
+  -599551 223097 369111 780/495 127/539 -439748 -366719 7079.533875
+  #x9a1b20 -8767.716801 766/13 812856 418762 9890.391923 #x1d7805 305/287
+  #x64a7a0 828315 51054 648/926 -859354 #x65ff52 798/938 #x766485
+  132/519 #x95089d 85032 233453 -954490 -901807 39/777 4499.595367
+  268/100 -11562 -6310.933784 956545 206992 638550 -820360 -403033
+  815/672 117/614 822484 #xa44c55 #xba816e -1823.086899 8266.763474 -266460
+  606425 703/611 -6630.523935 -106080 -884595 83435 416/308 3606.754982
+  -935595 -917459 #x35bf35 #x8f031b #x24c163 117270 160/978 806/201
+  -681967 -5762.964489 920/393 -95878 -369798 -531680 -817402 #xeb24fd
+  -398461 433117 8222.006728 810/812 505743 -753173 -357988 6523.452861
+  -5715.318237 7893.784992 -699922 286130 #x298f4c 26962 796/114 -2211.372339
+  -762983 #x4aa823 -452953 -867808 1521.927960 -457.774805 -7983.388381 -442626
+  399958 #x3d3b90 659392 5308.946250 #xd7c0e1 -899880 -879799 -6633.617369
+  #xa6d5 -523835 331908 923424 -13814 -906771 -6971.530560 577884
+  -257.179716 -6497.123762 156942 199397 765765 774364 #xb69d3 414143
+  -9121.233112 -656451 -249438 -610174 #x48c199 472/367 -815439 780/374
+  -2245.298304 #x82ebde 388289 #x8363e4 128/898 -604688 604893 299/376
+  524/494 13951 2011.706117 181874 7415.251378 -6003.555883 -481267 #x31f2b2
+  636880 16/626 82665 -2012.775974 767/132 572289 -7831.651301 284/894
+  461899 -969380 -50231 #x3ba3a -3405.296343 141589 #x780cfc #x68761
+  -3133.336621 -503954 59/158 -2447.998584 100152 -60719 #xb02ecd 878951
+  3068.916917 -200864 409807 -188909 801263 162032 9078.815016 -460748
+  479633 -670446 5272.684615 567292 #x74a93c #x1b1cdb -6733.599587 -202821
+  #xda0843 -873826 159/487 -674893 115720 67294 -3767.339034 390055
+  173/727 #x81795a 535921 #xb83d56 954/474 #x6cbde5 442271 136/616
+  244250 -840066 #xe37bf0 -6847.433639 -8155.925233 78/46 -525785 396229
+  -528638 -43825 -621013 -249109 -52482 -202468 151675 -958090
+  -3026.715381 462402 #x2e7784 273787 -304277 #xf3db1c -608.220095 803/970
+  327105 -952794 6134.608291 675/516 -837507 #x4ade44 -5804.120236 612513
+  742212 157/644 -510777 798309 6241.501497 2581.802215 -890800 -439592
+  -12429 -47786 -740885 804493 -9634.843134 37/910 382/902 -152338
+  311067 273/942 315/648 -3399.523628 -403710 -814452 461/969 #xfea96a
+  464782 #xf5951f -436160 -412128 #xe9c1de 305580 8114.174292 -52274
+  #x1c48a1 1949.664928 629089 918511 398519 5342.313090 -195662 -1273.698379
+  #x8067c0 -332996 #xa570ef -355216 140/629 677/254 8856.493068 #xdb1dec
+  9434.594686 574226 8503.101123 106831 1538.789762 -212846 247858 206301
+  2425.144919 109951 -152.265364 -3663.483890 -421232 119102 -885809 -337494
+  -645969 #x9b4823 #xcd65c7 868201 2191.621532 -132773 -174327 493587
+  -702258 -42302 -328040 274413 -421571 178127 509252 945/388
+  -999344 938/212 801296 717/245 -521536 -344994 263/524 -594261
+  #xf8a276 589/682 676469 461/550 #x94f805 #xa27264 #x1db4c0 -45134
+  -301079 -2827.407869 844660 -8076.140589 -652141 582871 -1497.144634 17237
+  -747482 -300505 336/984 -94792 24774 416032 771360 18/252
+  -7350.287969 -867533 #xf90e75 801641 2823.689802 #x175ea0 786817 -763903
+  31067 7140.842821 177692 76/923 6301.176765 161600 #x6e6e05 564/427
+  -616764 -50690 #x97c6c6 -803662 3317.183364 5333.231745 384/535 866/797
+  -48060 950828 -311703 -616123 #x67de3a 318/951 818337 712010
+  -794630 #x717107 652/522 -435534 -545401 682729 -972448 9070.377459
+  924506 190/41 -302207 12807 #xab6e1f #x83f8c0 -5348.304883 #xe413f5
+  -233321 -5857.386397 385165 -878659 -921611 7459.217234 -4888.815715 8450.861044
+  425/864 -231610 285/836 -1543.678890 377695 785275 242382 #xa3677
+  #x9df4d0 669692 430/907 6486.501621 3757.926801 319169 2831.659228 -6038.345183
+  #x507220 512743 #xc30a65 207267 333802 50/230 308507 36878
+  -209337 762133 #x799032 -655605 148586 1431.223752 801/694 -854364
+  -999507 #xc2962f 128014 -185849 1917.916067 8271.595069 2387.788194 513727
+  -196220 424/498 #x7dc9cd 876/666 -6729.709913 -29499 809570 -428130
+  6684 #xeb7139 -151790 133/953 8884.787283 150/934 #xbbc8eb 797009
+  -238156 4325.102777 -258868 -4705.531147 -7501.012306 -906729 -807018 #xcf8f20
+  6704.358520 457786 282/887 -349061 165/841 371/493 313/34 2783.099529
+  -227.940478 469452 -872832 122052 -468786 -981960 -497267 -183882
+  68/326 512697 787342 #x142638 -435889 #xedc86e 665/232 556046
+  976168 #x2e3668 -322675 4329.325100 288512 -606028 -4660.069109 161529
+  920107 705/282 #xaebca1 488834 52949 #x2f619d 982506 5757.670139
+  #xeab963 -555.313297 -29955 431/34 -338937 476043 -568698 9057.444050
+  #x26539d #x5b53c9 -5172.962393 -182272 #xe43f3d #xa09f9d 605/531 -6366.970179
+  877870 605836 #xc11ad5 351883 553/453 661217 #x5760b9 #x559bbb
+  586129 952524 -249878 -736014 -5374.987380 -6510.040457 518187 62450
+  67/728 -361483 194475 -839437 -948386 251745 -871517 #xb82449
+  #x9da0b6 758/643 7687.609224 838/884 -6576.497134 2324.362942 610/894 -379519
+  441567 140238 -7319.871914 -650641 3/784 -460966 -508249 #xc702fd
+  #x441220 912063 #xf4ae95 1058.899770 810/4 4352.620709 -451721 #x4564ec
+  723/491 616404 -552195 211790 -708972 -6424.584145 5972.806680 -21068
+  91411 -9390.458319 289690 371326 236217 138255 680/278 #x324174
+  594538 -673072 -464872 -6472.664985 5366.880630 512/552 841688 #x450a0
+  952/275 183/727 468/196 995454 756/82 -693743 #x10e970 -7509.192150
+  #xe63fc8 #xf3c005 -429044 307409 72/868 -687533 860/49 -7805.107037
+  -150273 -587713 4776.847502 -588397 -199887 533/120 7898.763437 818518
+  #x2f6592 #x743f60 -6285.307238 #x82a203 865017 877/296 296/232 -996882
+  471481 437693 -319710 #x8f4161 46707 #xf0536f 376/92 -596914
+  -879170 -523226 306/104 -2841.742689 #xd31e8e -346213 -403698 #x803367
+  -778243 -4254.762985 #xd06040 29431 301304 805879 5661.895361 #xfe538c
+  -59413 776/565 #xe3364f 4235.586017 #xd9f84d 93647 -175581 870103
+  44535 -605115 -333891 702762 696808 6429.153599 #x524fd4 631/925
+  573/681 876.500222 #x16633d 90934 688519 890/647 829/129 797004
+  319/30 302459 903460 139417 #x8718cd -643081 730264 293755
+  #x605ceb 348981 198/177 745/385 #x58fe4a 397289 -2792.588956 775838
+  -490162 #xcff369 773/904 -279374 #xe7425b -6117.456315 827238 411898
+  #xaf8e6e -467438 -142880 -78064 #xbfbe1b 586806 337/85 498/439
+  #xa78077 999/752 9410.204470 846/350 -595261 #xf95b5b #x56be87 208/3
+  -6544.061177 -682663 478/607 -408018 896746 -1522.060244 -892626 -8297.791057
+  582637 #xee8cc7 848410 #xd9a86 287301 223621 -229497 16/947
+  84/166 -139361 844527 412/756 550756 #x4795ef 86178 -123321
+  #x17755e 434470 -522595 -907249 -2714.331294 -2705.686101 793408 68074
+  -9517.038499 -1427.662070 #xc56e6b 388/558 #x44f886 24151 421/64 539311
+  #x28b68a 427986 831529 -619061 137948 #x86aaef -756627 -854203
+  -666379 785/971 -743382 700/833 1604.701292 #x424afd 469840 808257
+  #x3e1795 -121978 702/986 3521.591236 -195477 465881 605146 943/483
+  6532.351571 #x28ab7a 336664 9815.362943 -224443 398834 #xfd1a9f 643/186
+  -40691 583223 65/864 996223 24954 818481 595380 -55839
+  3968.266759 248/293 6338.725630 #x1f79fd 636087 827/759 9461.972819 #xcefadb
+  -691566 -81456 -2544.140151 819/335 813/858 #x38286b 136/16 540/22
+  671239 -4986.130412 -4003.368166 #x407b54 -877209 -225352 389/124 450/183
+  426/284 -317129 -821901 -643346 #x63e389 277/909 629/746 -1066.798878
+  #x1fd4c4 418524 -24910 -263484 540/295 #xeec4bf -1446.471609 855/625
+  710/324 22/18 821623 4992.020626 #x863d2d 61694 265623 905/822
+  3445.372421 91802 #x5db47f 999816 -5959.165546 -4546.688441 #x72689 #x86b525
+  314/861 315951 #x94c5ca 758/305 440/194 486716 40/810 315/12
+  691/27 163/340 #xc506fa #xe57b39 838118 976807 #x8b2403 #x5e2fed
+  935/538 #xaff0a5 46/404 -845204 6585.634559 35.855114 -445586 -766724
+  #x5ba320 -8264.993621 -7285.639175 672675 -189981 787086 #x78e04c -807912
+  -951462 3859.426731 -3361.808577 -269448 -2371.520199 -9760.140704 172802 #xad601f
+  -6132.385327 #xc70b36 -2882.732498 534232 695454 544360 #x8c419f -444971
+  -931867 -310009 #xe04a2b -828011 -370671 750/329 #x10d63 277611
+  -9637.988853 689757 4272.514590 478/613 -9334.578084 358/770 449942 -7722.091567
+  #xafef48 #xbffa08 29603 735651 #x8cdc3d #xd6d043 714463 734/12
+  828551 818/825 341668 #xba86c4 -6637.047111 585113 -18440 #xd25bc3
+  #xb1a931 -5913.281767 231/953 #xa51a09 419671 4079.943526 86/74 6164.844002
+  114325 -184380 #x8c74aa 901004 -315223 -279402 367/459 -358592
+  -642798 437329 -618931 669/935 5285.378401 668/454 -689313 992/559
+  327220 784097 #x4d1654 700532 -68968 -208911 123/383 -3789.052988
+  -422444 9783 580/14 -168349 -1684 8455.719384 810119 41/944
+  179/817 759/14 7379.945523 -348987 39341 12182 #x94a07f #xb9f221
+  4059.954350 6480.620285 929/102 -855868 -889437 169664 816/784 -5030.077600
+  221459 4984.024570 285479 686396 -112704 -404687 668690 995533
+  -386543 #x522f03 975373 7558.773507 63/847 -763903 #x37ce05 #x6892e6
+  #x1bcbfd -720117 907510 -8103.110150 -5589.085481 564953 130956 #x7b8c11
+  916060 669328 -361784 96124 #x8d898a -725820 3676.775257 -481663
+  #x8feb17 #xb843aa -443849 #xaff80e -172260 -479770 -801483 -935283
+  -7125.533357 323193 262/856 -818755 981/824 -722079 328/553 #x4f2f0a
+  527/455 3590.955622 -653428 684.507422 -536209 -984806 5035.365988 -126635
+  #x5d2e73 5096.621767 248319 #xf07389 -30172 -108384 691/586 #x9d10b6
+  -523009 -318456 #x9071a2 3082.714445 -5472.881225 -949.655972 955886 946448
+  607229 868/487 547643 -489558 124/193 -449978 7264.884008 -8185.444774
+  148/819 945535 8068.622772 267/904 #x3042e4 8830.572692 #x543931 398/121
+  #x62430b 556/681 883443 9307.176993 #x3f977b -326685 #xf329d1 915/754
+  890529 793120 -446756 636462 908/486 -1249.148848 -887953 -7405.666792
+  200454 -6959.616273 9639.526145 114/712 -8068.382698 63155 -265814 -2424.633414
+  -568440 352959 -785044 #xb70d36 566661 83/297 633/401 -106269
+  -940614 666814 6288.958833 -437030 152/363 290/18 -169605 -777552
+  #x52bad #x3f7b17 834809 756391 -259167 -3171.132626 -2802.897440 868554
+  192113 #x2cb821 -845950 #xe1802c 421183 191/798 -845770 378553
+  647148 -542820 393/633 -741022 383/505 392853 #x207031 -7699.450101
+  #x96e12e #xbc6f92 -512481 #x42f435 603913 9026.414823 -656780 431728
+  637/363 -7334.840320 -875179 750937 -388561 -871909 461/944 372/155
+  #x6923b4 -955885 66/896 -355000 #x62c128 908586 -321592 426240
+  641/256 -324533 #xe5b732 -341.990233 2189.763991 -745540 853/122 #x2d5ef
+  451005 120956 -700326 #xed2818 443/537 #x5f262a #xd2a682 -4223.869668
+  -6400.076297 835773 -309925 -789311 407/133 -4842.736927 51813 #x883016
+  365/608 -421280 439408 332/101 153866 498471 351/145 -697407
+  -429159 51452 #x191e87 671674 1741.379016 -370263 373351 #xddc9a9
+  #xcbda9d 8988.908523 683/984 -509935 339/629 704065 -638128 #x30e932
+  -5651.723456 -465351 275278 990/655 -915203 -3466.766232 #xd6f856 #x2d3d57
+  #x35abcb -281762 881528 -245038 -6188.876377 227179 744/259 7190.953025
+  -903710 608/416 #xc0d065 -9339.905666 454/435 -410340 -787958 593/441
+  230/833 608020 66/422 9070.967450 277.460108 675/133 134/136 -142074
+  1014.283151 #xdbec45 435524 -580055 #xbbe22b 3460.037389 910/306 -230.846447
+  -912392 482/1 3339.607219 -845203 #x2d8ce1 #x909a4e -49151 #x7e9f6e
+  542/431 -841744 #x615df9 -341527 627423 -97478 9390.609090 #xd181fc
+  109/324 -877537 2986.480238 -545681 -967771 #x276449 -6943.725860 -4804.378425
+  754661 111716 8467.789821 -702561 -362521 851/59 -684687 1430.363736
+  207733 605/571 -361199 394916 #x6e9bd9 #x44188b 6619 223718
+  666670 588/937 182/366 #xe8f6e1 120675 -692010 498/190 99/881
+  561475 3705.432512 930/156 #x6f13e 226521 -569431 -238755 -125413
+  773070 -322682 86/31 #x33af00 #x49977f 665351 1066.746342 -870356
+  -2883.058075 -701642 -7438.748351 -650410 -9380.729969 7585.520906 19009 480/7
+  -793160 #xc67930 253572 943765 -7133.292192 650397 899/888 572/663
+  #xe9ca71 328369 -8506.466707 421208 #xf6d4b1 -3209.169142 #x3174c2 -950931
+  442/495 461/519 -508.273983 316178 164/96 -8621.255182 #x575d2e 393470
+  711580 910468 -586154 #x9a3658 203365 -2667.029115 647156 #xaf77dc
+  -190810 -15917 -628724 -836361 444039 -4540.925426 -4163.896351 -931878
+  -432638 364/718 177/258 816/468 -353752 -966720 #xeac255 #xbdd5d4
+  424/96 454511 -996302 -918521 381/427 2224.122875 #x7ac411 #x103567
+  539/929 -926257 861/723 #x116dab 406446 346262 -54124 -597395
+  -414996 -886240 -569151 3448.490484 929/893 -460304 799139 187/975
+  293377 -5572 -831139 -849441 -180960 #xda1c3e -428.543877 -32640
+  451293 #x5cf726 938/812 -628356 -377508 20/777 #xb75cbd #xca880d
+  -605047 #x153632 801188 960/105 -117370 769959 -404711 192820
+  67556 379041 912490 -512538 79/404 196717 -8496.049424 -671762
+  221710 140372 114000 -6560.454021 -958674 78.018565 -84386 -938832
+  875/996 168/255 926639 321/54 154/784 746905 366966 5405.776794
+  199467 338820 #x6f744c -51470 163847 34/987 -266423 -253109
+  270/637 -429999 -520872 -970.369812 208774 -657450 434099 148/81
+  -831482 420010 588/633 #x1a988c #xdc308b -101.429392 -4429.365548 #x844be
+  60992 -6449.439852 -123319 776/571 -630126 472/57 340/941 406798
+  #x7585ac 98630 929566 480457 -175536 712269 -837548 -5119.532251
+  520/494 -678262 -731294 #x3812fa 440264 504938 7397.568476 413/281
+  -420572 526872 -3305.581275 5816.619533 #x6c9b17 #x58f85e 312964 -823606
+  -587019 845530 8798.496837 843022 186156 4028.236662 #xc4aee2 567/85
+  -530662 6404.478343 -907.512815 -989590 90/126 813/732 -595142 #xa5ec71
+  456664 479930 -773908 #x4a1eec #x369ed5 286/46 -587740 918525
+  9749.300654 1025.362838 -38040 #xbba0c 133987 796999 873/790 89695
+  -104402 -311471 #xaaffe0 832464 -349534 #x6f715a 228/352 699440
+  671271 #x9d879 418/648 -841287 -144023 -2699.219414 -2407.917738 -437989
+  220892 109434 -391663 543311 -634372 -959259 220293 -440452
+  -7592.182800 -1154.877317 -820986 -961358 551549 #xf20d7c 977931 320481
+  902/230 #x9fcc50 892/40 #xe20e86 #xf3512d #xb59b1a #xdd8823 144201
+  -2359.024797 9151.675910 #x5fcaf1 -313382 8891.469769 339846 779.671047 #xed27be
+  645104 979032 #x74c032 -7734.144478 #xfc442e -479399 113/229 -3800.821923
+  155860 -501607 737.025385 #xb5bb91 -515369 628556 -6721.005925 -2510.236250
+  #xb8a190 879420 #x8cf778 735603 419253 -2924.892376 4875.794065 -7487.024635
+  -176462 48/566 #xaefa7b #x42d3d3 413393 #x3fea0c 329/956 495/718
+  -548175 687247 #x3d679d 390240 523158 342/216 503/676 #x4825ea
+  662/883 #x9363d0 #x145e0c -394192 -2649.715221 -229624 507226 -916806
+  788385 -61259 885030 #xde514c 725/572 -899802 #xb43c51 -123058
+  #xfd332 355754 555/792 #xd29dcc -408132 -274435 4045.347164 503543
+  535/276 -58950 -336938 #x554c54 #xd9ff04 -9281.952436 -9119.913618 -542712
+  27210 222/515 126231 1876.505228 499/30 -728664 867815 8004
+  901287 156402 #xc6379e #x738a65 -317841 -592098 127/351 #xfa4094
+  #x116d9c 57582 414/249 -7888.153165 921479 760/43 6861.306684 699/549
+  -7500.183555 #x7e8c10 #x8aa584 75/478 585/172 -291986 #x4ee698 #x94e2c
+  6314.850256 801/371 134408 588001 #x8c1d3c 912302 -405823 #x2b70c4
+  #xedc178 678144 401/624 9406.494947 231211 -275440 -814675 857207
+  -328399 -266486 657/666 #x84e9d1 162552 -6129.329318 642/212 454795
+  #xf2bea6 250/207 #xef3f89 3162.633223 756/702 686834 139/792 727/29
+  -300174 -6293.594329 -190606 #x8b0883 -37120 905897 406624 -999766
+  #x9a7824 7967.048973 -696672 661/273 479823 -8062.791729 #x2dcd82 915251
+  #x863059 -141567 -567996 251370 -99305 558/40 #xda7086 #xf35e2c
+  95150 -207762 593/969 612102 265418 827573 #x29c9e7 334/172
+  #x8ef550 932196 #xc721dd -4103.436475 536/193 -684560 -3771.483020 632/69
+  -965396 #x89c3cb 411/227 970455 -9369.481005 #x1918e 951495 #x3eba82
+  292085 15832 220433 796/344 #xcea82b 280871 237/559 4113.006873
+  -594941 -564274 1095.005595 #xa1bad1 -493370 342272 594681 312091
+  599/724 100079 -578276 -75368 195/287 5892.159672 -280482 #x463f58
+  #x78fd3b 962/974 543/803 8/822 #x633e61 336/788 338161 390567
+  #x3a71af -4273.624916 #xf1f269 180108 798576 -933887 -893322 628057
+  947986 -6926.173236 422/131 #x80d3cc 579/770 #x89ab57 288/13 419005
+  #x48e91e -66962 7140.145135 -943588 -871451 869504 1134.078831 1182.615731
+  -597432 -656.375908 183594 6155.423308 427451 984/932 -401744 1298.803263
+  -8422.949194 #x9fd867 435933 8552.590531 612/775 #x32d963 532/558 809/799
+  -7693.610376 -679429 751/883 3963.662132 #xee3a95 57/923 754940 49/16
+  -832687 1619.843544 435/321 875827 #xd09f20 415660 -9259.884544 3085.417806
+  -862.972087 #x808d6 523070 -483684 -359531 502758 #xf29b0d -211711
+  -4374 148299 250436 1127.775493 427959 327817 -9845.083313 #x7bd7d1
+  519.236836 296482 -8534.259687 -880578 828/918 711327 612/139 #xda4aba
+  4154.707196 388610 3052.456744 -4451.186350 #xbb02a3 392/118 #x803583 #xb2c979
+  -8666.285117 #x63d237 5521.479300 #x1b8cb8 40786 144335 -67374 536315
+  233557 #x1d754c -2496.029688 822/402 -169673 -968566 312/62 -542323
+  -149.097865 #x9cd031 -3337.768291 -170440 957/469 494067 6947.420522 -825328
+  703916 155.320031 -287541 481/102 #xb9ee8b 401430 3912.105894 -27403
+  #xf2b05e 573847 -3711.967913 #xe77581 504/638 346/150 -595585 475/954
+  3616.234770 #x443fb2 #x18836c 704/966 202/524 -359679 17116 1612.129120
+  #xcf94bd #xf2642c 319776 -486487 -5634.433241 -907915 -6528.614524 986729
+  #xaab81e 291/156 625/392 121/143 474164 -822666 568/145 -972389
+  #xe687c5 327467 -738183 366256 727/602 5517.416456 596878 -316108
+  9145.226076 #x60ee28 828/213 #xba4e89 997/133 339625 #x2ebe2 887375
+  897794 -4253.608174 -766326 -6948.391552 9481.010756 522/961 #xae64e -3713.746206
+  24288 #x97531e 914795 -388988 14236 114675 975/775 82731
+  -952432 -7593.024401 -607427 471/917 #xe9c8d 715062 -898940 #x87cfe2
+  848375 #x251638 #xd640dd 894141 195/872 #xbcf051 400/683 145080
+  #x43497 756852 -380755 4/265 -136438 -348969 622/287 287.785115
+  4528.643551 #x68ae40 773410 -864698 -381210 -416036 -599095 -3113.951219
+  323825 242997 -241853 #xe0263 -5144.448968 821/570 803670 8246.691999
+  182/377 460/292 114806 561/320 -113495 -484197 #xa4f182 -3047.361088
+  2931.731323 -7983.973116 250/186 -9542.043916 -857273 -190928 -896351 228.960872
+  537917 -1138.093625 #xe89f6f 684/173 317119 #xb24803 3097.622335 81/986
+  -230868 786458 #xae4010 223678 -772891 541/411 -921100 750700
+  332/916 -119466 1532.101676 -722739 #xce39c3 51/967 -9112.610916 473669
+  #xb551b8 493938 -7423.887093 662/362 995420 22/958 #x931e75 610608
+  937014 #xb54a76 -296300 -3944.169544 320/200 549228 -614049 436343
+  920/65 -908511 319299 -704046 781429 -52860 389.950423 753/892
+  991/598 #x86c4dc 4831.420650 9341.533234 #x996043 -4886.093777 533953 -4354.854112
+  #x527730 191/481 845359 5696.424652 656/363 930408 -240013 2009.043351
+  -9022.625094 764094 -6366.938632 248533 282/394 9539.235516 975450 995/617
+  #x2b757c -642325 617/782 280278 65/80 477763 285230 951/348
+  9652.569107 #x6908d0 -134688 #xb0b9eb -7402.438932 779/356 -757352 736/881
+  -8365.094236 -319961 368274 #x684387 772919 -531340 141243 -6837.538816
+  -470649 454460 #x808234 8792.157441 422/995 278/195 -2196.616242 439/59
+  514234 -497070 581855 969/689 #x214dc8 #xe05961 495197 265370
+  1134.236534 120/800 195/761 -9263.102673 742881 -317987 -354390 -862383
+  #x9f054e 781/819 961/223 #x798ae 224589 -521088 #x808dac -1023.443786
+  626405 #x68d6da -9580.089927 489/120 341197 8865.487586 237287 273961
+  47808 -751785 800/539 599989 -5068.174083 209019 #xda364f 371294
+  -8176.665749 -3163.793911 -5179.658858 3150.085538 383383 #xcbb62 #x91c2f5 -29604
+  -152522 843/236 -274853 377542 #x4621d3 -548346 753360 -93291
+  8388.612321 786813 -7790.806237 #x93c9bc -594334 918816 #xbe44f9 302344
+  -9168.096259 918021 2012.156084 396/900 7965.931503 -739032 985008 -8649.340882
+  608/935 -9944.103577 -771887 8091 #xc8212e #xda7475 8826.137886 -3533.677291
+  -123902 -789541 -555690 -9344.651809 17455 -770.906924 81/879 -2861.112060
+  -780009 643714 -4578.281430 440431 -4548.423184 -145486 -412699 983432
+  -7692.214637 630901 -33324 -430132 #x7aa912 -319114 401/179 -319133
+  #x17ce3b 6472.810807 -486115 -5426.273125 487964 392542 248660 401677
+  #x197658 -635209 117/754 2690.647488 -39108 407/737 #xd43cef -772939
+  981/949 -169727 -202757 238/893 198390 98836 180319 -889924
+  4231.981748 353166 597520 598/522 288120 690/534 4612.057678 -821149
+  1413.271037 106/90 -6116 -769644 #xc75110 -260788 #x94dafd -16322
+  452477 763667 -793306 -750092 244143 #x4ee8d2 62/499 -754882
+  32987 55471 535694 501/758 342962 228819 -7771.129909 272848
+  962524 770429 44861 -460993 -621699 -626776 -1632.017616 -369646
+  #xfd8044 -233092 67493 195/656 824165 -369265 5300.507191 -940765
+  136319 -149320 4187 14738 #x849bd9 -931805 324032 419917
+  749215 256813 961029 233700 108965 -252982 -307870 -3087.937249
+  906520 6371.403845 #xd56b9a #x6f0894 -734283 903372 916507 #x883007
+  206.500008 678/389 786933 242/166 -7685.016527 -6017.314145 569114 165575
+  681576 -5798.151514 548783 853/469 631256 -3871.205630 -543014 -846726
+  #x6a209e -616702 259/749 #x876a9b -1392.593525 #x4545af 564/820 44506
+  #xc65938 -689382 778115 -664524 #x578abc 515/180 616199 -634.817444
+  -145308 #x4ab397 -432859 #x9d5a38 #xce3bb7 1701.221327 -671541 -6107.238129
+  81798 #x24a7ae #xa218ea 43994 891035 -5229.411089 510025 733745
+  290/607 964156 879859 -107289 47/33 469472 #xb903ad 3285.707775
+  -3435.038100 558684 4896.320197 164/713 -193351 -9499.558812 #x6460c 542/842
+  #x5d79c9 -746956 #xf2a0c 320/107 -6553.846123 -8185.184782 499/370 30/673
+  #x4455b2 #x1e7e70 -437538 865775 #x302780 596/510 #x20cbea -37668
+  #x5ca177 608/203 -110665 -970611 -67474 #xe278d6 168/626 5592.845875
+  -954949 961082 -3905.239043 #x2fb4c1 739072 265939 -918804 811760
+  #x709df 610257 -2541.909786 362/124 -101840 -761230 -689268 443576
+  725381 443079 857/332 563116 -450539 584551 -419022 -317768
+  -795949 1016.831424 998/662 8552.278580 281097 38130 972422 6516.679470
+  -198268 347379 -203156 323/675 #x303e8c -88056 934113 515/553
+  194/287 549/504 #x47159d 392131 235899 -32735 2054.917583 965839
+  -819652 -4596.325353 120/245 -812078 484/683 522/207 104928 560/494
+  #x3be084 509/233 -553675 493160 140791 #xef6663 -966361 763403
+  248/329 #x103bb9 -5043.942963 759535 #xc88998 -748521 -344688 649092
+  #x26d82b 639397 -404090 4717.634629 #xad8257 -9119.453111 976668 -626911
+  508654 -999810 65005 410061 -495989 -476869 -314816 526919
+  5703.754674 3304.099457 #xa56a24 1651.653857 285664 480949 -705439 #x80e28b
+  306789 #xaa2922 892941 -58243 -485664 #x779421 -428562 254857
+  -717147 459/493 -608618 818123 #x4c7f87 399/585 -978283 647937
+  761/348 55575 320336 546/263 326/956 684432 -794535 -268960
+  -4555.458197 #xc99084 #xbda04b -265355 -688502 -629543 270/587 -83908
+  -753875 -332134 -751641 672/664 -664955 #x807caa 310/87 #xcc3df3
+  #x7ef922 617807 621/458 #x72437e -843723 #x87e1d3 -2690.471597 -629813
+  602649 4905.119217 431/3 7831.322264 591393 #xefccd1 -115673 77/121
+  899831 #x5ceb54 657/403 -600935 415568 5593.724735 -473380 -85092
+  -335584 250623 -148033 -8802.381763 471688 -7617.822781 -292135 -83794
+  854.000097 514792 -504161 3984.244969 435906 6350.832515 596907 145/479
+  524/466 980/623 -904073 #x29ffd9 729518 #x41601a -85720 -3931.658658
+  #x58f0a2 3159.436575 5189.392216 38044 #xcc1a1a 249559 543149 -240831
+  667/599 #x60fa2f 679771 #x1bc7a3 336/765 1668.393811 313769 291292
+  8217.192790 -153473 974585 951708 907769 -735948 -3229.915790 #xf43767
+  -585738 -321908 -247337 6176.062232 -385561 508815 772738 851563
+  -3256.183654 -764964 #x9066b7 784.667927 -648415 917724 -254967 #x7c8134
+  344/116 870/600 #xab08f5 #x29b72d 68894 -942255 #xb7323f 744109
+  #x6042b0 998/389 376/755 -629692 #xdd6467 -778286 880415 89378
+  244914 727152 -9484.176825 -785564 -644.183191 916/501 -541869 99859
+  #xd16b0 #xc478e3 869679 482759 #x6f1443 363015 -918333 8891.287488
+  -599825 8990.973942 332.449449 -6391.167007 #x122343 557582 433/238 #x1341fb
+  -111554 851331 -238538 -265306 -439894 446000 236/676 6853.701651
+  13/796 -228390 204901 8457.220790 543199 -828557 2637.847023 #xd77d2d
+  6334.012159 -26235 103/866 794697 -3460.979700 796377 -360444 379602
+  -667.359814 430348 462006 #xebe613 867212 #x61ef70 -3915.416263 366607
+  #x80997 #x7bcd8e #xe0132c 921/342 -213130 -682.508254 115/174 #xefe6a8
+  #x8093ac 8634.568336 -1457.798510 354113 697/543 326/807 -751765 #x16c465
+  #x6cdc1e 41/248 -857763 -142627 -7767.527351 721933 594/346 131/312
+  7772.733620 -722071 202741 -7524.583198 -546 633118 920562 908519
+  9/24 -492857 #x6fd9a0 -59470 -7380.768958 -134039 797513 22307
+  -424637 -870375 676/772 -2861.538695 308490 87.757645 86374 -569725
+  #xb25a61 12788 458959 #x5cbc01 810856 508785 -688616 -563951
+  637/156 245271 444/7 #xa3cab2 #x67ecd6 646513 166/681 -627421
+  -388327 -666068 #x2d21ee #x9f264f -580479 -876333 #x3df636 521/737
+  #x96495b -87723 #xe57995 -759955 300579 138/905 -325305 750665
+  588/987 -695757 17013 -588875 #x245186 627230 313/750 #xbd2df7
+  672752 -187749 726637 #xd90e #xc330dd -24262 #x71f4d2 7883.590364
+  #x7e814f -835224 -557686 #xa051cc -23330 #xf7593e #xf58fbd 661/752
+  190/192 261708 -9515.282376 975/474 #x4b62d1 -449787 -2313.451305 567/135
+  821969 7028.002508 -390723 -740767 90/694 708/542 -8565.759491 159727
+  3313.923043 56630 340/431 5286.846432 960/643 -146665 754678 -995178
+  4860.100961 -2961.338242 #x98197e 359065 402/113 392197 -592805 -579179
+  861525 -415754 1270.401191 753.117235 -528765 -9439.365444 426087 #xa0ff1f
+  -9825.309566 230555 -877384 #x2ce6df 316454 989/56 -20457 608672
+  641413 33/164 -6222.823326 -8162.834259 #xd6a60d 1225.689464 -615397 488480
+  495/853 1634.217742 -692190 -684003 275259 -673820 #x2d0eb4 -361181
+  1814.304840 599043 -231579 -1971.892546 -561236 272147 900027 -409230
+  #x6604b6 494/881 344377 -3709.624749 -14821 717/158 721/600 #x9da7bf
+  783152 #x4d8f83 249/807 881188 -335089 700018 -653346 743/775
+  328751 #x387441 -516132 963/523 -464181 -163670 447703 -72955
+  -124381 -6263.337472 -727269 -553493 -999409 -5305.335157 853712 734/459
+  345093 341107 440/275 4882.980690 246/396 -263452 3958.825391 2512.578155
+  9763.964425 3361.182404 180802 933/246 7784.298401 768/722 -4273.231583 548778
+  572462 #x3ef91b #x95ef19 -678916 #x5004df 6491.870511 714802 -497059
+  -553921 -179298 -394797 309486 63174 -579423 266294 -480166
+  876/391 169/509 -270851 -519444 -673788 811/873 #xec024c 483644
+  117549 #x1bf7c5 -5329.110521 -926221 954/888 -4315.084575 #x47a016 #x859363
+  -440466 36251 -713523 601988 -511951 696/104 93369 154/914
+  107835 401/3 3585.233473 3822.660630 488/286 -503402 #xf1a3f7 -616126
+  #xaf9139 938896 579/209 -296960 283595 -960279 222378 3411.246511
+  #x32a7e 6117.686858 #x6f7a3d 213106 -7366.613919 -1906.903180 991/131 -54889
+  548.609626 -6815.774132 -754205 892055 975445 45507 3742.022554 #x84bd8f
+  302538 720744 539601 #xc5c6ce 4146.673713 #x23ea89 -481219 #xc8bc6c
+  -468410 335291 #x63d426 9712.825251 -45092 124/531 113/873 #x570743
+  226/664 158020 -681281 -356538 750084 490380 #x25ebcb -404499
+  -243425 -1143.498917 #x4c8ce3 -7235.834078 -211415 #x59324f 3974.594300 -7395.395758
+  -5625.413668 -243132 #x67a7d5 767134 1228.146246 620/169 -374.564707 -971630
+  #x39e4e8 274/974 -796446 -751280 616867 264/29 674/347 152/876
+  149406 -787483 #xd9b6c3 75.789112 -982816 #x3271ce 6400.271274 164975
+  1756.495158 633830 653475 -403303 176/882 -347960 #x423cc4 -157606
+  -642632 852/876 -2550.562429 -9659.636717 -170709 -4339.118575 #xa294f8 -972557
+  -240395 634503 92363 61/63 908673 #xbb7e28 #x61da2a -763792
+  #x847f8f 168548 952251 9164.447185 184/710 #xe0821 -158066 697/299
+  #xecbf06 246372 #x14b936 5239.193326 815/336 -3942.768519 #x462c0b 172032
+  -892287 87456 150/306 -568935 212788 -19948 -187283 -661243
+  #xb85243 -353996 -8746.580924 -8810.966750 860223 -1610.638385 77204 -683327
+  -500813 -3726.331618 -506008 548480 -5570.219181 794831 96/754 #x521527
+  5679.073947 631/695 218484 254/879 469937 #x4deda9 44199 #xe5669d
+  -5248.064266 -3423.266348 718/550 #x64ad7f 33/89 -892051 -336159 419540
+  -4469.214515 -757461 -557912 861/509 -798851 #x930316 810/890 35/895
+  #x583f02 355/542 542/399 -477000 977/405 660597 392/933 83/444
+  582/833 #xf1f1f4 1767.835518 -384848 -211919 -8841.057693 529704 -716204
+  367664 937270 #x1ef1c5 -3962.738452 165226 -804613 478/305 1844.964990
+  -427.730555 446/423 824/701 #xfdd386 829331 3720.762849 -498688 -7909.248047
+  189.198337 8525.665633 399784 #xe58481 4562.714846 #xedba65 6143.011818 -323575
+  #xbad6f6 -3797.731041 298933 -958994 #x48c0a7 -823063 #xbdfb4c -580590
+  -471993 -913082 781/950 -574153 493884 -985717 474764 404/625
+  545098 825/837 -216516 -844358 -297674 -560750 54813 337497
+  -965200 82867 -839035 150498 462/145 -731766 548423 -1958.800060
+  6731.314930 811.120751 884296 -594771 #xdd7f26 -131771 -642406 616395
+  -101386 522343 87891 891163 -997231 #x111557 8779.074752 -475674
+  -419682 237783 -115833 318/921 395760 548/363 -4.152766 9974.570691
+  -1473.386917 #xb1d6d7 1254.733399 42830 #x421de4 -773038 -449544 732655
+  302543 #x348884 790224 635072 -137893 37216 -857212 #xf2d077
+  #x36d32c -584914 -262916 68/433 399375 964379 348326 8335.871101
+  559016 -745385 303/55 6048.818993 #xef91a 363352 #x2374a -348109
+  -7565.655973 -557076 529624 347621 -875148 -5805.599899 994/836 #x6d582a
+  -900737 -359890 -872043 -3734.543993 222566 -1138.322041 662192 -662924
+  92/788 785570 #xc13afd #x7dcea6 130/16 #xd9a5fe #xe39ac6 571/400
+  637107 579307 #xd4b2cd -600713 428/420 #xb15ab 596187 #x1d4b27
+  661/399 #xf3fdd6 393/984 86537 571147 -228621 288658 -84098
+  -1047.736753 382829 179/221 202187 -165139 298/415 219/395 #x1d7320
+  -377968 674671 419/392 #x96f79a #x85168e 153653 645854 #xa0b40a
+  525/248 -566174 1255.570433 #x4fa396 173758 -605491 981531 7747.658696
+  6391.300594 922902 -571783 9121.489740 607651 -396505 608147 2777.205739
+  918416 7492.436257 #x208e6a #x286d51 9998.377826 786434 #x762069 1195.769409
+  296279 359007 6721.706821 #x35769f 6954.268739 #xd934aa #x439369 #x32819d
+  740/364 -343065 3314.676468 #x47ae94 156674 #x2ab06d -310450 -94129
+  140896 897997 188008 #x38fe41 878.010451 #xa6cee9 110/140 -743137
+  -212606 -760.811892 #xbfd533 384/392 #x3f178e 324444 466578 -6029.284442
+  -519151 741/32 -380311 #xba8f69 -9075.973760 -917087 429029 427/227
+  #x49129b -8655.814924 -363726 #xda1ac0 #xcfd2ea 4627.237866 6681.468664 #xe5b0a0
+  285/501 -106428 318/844 -877411 -4311.410081 -641060 799801 292556
+  #x8eb85d 283829 -698808 -703.312629 #x641faa 718/284 -187546 #x1fe767
+  290409 -11237 -2032.911700 355/176 409482 2039.822455 -1759.248284 9963.919137
+  911/7 795629 327/344 -4620.561194 -978787 #x2d5885 553103 878082
+  62/951 -748231 -466393 841/41 -794926 #x562d73 295598 199652
+  #x76f198 211/957 125/536 -722450 405/916 -167689 -400086 28931
+  9796.696070 -3258.365228 #xe4bc7f -9940.183909 -6116.802091 -5926.045463 764/272 -473372
+  #x5daeee #xd184fb #x176745 -300831 800307 -621855 -184941 -321589
+  141590 -325624 #x140d2f 334837 -412035 327959 -779489 -71654
+  945/11 820/12 #xbc51b3 754263 -35588 370098 445/210 #x6e6b94
+  59/190 -981783 -819674 -444653 257/526 823333 183261 -923493
+  105/989 #x2e52ff 6914.230988 288020 #x4ea271 78/242 -517305 -657028
+  -1754.496952 1012.718505 3145.773438 #x666b5a 274/127 793599 -213144 -588197
+  524334 -797447 -337067 20576 -599563 8270.467548 #x757d85 4577.677130
+  907/197 -630173 -200964 776943 -458418 -106015 -841197 775/488
+  -890796 577808 #xc37705 580/3 128/308 720/3 -646797 -728317
+  258/810 1442.442016 -1226.525907 -650693 -732731 -371415 3527.094324 92/638
+  -825439 -810432 -197294 258/715 1431.146411 -6057.966757 -253370 860/806
+  882/840 #xe66eb1 -424538 -829063 #x517fe6 853788 #xe035a4 -432475
+  689214 #xa4c1fd 494/404 -244691 #x1565b8 -248813 -356280 #x191522
+  746728 7191.990640 821864 112/618 #x493264 6100.433764 -547212 -282571
+  #x9206ae 274257 #x7c4cd0 -52679 548008 -447132 -546004 797068
+  804284 -940516 -708690 -2008.093914 -110773 666/570 733841 339146
+  921094 -788158 -7227.906755 695207 #xffdf20 564846 #x7d0d9a 623923
+  -178652 253005 -2428.965429 #xa43551 196/714 -256867 5097.145511 -144187
+  -245561 -5785.075857 809/231 657782 798861 -697730 -897463 #x89a7bb
+  #x47395a 348731 -743239 358/489 3482.989139 678868 94/92 808/825
+  675635 #xbd0a71 -145044 777405 782769 #xcb121b -489270 #x1b81f4
+  -492060 -980407 358911 -144166 -78803 831812 330/954 7302.419695
+  -6082.404645 3660.438122 325302 469/350 754852 #x418257 -39749 426/665
+  802/160 -635042 #xda1e89 -91583 -303866 -601213 #x689dfe 179013
+  -589110 434762 6447.610834 -94180 913/12 639/706 823/25 #xe08c5a
+  -626467 #x2dd52 7375.691336 674669 931/843 924626 -479928 1624.575046
+  #x8d848e -421428 435/481 31125 2968.723951 #x911140 636/656 -572868
+  -535041 -97391 156/453 -130849 -436380 -339854 -996975 -714906
+  -992382 851/694 -5023.346001 -126292 -658583 -5589.684407 -779071 -365048
+  #xd9471c 925609 -364928 645/578 262282 309/389 -486403 308684
+  27/407 758/965 -5536.654209 344177 -5444.026110 #xafdb50 702666 337/904
+  8118.061826 417903 -4159.027334 516/285 -716517 43/576 #x2805da 82366
+  -4068.698682 8832.443085 -135160 #xaa7004 996791 -423947 #xeb6ce4 #x519bbe
+  8645.537513 9529.692046 #xbf884d #x9cbd88 2011.672540 -3719.667532 866298 385816
+  966700 -728606 405437 363555 547477 #x45c07a #x72df1e 5594.859491
+  #x98d07d -363298 -802725 491/3 -4442.424192 #xb301fc 600058 231044
+  950656 900180 89/583 -6093.115419 684066 #xa639be #xb215b -5969.674117
+  384478 29/35 714/186 450318 364/462 595124 860/103 #xb9cb0c
+  413923 144338 185.372017 -564383 940/599 -690756 -65996 #x48cb13
+  #x52119b 209386 -975554 -521150 228142 51775 -139909 #x8b61a
+  -6665.927180 707/458 6210.555414 621.432583 557095 535856 #xf0b7f6 -4596.898222
+  #x195c3d #x7f5251 #x3811f7 159793 -361508 197574 #x85a61 -1567.267257
+  603/395 -382805 #x3e970b #xb78b6b 266/519 -137240 164802 -979.790793
+  561/428 6774.850057 #x77c3c 423867 871057 224/583 -3649.678779 -960659
+  98763 771586 4763.378846 -8661.549341 605639 939/479 183/992 -539803
+  803446 1574.145443 151/653 -847564 911.772026 -4840.083773 5971.064898 976468
+  277482 727656 #xdb7490 966/672 660860 #xd3722 -1532 992677
+  216856 6652.200849 576/217 #x26ef5b 770492 -406336 929/341 55774
+  -3468.336093 #xbcfbed -489138 -7616.434617 -797574 #xcc58ab 397565 679/43
+  -709040 #xe1218 -241559 -645013 5600.262055 369002 #x6cd1f3 #xaacfbc
+  -283851 #x84472 -114695 #x232153 58/862 973514 #x83ba34 240600
+  8809.997486 -968166 #x803c7f -7095.103047 #x1166e2 720982 725/538 #x6df7a0
+  -2117.535863 #x8b253c -639271 848261 -551346 7918.556176 9350.950633 #x8d07fb
+  -267240 5560.860572 540941 499769 677.208010 -485.190410 251928 166778
+  -9582.532582 421/646 -364300 383066 483/399 619917 789869 2368.695758
+  #xd941fc 4848.462987 816625 -3436.532893 737612 474806 8809.597557 -256738
+  851630 #xcc30a7 369/958 -14944 853163 585891 759270 -160519
+  346182 76.996804 -104567 536840 #x91b5f0 #xf68f32 #x94599d 973/482
+  419069 243/196 -812902 #x383b32 498604 612853 938/300 246.093259
+  -159474 -210536 643/283 -855490 -625497 -661813 #x51b930 1720.482004
+  -27383 848254 -419190 -5963.385911 #x451d91 -426274 627012 -720334
+  128979 -6236.299333 379/4 #x74e517 386431 #xa6225b -331830 438/655
+  -492899 #xe5da2c 52393 817232 -418951 -508004 #xf4d392 222/232
+  -5981.599842 744952 113271 485431 747172 215/721 929282 -200844
+  4444.192211 -208988 235107 -824383 970665 #x1588f6 176869 -991337
+  #x219552 -85.119471 872208 113938 43635 575721 #x8d9858 467076
+  -776060 1500.021460 -4171.948533 #x4dbaee #x2b4e4f -673959 -7855.207395 #x48d7fe
+  -80540 131/934 795657 #x2babbe 999995 -327536 454/177 348/219
+  739827 1491.288536 605259 -2911.992711 -720298 486/693 1102.015426 488452
+  650277 -904205 266/193 283/56 5686.971174 6766.203990 -406372 762368
+  -222196 591/994 235312 -921161 489600 975/175 -880944 3729.267479
+  -605508 517841 542/124 70513 -75271 -43864 44/701 #x67bebc
+  -70323 381754 990377 200320 17/374 -291787 234019 -71977
+  -9270.330936 275136 891/241 8661.266837 -1100.674240 574/99 #x57656b 502347
+  -649889 -991708 -259024 370/80 -676834 164.781717 129/892 6847.680409
+  262/535 -971334 -5274.164908 648394 931261 #x8c682b -648639 #x46ed03
+  9305.305514 #x411bb7 10/277 907/104 -806003 486780 528799 -9915.184172
+  637/30 #xd2529a 117315 651042 #xcb4885 #x123bed 16519 699/797
+  -697840 300/157 -547841 -903018 655/699 -29539 677899 #xb7e458
+  193110 554903 819/584 568/600 800742 3225.346145 788/914 720556
+  #x65428f -2758.085174 -355883 #x482fb 985251 42672 2172 #xd81f43
+  695110 575701 5079.043141 551/852 #x92b130 -152810 39532 392761
+  687921 -7393.793267 -928444 882/654 937395 #x974f2c 971609 -14040
+  458111 #xcced04 #xb229f0 310996 #x6fc5dc -686339 904/146 #x12ec2b
+  58/991 503630 -80508 -170329 837/675 297523 204050 -816349
+  -1392.246803 #xd31bb1 94/546 248/18 458186 -831816 #x38a3fa #x204234
+  4583.227864 359/344 -24958 -945262 #xdecb97 #xfe40dd 303482 -566986
+  95245 -165245 -179681 #x31343c -836366 #x8ec0a9 6464.871895 246324
+  153/171 #xb41525 49557 -914517 506701 #x94d934 -913694 700526
+  130/694 #x7be6b0 251462 -913731 #x7d4d1c #x279895 #x31f661 -970280
+  647/925 #xd2a3cf -298687 -129231 585/540 -921312 235/284 5393.201093
+  176/325 69.624925 -4311.202076 -445306 6413 #x94cad7 951366 971207
+  #xace247 460614 #x23de5b 733/659 165805 -447059 #x370f9e 186853
+  766217 122812 -578256 -334123 663060 #x4e968b 789/69 818679
+  71/155 7188.373312 857/370 430/755 -288502 -626824 990065 410/804
+  -653685 -2641.464740 202/424 #x99c9b4 4081.925221 906496 826/646 404067
+  #x2c2ea8 #x114012 -794596 608879 #xfe3a73 325.774813 166887 909763
+  #xd3b049 -1847 147/247 -9068.447000 -813770 -624018 142968 147335
+  920/677 134679 #x758886 -906511 162368 #xcd2394 468685 541154
+  7593.951268 -4706.946636 754958 872968 220/391 -6367.433712 #x8fa820 763/64
+  #x3b06a0 464386 -2107.223828 -60635 -458136 403355 369309 -269801
+  267/275 -819738 #x693ef0 371/939 -408074 925/192 -936305 76380
+  -90347 634088 71096 1251.869486 -6157.570773 974/984 -3514.102394 -31297
+  3578.375520 -1255.580588 428/758 #x8485b9 -643.278444 339/624 13388 -485502
+  553413 230522 5694.676843 -291121 653/682 230245 #x87c349 #xe82290
+  #x12ed #x42f2d6 546/134 -742041 516/552 #x33a4d5 -602051 987/873
+  -5751.428214 #x145943 368971 119/567 371194 806422 #x81f1bd 984255
+  -5209.590190 601/599 6449.730911 383841 312818 728450 -2316.997990 917203
+  813362 #x4e0e51 756/621 524/167 943555 #x60945d #x174e46 -169424
+  -93513 8620.023514 #xa7260e #x2db5b6 -405927 -960606 24.826374 4116.771163
+  -40953 213/110 616/937 166688 304645 522/192 #x2dc20 -475146
+  66143 1801.468515 787576 -9566.006072 784631 -331001 630235 -143301
+  -974690 550/995 1484.173511 8359.825109 -139596 535/897 756.537306 409/903
+  -573202 543849 439263 -619932 656773 -770503 -379136 367781
+  3485.955209 -567496 -597920 -2298.618461 -421085 157607 409/998 569060
+  3147.127203 #x63c273 476794 149/652 -694311 877/364 666232 #x786502
+  #xd7ad86 #x3de4f0 -114089 271744 -709633 683703 92524 26206
+  535/971 #x2c365f 330499 #x7599f0 -3315.276185 1783.631743 -54188 -803082
+  #x29892f #x663f0f 5590.077257 485119 930694 -946493 #x9f7069 -967.018429
+  #x7e30a7 954473 74540 471/73 188646 #xee1efb #xe9a5f4 574161
+  603120 235/519 337390 #x9d13f0 #x10cce6 863554 716802 107/712
+  -9299.222664 2178.197118 -7421.673451 157/769 #x97e26 -515.754346 #xdda5bb #xfcc5c6
+  #xd1f4f0 411/97 #xc9fe61 -669261 409642 -8990.291064 -27789 #x36e7b1
+  910/640 345/678 #x6fe92 184647 -4923.333873 6256.649296 -375683 -452086
+  778/741 -655951 457347 #x63d567 597/628 -7989.638570 864452 2283.516242
+  751/1 -364847 184/348 -200181 717.599089 187838 126/525 778654
+  57/554 842483 221.272880 330137 -5104 -3396.779407 #xecb42 #x495650
+  933088 -461122 948862 -270637 9533.558456 358650 2635.748625 772/728
+  -43206 -770935 152/142 -8061.182403 591502 3993.798068 -608854 1032.463460
+  -84697 567603 -3559.165137 893828 #xe0f880 929471 95701 -6651.858045
+  #x31634d 960014 #x8a1a38 #x63ed45 #xcd283a -434553 #x7b14c1 -507338
+  544/703 933621 903668 167628 11/893 -667860 #x5c4343 #xc764e9
+  #xf71993 -2054.480695 -105175 -361565 #x89961 906551 -29763 -144241
+  -456696 632305 5241.115409 265/47 721/267 -34204 113.442841 -749326
+  66070 -762793 #x2f825b 7987.298126 783161 785488 -403708 744734
+  158650 -929631 -671535 527419 366930 947714 555/154 550/282
+  -855334 541/57 478130 301/857 #x8e04a1 -709700 74/882 225292
+  699/684 -137357 120/613 -487642 #x35d1e4 -401979 -818092 634.394646
+  101503 -429010 -332768 679473 -226403 -8514.828888 709656 #x4fa91b
+  1920.933196 780739 #x4cbd5b -475060 796271 -713.766928 853351 5580.585840
+  -231321 754/401 -4293.052477 #xae7ab7 -826484 -186713 416141 -450510
+  #x16731e -9983.221342 -288286 #x1d5356 -42235 488271 -849502 -615906
+  -526676 -952570 -677491 -124.971646 307820 #x1c18cd 832/691 741819
+  746/223 #x3c3d32 9582.359520 -549079 632/860 963579 -749185 -972081
+  2451 4900.383039 -500923 691476 133/891 -516101 402/805 91200
+  5499 #xa8762d 673400 #x9483c0 256614 809/288 939421 971984
+  433/308 -632678 #x862805 -896600 -4084.235332 5201.973114 969/619 -674298
+  -958596 425/358 954066 -666112 2867.108542 #xa31403 869103 820221
+  196976 -122128 138003 65706 157/780 547432 847/42 -283959
+  #x48bef5 107807 105308 -756935 24198 3566.200472 #x2547b6 6195.583102
+  58598 -951431 -79382 -56640 245/480 -7353.915597 413/289 -590726
+  666159 -458513 622078 590/879 948/245 843/546 4125.609194 -224516
+  #x4512e6 -659897 532/662 #x95b971 8204.946263 7786.793755 177/703 156466
+  539267 -942643 #x5af885 146/781 71170 292667 #x51acee #xf93cef
+  -809849 -879395 #xe5db00 20274 593609 457704 278/172 -8116.787608
+  #x7ff04e -925496 -8702.939396 -7683.362467 272009 -834756 38684 661187
+  #x4ffff3 -312389 -532863 -213905 351/320 168/298 841461 292/735
+  183/131 -177553 -26488 961374 721/132 -457990 -103181 1120.101873
+  #x35ad55 -767878 5011.722411 697/646 542055 533141 -421780 -16830
+  377902 832838 #x5dc8d3 621/57 7572.969894 677851 1077.347754 980/315
+  -15710 6079.937863 #x765869 779/484 -353141 -8199.843209 #x1810ea 842187
+  -7964.156746 #x3c3203 597/704 -344038 43781 455/620 33/243 -741966
+  596502 -233530 -131521 170470 #x947d35 706/224 -164122 -854010
+  #xdc5b6f 471879 -788805 655645 #x65a074 807209 #x633cd1 -27305
+  272/913 103/97 -615764 -9862.714277 839/584 3913.226123 753944 #xdbe832
+  1530.921175 442068 501764 566722 -270890 -466524 -833970 -805023
+  -616850 -2300.481701 142/350 806473 #x5de932 -618878 579425 -1398.583993
+  321/723 396845 401965 #xa10e95 -179261 2391.437030 9077.784272 6179.661322
+  -211870 -556779 843124 483/819 #xc2ca36 -9619.391843 352/849 287/970
+  4902.023609 -779278 807/948 24/741 6335.345019 -2449.943194 236/813 -904065
+  -784041 881/385 641177 #x7d0ea9 288899 124143 #x90513f -688316
+  454890 -441554 -241638 -504144 -738891 -9031.910002 -9449.543750 #x41d19
+  592/695 2646.406922 4953.521706 -233342 #xc04663 720589 -252602 -457473
+  437/889 -186.223193 #x7d85ca #xaab7 878121 -336545 -633224 #x400627
+  -724978 -319985 296188 260/294 4192.854023 #x24bae4 304/838 887941
+  595/282 #xc60cb -498859 #x486be 5307.855086 -5713.162248 8237.955070 776/383
+  #x7f0756 441246 -324232 76586 -6456.385060 948/29 -965902 -640488
+  #xe6a6a7 626884 532/372 -444797 -398600 7703.821879 9987.528977 926945
+  -721527 933625 -41352 587.848793 61/828 -441950 #x3fc399 -253108
+  -708067 641013 468/635 -1069.811236 -673002 #x8ddb17 971601 578/515
+  -574321 -359029 #x4eab87 687697 368/103 -934354 719/347 128/510
+  -50722 -767793 818/203 455782 608/534 #x20fbdc #xa658c5 9149.516478
+  296996 #x35edd3 -376422 -916306 524/103 751261 -585242 99943
+  #xabb329 #x8cb000 239375 #xa16067 -740139 -327224 1919.478285 30/745
+  297688 470449 -5998.913697 5265.927734 -90549 825/104 #x776eee 381239
+  12417 977177 -2356.053018 598997 -672738 #xe9b630 #xafd9bb 495/501
+  184373 #xe84e0d -606428 #x55921d 370786 #x5c8621 3953.738184 730597
+  730187 481302 109/811 #x63e854 1277.971770 -818071 #x1d9c9 -723669
+  -866557 162.713942 247/671 671529 -200138 893/595 -811312 -167.993438
+  #x764efc 39763 #x2b7cd0 965916 #x6fe2f7 70032 744.765997 -8615.967166
+  #xd138cc -167761 624178 46/205 327689 -5231.867244 -246102 511822
+  -633640 -840241 -961550 751/94 762/584 #x9c0719 -9036.034228 911525
+  773/972 453/209 -6942.243277 -982816 -895560 -587159 166967 #x1ca822
+  -405230 2309.599493 2852.784896 #xb1c73b -786005 -71854 41899 797325
+  #x2f806c 359/331 #xafa71c 612818 #xece599 587/127 806/169 -48462
+  244132 1541.176856 -245.989303 326380 #x4d6164 984120 769305 5314.434540
+  -42328 -875374 543/762 -762301 -692973 -928703 -9868.868274 629/736
+  -505976 314415 #xd47b1e 544112 #x6e4b3e -1443.481420 797974 645/88
+  -791624 4365.593572 #x40029a #x3f01a2 807788 -6573 9149.516661 -219332
+  155/855 224310 577/898 -252462 717/780 196291 #x1380a8 #xb94bed
+  #x5b3c14 -604295 5270.300098 309/497 449831 -5084.378337 -44029 #x104f70
+  1073.243418 473/877 -803071 122417 480738 -1407.286099 351434 909218
+  749/348 7302.365190 997/817 752652 892/949 416577 15/893 #xe04a2c
+  #x19efcd #xd98f 488418 -817595 948591 588973 -662245 -837698
+  572519 3730.092900 #xdb3ed1 918016 -2381.142996 409293 530/85 -952975
+  727/917 -570710 #xa3c1a0 157509 -669272 -48860 9617.425584 95/831
+  -1765.364336 266627 -892325 597723 -139781 808035 -110411 781656
+  88398 #x2c5df8 -5266.987395 5/634 577248 -5242 -5306.750068 #xad7aa7
+  -6873.094943 #x2ec29c -652143 580/464 #xc71073 699/263 558977 #x5636b6
+  852484 566208 558868 380085 -4479.326985 207518 -358976 285432
+  503003 396431 -309640 #xf08ca8 730256 -447918 #x3bbf8b 182/210
+  808/23 38088 119765 6756.056719 #x2a64c2 #xa44b49 716/579 964272
+  -8233.292997 #xabdf24 -7247.415445 150332 797048 #xe5afe0 -108912 990/590
+  #x802200 3738.485912 473100 237/115 183403 -409323 -9948.854741 #x53ad38
+  4769.024631 762316 46776 938/505 #x86ebbf -953036 975/358 552/879
+  946651 #xae5412 283949 -995539 930/905 -873045 -567970 761/724
+  -513790 817096 #x21e5d2 #xff0ca3 571593 -190291 9603.277636 -425107
+  -8502.620645 450/445 769748 9489.727609 -887515 -2457.435700 #x5c0310 -813901
+  73.552669 589988 233.568852 493160 3366.237328 -709952 -248487 #x2a8078
+  -28521 287853 226/481 237435 #x924170 -363268 -498551 -968306
+  -5572.921467 24/885 #x7da643 -451272 -6770.882295 -291882 -98.639596 913438
+  455/242 -381881 -163183 503309 #x478b3d 684/758 152783 #x674173
+  -7759.977624 314367 #xaf1aa0 -316406 -38114 348700 -257970 310543
+  472001 2993.158155 -4870.331484 388/652 -991086 978/489 -8090.074611 -4966.327289
+  7946.060335 236975 -5189.263146 -250469 3833.915082 -867555 22634 625382
+  -825160 -867123 -705720 744182 2343.600923 -754417 357/185 -286774
+  -4029.058188 -527968 422/709 -825958 424431 283974 466/764 74/98
+  #xc7d0c4 612/222 977392 #x6c7bd1 -283149 231731 -84431 469/803
+  2536.868039 -21799 -828509 1049.005107 4243.782773 #xdf7854 -667451 -144631
+  78085 375800 419237 683/886 -6008.113407 26/199 #x3abdf4 469166
+  937685 -275507 #x2f42fe -227847 #xb98b41 405860 #x680c1e 335645
+  9793.241567 -439705 -941441 919/248 121733 274/536 721.242606 337097
+  -441665 -294731 #x284620 317/308 9690.138817 6389.577591 288906 4090.815233
+  300673 932934 -409712 -9043.753891 195/850 -596808 -9411.751417 -937877
+  740594 #xfaf342 407/94 115765 601/171 526569 #x94f805 216133
+  207/633 -9135 -860873 364/157 #x14df59 739/782 -2113.698372 #x6256d1
+  539684 771341 1618.033268 728/941 9/777 7932.685618 787/306 987988
+  713939 700/572 497/445 -666492 -681184 431104 -874151 -494904
+  4208 282.036334 659126 -279024 -8917.919696 #xa0766f 643/749 -689842
+  -177280 -572104 612230 752378 5471.309271 -392690 #xf45539 783872
+  616784 96/122 -962429 461/493 -431574 449784 174198 78/326
+  #x89d057 -2406.018400 573431 8117.298614 310039 153/487 -5563.235717 -3088.495696
+  #x8fe2a5 179496 900/96 252328 2093.334197 #x4ea6a1 -387509 #xa48162
+  416/833 -6013.232316 -483263 -507566 -457.437672 #xd4e438 #xe133c0 -401044
+  337736 320/996 779/296 #x7af14f -746602 -903614 -706672 69025
+  796/831 236/297 716449 -6669.391213 -7472.226169 797601 -816274 972657
+  -337817 317146 -9694.677416 467338 3227.811368 45185 -155827 -226874
+  #x391b99 868/262 #x6557be -861.679463 8376.756928 1549.686536 -206885 5178.207029
+  -213004 738290 673237 -370267 -651.291341 -163783 -2695.799410 -515209
+  #x259fa6 5622.070556 741276 693591 -444188 -603923 -274687 #xbdd60
+  #x3b7fa9 66873 -13203 396086 #xb4ebbf -642743 -572786 -22457
+  569/293 520/783 193448 284886 200354 926985 -323565 -9033.997298
+  943102 -484530 #xa19f0a 893225 46/158 #x56b4e5 373767 -554083
+  -278467 #x7eb592 #x8d3a02 606569 1320.118632 -618797 732/34 -378267
+  326059 543/266 2099.533340 561042 121116 632617 -539993 -172091
+  324206 -564342 -19630 92/587 147050 #x23a9dc 230606 -666350
+  -4901.077705 -292857 #xb499df -787946 -794370 -52057 399275 379453
+  5204.031073 -429062 -318694 685/173 -80773 -454065 #xacad0e -556284
+  -235215 -110308 -804562 231080 -651864 5771.240407 337011 286/660
+  #x9f77d6 -307294 727/462 -136497 -969836 877932 -58719 545251
+  943521 5168.846418 #xf8685 567369 -315931 -246703 935747 #x7eb5aa
+  336077 -8332.719720 #x726d5 -801.864260 193026 -845925 #x53a72f 597533
+  322509 247/589 696714 #x8b2bf7 -984632 -287921 784542 14834
+  797612 8207.314174 76/685 -61955 -750580 -4748.047728 520/178 #xd67d5e
+  395/494 461/470 647168 4/326 #x74d07b -4598 510217 5779.553065
+  488/364 -728678 930143 #x21ff80 2174 -6355.447842 -6083.481730 -154988
+  #xa16f9f #xbdcb93 263526 -537406 #x3cceaa #x81c4ff 29113 547422
+  1638.771567 543783 6295.635510 778832 401/504 -9077.628013 #xdc05c3 904/351
+  -474844 -8361.967012 234/756 421978 -526508 307631 #xc8991a -317482
+  -522007 -97876 #x90c2b1 #x86663 939540 984/940 -132352 #xd8d934
+  7670.092611 931006 698/761 842828 -9273.872231 -696071 715/101 #x81e70a
+  335353 #x72a06f 774/407 #xf5c00c -1725.451706 967/185 -9359.590291 6680.347795
+  #x112cd3 -4654.320451 134417 -5610.172996 -695466 -866377 641717 #xbcdc0d
+  644/457 916013 -201286 41087 113/819 485117 144/463 #x1dfffb
+  #x27076d 796/170 87893 -141458 #xe46486 165/187 #xa1c77 992/654
+  #x824b6a 649140 -767594 7350.936927 -761318 229/927 -334519 394/997
+  -7564.302120 -748741 681486 #xb420b 215/875 279/675 -33792 801519
+  -295744 #xe428b8 356/868 -882889 #xec8164 466353 489092 359/588
+  #xe69a8f -59633 6246.007016 7989.451838 865/658 -4347.636431 729/869 873/477
+  #x5f73eb 3607.963012 #x1acf4c 6694.255998 -2011.026821 -746127 -876320 123036
+  70479 592262 352139 #x296eeb 530161 -7341.891412 243/367 -206581
+  195897 -78636 -618214 #xcde896 -37400 4298.951841 -619767 4609.183361
+  -290641 -3373 588/397 85/324 #x5b7efe 341/900 746795 992171
+  -969464 612324 -7597.164172 2826.116611 250/343 16512 #x2b337c -560998
+  749089 #x13fc4d 400/537 -281946 -910261 167/371 529/46 452/349
+  #x397155 #x6820f7 7185.905462 265232 685352 173333 #x79a3be -563441
+  #xcb40bd -217571 #x798788 175/280 895/422 203/187 -967343 -113943
+  -302588 -692950 5580.133590 860/677 #x8bccaa 121/169 943857 972/620
+  -169.561815 #x949a28 888/410 548140 #x9882e2 426/183 551591 #x7c5ca5
+  197431 #xc4c0a7 9586.904065 996285 613621 171043 5876.958626 -2825.377549
+  #xe944df -29044 -8054.815092 417/33 #xe74c39 -669124 588/15 853712
+  -261865 -538107 -671309 #x12222d 728956 #x2eb95 -8197.596415 560646
+  #xd46478 -229391 187124 -314875 925397 373387 5621.034944 474297
+  -159565 4364.248529 742.747351 467187 #x8e3a2d 991/454 117/915 136.953036
+  #x30bdf1 -1312.963033 -232239 865276 755702 415456 187056 #x5cd279
+  #xbfeed 556281 295/666 901/524 #xfa1456 680073 67/257 #x64dda2
+  #xe0fcb0 -981782 939472 816655 891056 -542254 32462 185/38
+  #xe297a 98661 #x23624c #xa84be3 -736707 -481704 196.201224 206159
+  543106 #xf4c779 539/842 #x502eda -309408 -7738.878895 -446127 -32244
+  -208536 264110 370338 #x98430 30/203 -104021 218997 375/122
+  419/934 7814.933430 #x8f0efc -75959 -976681 #xcb9599 327973 665840
+  5326.542604 813469 5681.300435 -7283.667027 -799259 154922 344/635 -781764
+  -211966 -34866 -819422 -185212 713555 325/603 694/550 4236.860351
+  -273862 -97251 -628860 407/712 -204143 -314883 -712973 448/40
+  7264.258956 -480807 739/74 -437082 #xc7c0f7 898306 -8965.557686 676940
+  809/19 #x6accd1 -895710 -743316 99183 #xe2fdb6 -1734.249151 260548
+  #x11270c 70/591 715578 93639 3921.049693 236/159 #x52c349 381756
+  348491 -123458 328556 128/624 -95241 523/906 -985432 268/882
+  432627 831427 12417 439858 #x64f982 -50581 723151 848335
+  #xdc9003 -6842.220981 -376422 -2073.256887 #x182302 #x2bc624 997/908 #x8214ba
+  -93333 724/388 578816 -580123 175/206 #x1da620 -503247 133/182
+  #xa2f624 3275.151454 276779 866/769 #x673e1a 740/694 429/370 445396
+  -843902 -106721 51/604 119.816317 -916741 -616701 450583 5433.748908
+  -980.046437 #x129fa9 229802 124160 6193.802857 #x431dac 163369 67/707
+  926573 894732 -117647 -353686 2918.063906 691208 -96690 436/711
+  #xd876a5 30796 #xe4c871 500071 -511495 -4576.061071 #x11769 101758
+  -6686.271037 #x5df334 304819 997879 551/860 -5948.893656 -993483 835585
+  694486 -212107 #x882a83 #xd6a97d -2433.962526 545228 2312.698649 -110367
+  722829 394/352 894/812 -754848 #x9314f 691/470 #xcc0514 420/949
+  -3082.084540 9856.290681 -773844 6176.548628 #x2871a9 -680939 #xce961f 62120
+  799/908 #x647bb8 -372875 -327621 -524657 281216 #x50b32a -409918
+  #xec8d0c -837651 5977.364270 309/182 939/103 #x88508b -62.180175 1552.340158
+  765969 -337.843026 #x25f79a 545/925 -381615 #xa352f2 646677 640181
+  #x2c6b0f 345150 753/42 655789 540100 -839932 #x99944c #xd88d08
+  -810732 369461 -609885 -5669.713851 -722843 #x818752 449/857 861/248
+  931/807 1496.282557 709/628 297919 #x311a97 758643 #xc7b4a0 958/198
+  889/181 #x333e6f 30057 687068 218990 352037 242/372 938/250
+  -861161 256330 542/565 767/39 8837.148705 -797537 210520 1382.344377
+  384027 -483127 584/822 -677119 651/709 -963706 #x34c7d8 -6168.592792
+  #xff948b 3659 652.118362 -814515 #x117a85 529/95 -567732 249180
+  746424 #xac950 346901 471/532 958079 6493.971735 828262 257/159
+  #x6fe7c3 741733 -842575 329571 658509 -7923.363775 476/575 #x64724e
+  114/477 -198918 602/23 -760668 -814274 -8193.974309 -5667 530/886
+  805.189623 183284 -603435 766596 107/382 -785522 #x8caa31 -460266
+  -689484 495/181 -4919 700/807 395323 -8415.782351 #xb88981 -621045
+  #xebe825 #x95e89c #xdc9d3a 715/512 428/929 284/994 58410 -280947
+  6300.843389 418184 418/125 9641 -8157.211412 -895302 117/439 219/290
+  -79947 530/526 225136 845303 #x1dab79 266223 902/180 615799
+  9633.226204 458.343489 #xdf8878 -2907.458215 -715424 601/106 #xfd37a4 136/137
+  #xe2bec4 501343 #xad812b #x582523 -547531 -431.103543 -536025 -798239
+  -731247 #xaa54f6 720884 128624 -4593.785153 #x7914ae 6408.922787 -611231
+  466/996 -273916 -7768.114322 -64310 74/881 263/380 -755408 179863
+  -910620 #x9ebd3c 553859 602/136 -334407 -691476 543702 -457893
+  268161 -336625 286849 618463 -810024 #xc39f1a #xcc936c 532/660
+  #xd5e1d1 931641 -900809 #x87e660 -7545.994311 -746020 206133 9704.703167
+  -2497.991204 9614.038133 473897 -914694 -5047.806400 982/822 -359789 #xe405b4
+  -434.604813 6599.711446 -550797 454105 -536796 #xd5466f -590564 360635
+  -267006 -663425 -8718.925391 504104 69956 -273905 -983963 645/796
+  955.939011 #xaa04e1 -131847 -7425.505180 320/146 989/397 -995333 -1411.753136
+  #x9c355e 780517 458154 -886054 139.640334 -164632 -60625 76147
+  -9505.200802 #xe965a0 -197170 -786076 377/364 963476 896/680 369404
+  6963.735017 -954239 #x6c31ff 436665 309790 56/403 488/789 -793085
+  9649.825382 149/91 -191169 -591176 332742 3362.542060 -914005 465814
+  587/611 #x17664d #xd9828c -301891 389561 -493092 105/132 -6366.410679
+  #x9ecdb3 617974 528681 #xefd98b 562/833 -70444 -906521 9503.277867
+  -134606 #x488bb6 52/328 7124.103008 367187 -972503 449/928 786314
+  -656613 688/166 #x206b80 -909583 #x1b8c1d 740796 339/232 #xd23729
+  #x986186 554/767 232/637 355/335 3420.330683 392356 #x82fd63 35/203
+  508129 987883 #xa82e66 424.018914 758376 634318 210/297 #x592b9f
+  #x4587fe #x89a6c2 -2605.943744 8064.512746 -5161.166454 #xcb675a -238160 327/376
+  -226378 -123447 653/278 680/207 -503109 #x528162 #x90b26d 254520
+  -960341 58/882 #x2118c7 #x4d0c5b -966922 569988 943162 8677.260543
+  #x91354a 9173.463470 -745491 879944 187.370672 -524788 345836 #x6362f9
+  6349.966930 #xecdc91 #x324f51 -520278 -1248.208599 972442 698658 -3596.447951
+  #x85e3b9 790953 #xbd0bd3 552543 -664816 3311.639039 149807 -8352.392297
+  -6693.435574 -816608 253216 244780 -151156 #xdf7af6 -674498 66226
+  401919 872/241 214414 -242146 -1019.547569 -293410 279274 #xb30d06
+  664499 80221 #xa06441 645/553 -856121 593779 385125 -623084
+  -6577.577045 734300 91757 -3855.873271 -911140 #x4b2e99 -552085 -2435.662512
+  1780.229420 -368061 #x605502 5728.732554 761799 -3737.310986 -270295 4551.376936
+  838346 3335.053036 498907 295511 605/783 -630398 -5186.316129 -891806
+  #x2e5639 839/60 -6043.710427 468/936 447/621 696/775 865693 2787.219749
+  #x7dcd1d #x3f3cbb 4749.534185 952687 -310172 #x3fe75f -5406 272520
+  #xf54d97 600/508 #x710bb7 412/220 -371628 654/747 -999176 317/977
+  #xcf596c 970074 712663 491/437 -4734.731185 -850401 622/130 #x220ccd
+  825172 887/854 #xf5078e 181034 -421749 9983.045124 -876187 913588
+  515726 -450501 996572 #xfa37fb 6773.619809 #x446802 -4242.395984 #xb96b71
+  -607453 341937 166269 152/625 -902075 860924 840163 549899
+  -763684 491461 -201917 -868629 19730 -707383 543/917 445757
+  784/324 878502 783/435 8912.836317 9.613661 #xab8229 #x83164f -557389
+  -353521 -665834 #x75add9 853/11 136619 -8221.407081 -697879 -159064
+  39933 164567 406/191 #x39e05 907.720046 777/394 53677 -4797.602252
+  298245 -143403 #xd512da 545432 617204 -92024 -3280.868769 -576503
+  -671944 194/927 #x45f3ca 8265.935580 -267961 187318 5629.880993 517/976
+  460/5 6059.251453 442/67 -243097 742261 -574449 -508488 846/944
+  432516 -136385 -4790.501090 -1118.395141 1581.945369 -569778 180696 -450119
+  #xb022aa 3184.046597 -2734.730703 115729 926/384 9596.109748 29603 #xb6f3ca
+  -9965.797978 #x87bf95 3032.678649 398165 348/569 -153553 #x53cccf 574/565
+  -233637 #x251b49 269045 -96929 676938 9002.316025 889711 -637505
+  #x1c7da0 -4877.146176 -501215 -250498 #x113025 -414187 8752.194588 #xb83650
+  -892347 -7085.497585 240/429 #x1f4b5f -5725.499250 637/982 -5644.231889 398134
+  -9798.753934 933040 847/183 153244 231363 126/166 852430 -556859
+  -6496.441204 395039 605846 767280 -435078 -161913 695/161 1204.532932
+  -234091 3858 574523 883242 829788 209484 168019 130/866
+  -173532 66593 -838486 -454371 40407 -998505 #x4210ef 3078.411470
+  289/482 -78292 #x78d1e7 274971 782/722 #x7b0595 8626.406232 820552
+  917786 -474278 -802 809221 -165923 -968711 -241843 277814
+  -913965 #xd1210 612462 6237.939717 304/579 559061 -444163 -850564
+  -698760 171502 927315 #xaf8ef5 1751.052932 -691449 739228 #x55d1c6
+  -406196 #x5c5659 269/337 48790 -8169.946044 562518 -7031.420027 916055
+  726/403 #x313c63 652111 -954434 260683 608/762 #xa9f289 699569
+  36844 #x9eb42d 930.524109 286773 252676 -205886 -850640 798590
+  1/940 -637975 944/405 #x9f7b47 #x7514a1 -957047 -671638 311/84
+  -484478 #xa539ad 5810.799213 -3501.745568 -25798 784/354 -9676.344172 #x96df44
+  -810810 -864886 896/647 -154238 #x425de5 -224735 #x3b41a2 9939.569306
+  147/856 #x1e1a2b 1459.807233 -199007 4707.540957 402806 -493938 635603
+  #x124e6e 68035 273247 -541997 778714 757/481 -4171.422855 2385.615189
+  #xc50104 625013 -177798 1205.617980 -734815 115167 -163969 151907
+  2336.799600 768828 661.066209 -816819 -26183 -937888 370434 -502013
+  982350 -6829.567006 #x1ff4a4 -41431 504763 #x4401fe 650059 -148026
+  -2035.654069 311952 649366 #x9610d0 6605.580276 864639 968256 999969
+  326207 385/479 837/137 -765253 44267 #xc31469 686198 394047
+  #x1e01c4 -755936 -9702.292737 43/25 8888.565164 -851273 639/737 #x522e55
+  1101.332244 632/497 -181380 9808.912545 943/986 1629 -5247.385753 767/416
+  #x11c5f8 654798 #xa8cdb4 #x8e3b9a 3266.682898 944807 -2739.318382 -218980
+  768204 -395102 532/560 -9580.504699 301006 6692.443324 #xb4e99d #xe90285
+  -314128 -44830 273/249 -535291 -398157 #x6ff36b 332345 #xffe4fa
+  935/244 3114.030528 -6835.851922 #xa62508 2302.913064 421780 #x379170 -3607.969321
+  -43961 780/823 1000 -471770 974405 562.939598 472542 #xb0dd92
+  #x6a6ed3 5316.882393 965653 151/588 7378.794219 #x197eb -400010 #x55c88f
+  903071 544357 -543138 -632891 214/884 383800 9831.867416 -972577
+  #x4f44f8 749865 851241 -455658 -794158 -54916 728/900 #xc6a4a3
+  353652 301/384 -593972 965226 279206 203217 #x93a4f2 581653
+  846/517 #x878b0e 2936.837224 54104 653/944 572824 -3885.284997 -182397
+  183/765 -590078 -2238.979680 6315.929504 -527435 -396065 -888623 -574641
+  768/375 2213.176572 931/618 246945 543213 546750 #xae78fb 56675
+  #x6d257c 509.282578 820621 -655782 #x5660c4 543078 756/153 900703
+  #x4199b6 21709 -997782 510430 380125 -962478 -1885.562999 -143056
+  97084 779/746 -523485 #xb3a5de 547839 750689 #x96a1da 223351
+  241724 215119 604767 75/564 -4803.940552 -639943 169263 178739
+  -763.161349 143349 -846134 618426 -122616 8657.450705 #xb65958 -824450
+  -755617 -202148 187188 593/109 -535127 -7553.127676 870304 980259
+  #xf4f12a 30/334 4992.811648 838918 -605384 416/644 848576 4811.765767
+  1/900 131/596 75/460 438356 #x789217 750/142 950139 -366140
+  569/146 1374.804909 617/385 964/776 -244802 953445 743282 -89124
+  -52364 -459614 281705 3506.854932 182/701 380507 702996 -1766.270628
+  6980.027962 249724 -905260 -293.854750 845878 299/563 #x2b0cb9 353153
+  -890523 -560109 226175 182587 665/136 -992514 -527560 821/331
+  716607 -602246 123466 -139865 226/175 7002.276400 3238 -217535
+  771330 -402333 573990 -6397.366506 763250 -840558 263/949 #x20af52
+  306267 -4333.756996 656279 -9845.690692 #xada6b3 -331025 665278 #xe2e6d4
+  -472535 9366.513600 -8137.988403 -6311.102101 #x277e3d 986/369 353854 -897603
+  #xa40dd0 807/902 766716 868/264 -208291 #xafe263 81080 706302
+  83858 -737868 388640 847446 590/82 910700 486/457 9131.150649
+  #x6034ed #xd85461 967240 #xf37b03 #x809491 136441 #xcdf267 720517
+  -868120 -32017 #x48b4e9 #x9f051f 196/77 42/339 -26830 -570695
+  49/273 106248 #xbbf901 -812664 40132 -742906 3466.052468 595397
+  661035 -162975 #x430b51 -2966.812743 -780744 44386 994/89 -17579
+  -915397 717119 2163.013246 #xd55f0 295/633 -6589.004167 #x49d741 -653766
+  813512 #x80417e -403891 -521994 #x6ff30c -537754 280/773 433894
+  695449 #x3aa043 -555906 5985.140113 74745 #x2afb3e 207209 248/434
+  -666.165161 243643 629155 873052 -983962 -136134 400568 #x3f9cc
+  -709500 #x34bcb8 -356039 #x23c75a #x3b1a5 774/787 833614 703768
+  425460 542/111 748582 357444 -14033 1714.905187 #xd620f5 546666
+  -605.096332 511200 9572.292774 -931602 #x619860 351/523 2595.078516 -989976
+  513719 592672 991271 86/843 -796037 42172 923781 -910895
+  -5745.517089 9319.929569 3612.481942 657931 361/533 #xed99f0 328337 -423429
+  -40083 -196069 #x5ba84a -5176.773136 8758.819106 -148.699128 #xe22d18 514/6
+  #xf26f54 4815.620448 -4535.787708 845413 39497 9200.776603 795524 331/884
+  256849 -558916 #xb40199 105043 780/157 #x527644 #x2e46af 109/784
+  697/217 -5859.157130 -750899 886969 999/513 549/22 -337265 #x5295f0
+  -413888 -9641.084844 -299216 838730 579696 2001.598301 -904431 #xb8f9ad
+  763352 -239673 -79213 -790431 606/582 712662 -687379 717/33
+  -8140.250413 845/831 -7330.114709 505794 -873953 -1949.343664 566/803 -8174.130040
+  -66185 175831 689/573 3683.629756 -332828 -799299 -764994 985/146
+  -770165 -6550.565587 -185321 -7685.503599 #xb36cdb -8612.202215 591097 -420468
+  -3344.177107 407535 #xa770fd 989/977 798118 -412.669413 -436189 45462
+  -194756 -862473 -117418 -5170.514041 5086.229631 -182254 945991 -187949
+  -853529 -613361 347871 632/410 -479636 -499529 -148439 246328
+  -6373.121179 412776 588613 535241 -56456 455/100 2095.530836 899768
+  111669 798293 -391734 674/250 -532939 -706215 -301245 -955410
+  #x7593c9 #x2dedc4 388/748 #xaa7312 138633 -1411.056505 564018 #x68b8b6
+  439847 -235782 3088.701491 398627 #x312e36 #x32bf30 #x13b9c7 -853608
+  389/481 637209 -780209 458/312 687/917 -275202 #xf6540d 937308
+  #x2e6311 256/296 7040.571539 531447 3302.601201 525008 986881 80/59
+  797499 938446 #x38e1f2 #x536cbb 303093 #x5ad71c 264/801 #x98776e
+  656/508 #xaa8c48 157054 624795 202074 -347942 4469.939986 724864
+  #x434cf0 180909 281703 874398 #x86d979 -135998 #x87356f -1985.055967
+  872096 900351 659637 -783191 -951598 -9050.967452 #xa01a86 730481
+  6806.331289 -6903.081539 251/109 -336779 33178 #x1c40cd -1285 770/997
+  #x7576f8 -140373 555/750 391/146 87/727 1704.507966 -40974 #x234965
+  645467 243/256 952/466 -8624.737404 -359796 #x79571f 93/966 99/629
+  426142 816354 -4648.831017 -2168.685715 226852 354/553 -492805 406497
+  665679 341/774 #x905a81 531105 -128411 -1570.721450 -698448 188609
+  #xa18b9 8457.929106 -667883 -365264 64/172 #x501e8a 8115.515793 #x3ab563
+  5338.795278 109500 375379 944563 712/2 959154 5645.770870 780817
+  324/654 #x5b4ef9 1276.823745 772976 123343 -741099 439/937 631292
+  886671 #x68b8af 488312 -198312 3718.028950 #xa9b99c -210634 490371
+  2307.540826 #xc3594c #xb6b489 424418 -261957 361503 #x584224 1333.624932
+  559884 583875 474/570 #x6edf1a 584502 732491 -437047 797/761
+  814567 -801859 1/297 -7521 415/396 131197 5488.013115 -581163
+  #xce39ea 846524 #x9a802a -961.286732 -352981 -6321.986113 -314483 290184
+  -608841 48618 -896864 311830 -1555.655630 498/956 79/129 -9505.978552
+  #xe22006 #xe8bc8e -918000 698652 -606367 -6604.361641 -90629 175/14
+  4804.136569 -4173.740797 -1156.210495 #xf172f2 -885726 -7356.360959 -310089 -389.050528
+  682.620885 -911779 7456.144687 #x5d5a25 -178866 -258680 953/44 -3791.847865
+  265546 740594 -7939.954319 #x489006 915/772 435/726 -2637.573280 -414.002291
+  324/804 #xb204bd -9161.142861 #x86a23f #xd6b7c2 -463651 -9115.452574 -448775
+  385196 -5067.531160 -784810 -89395 2/46 -514991 59515 -162645
+  -1583.515960 378/435 #x551222 -651354 249880 400798 828/736 #x366c21
+  151/617 834469 134984 663006 531/927 804/514 -7103.761858 -688351
+  254/935 282437 -561045 #x51737a 453/259 -645669 821803 #x393bc4
+  #x704d3b 384/372 #xeaccc6 -330506 86670 -549373 236/531 -908972
+  -591830 82110 346/805 808/817 909136 #xd60739 854987 673725
+  #xad7dc0 604/316 427/693 -364176 -111805 #xfbbbb4 6281.197482 894205
+  -472056 3015.171965 -67815 40/637 -9067.798919 -913725 #x39ec69 361/763
+  -978890 276326 540/706 -640292 -976566 -9967.329766 #xa1cf3a 5638.474467
+  722/392 -554278 -488391 266568 -173214 591842 -7928.302068 13556
+  -689683 -486897 138/607 -89961 #xbbe11a 993369 #x298fd3 176/673
+  332100 -3404.650069 -62000 215/58 848/230 #x7c2d5c #x16c9f2 108/965
+  -632616 787749 333605 649243 283/258 660385 478/975 -9547.562860
+  443/976 -5709.738567 26822 6774.813381 -3578.134081 -550423 -7422.884695 699/788
+  837222 565732 966412 26421 #x59af8c 496/455 #xae6a25 #xa7612b
+  743071 952/666 101380 #xa32f44 775265 -2571.760345 867089 892410)
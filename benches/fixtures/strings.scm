@@ -0,0 +1,3002 @@
+; Lots of string literals, with escapes, for benchmarking the lexer and
+; reader.
+(define s0 "dog lambda jumps\t\x41;")
+(define s1 "scheme brown quick café ünïcödé scheme")
+(define s2 "the the jumps lazy ünïcödé the café the\n")
+(define s3 "λ café jumps the lazy jumps lambda string\n")
+(define s4 "\"port dog dog λ brown port dog\"")
+(define s5 "quick λ scheme café\t\x41;")
+(define s6 "λ the port lambda café lazy\n")
+(define s7 "over lambda")
+(define s8 "quick brown jumps jumps scheme quick dog λ\t\x41;")
+(define s9 "the over jumps")
+(define s10 "λ brown string port lazy fox dog port lazy dog")
+(define s11 "string ünïcödé string ünïcödé jumps string port scheme")
+(define s12 "dog ünïcödé fox λ jumps λ\n")
+(define s13 "\"scheme λ dog\"")
+(define s14 "quick string the lazy quick lambda the λ the brown")
+(define s15 "\"λ scheme fox string lazy lambda fox\"")
+(define s16 "over port brown café")
+(define s17 "jumps fox jumps lazy lambda\n")
+(define s18 "café ünïcödé jumps fox quick")
+(define s19 "brown lazy jumps jumps fox lazy port lambda ünïcödé quick")
+(define s20 "\"quick café λ dog brown café over quick brown string scheme\"")
+(define s21 "port fox port")
+(define s22 "dog λ lazy lazy lambda jumps jumps")
+(define s23 "over quick scheme scheme ünïcödé string string café scheme port brown\n")
+(define s24 "brown jumps fox dog quick fox")
+(define s25 "fox brown ünïcödé jumps ünïcödé lambda")
+(define s26 "over the ünïcödé brown scheme over lazy lambda")
+(define s27 "ünïcödé quick brown λ port the jumps ünïcödé lambda brown lazy")
+(define s28 "brown string fox ünïcödé")
+(define s29 "dog ünïcödé string string scheme\t\x41;")
+(define s30 "dog lazy string jumps ünïcödé")
+(define s31 "lazy fox")
+(define s32 "scheme λ quick ünïcödé scheme\t\x41;")
+(define s33 "λ over lazy fox\t\x41;")
+(define s34 "\"brown brown\"")
+(define s35 "the brown\t\x41;")
+(define s36 "the jumps the jumps λ lazy café\t\x41;")
+(define s37 "λ string brown")
+(define s38 "string λ brown\n")
+(define s39 "quick café λ jumps jumps brown dog scheme the fox")
+(define s40 "scheme quick over quick\n")
+(define s41 "\"jumps string ünïcödé lambda\"")
+(define s42 "over scheme fox café dog lazy dog café the\n")
+(define s43 "the brown lazy fox port dog fox λ")
+(define s44 "lambda port quick the port scheme\t\x41;")
+(define s45 "ünïcödé port lambda jumps port the port lambda")
+(define s46 "\"port λ the jumps port\"")
+(define s47 "ünïcödé jumps dog λ lambda\n")
+(define s48 "fox the jumps the\n")
+(define s49 "ünïcödé quick port brown ünïcödé ünïcödé fox lambda")
+(define s50 "jumps ünïcödé lazy\t\x41;")
+(define s51 "\"lambda scheme lazy ünïcödé the\"")
+(define s52 "fox jumps scheme\t\x41;")
+(define s53 "lambda dog string λ port jumps")
+(define s54 "scheme quick\n")
+(define s55 "ünïcödé over\n")
+(define s56 "lazy dog ünïcödé ünïcödé scheme dog the the ünïcödé string\t\x41;")
+(define s57 "fox ünïcödé lazy λ over lazy café café fox port café")
+(define s58 "\"the café lazy\"")
+(define s59 "jumps café café ünïcödé lambda brown\t\x41;")
+(define s60 "dog string ünïcödé quick string fox the dog")
+(define s61 "the brown fox port ünïcödé lazy")
+(define s62 "string lazy over café fox")
+(define s63 "brown ünïcödé brown fox ünïcödé port string\n")
+(define s64 "lambda port string lazy brown lazy port string over brown fox")
+(define s65 "brown brown")
+(define s66 "\"quick café the scheme over lambda ünïcödé lambda\"")
+(define s67 "the ünïcödé λ λ quick scheme lazy quick string\t\x41;")
+(define s68 "brown scheme ünïcödé café λ the over ünïcödé\t\x41;")
+(define s69 "jumps the jumps the quick scheme\n")
+(define s70 "café fox quick ünïcödé café fox the\t\x41;")
+(define s71 "scheme lazy λ jumps scheme scheme string")
+(define s72 "ünïcödé ünïcödé port λ fox ünïcödé fox over lazy")
+(define s73 "brown the café the fox over string\t\x41;")
+(define s74 "\"lazy café lambda\"")
+(define s75 "jumps fox\t\x41;")
+(define s76 "string the lazy quick dog quick string λ lambda quick quick")
+(define s77 "scheme quick ünïcödé café port lambda lazy")
+(define s78 "the brown lazy brown dog dog λ\t\x41;")
+(define s79 "\"dog λ scheme\"")
+(define s80 "brown café port λ fox ünïcödé ünïcödé brown scheme scheme port")
+(define s81 "port fox\n")
+(define s82 "\"the ünïcödé lambda ünïcödé lazy ünïcödé\"")
+(define s83 "λ jumps café lambda over string λ")
+(define s84 "quick lambda fox scheme port lambda string string fox port")
+(define s85 "port lazy string scheme λ quick dog\t\x41;")
+(define s86 "fox dog the café\t\x41;")
+(define s87 "\"quick quick scheme scheme scheme jumps ünïcödé\"")
+(define s88 "fox lazy λ brown λ ünïcödé jumps fox fox")
+(define s89 "café brown lazy scheme café fox")
+(define s90 "fox the port lambda the scheme lazy over lazy λ")
+(define s91 "\"café jumps over\"")
+(define s92 "over lambda café λ jumps the café brown fox\n")
+(define s93 "\"ünïcödé fox jumps string over the string dog\"")
+(define s94 "lazy café port port lazy the λ the λ café dog")
+(define s95 "the quick fox\t\x41;")
+(define s96 "café jumps port café quick string fox lazy dog café fox\t\x41;")
+(define s97 "lambda quick lazy quick scheme lambda jumps\t\x41;")
+(define s98 "over quick lambda brown ünïcödé")
+(define s99 "ünïcödé brown the lazy fox\n")
+(define s100 "lambda scheme port string fox")
+(define s101 "brown λ quick λ quick ünïcödé café brown")
+(define s102 "over λ string string over fox\n")
+(define s103 "λ café lazy scheme dog scheme the\t\x41;")
+(define s104 "port jumps dog fox port the quick jumps")
+(define s105 "\"café dog scheme café fox brown\"")
+(define s106 "lambda λ jumps the over string the café ünïcödé scheme brown\t\x41;")
+(define s107 "café brown brown over brown")
+(define s108 "\"quick the dog lambda fox jumps dog\"")
+(define s109 "string ünïcödé the")
+(define s110 "port over string café port café dog ünïcödé")
+(define s111 "café brown the ünïcödé ünïcödé the scheme quick quick ünïcödé jumps")
+(define s112 "over scheme lambda quick scheme over jumps ünïcödé fox quick\n")
+(define s113 "λ jumps fox lambda quick")
+(define s114 "lambda lazy scheme café port café over brown quick lazy lambda")
+(define s115 "lazy lazy lambda jumps café λ fox")
+(define s116 "fox brown λ quick over\t\x41;")
+(define s117 "brown port dog lambda café\n")
+(define s118 "the port lazy over the string lambda brown the quick λ")
+(define s119 "dog fox ünïcödé the string dog port")
+(define s120 "quick string port over scheme ünïcödé fox")
+(define s121 "the the λ ünïcödé fox scheme\t\x41;")
+(define s122 "string string lazy brown fox λ quick the brown string port\n")
+(define s123 "the ünïcödé café\t\x41;")
+(define s124 "over lazy fox string ünïcödé lazy string fox dog over port")
+(define s125 "ünïcödé lazy quick brown λ quick scheme λ fox café lambda")
+(define s126 "λ ünïcödé café\n")
+(define s127 "lambda jumps café λ")
+(define s128 "dog jumps")
+(define s129 "lambda λ string fox quick dog lazy jumps")
+(define s130 "café lambda string fox jumps string port port fox")
+(define s131 "quick dog\n")
+(define s132 "\"quick over the jumps\"")
+(define s133 "over dog quick jumps port\t\x41;")
+(define s134 "café café ünïcödé fox lazy ünïcödé lambda ünïcödé\n")
+(define s135 "\"lazy lazy λ\"")
+(define s136 "\"jumps ünïcödé over scheme port quick over jumps lambda the\"")
+(define s137 "\"lambda brown café\"")
+(define s138 "fox string quick the brown λ ünïcödé over the λ the\t\x41;")
+(define s139 "\"the lazy the\"")
+(define s140 "\"scheme café jumps café\"")
+(define s141 "quick lambda string port dog quick\t\x41;")
+(define s142 "brown café fox quick string port")
+(define s143 "string lambda scheme fox fox scheme string café the jumps over")
+(define s144 "lazy λ fox port brown lazy scheme\n")
+(define s145 "fox dog λ the string brown lambda ünïcödé")
+(define s146 "fox over lambda λ dog brown port λ scheme dog\t\x41;")
+(define s147 "brown port ünïcödé the")
+(define s148 "λ λ café lambda jumps port dog string string\t\x41;")
+(define s149 "brown λ brown jumps ünïcödé ünïcödé scheme over scheme dog ünïcödé")
+(define s150 "café port lambda dog brown port port scheme over")
+(define s151 "string dog jumps scheme fox fox lazy scheme scheme scheme jumps\n")
+(define s152 "port ünïcödé lazy λ the\n")
+(define s153 "port ünïcödé ünïcödé λ dog port scheme lambda string jumps")
+(define s154 "\"the jumps lambda over string port lazy dog\"")
+(define s155 "quick quick brown lazy lazy quick brown quick")
+(define s156 "quick string lambda café the λ")
+(define s157 "ünïcödé café\t\x41;")
+(define s158 "ünïcödé fox jumps")
+(define s159 "\"jumps dog scheme scheme jumps λ brown jumps the over\"")
+(define s160 "string jumps")
+(define s161 "\"dog lazy brown quick jumps port the ünïcödé café\"")
+(define s162 "λ jumps dog")
+(define s163 "jumps λ lazy quick café fox string lambda string over brown")
+(define s164 "over ünïcödé")
+(define s165 "λ scheme lazy")
+(define s166 "\"café brown the over over lambda fox\"")
+(define s167 "the brown lambda\t\x41;")
+(define s168 "jumps λ fox lazy over the λ lambda")
+(define s169 "lazy scheme fox\t\x41;")
+(define s170 "port jumps string café dog café\t\x41;")
+(define s171 "café fox\n")
+(define s172 "\"ünïcödé café port port fox λ lambda port jumps\"")
+(define s173 "quick scheme port port string λ port lazy fox lazy\t\x41;")
+(define s174 "quick lambda lambda brown dog over café jumps")
+(define s175 "quick dog over lambda over\t\x41;")
+(define s176 "brown scheme lazy")
+(define s177 "jumps ünïcödé λ scheme the brown fox café\n")
+(define s178 "lambda string the quick lambda dog quick port quick\t\x41;")
+(define s179 "fox lambda lambda over port quick port café")
+(define s180 "port quick café over ünïcödé lambda fox lazy\t\x41;")
+(define s181 "fox quick café string quick scheme string\n")
+(define s182 "\"ünïcödé ünïcödé lazy lambda fox over λ the lazy\"")
+(define s183 "brown ünïcödé the fox brown")
+(define s184 "dog λ scheme the dog")
+(define s185 "dog the")
+(define s186 "lazy café the fox λ\t\x41;")
+(define s187 "\"over over lambda café lazy\"")
+(define s188 "brown scheme café fox lazy quick ünïcödé café the port lambda\n")
+(define s189 "fox scheme λ brown over port quick string port")
+(define s190 "dog string quick ünïcödé jumps\n")
+(define s191 "lazy string the over scheme\t\x41;")
+(define s192 "\"café the port λ café jumps lazy lambda string over port\"")
+(define s193 "ünïcödé string port\n")
+(define s194 "café over lazy fox λ jumps café jumps λ\t\x41;")
+(define s195 "over port quick lambda lambda jumps quick the over\t\x41;")
+(define s196 "fox jumps café quick fox λ lambda over\n")
+(define s197 "brown lambda jumps ünïcödé fox")
+(define s198 "λ the over the jumps lambda ünïcödé brown string over quick")
+(define s199 "over fox over\n")
+(define s200 "the port port dog dog fox over jumps lazy lazy\n")
+(define s201 "ünïcödé λ port string scheme\n")
+(define s202 "jumps scheme jumps string lambda jumps")
+(define s203 "scheme scheme ünïcödé λ")
+(define s204 "jumps quick the port lazy\t\x41;")
+(define s205 "scheme lazy lazy port")
+(define s206 "quick over the lazy lazy fox over over dog over lambda")
+(define s207 "lazy scheme lambda scheme port\t\x41;")
+(define s208 "dog ünïcödé the lambda dog quick\t\x41;")
+(define s209 "scheme jumps jumps café dog\n")
+(define s210 "string fox the café")
+(define s211 "jumps λ")
+(define s212 "\"brown dog brown jumps the ünïcödé scheme scheme quick scheme lambda\"")
+(define s213 "lambda over dog\t\x41;")
+(define s214 "café fox jumps scheme scheme the λ quick\n")
+(define s215 "\"string the the dog brown string ünïcödé\"")
+(define s216 "ünïcödé quick\n")
+(define s217 "lazy λ quick quick λ port the scheme port lambda λ\t\x41;")
+(define s218 "fox lazy café dog quick ünïcödé")
+(define s219 "\"quick jumps λ lazy ünïcödé the the port port\"")
+(define s220 "lambda quick lambda quick over quick lazy")
+(define s221 "lambda dog λ jumps over jumps λ λ fox scheme\t\x41;")
+(define s222 "dog port port over port string dog dog the jumps")
+(define s223 "quick ünïcödé the jumps ünïcödé")
+(define s224 "\"dog λ brown fox quick\"")
+(define s225 "lazy ünïcödé scheme lazy scheme port λ lazy lambda over café\n")
+(define s226 "lazy lazy dog string λ scheme port")
+(define s227 "scheme ünïcödé jumps port λ dog scheme dog jumps port\t\x41;")
+(define s228 "quick jumps scheme port λ café")
+(define s229 "string jumps λ jumps lazy ünïcödé ünïcödé jumps lambda lambda lambda")
+(define s230 "\"over string over the\"")
+(define s231 "\"lazy scheme fox scheme quick fox fox\"")
+(define s232 "café ünïcödé scheme ünïcödé\t\x41;")
+(define s233 "port port\n")
+(define s234 "jumps the string string")
+(define s235 "port the dog λ λ lazy the brown fox\n")
+(define s236 "scheme lazy quick jumps lazy scheme lambda fox brown\n")
+(define s237 "\"brown string λ port over over port lambda scheme λ quick\"")
+(define s238 "dog over over lambda fox λ the scheme")
+(define s239 "the string ünïcödé λ λ café dog quick café")
+(define s240 "café ünïcödé")
+(define s241 "brown λ lambda λ string quick λ\n")
+(define s242 "quick dog brown lazy café")
+(define s243 "\"ünïcödé string fox fox café quick brown\"")
+(define s244 "λ lambda scheme jumps port dog lazy\n")
+(define s245 "scheme over lazy ünïcödé jumps lambda café quick brown string dog")
+(define s246 "port fox café jumps scheme\t\x41;")
+(define s247 "lambda café fox port quick fox lambda λ ünïcödé\n")
+(define s248 "brown jumps quick lambda jumps lazy ünïcödé fox the")
+(define s249 "quick scheme dog string lazy ünïcödé lambda brown lambda fox\n")
+(define s250 "\"scheme jumps ünïcödé dog dog\"")
+(define s251 "ünïcödé brown port café the lambda port port fox λ lazy")
+(define s252 "lazy ünïcödé λ the over\t\x41;")
+(define s253 "lazy string string dog lambda over scheme lazy string the")
+(define s254 "\"ünïcödé brown café brown string café jumps\"")
+(define s255 "fox jumps lazy over string jumps\n")
+(define s256 "\"café lambda port over\"")
+(define s257 "\"fox scheme string dog ünïcödé\"")
+(define s258 "dog jumps string over the quick ünïcödé dog brown string over\n")
+(define s259 "over λ scheme port fox scheme the café dog")
+(define s260 "the jumps dog brown string dog jumps ünïcödé fox")
+(define s261 "jumps dog the port café brown dog\t\x41;")
+(define s262 "\"fox fox brown lazy\"")
+(define s263 "string quick fox the λ quick brown string brown dog\n")
+(define s264 "\"the quick jumps jumps string\"")
+(define s265 "the string scheme over λ ünïcödé dog quick scheme lambda fox")
+(define s266 "\"dog brown quick\"")
+(define s267 "lambda string fox scheme λ")
+(define s268 "fox quick quick string λ lambda")
+(define s269 "\"scheme the café string ünïcödé fox quick λ\"")
+(define s270 "\"lazy ünïcödé λ dog over lambda brown port\"")
+(define s271 "string fox the λ ünïcödé port dog over dog")
+(define s272 "café café dog dog ünïcödé jumps lambda fox brown quick jumps")
+(define s273 "the the")
+(define s274 "fox lambda café quick scheme lazy brown fox port scheme\t\x41;")
+(define s275 "\"café string port jumps lambda string port λ\"")
+(define s276 "ünïcödé brown port lazy quick lambda the string ünïcödé port jumps")
+(define s277 "port string café quick scheme string jumps port over lambda ünïcödé\n")
+(define s278 "\"string string over port jumps\"")
+(define s279 "over lazy jumps jumps lazy")
+(define s280 "quick lambda the jumps café")
+(define s281 "quick dog λ the dog ünïcödé scheme fox the")
+(define s282 "the lambda lambda port scheme brown port café\n")
+(define s283 "the over string ünïcödé dog\n")
+(define s284 "lazy fox over ünïcödé lazy scheme λ port")
+(define s285 "string quick jumps port jumps lambda dog fox\n")
+(define s286 "over string")
+(define s287 "quick scheme jumps the ünïcödé dog lambda dog dog ünïcödé\t\x41;")
+(define s288 "port brown ünïcödé ünïcödé lazy fox over lazy λ\t\x41;")
+(define s289 "\"scheme dog jumps jumps fox brown dog over\"")
+(define s290 "over café string lazy string lambda dog")
+(define s291 "scheme fox")
+(define s292 "port string the café jumps λ\n")
+(define s293 "\"the lazy string string\"")
+(define s294 "lazy port ünïcödé over fox fox dog dog scheme\t\x41;")
+(define s295 "\"over port jumps quick\"")
+(define s296 "over brown lazy jumps string lambda port")
+(define s297 "the string")
+(define s298 "fox scheme scheme scheme λ port scheme lazy\t\x41;")
+(define s299 "λ brown the scheme over lambda λ\n")
+(define s300 "\"lambda scheme quick quick brown quick dog brown\"")
+(define s301 "the over ünïcödé ünïcödé quick the scheme string ünïcödé fox\t\x41;")
+(define s302 "lambda the")
+(define s303 "port jumps port dog quick\n")
+(define s304 "brown port\n")
+(define s305 "\"λ string jumps ünïcödé string lambda port λ fox jumps dog\"")
+(define s306 "\"dog the λ over dog dog scheme string fox café\"")
+(define s307 "scheme port port λ brown")
+(define s308 "\"lambda fox port over lazy fox jumps port fox\"")
+(define s309 "jumps lazy lambda λ string over λ λ port ünïcödé\t\x41;")
+(define s310 "jumps string quick\n")
+(define s311 "brown scheme café brown over")
+(define s312 "brown lazy fox")
+(define s313 "jumps quick scheme lazy lazy jumps dog\t\x41;")
+(define s314 "\"scheme over\"")
+(define s315 "\"quick λ café string jumps over café port jumps\"")
+(define s316 "café string café ünïcödé brown ünïcödé jumps dog dog dog scheme\n")
+(define s317 "\"fox port jumps dog scheme over\"")
+(define s318 "lambda dog\n")
+(define s319 "λ ünïcödé scheme fox λ lambda fox jumps lambda\n")
+(define s320 "jumps dog the port café over café dog lambda jumps fox\n")
+(define s321 "string λ brown string\n")
+(define s322 "\"lazy quick scheme lazy quick\"")
+(define s323 "quick ünïcödé the dog café the ünïcödé the quick dog")
+(define s324 "\"lazy café dog\"")
+(define s325 "fox lambda string ünïcödé the λ λ ünïcödé λ port\n")
+(define s326 "brown jumps dog port\n")
+(define s327 "scheme quick café\n")
+(define s328 "ünïcödé dog fox")
+(define s329 "scheme λ jumps port dog dog scheme")
+(define s330 "dog jumps scheme\t\x41;")
+(define s331 "λ port lazy dog λ")
+(define s332 "fox over quick quick λ scheme jumps string")
+(define s333 "\"lambda the brown λ café\"")
+(define s334 "lambda string ünïcödé the jumps ünïcödé quick")
+(define s335 "the scheme\n")
+(define s336 "jumps port string dog over\n")
+(define s337 "\"jumps fox lazy ünïcödé over brown λ\"")
+(define s338 "over the lazy lazy\n")
+(define s339 "port café\t\x41;")
+(define s340 "lambda quick fox ünïcödé fox brown quick string λ lambda ünïcödé")
+(define s341 "ünïcödé over dog string jumps fox\t\x41;")
+(define s342 "quick dog dog café λ string ünïcödé\n")
+(define s343 "\"λ lazy dog café\"")
+(define s344 "jumps fox fox brown quick λ café")
+(define s345 "lazy lazy ünïcödé lazy lambda brown over")
+(define s346 "dog scheme\n")
+(define s347 "scheme over dog dog string fox")
+(define s348 "dog string")
+(define s349 "\"string ünïcödé lazy scheme ünïcödé lambda\"")
+(define s350 "fox λ fox string")
+(define s351 "scheme string lazy ünïcödé fox fox\n")
+(define s352 "lazy fox dog lambda lazy λ λ jumps fox")
+(define s353 "quick port ünïcödé")
+(define s354 "port quick café string fox dog\t\x41;")
+(define s355 "over λ dog the ünïcödé port lazy lambda fox the over\n")
+(define s356 "jumps dog café λ ünïcödé\n")
+(define s357 "the scheme brown scheme jumps jumps dog jumps dog brown λ")
+(define s358 "dog over")
+(define s359 "ünïcödé fox string café café λ lazy lambda\n")
+(define s360 "fox ünïcödé lazy scheme string jumps ünïcödé port over")
+(define s361 "the ünïcödé port string café jumps")
+(define s362 "jumps jumps ünïcödé jumps port\n")
+(define s363 "café string\t\x41;")
+(define s364 "fox port λ λ fox dog string brown string\t\x41;")
+(define s365 "quick scheme the lazy string lazy")
+(define s366 "lambda over over\t\x41;")
+(define s367 "café jumps over")
+(define s368 "lazy café lambda port lazy port dog café jumps dog port\t\x41;")
+(define s369 "over dog port jumps the over port port dog the\n")
+(define s370 "quick λ string lambda brown over lazy string")
+(define s371 "over jumps port string the\n")
+(define s372 "jumps brown scheme λ brown")
+(define s373 "scheme café brown port")
+(define s374 "\"fox the brown string scheme the dog\"")
+(define s375 "ünïcödé lazy the quick")
+(define s376 "port brown quick quick")
+(define s377 "ünïcödé port over\t\x41;")
+(define s378 "scheme lazy café port ünïcödé scheme λ over ünïcödé port")
+(define s379 "café dog λ λ dog string jumps\t\x41;")
+(define s380 "\"quick port dog\"")
+(define s381 "\"the brown port brown\"")
+(define s382 "scheme ünïcödé\t\x41;")
+(define s383 "string quick the ünïcödé\t\x41;")
+(define s384 "the jumps quick port dog brown ünïcödé ünïcödé\n")
+(define s385 "café scheme\n")
+(define s386 "lambda fox jumps café λ")
+(define s387 "string ünïcödé fox jumps ünïcödé fox")
+(define s388 "lambda the quick scheme brown ünïcödé port")
+(define s389 "quick jumps port dog string fox dog over string lambda lambda")
+(define s390 "brown string quick λ port the dog lambda")
+(define s391 "\"dog brown the jumps dog λ port\"")
+(define s392 "port dog lazy jumps\n")
+(define s393 "quick jumps ünïcödé lazy lambda quick λ")
+(define s394 "over brown over quick port fox fox λ")
+(define s395 "\"port fox\"")
+(define s396 "over port\n")
+(define s397 "brown ünïcödé λ quick string\n")
+(define s398 "\"scheme string string port λ ünïcödé ünïcödé quick\"")
+(define s399 "\"string λ over\"")
+(define s400 "lazy lambda string quick dog lambda brown fox over")
+(define s401 "dog fox over scheme ünïcödé quick port café brown scheme brown")
+(define s402 "λ jumps the\t\x41;")
+(define s403 "fox string the lazy the")
+(define s404 "scheme jumps ünïcödé scheme scheme\n")
+(define s405 "the brown café over the ünïcödé λ\t\x41;")
+(define s406 "\"dog scheme lambda string fox string dog café jumps scheme\"")
+(define s407 "ünïcödé fox café\t\x41;")
+(define s408 "scheme string over brown string the lazy λ quick lambda")
+(define s409 "lazy jumps over the\n")
+(define s410 "lazy scheme lambda over the jumps\n")
+(define s411 "scheme the string café jumps λ brown string scheme ünïcödé\n")
+(define s412 "string ünïcödé jumps quick quick fox lambda\t\x41;")
+(define s413 "the café scheme port jumps scheme scheme dog lambda\n")
+(define s414 "port dog quick lazy brown ünïcödé brown scheme ünïcödé")
+(define s415 "fox dog quick jumps the dog fox over string over")
+(define s416 "brown fox λ quick fox\t\x41;")
+(define s417 "\"dog brown café over brown string quick lazy λ string\"")
+(define s418 "\"λ string\"")
+(define s419 "café ünïcödé string brown brown jumps the the ünïcödé port\t\x41;")
+(define s420 "port ünïcödé brown")
+(define s421 "port café string dog dog\n")
+(define s422 "scheme string brown λ lambda over λ ünïcödé")
+(define s423 "\"quick fox dog lambda over the\"")
+(define s424 "\"string jumps λ\"")
+(define s425 "quick quick lazy ünïcödé")
+(define s426 "\"café quick port jumps over string dog brown café jumps\"")
+(define s427 "lambda dog fox string the")
+(define s428 "scheme café fox over ünïcödé\n")
+(define s429 "\"lambda scheme scheme jumps ünïcödé port café λ brown the\"")
+(define s430 "scheme quick fox quick λ café the\t\x41;")
+(define s431 "λ quick quick ünïcödé the dog")
+(define s432 "\"λ lambda quick fox café\"")
+(define s433 "ünïcödé lazy scheme fox λ over jumps the fox\n")
+(define s434 "ünïcödé fox")
+(define s435 "\"fox jumps jumps lambda quick quick dog fox brown jumps\"")
+(define s436 "dog jumps dog fox fox over dog lazy\t\x41;")
+(define s437 "dog fox lazy fox\t\x41;")
+(define s438 "\"ünïcödé lambda fox lambda port over\"")
+(define s439 "\"over quick port dog jumps scheme string λ café the the\"")
+(define s440 "lazy brown\n")
+(define s441 "lambda dog café fox scheme café the port")
+(define s442 "dog over ünïcödé")
+(define s443 "\"λ fox fox port λ\"")
+(define s444 "jumps jumps scheme lazy port lambda port café port the lazy\t\x41;")
+(define s445 "café ünïcödé fox dog lambda string scheme")
+(define s446 "\"port brown fox jumps jumps scheme λ brown lambda quick lambda\"")
+(define s447 "\"fox ünïcödé dog string scheme café over port jumps\"")
+(define s448 "ünïcödé port port café the\t\x41;")
+(define s449 "the port fox fox café brown the λ port")
+(define s450 "ünïcödé ünïcödé the lazy string ünïcödé")
+(define s451 "fox string the lambda jumps café scheme jumps port")
+(define s452 "string jumps\t\x41;")
+(define s453 "ünïcödé lazy lazy string jumps the brown quick lambda string")
+(define s454 "port lambda the lazy port café over over")
+(define s455 "quick brown λ fox café")
+(define s456 "lazy lambda lambda jumps")
+(define s457 "port quick the brown ünïcödé the string string the\t\x41;")
+(define s458 "λ string fox scheme λ")
+(define s459 "ünïcödé over brown over quick fox lambda lambda over λ fox\n")
+(define s460 "lambda café over the lazy string café\t\x41;")
+(define s461 "dog string jumps dog port scheme brown ünïcödé brown quick λ")
+(define s462 "\"lambda string brown\"")
+(define s463 "the café fox ünïcödé fox the brown λ dog")
+(define s464 "scheme the scheme quick brown ünïcödé scheme lazy the ünïcödé")
+(define s465 "λ string quick quick λ ünïcödé brown the lazy café\t\x41;")
+(define s466 "the scheme string café")
+(define s467 "the port lazy brown fox brown café port the café\t\x41;")
+(define s468 "café over lazy port café string port port over the the\n")
+(define s469 "the over λ brown scheme\n")
+(define s470 "\"scheme lambda over\"")
+(define s471 "the λ over the fox λ lambda quick")
+(define s472 "port lazy quick λ over\t\x41;")
+(define s473 "brown scheme string port scheme")
+(define s474 "\"port the café\"")
+(define s475 "fox over")
+(define s476 "\"lazy port over lazy dog jumps\"")
+(define s477 "quick dog port")
+(define s478 "fox string lazy scheme lambda fox quick\n")
+(define s479 "\"fox λ brown jumps string café the\"")
+(define s480 "lambda lambda brown quick port brown café quick λ string\t\x41;")
+(define s481 "jumps café jumps lazy scheme brown fox\n")
+(define s482 "quick lambda café λ λ lazy string lazy\n")
+(define s483 "lazy jumps lazy")
+(define s484 "café dog over\t\x41;")
+(define s485 "port lazy scheme string\n")
+(define s486 "dog lazy over lazy the")
+(define s487 "scheme scheme café scheme")
+(define s488 "ünïcödé quick lazy ünïcödé λ fox\t\x41;")
+(define s489 "over λ over lambda")
+(define s490 "dog quick λ brown string string lambda string ünïcödé\t\x41;")
+(define s491 "jumps string λ quick dog ünïcödé\n")
+(define s492 "dog quick café ünïcödé the lambda lambda\n")
+(define s493 "café over jumps café string")
+(define s494 "port quick")
+(define s495 "\"λ ünïcödé\"")
+(define s496 "café ünïcödé brown λ port dog")
+(define s497 "brown over lambda fox lambda dog port brown scheme fox quick\t\x41;")
+(define s498 "jumps café lazy scheme scheme lambda brown café lazy over\n")
+(define s499 "string fox fox scheme the")
+(define s500 "lazy fox café ünïcödé dog over jumps port quick over the")
+(define s501 "λ fox lambda ünïcödé port jumps port the string scheme brown\t\x41;")
+(define s502 "\"ünïcödé port over string brown lambda λ ünïcödé over\"")
+(define s503 "quick scheme ünïcödé scheme fox")
+(define s504 "ünïcödé quick lambda fox\n")
+(define s505 "\"string lazy over jumps fox lazy the λ lambda over fox\"")
+(define s506 "ünïcödé string fox café jumps brown lambda café\n")
+(define s507 "λ λ fox the")
+(define s508 "string jumps fox\n")
+(define s509 "fox lambda lambda dog lazy the quick λ brown\t\x41;")
+(define s510 "\"fox brown fox jumps over café café the\"")
+(define s511 "dog string port λ jumps port fox dog jumps fox lambda")
+(define s512 "\"lazy lazy scheme\"")
+(define s513 "\"lambda fox lambda string lambda ünïcödé\"")
+(define s514 "\"λ lazy string quick dog café port fox brown\"")
+(define s515 "port λ brown\t\x41;")
+(define s516 "brown fox quick over λ café dog")
+(define s517 "lambda lambda")
+(define s518 "\"fox jumps dog lambda jumps lambda jumps lambda\"")
+(define s519 "ünïcödé port string string\n")
+(define s520 "lazy port lambda the lambda fox dog the port dog port\n")
+(define s521 "\"the lambda scheme\"")
+(define s522 "lambda the jumps string port fox")
+(define s523 "scheme jumps string port over port port")
+(define s524 "over café lambda lambda string ünïcödé quick\n")
+(define s525 "\"brown lambda dog over lambda λ brown lambda brown\"")
+(define s526 "café fox\t\x41;")
+(define s527 "λ fox lambda quick ünïcödé over brown over café string\t\x41;")
+(define s528 "\"lambda fox the over scheme café string café port scheme\"")
+(define s529 "port string jumps scheme lazy")
+(define s530 "\"lambda quick lazy\"")
+(define s531 "scheme jumps dog over the lambda café lazy string the")
+(define s532 "over scheme ünïcödé quick")
+(define s533 "\"over lazy λ scheme\"")
+(define s534 "scheme dog string the lambda café lambda dog λ string lambda")
+(define s535 "fox brown scheme string jumps scheme dog\t\x41;")
+(define s536 "string quick the quick ünïcödé over jumps quick lazy")
+(define s537 "brown quick brown dog lambda\t\x41;")
+(define s538 "fox scheme port lazy the")
+(define s539 "port λ lazy scheme")
+(define s540 "lazy ünïcödé café over jumps lambda lazy lambda")
+(define s541 "lazy ünïcödé lambda brown")
+(define s542 "ünïcödé over scheme brown dog over lazy jumps over lambda café\t\x41;")
+(define s543 "port the quick the over the ünïcödé jumps port lambda")
+(define s544 "\"string quick port λ\"")
+(define s545 "\"dog over ünïcödé over the lambda string\"")
+(define s546 "scheme café quick\t\x41;")
+(define s547 "quick the port string jumps lazy")
+(define s548 "quick λ string")
+(define s549 "café λ scheme port the scheme the quick lazy brown\t\x41;")
+(define s550 "dog scheme over ünïcödé the scheme string brown lazy the")
+(define s551 "jumps quick the jumps scheme café dog brown dog ünïcödé over")
+(define s552 "dog scheme dog scheme fox λ\n")
+(define s553 "dog lazy lambda the fox port café\t\x41;")
+(define s554 "\"fox lazy fox dog jumps\"")
+(define s555 "ünïcödé the café the fox λ dog the fox café\n")
+(define s556 "jumps string café the ünïcödé")
+(define s557 "fox café lambda lambda port dog\n")
+(define s558 "over café brown dog string ünïcödé ünïcödé brown the λ quick")
+(define s559 "over port lazy scheme scheme brown dog lazy")
+(define s560 "ünïcödé lambda port jumps jumps fox lazy brown")
+(define s561 "over ünïcödé ünïcödé quick")
+(define s562 "\"brown lazy\"")
+(define s563 "λ brown string ünïcödé")
+(define s564 "brown port café dog dog jumps string\n")
+(define s565 "port the over lazy jumps jumps dog jumps port over quick\n")
+(define s566 "lazy brown the brown the brown ünïcödé fox string brown quick")
+(define s567 "ünïcödé ünïcödé café over the lazy fox café the brown café\t\x41;")
+(define s568 "\"port string café λ quick port the brown brown\"")
+(define s569 "café lazy over the over fox fox quick\n")
+(define s570 "dog lazy jumps dog λ quick")
+(define s571 "lazy fox fox λ scheme dog jumps scheme scheme fox the")
+(define s572 "lambda lambda fox λ café λ café jumps ünïcödé over\t\x41;")
+(define s573 "jumps over quick port λ\t\x41;")
+(define s574 "scheme jumps jumps\n")
+(define s575 "café over λ dog café\t\x41;")
+(define s576 "port brown the scheme\n")
+(define s577 "string jumps lambda café")
+(define s578 "\"jumps lazy the jumps λ port brown\"")
+(define s579 "dog quick fox lambda")
+(define s580 "\"dog port the café the\"")
+(define s581 "\"port jumps ünïcödé quick lambda over λ over\"")
+(define s582 "jumps ünïcödé the λ λ port dog scheme jumps dog")
+(define s583 "scheme lazy string lazy")
+(define s584 "dog dog quick jumps jumps")
+(define s585 "\"fox scheme\"")
+(define s586 "over string ünïcödé scheme quick ünïcödé ünïcödé\t\x41;")
+(define s587 "dog lazy over café jumps over lazy quick brown jumps")
+(define s588 "brown ünïcödé brown dog ünïcödé\t\x41;")
+(define s589 "dog λ lazy brown scheme the\n")
+(define s590 "fox quick the scheme ünïcödé ünïcödé fox ünïcödé\t\x41;")
+(define s591 "brown port lambda lazy scheme the lazy lambda fox lambda jumps")
+(define s592 "quick dog")
+(define s593 "dog café quick")
+(define s594 "\"λ ünïcödé jumps brown fox brown brown jumps lazy\"")
+(define s595 "λ quick ünïcödé port quick café")
+(define s596 "quick scheme λ fox fox brown λ\n")
+(define s597 "jumps café café string quick fox the lambda fox scheme port\n")
+(define s598 "ünïcödé café scheme jumps string jumps ünïcödé brown dog\n")
+(define s599 "brown quick café")
+(define s600 "\"lazy dog dog jumps brown\"")
+(define s601 "string lazy string lambda quick dog over scheme the over café")
+(define s602 "\"string quick quick the lazy\"")
+(define s603 "the fox lambda café lazy brown quick brown ünïcödé jumps string\t\x41;")
+(define s604 "\"port café lazy ünïcödé λ\"")
+(define s605 "the café string over the\t\x41;")
+(define s606 "dog lazy string λ string café fox λ")
+(define s607 "brown quick scheme string lambda fox quick")
+(define s608 "string string lazy\t\x41;")
+(define s609 "jumps brown café jumps over\n")
+(define s610 "quick lambda dog string jumps fox\t\x41;")
+(define s611 "\"quick ünïcödé λ scheme café string lazy dog port brown\"")
+(define s612 "\"café jumps over string\"")
+(define s613 "the jumps λ café scheme")
+(define s614 "string port port quick lazy brown the lambda lazy string\t\x41;")
+(define s615 "ünïcödé jumps the quick scheme ünïcödé lambda")
+(define s616 "scheme jumps λ")
+(define s617 "fox ünïcödé string")
+(define s618 "scheme string string\n")
+(define s619 "string scheme")
+(define s620 "\"jumps dog café the quick jumps lambda dog\"")
+(define s621 "brown fox brown brown lazy λ quick port")
+(define s622 "brown café fox ünïcödé over λ\t\x41;")
+(define s623 "brown fox string dog string")
+(define s624 "\"dog dog over the ünïcödé the\"")
+(define s625 "\"port the over scheme lazy string lazy\"")
+(define s626 "the brown lazy dog the jumps string café")
+(define s627 "port brown fox\n")
+(define s628 "\"dog scheme lazy quick fox over dog jumps\"")
+(define s629 "the café café café")
+(define s630 "\"over lazy fox fox jumps lazy\"")
+(define s631 "brown ünïcödé over")
+(define s632 "quick fox λ ünïcödé lazy scheme scheme string café brown dog")
+(define s633 "dog scheme dog lambda dog lambda over jumps brown lambda")
+(define s634 "\"jumps lazy scheme café scheme λ café\"")
+(define s635 "café over café\n")
+(define s636 "over café λ λ")
+(define s637 "lambda lambda the λ ünïcödé port jumps scheme brown jumps")
+(define s638 "over lambda brown port ünïcödé fox café over fox lambda")
+(define s639 "lambda jumps over fox")
+(define s640 "lambda λ café quick lazy jumps the the port λ\t\x41;")
+(define s641 "scheme jumps fox")
+(define s642 "ünïcödé ünïcödé λ brown jumps ünïcödé the jumps λ")
+(define s643 "\"dog café brown\"")
+(define s644 "\"λ ünïcödé λ ünïcödé café café café ünïcödé\"")
+(define s645 "\"dog port\"")
+(define s646 "\"dog ünïcödé jumps λ scheme port\"")
+(define s647 "quick fox fox ünïcödé quick")
+(define s648 "brown port scheme port café café lazy")
+(define s649 "port lambda scheme scheme the\t\x41;")
+(define s650 "quick jumps quick scheme dog the λ quick dog")
+(define s651 "the dog dog\t\x41;")
+(define s652 "\"brown café scheme fox scheme string café quick port port\"")
+(define s653 "brown brown brown fox over jumps fox λ")
+(define s654 "\"the fox\"")
+(define s655 "\"dog jumps string lambda string jumps port port lazy\"")
+(define s656 "lambda scheme dog lazy\t\x41;")
+(define s657 "scheme the brown jumps lambda fox café over dog the fox\t\x41;")
+(define s658 "dog lazy lambda brown string string fox café\n")
+(define s659 "\"the jumps the dog café jumps over ünïcödé lazy the\"")
+(define s660 "lazy jumps port jumps scheme fox café scheme\t\x41;")
+(define s661 "port quick\t\x41;")
+(define s662 "\"scheme port quick jumps over the scheme\"")
+(define s663 "ünïcödé lambda λ brown string quick fox over")
+(define s664 "café string the brown ünïcödé café quick λ brown café")
+(define s665 "string café ünïcödé dog over over over fox the")
+(define s666 "\"lambda ünïcödé fox λ\"")
+(define s667 "dog café jumps scheme scheme ünïcödé jumps lambda dog café\t\x41;")
+(define s668 "\"brown λ quick jumps\"")
+(define s669 "string scheme dog\t\x41;")
+(define s670 "café lambda café lambda over lambda port\n")
+(define s671 "lambda lazy λ\t\x41;")
+(define s672 "café string the over jumps jumps the ünïcödé fox\t\x41;")
+(define s673 "\"string lazy string café fox the\"")
+(define s674 "quick over café dog quick brown ünïcödé\n")
+(define s675 "quick quick string ünïcödé dog quick ünïcödé lambda over λ")
+(define s676 "lambda dog λ λ quick fox λ lazy")
+(define s677 "lazy over lambda ünïcödé\n")
+(define s678 "scheme λ lambda lambda port quick\n")
+(define s679 "\"λ ünïcödé lazy café lambda brown\"")
+(define s680 "\"lambda jumps scheme ünïcödé café fox\"")
+(define s681 "\"jumps dog quick lambda over λ dog port over lambda string\"")
+(define s682 "jumps lazy ünïcödé port the string string brown dog scheme\t\x41;")
+(define s683 "ünïcödé lazy quick ünïcödé port λ jumps quick")
+(define s684 "\"over jumps over port string\"")
+(define s685 "quick lazy string lazy quick λ string over dog")
+(define s686 "brown scheme")
+(define s687 "fox café dog quick lambda jumps brown quick fox")
+(define s688 "the over jumps the fox lambda\n")
+(define s689 "scheme café lazy port scheme the\n")
+(define s690 "jumps over dog scheme café scheme café string\t\x41;")
+(define s691 "scheme scheme dog ünïcödé ünïcödé jumps jumps lambda\n")
+(define s692 "port lambda port fox brown scheme")
+(define s693 "lazy scheme quick the λ")
+(define s694 "the λ brown")
+(define s695 "fox port brown brown scheme\n")
+(define s696 "lambda over over port over scheme port string quick\n")
+(define s697 "lambda fox dog lambda café the brown quick lazy lazy ünïcödé\t\x41;")
+(define s698 "port over dog λ\t\x41;")
+(define s699 "jumps ünïcödé lazy fox brown lazy fox\n")
+(define s700 "λ quick\t\x41;")
+(define s701 "\"ünïcödé λ lambda jumps dog dog dog\"")
+(define s702 "over dog jumps brown the ünïcödé jumps lambda fox λ")
+(define s703 "café string dog the string lambda ünïcödé the café\n")
+(define s704 "lazy over fox lambda string fox lazy ünïcödé quick ünïcödé quick")
+(define s705 "\"the fox\"")
+(define s706 "dog scheme café")
+(define s707 "the lazy jumps brown ünïcödé fox scheme λ lambda dog scheme\n")
+(define s708 "café port the café\t\x41;")
+(define s709 "\"scheme lazy brown scheme the\"")
+(define s710 "over λ")
+(define s711 "string dog lambda")
+(define s712 "λ quick string")
+(define s713 "lazy jumps scheme string café quick port string dog jumps fox")
+(define s714 "scheme café string quick string café lazy café lambda dog")
+(define s715 "port brown ünïcödé brown café lambda λ port ünïcödé λ port")
+(define s716 "quick port string λ dog dog café port fox\t\x41;")
+(define s717 "café λ dog")
+(define s718 "\"brown ünïcödé brown café brown\"")
+(define s719 "quick dog over ünïcödé jumps lambda")
+(define s720 "port port string lazy jumps scheme\n")
+(define s721 "λ brown dog λ the\n")
+(define s722 "\"the jumps the dog string café brown\"")
+(define s723 "quick jumps brown café jumps jumps quick jumps over ünïcödé")
+(define s724 "quick brown λ lazy")
+(define s725 "scheme string café")
+(define s726 "λ port the over lambda scheme quick port lazy\t\x41;")
+(define s727 "the ünïcödé dog port ünïcödé lazy fox quick port")
+(define s728 "over string")
+(define s729 "dog quick lambda port ünïcödé brown fox scheme jumps port")
+(define s730 "dog lazy")
+(define s731 "dog the port ünïcödé jumps λ scheme café\t\x41;")
+(define s732 "string λ λ jumps café quick quick over\n")
+(define s733 "scheme over string fox lambda brown scheme")
+(define s734 "the λ quick dog scheme")
+(define s735 "fox over λ scheme the the")
+(define s736 "over fox jumps lambda port the fox dog\n")
+(define s737 "\"brown lambda café λ string lambda café fox\"")
+(define s738 "lambda λ brown fox over café the string\t\x41;")
+(define s739 "\"port jumps scheme fox jumps quick λ port quick string\"")
+(define s740 "quick fox ünïcödé scheme fox brown\n")
+(define s741 "fox quick port ünïcödé")
+(define s742 "fox over ünïcödé over dog lambda jumps port lazy port\t\x41;")
+(define s743 "café lazy port the brown fox\n")
+(define s744 "quick λ lambda over port λ port the over\n")
+(define s745 "the quick over quick string lazy\n")
+(define s746 "over fox brown\n")
+(define s747 "string fox over")
+(define s748 "quick port quick string lazy scheme string jumps\t\x41;")
+(define s749 "scheme lambda ünïcödé the\n")
+(define s750 "fox fox λ lazy lambda brown dog quick port")
+(define s751 "ünïcödé fox lazy port fox\t\x41;")
+(define s752 "\"lazy lambda quick café scheme fox the lambda quick\"")
+(define s753 "port the dog jumps café dog string brown")
+(define s754 "string ünïcödé λ")
+(define s755 "port fox café jumps port\n")
+(define s756 "λ ünïcödé jumps jumps fox ünïcödé")
+(define s757 "scheme quick dog lambda string lambda brown λ quick fox café")
+(define s758 "\"ünïcödé jumps dog string over café\"")
+(define s759 "over lazy over quick dog café the scheme\t\x41;")
+(define s760 "scheme string port lazy lazy λ string ünïcödé lambda")
+(define s761 "\"brown quick quick ünïcödé fox jumps port dog string brown lambda\"")
+(define s762 "over fox lazy brown λ dog fox ünïcödé")
+(define s763 "lazy lazy fox λ jumps dog string scheme port\n")
+(define s764 "λ over dog\t\x41;")
+(define s765 "fox scheme dog dog string ünïcödé lazy quick")
+(define s766 "over port dog jumps ünïcödé lambda dog quick port λ jumps")
+(define s767 "port dog scheme string\t\x41;")
+(define s768 "brown quick lazy\t\x41;")
+(define s769 "fox dog lambda string string\t\x41;")
+(define s770 "\"fox brown the fox\"")
+(define s771 "lazy café café scheme scheme")
+(define s772 "scheme string jumps quick ünïcödé over\n")
+(define s773 "brown dog dog brown brown the jumps ünïcödé ünïcödé\t\x41;")
+(define s774 "\"dog λ fox ünïcödé lambda\"")
+(define s775 "λ ünïcödé brown dog fox quick café over")
+(define s776 "lambda lambda lambda lambda fox ünïcödé λ ünïcödé")
+(define s777 "quick the dog scheme string λ scheme lambda scheme string")
+(define s778 "jumps λ lazy the quick\n")
+(define s779 "\"brown port string jumps café brown λ scheme\"")
+(define s780 "over brown the lazy λ λ café dog\t\x41;")
+(define s781 "the scheme")
+(define s782 "lazy lambda string port dog brown λ port λ\t\x41;")
+(define s783 "over λ lazy over quick café ünïcödé")
+(define s784 "port lambda over port quick lazy port café jumps\t\x41;")
+(define s785 "the over scheme brown ünïcödé café fox\n")
+(define s786 "ünïcödé jumps jumps string café")
+(define s787 "over string lazy λ dog scheme quick\t\x41;")
+(define s788 "\"jumps ünïcödé fox port dog the café ünïcödé lazy ünïcödé lazy\"")
+(define s789 "λ café ünïcödé")
+(define s790 "\"brown jumps λ string brown lambda\"")
+(define s791 "brown jumps\n")
+(define s792 "jumps quick brown lazy")
+(define s793 "string λ lazy λ brown scheme brown scheme port fox")
+(define s794 "scheme quick dog lazy ünïcödé jumps string jumps")
+(define s795 "brown string string ünïcödé scheme quick brown")
+(define s796 "λ ünïcödé the fox\t\x41;")
+(define s797 "fox λ scheme ünïcödé dog café port jumps")
+(define s798 "\"quick quick jumps quick scheme jumps quick\"")
+(define s799 "ünïcödé lazy jumps scheme port over lambda lambda quick the quick")
+(define s800 "lazy scheme string fox")
+(define s801 "brown café the lambda lambda lazy over lazy lambda the")
+(define s802 "fox café scheme port ünïcödé lambda")
+(define s803 "lambda café lambda λ brown the brown\t\x41;")
+(define s804 "fox λ dog ünïcödé the port café fox lazy lambda quick")
+(define s805 "scheme port\t\x41;")
+(define s806 "\"fox lambda λ brown lambda string café port\"")
+(define s807 "dog the jumps café jumps the\t\x41;")
+(define s808 "quick scheme\n")
+(define s809 "dog brown brown string lazy the fox port")
+(define s810 "jumps the fox brown")
+(define s811 "fox café café")
+(define s812 "jumps the scheme\t\x41;")
+(define s813 "string jumps\n")
+(define s814 "lambda dog string café over scheme ünïcödé")
+(define s815 "\"fox café the the\"")
+(define s816 "\"dog ünïcödé string jumps dog over lazy\"")
+(define s817 "\"café the scheme\"")
+(define s818 "jumps dog the jumps over lazy brown\t\x41;")
+(define s819 "jumps λ")
+(define s820 "brown lazy jumps string λ string the λ\t\x41;")
+(define s821 "ünïcödé string")
+(define s822 "brown lambda lambda port dog λ port lambda over jumps café")
+(define s823 "quick λ string scheme\t\x41;")
+(define s824 "lazy string quick ünïcödé dog the lazy")
+(define s825 "café lazy lambda dog lazy port fox ünïcödé scheme\t\x41;")
+(define s826 "brown dog port the λ\n")
+(define s827 "fox scheme scheme lambda port lambda café port brown string quick\t\x41;")
+(define s828 "dog brown lazy fox dog over jumps lambda the string")
+(define s829 "port λ jumps lazy jumps dog jumps brown string café lambda")
+(define s830 "lambda quick ünïcödé jumps lambda over string")
+(define s831 "jumps quick café port port lazy lambda jumps port string")
+(define s832 "quick ünïcödé quick lazy ünïcödé the port lambda quick\n")
+(define s833 "dog ünïcödé port fox λ the")
+(define s834 "\"the lambda fox café port\"")
+(define s835 "\"dog port lazy lambda jumps lazy jumps λ\"")
+(define s836 "dog λ café lazy quick ünïcödé lambda ünïcödé string fox")
+(define s837 "string brown ünïcödé dog over port lambda jumps string")
+(define s838 "string jumps over over port ünïcödé\n")
+(define s839 "\"dog lambda string the lazy string quick the\"")
+(define s840 "over scheme fox brown lambda café string")
+(define s841 "\"jumps café jumps brown\"")
+(define s842 "the over over scheme the quick brown scheme scheme")
+(define s843 "the ünïcödé café the fox quick jumps")
+(define s844 "dog ünïcödé over")
+(define s845 "café dog quick scheme ünïcödé ünïcödé dog")
+(define s846 "fox lazy lazy fox café ünïcödé lambda λ")
+(define s847 "brown jumps port")
+(define s848 "lambda jumps lambda brown jumps over lambda dog lazy café string")
+(define s849 "\"lazy fox lambda dog over quick\"")
+(define s850 "dog over port scheme lazy jumps λ\n")
+(define s851 "string port port the over quick fox string\n")
+(define s852 "port dog dog port port ünïcödé fox port café dog lambda\t\x41;")
+(define s853 "λ port fox λ string dog jumps brown jumps")
+(define s854 "port lazy lazy\t\x41;")
+(define s855 "fox lazy string lazy lazy lazy scheme lazy quick\n")
+(define s856 "\"lambda quick dog λ the brown port brown fox jumps scheme\"")
+(define s857 "the jumps fox café jumps string the brown lambda over λ")
+(define s858 "scheme quick ünïcödé café café\n")
+(define s859 "the ünïcödé quick lazy lazy λ\t\x41;")
+(define s860 "string lambda")
+(define s861 "\"string lazy brown\"")
+(define s862 "over quick port lambda lambda jumps dog jumps dog scheme")
+(define s863 "fox scheme\t\x41;")
+(define s864 "\"scheme scheme quick the lambda ünïcödé λ\"")
+(define s865 "scheme string lazy jumps over dog brown café fox\t\x41;")
+(define s866 "string brown string")
+(define s867 "string lambda")
+(define s868 "fox dog over café brown port lazy ünïcödé string\n")
+(define s869 "lazy λ jumps port scheme port lambda café scheme the quick")
+(define s870 "\"port brown ünïcödé fox\"")
+(define s871 "\"quick lambda ünïcödé fox the lazy scheme dog scheme café over\"")
+(define s872 "λ the brown fox fox scheme\t\x41;")
+(define s873 "port ünïcödé over")
+(define s874 "the lambda quick jumps\t\x41;")
+(define s875 "brown lazy string")
+(define s876 "quick lazy brown\t\x41;")
+(define s877 "quick over brown café string dog ünïcödé the fox port")
+(define s878 "brown the string string string dog\n")
+(define s879 "\"string port ünïcödé over quick the port\"")
+(define s880 "jumps scheme café scheme brown jumps the\n")
+(define s881 "scheme string the scheme\n")
+(define s882 "the dog")
+(define s883 "the lazy dog jumps brown jumps jumps port dog lambda string")
+(define s884 "\"dog lambda café brown λ port the quick\"")
+(define s885 "\"lazy string quick quick fox\"")
+(define s886 "dog lambda\t\x41;")
+(define s887 "string port the lazy lambda café lambda jumps lambda café\t\x41;")
+(define s888 "café dog the over λ brown")
+(define s889 "dog over over")
+(define s890 "lazy fox dog ünïcödé λ jumps string lazy over scheme")
+(define s891 "lazy port lambda ünïcödé scheme\n")
+(define s892 "dog λ port string fox λ dog jumps brown lambda")
+(define s893 "jumps fox dog lazy ünïcödé jumps scheme")
+(define s894 "scheme string λ jumps")
+(define s895 "lazy the string")
+(define s896 "quick dog the ünïcödé jumps port jumps the dog\n")
+(define s897 "café scheme λ")
+(define s898 "quick lazy\t\x41;")
+(define s899 "string dog lazy fox dog brown fox quick\t\x41;")
+(define s900 "dog fox quick fox string lambda over λ quick λ")
+(define s901 "dog scheme λ\t\x41;")
+(define s902 "\"the fox fox café dog over\"")
+(define s903 "fox over λ brown lambda lambda the lazy\n")
+(define s904 "jumps scheme\n")
+(define s905 "lazy string lazy port\n")
+(define s906 "quick λ the scheme quick lazy\t\x41;")
+(define s907 "lazy over ünïcödé café dog")
+(define s908 "brown scheme brown brown ünïcödé")
+(define s909 "café lazy ünïcödé λ dog quick λ quick lazy fox jumps")
+(define s910 "scheme over quick brown ünïcödé string fox\n")
+(define s911 "string port scheme lambda quick scheme brown port ünïcödé the over\n")
+(define s912 "lazy scheme lambda")
+(define s913 "café café string string jumps ünïcödé\n")
+(define s914 "lazy port quick fox quick the brown scheme\n")
+(define s915 "café jumps ünïcödé λ the over λ quick\n")
+(define s916 "string lambda")
+(define s917 "jumps café scheme")
+(define s918 "lazy over jumps café over brown over scheme\n")
+(define s919 "\"λ λ\"")
+(define s920 "\"over jumps port café over ünïcödé scheme lazy the λ fox\"")
+(define s921 "over fox over jumps port the lazy scheme over lambda\n")
+(define s922 "scheme string lazy brown string dog\t\x41;")
+(define s923 "over café")
+(define s924 "fox café ünïcödé")
+(define s925 "fox dog dog port\n")
+(define s926 "lazy scheme port brown scheme")
+(define s927 "λ ünïcödé\t\x41;")
+(define s928 "dog λ lazy fox scheme port string")
+(define s929 "\"café lambda string dog quick\"")
+(define s930 "lambda lambda string\n")
+(define s931 "ünïcödé λ lambda string dog quick jumps dog jumps over")
+(define s932 "lambda lazy scheme café the string")
+(define s933 "string fox")
+(define s934 "port scheme string brown lazy lambda lambda scheme")
+(define s935 "jumps λ port")
+(define s936 "fox brown string quick port fox string λ lambda port dog")
+(define s937 "lazy lazy port λ dog port brown quick dog port\t\x41;")
+(define s938 "lazy lazy λ jumps the scheme\t\x41;")
+(define s939 "scheme λ café jumps lazy brown string")
+(define s940 "λ ünïcödé jumps ünïcödé dog lazy port brown\n")
+(define s941 "\"over jumps\"")
+(define s942 "lambda jumps string fox the string")
+(define s943 "the jumps fox café jumps café")
+(define s944 "quick brown lambda brown string ünïcödé over string brown λ")
+(define s945 "jumps port brown fox brown fox quick λ brown port")
+(define s946 "the lazy")
+(define s947 "lazy jumps the fox lambda the lazy dog")
+(define s948 "café lambda over café over port the café port brown")
+(define s949 "the string lazy jumps λ brown")
+(define s950 "ünïcödé café jumps quick")
+(define s951 "\"fox port over the\"")
+(define s952 "fox café café brown fox ünïcödé\n")
+(define s953 "string brown port fox café fox dog fox lazy string λ")
+(define s954 "quick ünïcödé ünïcödé scheme café quick\t\x41;")
+(define s955 "over scheme jumps brown brown ünïcödé string café\t\x41;")
+(define s956 "lazy dog café lambda dog over lazy\n")
+(define s957 "café port λ\t\x41;")
+(define s958 "dog café café scheme lazy fox café brown ünïcödé scheme quick\t\x41;")
+(define s959 "\"over ünïcödé ünïcödé dog λ over fox dog port over string\"")
+(define s960 "\"lambda lambda port quick quick fox λ café dog ünïcödé\"")
+(define s961 "port dog")
+(define s962 "scheme brown string jumps brown fox string\n")
+(define s963 "over the lambda over fox the quick ünïcödé scheme")
+(define s964 "ünïcödé fox quick café ünïcödé port café λ\n")
+(define s965 "port λ dog scheme jumps\t\x41;")
+(define s966 "café café ünïcödé jumps scheme lazy over string")
+(define s967 "fox λ fox λ brown the over\t\x41;")
+(define s968 "scheme lambda café fox ünïcödé jumps lambda")
+(define s969 "quick dog quick ünïcödé scheme quick port dog\n")
+(define s970 "over λ λ jumps dog quick lambda brown\t\x41;")
+(define s971 "the quick jumps fox fox lambda scheme λ lambda\t\x41;")
+(define s972 "brown fox café over café jumps lazy over\t\x41;")
+(define s973 "quick quick jumps quick lambda the quick")
+(define s974 "over jumps")
+(define s975 "dog lazy\t\x41;")
+(define s976 "\"dog port jumps lambda\"")
+(define s977 "\"lazy the port brown lazy the fox fox\"")
+(define s978 "λ lambda fox fox λ")
+(define s979 "λ fox port port lazy the over fox jumps ünïcödé")
+(define s980 "\"jumps quick over\"")
+(define s981 "ünïcödé fox scheme brown ünïcödé quick lazy ünïcödé string")
+(define s982 "\"jumps dog λ\"")
+(define s983 "scheme lazy lambda\t\x41;")
+(define s984 "port quick fox string ünïcödé fox quick scheme the port lazy\t\x41;")
+(define s985 "ünïcödé lambda café the scheme")
+(define s986 "ünïcödé dog the the scheme port")
+(define s987 "brown scheme dog lazy the λ\t\x41;")
+(define s988 "string brown jumps over ünïcödé λ string lambda jumps")
+(define s989 "over ünïcödé lambda brown over brown port fox")
+(define s990 "brown fox lambda port quick brown jumps string dog lazy ünïcödé")
+(define s991 "dog port λ lambda the lazy")
+(define s992 "port the scheme over lazy café the café string")
+(define s993 "over λ scheme the\n")
+(define s994 "café lambda lazy\n")
+(define s995 "λ jumps ünïcödé lazy port café ünïcödé brown café scheme\t\x41;")
+(define s996 "ünïcödé lambda ünïcödé lazy scheme over lambda\t\x41;")
+(define s997 "\"café scheme quick lazy\"")
+(define s998 "fox over lazy")
+(define s999 "lambda quick lazy port ünïcödé port scheme\n")
+(define s1000 "\"brown scheme the over ünïcödé jumps lambda lazy lazy fox café\"")
+(define s1001 "the brown lazy λ quick café café scheme scheme lazy\t\x41;")
+(define s1002 "dog fox brown brown port quick jumps lazy\n")
+(define s1003 "ünïcödé over port scheme café lambda dog over lambda λ\n")
+(define s1004 "fox ünïcödé jumps brown lazy scheme\t\x41;")
+(define s1005 "jumps jumps over port ünïcödé port")
+(define s1006 "port the quick jumps over\t\x41;")
+(define s1007 "lambda ünïcödé over λ ünïcödé string string string")
+(define s1008 "string scheme brown fox the lazy port string")
+(define s1009 "brown jumps lambda ünïcödé quick\t\x41;")
+(define s1010 "over quick dog λ over jumps")
+(define s1011 "café brown")
+(define s1012 "fox brown\t\x41;")
+(define s1013 "jumps port café café quick ünïcödé jumps brown\t\x41;")
+(define s1014 "scheme port")
+(define s1015 "scheme dog λ lambda λ jumps dog jumps port ünïcödé")
+(define s1016 "jumps dog port fox scheme café string brown scheme over lazy\n")
+(define s1017 "lambda café string dog string port dog λ")
+(define s1018 "lambda dog scheme jumps fox the λ string jumps the")
+(define s1019 "over ünïcödé port quick dog lazy the")
+(define s1020 "port over the brown port brown café fox lambda")
+(define s1021 "λ lambda dog")
+(define s1022 "quick string the lazy brown over quick the ünïcödé")
+(define s1023 "the brown fox ünïcödé brown")
+(define s1024 "fox dog string over string")
+(define s1025 "\"jumps dog scheme jumps\"")
+(define s1026 "λ brown fox scheme port λ café lambda\t\x41;")
+(define s1027 "lazy over string dog scheme café ünïcödé fox quick")
+(define s1028 "fox port\n")
+(define s1029 "brown string jumps scheme λ")
+(define s1030 "string lambda λ café\n")
+(define s1031 "scheme lambda\n")
+(define s1032 "jumps dog lazy brown jumps port string lambda")
+(define s1033 "over lazy jumps quick fox brown the brown ünïcödé the ünïcödé\t\x41;")
+(define s1034 "\"over lambda the scheme café λ\"")
+(define s1035 "dog λ λ over café string fox λ string brown λ")
+(define s1036 "scheme quick λ scheme lambda λ port lazy")
+(define s1037 "café over lambda lambda jumps jumps\n")
+(define s1038 "fox brown fox the brown")
+(define s1039 "\"ünïcödé scheme lazy fox λ ünïcödé brown ünïcödé\"")
+(define s1040 "port café brown string quick ünïcödé brown over")
+(define s1041 "dog scheme jumps fox brown over the")
+(define s1042 "lazy brown over over the ünïcödé dog jumps over\t\x41;")
+(define s1043 "\"lambda scheme over λ fox\"")
+(define s1044 "scheme port fox lazy scheme scheme lambda ünïcödé fox ünïcödé")
+(define s1045 "\"string ünïcödé jumps λ fox scheme port λ quick fox scheme\"")
+(define s1046 "over café lambda fox jumps quick jumps lazy scheme")
+(define s1047 "jumps fox brown port lazy")
+(define s1048 "\"lambda brown λ λ the\"")
+(define s1049 "the string lazy fox\t\x41;")
+(define s1050 "over string port fox port lazy")
+(define s1051 "lazy over scheme over fox quick string lazy quick the lambda\n")
+(define s1052 "\"scheme dog\"")
+(define s1053 "lazy ünïcödé lazy")
+(define s1054 "dog fox jumps fox string\t\x41;")
+(define s1055 "\"port string\"")
+(define s1056 "scheme lambda brown quick string over lazy over dog\t\x41;")
+(define s1057 "over port fox over ünïcödé lambda\n")
+(define s1058 "\"jumps quick lambda the ünïcödé lazy\"")
+(define s1059 "port scheme fox café ünïcödé")
+(define s1060 "\"scheme the string over lazy\"")
+(define s1061 "\"lazy lambda brown\"")
+(define s1062 "string brown")
+(define s1063 "port fox café lazy fox scheme quick λ string\t\x41;")
+(define s1064 "string the port\t\x41;")
+(define s1065 "lazy port")
+(define s1066 "quick port ünïcödé lazy string\n")
+(define s1067 "\"scheme over dog ünïcödé quick\"")
+(define s1068 "dog brown port quick quick brown lambda\n")
+(define s1069 "over over quick fox over scheme lambda port")
+(define s1070 "port lambda port\t\x41;")
+(define s1071 "brown port\t\x41;")
+(define s1072 "scheme lambda dog string lazy port lazy port\n")
+(define s1073 "café the lambda")
+(define s1074 "\"lambda ünïcödé over port over dog quick jumps scheme\"")
+(define s1075 "dog port jumps jumps")
+(define s1076 "ünïcödé port\t\x41;")
+(define s1077 "lambda port dog brown")
+(define s1078 "the scheme brown quick\t\x41;")
+(define s1079 "scheme ünïcödé port jumps dog λ ünïcödé brown lazy string over\n")
+(define s1080 "lambda port lambda port")
+(define s1081 "café quick café port fox ünïcödé\n")
+(define s1082 "lambda λ\n")
+(define s1083 "brown port quick jumps dog the café")
+(define s1084 "quick ünïcödé brown brown\t\x41;")
+(define s1085 "over scheme dog dog scheme lambda fox lazy string brown fox")
+(define s1086 "dog scheme port brown ünïcödé ünïcödé")
+(define s1087 "jumps café café the brown")
+(define s1088 "\"the ünïcödé scheme brown port dog lambda lambda λ lazy\"")
+(define s1089 "fox quick ünïcödé quick port port jumps scheme λ")
+(define s1090 "quick lambda lazy\t\x41;")
+(define s1091 "string quick port jumps")
+(define s1092 "string string λ lazy scheme")
+(define s1093 "string brown lazy quick jumps lambda string café")
+(define s1094 "ünïcödé brown dog ünïcödé dog brown")
+(define s1095 "lambda port jumps\t\x41;")
+(define s1096 "quick ünïcödé fox jumps")
+(define s1097 "\"lambda scheme lazy lazy jumps\"")
+(define s1098 "λ dog lambda lazy scheme café")
+(define s1099 "ünïcödé λ lambda café λ fox λ\n")
+(define s1100 "\"string over the café port café lambda over port\"")
+(define s1101 "string port café the brown fox over lambda λ the string")
+(define s1102 "fox lazy quick lambda")
+(define s1103 "\"scheme string\"")
+(define s1104 "scheme fox\n")
+(define s1105 "quick scheme string λ over the")
+(define s1106 "brown brown jumps\t\x41;")
+(define s1107 "\"dog port café the jumps\"")
+(define s1108 "ünïcödé jumps scheme fox dog string")
+(define s1109 "\"jumps dog the over\"")
+(define s1110 "string the fox lambda\t\x41;")
+(define s1111 "quick quick café café\n")
+(define s1112 "ünïcödé string dog lambda scheme lambda string")
+(define s1113 "dog lazy café\t\x41;")
+(define s1114 "the ünïcödé brown lambda quick ünïcödé scheme string ünïcödé fox jumps\t\x41;")
+(define s1115 "over lambda ünïcödé lambda the jumps port λ jumps dog lambda")
+(define s1116 "ünïcödé café")
+(define s1117 "\"ünïcödé the dog\"")
+(define s1118 "string lambda")
+(define s1119 "port lazy string string\t\x41;")
+(define s1120 "quick brown dog port café quick")
+(define s1121 "quick the fox jumps ünïcödé brown brown dog quick café")
+(define s1122 "lazy jumps port λ lazy ünïcödé port fox string brown λ")
+(define s1123 "lambda port λ ünïcödé dog lazy scheme over")
+(define s1124 "ünïcödé jumps ünïcödé ünïcödé lazy lazy ünïcödé")
+(define s1125 "\"lazy the the over λ the\"")
+(define s1126 "jumps dog fox dog ünïcödé jumps fox lazy")
+(define s1127 "fox string string café lambda over the port lambda string café")
+(define s1128 "λ lazy λ café quick\t\x41;")
+(define s1129 "fox jumps port lazy café lambda jumps ünïcödé brown fox")
+(define s1130 "lambda jumps lambda the jumps scheme")
+(define s1131 "over fox dog string quick quick quick jumps string λ over")
+(define s1132 "scheme dog string λ brown\t\x41;")
+(define s1133 "over fox brown lambda quick string quick the scheme")
+(define s1134 "the lazy lambda\t\x41;")
+(define s1135 "quick scheme\t\x41;")
+(define s1136 "lazy dog quick fox lazy ünïcödé brown fox café lambda quick\n")
+(define s1137 "\"λ lazy fox the jumps quick the quick\"")
+(define s1138 "\"string the λ quick\"")
+(define s1139 "\"scheme dog ünïcödé lazy brown lambda port\"")
+(define s1140 "lazy scheme port over ünïcödé string lambda\t\x41;")
+(define s1141 "café fox scheme lambda scheme string")
+(define s1142 "\"ünïcödé brown lambda λ lambda\"")
+(define s1143 "the lazy the jumps ünïcödé quick scheme λ lambda ünïcödé over")
+(define s1144 "quick scheme quick\t\x41;")
+(define s1145 "string fox")
+(define s1146 "café the dog lambda lambda the café string café lazy over")
+(define s1147 "the brown string quick lambda brown")
+(define s1148 "\"lazy quick string lazy\"")
+(define s1149 "ünïcödé the over lazy the brown brown quick\t\x41;")
+(define s1150 "\"ünïcödé quick\"")
+(define s1151 "lambda scheme lazy over fox over dog dog lazy over dog\n")
+(define s1152 "scheme café lazy\t\x41;")
+(define s1153 "scheme the lazy over brown lazy lambda brown café\t\x41;")
+(define s1154 "scheme ünïcödé\n")
+(define s1155 "\"scheme port\"")
+(define s1156 "\"scheme dog café port\"")
+(define s1157 "brown lazy scheme")
+(define s1158 "port ünïcödé scheme over string scheme over jumps")
+(define s1159 "\"ünïcödé lazy lazy quick\"")
+(define s1160 "café lambda quick port quick port over\t\x41;")
+(define s1161 "\"over λ lazy lazy jumps fox over brown quick\"")
+(define s1162 "café dog the quick jumps scheme")
+(define s1163 "lazy string string quick lazy port ünïcödé jumps brown\n")
+(define s1164 "quick λ the over lambda port brown brown quick over\t\x41;")
+(define s1165 "string λ brown lazy dog")
+(define s1166 "jumps ünïcödé jumps jumps café\n")
+(define s1167 "brown port λ café over brown over\n")
+(define s1168 "lazy café port port port dog dog")
+(define s1169 "ünïcödé λ string fox lambda fox scheme the port")
+(define s1170 "λ over")
+(define s1171 "café fox fox jumps\t\x41;")
+(define s1172 "fox the dog the scheme port dog\t\x41;")
+(define s1173 "\"dog dog\"")
+(define s1174 "\"port the the port string lambda lambda quick dog\"")
+(define s1175 "over dog port λ quick brown brown string lazy\n")
+(define s1176 "the port quick λ fox ünïcödé quick λ port quick quick")
+(define s1177 "the café fox")
+(define s1178 "string jumps string quick over ünïcödé")
+(define s1179 "lazy jumps fox brown lambda dog over over ünïcödé\t\x41;")
+(define s1180 "jumps over jumps fox lambda scheme string lambda string")
+(define s1181 "λ fox brown jumps\n")
+(define s1182 "quick lambda ünïcödé dog lambda brown")
+(define s1183 "\"lazy lazy lazy the\"")
+(define s1184 "scheme port the brown scheme quick the quick λ jumps the")
+(define s1185 "\"ünïcödé string dog ünïcödé\"")
+(define s1186 "over over jumps over the café ünïcödé ünïcödé lambda\n")
+(define s1187 "\"café brown the lazy dog\"")
+(define s1188 "λ over over scheme λ fox fox")
+(define s1189 "λ lambda the brown string string lambda café")
+(define s1190 "\"café the scheme café the scheme the scheme quick\"")
+(define s1191 "lazy lambda the")
+(define s1192 "dog scheme port fox lazy string over the string fox lazy\t\x41;")
+(define s1193 "café café café lazy scheme lambda")
+(define s1194 "scheme scheme jumps scheme string dog\t\x41;")
+(define s1195 "λ lambda lazy café ünïcödé port brown")
+(define s1196 "fox string over quick")
+(define s1197 "the over the the")
+(define s1198 "jumps café the lambda lazy lambda port over café the")
+(define s1199 "fox dog fox port dog string string lambda lazy")
+(define s1200 "scheme lazy jumps ünïcödé\t\x41;")
+(define s1201 "lazy jumps fox port the lambda\n")
+(define s1202 "fox scheme")
+(define s1203 "\"fox jumps fox port\"")
+(define s1204 "string over port dog string string dog λ string scheme dog\n")
+(define s1205 "string café ünïcödé over over dog café over")
+(define s1206 "café the string quick")
+(define s1207 "scheme lazy")
+(define s1208 "\"λ string scheme fox scheme fox the\"")
+(define s1209 "lambda λ dog lazy lambda string fox scheme fox")
+(define s1210 "dog scheme café scheme café brown ünïcödé over lambda")
+(define s1211 "string string string λ port λ")
+(define s1212 "λ over port ünïcödé string the dog lazy dog dog\n")
+(define s1213 "port port port dog string the café λ")
+(define s1214 "quick quick the λ brown dog lambda brown scheme lazy\n")
+(define s1215 "lazy dog dog scheme the the port")
+(define s1216 "lambda λ dog string fox café")
+(define s1217 "\"λ scheme fox the\"")
+(define s1218 "\"λ string quick lambda lambda lazy ünïcödé λ over string\"")
+(define s1219 "\"λ port fox scheme fox café quick λ jumps quick quick\"")
+(define s1220 "fox the quick lazy ünïcödé ünïcödé λ string port lambda")
+(define s1221 "\"ünïcödé brown jumps string dog string λ café scheme\"")
+(define s1222 "over port string jumps string fox\n")
+(define s1223 "quick jumps\n")
+(define s1224 "lazy fox lazy")
+(define s1225 "lazy quick over brown\t\x41;")
+(define s1226 "\"fox λ port lambda lazy the λ λ\"")
+(define s1227 "the over string café fox λ over\n")
+(define s1228 "scheme the")
+(define s1229 "lambda quick")
+(define s1230 "\"café scheme jumps string string over\"")
+(define s1231 "the over port lazy jumps the dog fox")
+(define s1232 "scheme dog scheme\n")
+(define s1233 "quick lambda\t\x41;")
+(define s1234 "quick over port lazy over lambda port quick fox quick\t\x41;")
+(define s1235 "dog lambda quick lambda the string scheme lambda over string jumps\t\x41;")
+(define s1236 "λ port λ fox jumps")
+(define s1237 "quick λ fox dog the the fox over café the brown\t\x41;")
+(define s1238 "over port dog ünïcödé port lazy quick\n")
+(define s1239 "port lambda port quick port lambda λ\n")
+(define s1240 "fox lazy fox ünïcödé jumps string")
+(define s1241 "\"fox over dog string\"")
+(define s1242 "brown quick brown")
+(define s1243 "over port")
+(define s1244 "quick port over λ lambda lazy fox string")
+(define s1245 "port brown over lazy lambda the")
+(define s1246 "λ lazy jumps quick fox port fox dog ünïcödé fox string\t\x41;")
+(define s1247 "over string café scheme quick brown over quick")
+(define s1248 "jumps port café brown\t\x41;")
+(define s1249 "\"lambda café café fox dog quick the λ café port the\"")
+(define s1250 "ünïcödé fox scheme scheme dog lambda\n")
+(define s1251 "λ quick scheme café λ port lambda")
+(define s1252 "λ λ\t\x41;")
+(define s1253 "\"λ quick brown jumps string λ λ\"")
+(define s1254 "\"ünïcödé lazy\"")
+(define s1255 "ünïcödé the lambda\t\x41;")
+(define s1256 "\"dog café fox lambda quick string ünïcödé ünïcödé café the lambda\"")
+(define s1257 "jumps café quick fox jumps ünïcödé over port\n")
+(define s1258 "lambda jumps")
+(define s1259 "jumps ünïcödé quick brown fox")
+(define s1260 "lambda port jumps lazy\t\x41;")
+(define s1261 "scheme ünïcödé over jumps the lazy the port lambda lazy")
+(define s1262 "\"ünïcödé dog jumps λ ünïcödé lazy\"")
+(define s1263 "the port the ünïcödé fox string")
+(define s1264 "fox fox dog the brown ünïcödé lazy\n")
+(define s1265 "brown the")
+(define s1266 "café ünïcödé ünïcödé ünïcödé fox")
+(define s1267 "scheme brown dog")
+(define s1268 "lazy fox")
+(define s1269 "café port port fox quick scheme")
+(define s1270 "over ünïcödé\t\x41;")
+(define s1271 "λ dog dog scheme the\t\x41;")
+(define s1272 "lazy the jumps string dog quick the")
+(define s1273 "lambda quick port ünïcödé lambda lazy λ ünïcödé port fox")
+(define s1274 "over dog quick café lazy")
+(define s1275 "fox over jumps dog\t\x41;")
+(define s1276 "fox λ ünïcödé the jumps")
+(define s1277 "\"café scheme brown lazy dog port jumps scheme\"")
+(define s1278 "ünïcödé quick λ brown fox the lazy café\n")
+(define s1279 "scheme fox jumps scheme brown ünïcödé λ lazy\t\x41;")
+(define s1280 "over λ string port port fox\n")
+(define s1281 "the over jumps\t\x41;")
+(define s1282 "\"scheme the lazy\"")
+(define s1283 "over jumps λ dog lazy café")
+(define s1284 "brown lambda\t\x41;")
+(define s1285 "string café string lazy fox the the over string string")
+(define s1286 "quick scheme string quick quick jumps λ")
+(define s1287 "\"λ brown brown jumps string café the brown over fox\"")
+(define s1288 "lambda over quick café")
+(define s1289 "brown ünïcödé lazy\n")
+(define s1290 "lazy the lazy café\n")
+(define s1291 "\"the dog fox ünïcödé ünïcödé the λ\"")
+(define s1292 "\"lazy dog dog port λ fox ünïcödé brown\"")
+(define s1293 "fox over ünïcödé quick quick jumps\n")
+(define s1294 "ünïcödé scheme jumps")
+(define s1295 "ünïcödé quick scheme λ λ jumps lazy lambda dog")
+(define s1296 "\"string dog string\"")
+(define s1297 "quick scheme quick string fox over lambda lazy over string lambda\t\x41;")
+(define s1298 "\"scheme brown over scheme lambda\"")
+(define s1299 "ünïcödé ünïcödé ünïcödé quick brown fox brown string scheme ünïcödé\t\x41;")
+(define s1300 "\"over lazy fox λ quick brown jumps λ\"")
+(define s1301 "quick café café λ over fox over dog lambda café jumps")
+(define s1302 "port fox quick lambda café ünïcödé jumps over brown fox\t\x41;")
+(define s1303 "ünïcödé scheme lazy\t\x41;")
+(define s1304 "port ünïcödé dog\n")
+(define s1305 "\"ünïcödé fox lambda scheme dog brown jumps dog scheme scheme over\"")
+(define s1306 "ünïcödé scheme scheme lazy lambda brown fox jumps scheme")
+(define s1307 "scheme brown ünïcödé café over quick")
+(define s1308 "ünïcödé lazy λ brown dog string jumps scheme string\n")
+(define s1309 "ünïcödé the scheme fox quick\t\x41;")
+(define s1310 "ünïcödé scheme café fox lazy lazy brown brown brown the")
+(define s1311 "port port jumps scheme")
+(define s1312 "jumps ünïcödé λ brown")
+(define s1313 "\"over jumps scheme string jumps\"")
+(define s1314 "ünïcödé jumps λ fox over scheme brown\t\x41;")
+(define s1315 "\"scheme ünïcödé ünïcödé port lazy café jumps brown jumps\"")
+(define s1316 "brown the port string lambda fox fox")
+(define s1317 "lambda the quick string fox port\t\x41;")
+(define s1318 "port string brown")
+(define s1319 "the quick dog string string the scheme lazy quick dog quick\n")
+(define s1320 "lazy lambda\n")
+(define s1321 "\"scheme fox ünïcödé dog café café port fox\"")
+(define s1322 "\"over over fox brown port port ünïcödé\"")
+(define s1323 "over ünïcödé quick\t\x41;")
+(define s1324 "lambda string")
+(define s1325 "fox scheme lazy jumps dog over the port fox fox fox\n")
+(define s1326 "quick over ünïcödé the")
+(define s1327 "\"fox jumps café fox fox string string\"")
+(define s1328 "over fox lazy the λ scheme")
+(define s1329 "over quick ünïcödé fox brown lambda string string brown string")
+(define s1330 "\"over scheme port scheme fox\"")
+(define s1331 "café port dog brown dog")
+(define s1332 "fox café brown scheme over scheme over brown string dog λ")
+(define s1333 "port café fox scheme\n")
+(define s1334 "string ünïcödé dog string lambda jumps string café ünïcödé\t\x41;")
+(define s1335 "\"brown lambda port brown over fox lambda fox café λ\"")
+(define s1336 "over dog fox jumps jumps ünïcödé")
+(define s1337 "over port scheme quick dog brown ünïcödé brown")
+(define s1338 "quick scheme the dog the brown café jumps jumps scheme café\t\x41;")
+(define s1339 "\"quick string\"")
+(define s1340 "ünïcödé lazy string café scheme\t\x41;")
+(define s1341 "café λ dog jumps λ scheme fox brown quick λ\n")
+(define s1342 "λ café")
+(define s1343 "\"fox string lazy ünïcödé λ jumps\"")
+(define s1344 "café lambda\t\x41;")
+(define s1345 "café dog over over lambda")
+(define s1346 "\"lambda over λ the string fox λ lazy the ünïcödé\"")
+(define s1347 "over café scheme string")
+(define s1348 "string jumps λ")
+(define s1349 "lazy scheme fox the λ fox dog lazy port lambda")
+(define s1350 "over the fox ünïcödé scheme string lazy string")
+(define s1351 "ünïcödé λ café brown quick lambda over\n")
+(define s1352 "lazy string\n")
+(define s1353 "quick lazy string jumps café ünïcödé lambda brown ünïcödé string\t\x41;")
+(define s1354 "\"jumps jumps\"")
+(define s1355 "lambda the brown quick string quick")
+(define s1356 "jumps quick fox dog brown jumps the jumps")
+(define s1357 "café jumps")
+(define s1358 "λ café λ")
+(define s1359 "fox quick λ quick lazy\t\x41;")
+(define s1360 "over brown scheme λ jumps dog fox the")
+(define s1361 "over string lazy scheme ünïcödé lambda λ lambda\t\x41;")
+(define s1362 "quick fox")
+(define s1363 "\"port the fox jumps port scheme\"")
+(define s1364 "\"string λ port dog lambda fox lazy scheme café ünïcödé\"")
+(define s1365 "café dog")
+(define s1366 "lambda dog\n")
+(define s1367 "lambda λ over string dog port string quick jumps")
+(define s1368 "ünïcödé λ port café port fox lambda jumps")
+(define s1369 "the brown over brown lazy brown jumps lazy\n")
+(define s1370 "\"jumps fox the lazy scheme\"")
+(define s1371 "scheme lazy quick string ünïcödé ünïcödé quick café the quick ünïcödé\t\x41;")
+(define s1372 "λ quick brown lambda port the dog lambda fox quick ünïcödé")
+(define s1373 "brown lazy lambda quick port lazy scheme over fox port")
+(define s1374 "ünïcödé lambda café lazy lambda string brown dog lazy dog jumps\n")
+(define s1375 "\"string fox dog café quick lambda\"")
+(define s1376 "fox dog port string lambda\t\x41;")
+(define s1377 "dog lazy scheme port fox ünïcödé quick brown lambda λ")
+(define s1378 "lazy lazy brown scheme jumps fox\n")
+(define s1379 "brown scheme λ port lazy jumps jumps over λ dog")
+(define s1380 "café the jumps scheme café brown the")
+(define s1381 "café ünïcödé lambda lazy\n")
+(define s1382 "\"λ fox scheme quick lambda string λ lazy fox the fox\"")
+(define s1383 "quick café dog scheme jumps")
+(define s1384 "dog dog café string\n")
+(define s1385 "\"scheme string string scheme lambda\"")
+(define s1386 "dog jumps\t\x41;")
+(define s1387 "scheme string the\t\x41;")
+(define s1388 "lambda lazy café the dog café λ ünïcödé dog λ")
+(define s1389 "\"brown café lazy lambda café ünïcödé lambda scheme fox\"")
+(define s1390 "port lambda port the dog brown jumps over over café brown")
+(define s1391 "\"quick over the string ünïcödé\"")
+(define s1392 "ünïcödé quick dog brown scheme lambda scheme")
+(define s1393 "\"string the brown dog over quick quick dog port quick jumps\"")
+(define s1394 "fox the lazy dog λ the")
+(define s1395 "port jumps quick\n")
+(define s1396 "\"quick scheme café ünïcödé port ünïcödé lambda lazy fox quick\"")
+(define s1397 "\"ünïcödé port café string string\"")
+(define s1398 "\"dog lazy café over the λ dog scheme over\"")
+(define s1399 "the string string over scheme lazy")
+(define s1400 "\"brown café jumps lazy scheme ünïcödé λ jumps jumps lazy\"")
+(define s1401 "\"café scheme\"")
+(define s1402 "brown λ over dog fox brown")
+(define s1403 "scheme fox string over ünïcödé quick the ünïcödé the\n")
+(define s1404 "ünïcödé port café\n")
+(define s1405 "scheme quick string")
+(define s1406 "\"scheme over lazy λ quick over ünïcödé café lambda fox string\"")
+(define s1407 "\"café λ fox λ port jumps quick ünïcödé ünïcödé café\"")
+(define s1408 "café the over café string ünïcödé lambda lazy ünïcödé over\n")
+(define s1409 "\"jumps over quick quick scheme lambda over over lambda quick dog\"")
+(define s1410 "jumps fox ünïcödé lambda over brown port brown fox ünïcödé brown\n")
+(define s1411 "\"brown fox lambda λ brown over ünïcödé café jumps fox\"")
+(define s1412 "ünïcödé lambda λ dog string ünïcödé")
+(define s1413 "fox scheme string lambda the ünïcödé lambda")
+(define s1414 "dog dog quick lazy over")
+(define s1415 "\"jumps café café quick quick lazy\"")
+(define s1416 "lazy café dog string\n")
+(define s1417 "over brown dog brown dog lambda")
+(define s1418 "\"café fox\"")
+(define s1419 "brown quick ünïcödé over port jumps jumps lazy over\t\x41;")
+(define s1420 "over ünïcödé scheme λ lazy jumps café lazy string brown string")
+(define s1421 "café lazy jumps lambda scheme")
+(define s1422 "scheme scheme jumps fox lazy the lambda fox\t\x41;")
+(define s1423 "dog the")
+(define s1424 "lazy lazy")
+(define s1425 "the the brown string over port quick the brown")
+(define s1426 "fox ünïcödé café fox brown ünïcödé")
+(define s1427 "jumps scheme lazy dog fox dog jumps\n")
+(define s1428 "dog port λ dog string café string\n")
+(define s1429 "the string ünïcödé lambda ünïcödé\t\x41;")
+(define s1430 "lambda ünïcödé scheme the brown dog jumps\t\x41;")
+(define s1431 "brown port the lambda over λ scheme lazy fox brown\t\x41;")
+(define s1432 "ünïcödé lambda string port over lazy fox\t\x41;")
+(define s1433 "quick dog lambda ünïcödé")
+(define s1434 "\"over scheme port\"")
+(define s1435 "jumps over jumps dog quick ünïcödé scheme scheme")
+(define s1436 "scheme fox quick over café café over")
+(define s1437 "\"lazy λ brown the lambda scheme quick lazy the lambda port\"")
+(define s1438 "lambda scheme scheme dog dog fox λ λ brown")
+(define s1439 "ünïcödé scheme over port over dog jumps café lambda lambda λ")
+(define s1440 "\"jumps λ scheme λ jumps the string\"")
+(define s1441 "the port jumps ünïcödé")
+(define s1442 "\"quick λ string lambda lambda λ λ café\"")
+(define s1443 "λ lazy")
+(define s1444 "fox over fox over the scheme ünïcödé λ\n")
+(define s1445 "λ café jumps lambda the λ brown\t\x41;")
+(define s1446 "ünïcödé lambda λ λ café scheme")
+(define s1447 "jumps lazy the")
+(define s1448 "fox scheme brown jumps the café jumps")
+(define s1449 "café ünïcödé jumps brown fox over the fox")
+(define s1450 "\"quick scheme lazy\"")
+(define s1451 "λ the the quick")
+(define s1452 "\"brown lambda quick\"")
+(define s1453 "quick ünïcödé lambda")
+(define s1454 "café scheme fox fox lazy the fox over port quick")
+(define s1455 "scheme fox café")
+(define s1456 "\"lazy lambda quick\"")
+(define s1457 "fox string port λ string lazy lazy")
+(define s1458 "string café dog over café dog\t\x41;")
+(define s1459 "the λ string fox lambda café café")
+(define s1460 "fox over brown fox café brown quick lazy")
+(define s1461 "\"port quick fox ünïcödé dog lambda\"")
+(define s1462 "the dog dog the the over\n")
+(define s1463 "café string jumps\t\x41;")
+(define s1464 "the string fox ünïcödé quick brown ünïcödé string")
+(define s1465 "dog lambda string scheme quick λ café")
+(define s1466 "scheme brown ünïcödé fox lazy quick the over")
+(define s1467 "ünïcödé fox café jumps brown lambda ünïcödé scheme\t\x41;")
+(define s1468 "brown scheme lambda")
+(define s1469 "λ the")
+(define s1470 "ünïcödé fox\n")
+(define s1471 "lambda café dog dog")
+(define s1472 "\"ünïcödé quick jumps\"")
+(define s1473 "over brown ünïcödé dog lambda the the lazy brown quick\n")
+(define s1474 "fox lambda\n")
+(define s1475 "\"fox string\"")
+(define s1476 "lazy quick ünïcödé café ünïcödé café café brown")
+(define s1477 "café brown lambda ünïcödé quick")
+(define s1478 "ünïcödé over jumps ünïcödé port café λ λ lambda\t\x41;")
+(define s1479 "\"the lazy jumps string\"")
+(define s1480 "jumps port fox")
+(define s1481 "over string over\t\x41;")
+(define s1482 "brown string over λ λ lambda lambda string port")
+(define s1483 "café lambda quick string string port scheme brown λ")
+(define s1484 "\"port string jumps jumps dog ünïcödé the brown scheme\"")
+(define s1485 "lazy fox λ port café ünïcödé")
+(define s1486 "\"port jumps café fox jumps λ port\"")
+(define s1487 "café brown the over λ")
+(define s1488 "\"port fox over lambda\"")
+(define s1489 "\"lazy string over scheme port fox λ\"")
+(define s1490 "quick over café lambda café port quick over lambda lambda the\n")
+(define s1491 "ünïcödé scheme lazy")
+(define s1492 "λ scheme jumps brown port café lazy\n")
+(define s1493 "fox dog dog over quick dog café\t\x41;")
+(define s1494 "scheme brown lambda brown fox quick over brown café over fox\n")
+(define s1495 "lambda dog jumps fox string\t\x41;")
+(define s1496 "jumps lambda brown over brown string café fox dog")
+(define s1497 "jumps jumps scheme port jumps jumps lambda fox dog\t\x41;")
+(define s1498 "lazy lambda fox quick port")
+(define s1499 "scheme lambda over\n")
+(define s1500 "lambda string brown string string lazy quick jumps brown string\t\x41;")
+(define s1501 "the brown café the dog over jumps dog")
+(define s1502 "\"quick café lazy jumps\"")
+(define s1503 "scheme the fox over λ")
+(define s1504 "λ lambda the fox jumps port quick fox")
+(define s1505 "\"ünïcödé port scheme the over\"")
+(define s1506 "brown the fox string lambda")
+(define s1507 "string port the café lazy ünïcödé scheme port the jumps\t\x41;")
+(define s1508 "over café jumps lambda quick λ")
+(define s1509 "lazy string jumps string port ünïcödé string λ dog lambda")
+(define s1510 "port string café port λ café port string scheme quick\n")
+(define s1511 "fox café the brown λ\t\x41;")
+(define s1512 "fox dog port fox quick over lazy brown the\t\x41;")
+(define s1513 "brown string jumps over lazy lazy ünïcödé ünïcödé string λ")
+(define s1514 "λ brown string brown jumps lazy over the over string\t\x41;")
+(define s1515 "\"dog café jumps fox brown\"")
+(define s1516 "\"port brown lambda café café dog over\"")
+(define s1517 "ünïcödé lambda lambda dog")
+(define s1518 "string port lambda over café quick\n")
+(define s1519 "quick scheme café quick café the ünïcödé")
+(define s1520 "fox brown\t\x41;")
+(define s1521 "ünïcödé jumps lambda the port ünïcödé the brown")
+(define s1522 "\"quick lambda scheme λ\"")
+(define s1523 "lazy lazy scheme string dog")
+(define s1524 "café fox lambda fox port scheme lazy dog fox\n")
+(define s1525 "port jumps brown the port port lazy string dog ünïcödé brown\t\x41;")
+(define s1526 "dog over port ünïcödé")
+(define s1527 "brown ünïcödé dog port dog over string café fox scheme\t\x41;")
+(define s1528 "brown jumps fox fox scheme λ dog fox the")
+(define s1529 "quick the lazy\n")
+(define s1530 "\"λ string string dog\"")
+(define s1531 "\"ünïcödé café\"")
+(define s1532 "port ünïcödé lazy lazy fox string port dog fox\n")
+(define s1533 "\"string port scheme brown fox over brown ünïcödé quick port\"")
+(define s1534 "\"quick lambda lazy port\"")
+(define s1535 "lambda jumps port café jumps over jumps quick string")
+(define s1536 "jumps over λ lazy scheme over\t\x41;")
+(define s1537 "scheme ünïcödé λ brown ünïcödé café over fox the")
+(define s1538 "lazy quick over dog ünïcödé")
+(define s1539 "quick ünïcödé over string lambda lazy jumps ünïcödé")
+(define s1540 "string ünïcödé port string ünïcödé fox λ lazy ünïcödé quick scheme")
+(define s1541 "\"over brown\"")
+(define s1542 "λ lazy ünïcödé lambda jumps port quick quick λ")
+(define s1543 "dog string jumps\t\x41;")
+(define s1544 "over quick scheme port string lambda\t\x41;")
+(define s1545 "\"quick the\"")
+(define s1546 "\"fox scheme fox dog scheme café jumps\"")
+(define s1547 "string lazy café port scheme the\n")
+(define s1548 "scheme brown λ ünïcödé ünïcödé port ünïcödé\t\x41;")
+(define s1549 "\"ünïcödé jumps dog lambda the the café λ dog\"")
+(define s1550 "over λ over ünïcödé lazy café jumps string lambda\n")
+(define s1551 "quick jumps fox λ lambda lambda brown string lambda ünïcödé brown\n")
+(define s1552 "\"lazy quick\"")
+(define s1553 "fox quick port lazy\t\x41;")
+(define s1554 "\"fox fox scheme\"")
+(define s1555 "lambda ünïcödé")
+(define s1556 "jumps over lazy string scheme the lambda dog")
+(define s1557 "port over lazy brown")
+(define s1558 "λ lazy quick over scheme ünïcödé jumps café quick string over")
+(define s1559 "string quick port café\n")
+(define s1560 "café scheme dog brown quick the string quick quick")
+(define s1561 "string fox scheme the lambda lambda dog lambda")
+(define s1562 "fox quick over string café brown\t\x41;")
+(define s1563 "dog λ fox fox lambda lazy")
+(define s1564 "quick port jumps brown dog scheme\n")
+(define s1565 "jumps port dog port ünïcödé ünïcödé\n")
+(define s1566 "\"over over jumps\"")
+(define s1567 "lazy ünïcödé over λ quick string quick lambda scheme port lambda\n")
+(define s1568 "brown jumps fox fox\n")
+(define s1569 "ünïcödé the over quick λ quick over brown λ port")
+(define s1570 "λ over ünïcödé scheme brown ünïcödé quick jumps lambda\t\x41;")
+(define s1571 "fox dog the café string over over dog lazy\n")
+(define s1572 "dog quick lazy café lambda\t\x41;")
+(define s1573 "dog scheme λ port quick the λ the port the scheme")
+(define s1574 "\"brown ünïcödé over the quick lambda\"")
+(define s1575 "\"café lambda jumps café the\"")
+(define s1576 "\"dog over lazy ünïcödé quick lazy ünïcödé dog lambda the the\"")
+(define s1577 "\"fox string café café brown port ünïcödé\"")
+(define s1578 "\"fox quick brown fox\"")
+(define s1579 "brown port the café ünïcödé the quick fox ünïcödé brown quick\t\x41;")
+(define s1580 "brown the café café")
+(define s1581 "café lazy dog the lambda\n")
+(define s1582 "\"port scheme lazy the dog quick\"")
+(define s1583 "dog quick λ λ string fox lambda ünïcödé fox brown lazy\t\x41;")
+(define s1584 "scheme quick scheme dog dog lambda over fox jumps dog")
+(define s1585 "fox ünïcödé fox over fox over over quick port string\n")
+(define s1586 "ünïcödé string ünïcödé fox ünïcödé")
+(define s1587 "\"ünïcödé λ dog port lambda jumps fox ünïcödé lambda lambda\"")
+(define s1588 "brown jumps jumps port")
+(define s1589 "brown port dog dog jumps scheme café λ scheme lazy café")
+(define s1590 "quick lazy the port café port\n")
+(define s1591 "\"ünïcödé λ over\"")
+(define s1592 "dog brown scheme café fox café scheme ünïcödé dog")
+(define s1593 "\"ünïcödé the fox dog λ dog the ünïcödé fox dog brown\"")
+(define s1594 "the ünïcödé dog jumps string lambda brown dog\n")
+(define s1595 "ünïcödé λ scheme brown lambda brown λ ünïcödé")
+(define s1596 "\"port fox over string ünïcödé λ fox café\"")
+(define s1597 "quick quick lambda the ünïcödé string jumps brown fox\n")
+(define s1598 "λ café quick lazy lambda\n")
+(define s1599 "brown port")
+(define s1600 "\"dog fox fox\"")
+(define s1601 "jumps port λ scheme port jumps lazy")
+(define s1602 "\"λ café ünïcödé scheme string quick scheme café café\"")
+(define s1603 "lazy ünïcödé café dog quick fox jumps jumps")
+(define s1604 "ünïcödé the jumps dog ünïcödé\n")
+(define s1605 "string lazy port ünïcödé\t\x41;")
+(define s1606 "over the")
+(define s1607 "brown ünïcödé café lazy port\n")
+(define s1608 "port string dog brown port brown")
+(define s1609 "lambda brown fox over fox fox lazy jumps string café fox\t\x41;")
+(define s1610 "scheme quick jumps café café port the scheme\t\x41;")
+(define s1611 "λ lambda dog jumps the port")
+(define s1612 "\"the the jumps the\"")
+(define s1613 "jumps fox the lambda")
+(define s1614 "\"jumps lazy quick string lazy brown\"")
+(define s1615 "the ünïcödé")
+(define s1616 "dog quick the scheme\n")
+(define s1617 "over scheme lazy λ port scheme dog port string scheme")
+(define s1618 "λ string string ünïcödé quick")
+(define s1619 "λ jumps fox string over\n")
+(define s1620 "fox quick quick λ jumps\n")
+(define s1621 "dog λ scheme quick jumps lazy scheme scheme")
+(define s1622 "quick jumps lambda lambda jumps\n")
+(define s1623 "ünïcödé café quick dog over dog\n")
+(define s1624 "café dog the lambda quick quick string lambda\n")
+(define s1625 "over brown jumps lazy λ lambda\t\x41;")
+(define s1626 "\"café string brown quick λ quick lazy\"")
+(define s1627 "fox port")
+(define s1628 "\"quick ünïcödé fox string lazy lambda ünïcödé café\"")
+(define s1629 "string port fox quick lazy lambda")
+(define s1630 "fox dog lazy λ port scheme\n")
+(define s1631 "over port λ dog lambda\t\x41;")
+(define s1632 "\"jumps brown over lazy over\"")
+(define s1633 "quick dog café")
+(define s1634 "string λ the café lazy ünïcödé quick port quick jumps\t\x41;")
+(define s1635 "fox the café the lambda port brown λ\n")
+(define s1636 "\"ünïcödé over λ port lazy jumps the dog\"")
+(define s1637 "\"λ scheme brown fox ünïcödé brown\"")
+(define s1638 "lazy λ port the fox the")
+(define s1639 "over lazy jumps lazy λ λ fox the ünïcödé scheme")
+(define s1640 "jumps string scheme port ünïcödé lambda port jumps")
+(define s1641 "scheme café café café café quick dog port")
+(define s1642 "λ lazy dog brown quick fox string jumps")
+(define s1643 "scheme café fox over")
+(define s1644 "fox fox over scheme lambda lazy dog\t\x41;")
+(define s1645 "scheme string λ port port string dog quick λ quick scheme\t\x41;")
+(define s1646 "lazy string\t\x41;")
+(define s1647 "\"ünïcödé port lambda the quick λ fox café lazy\"")
+(define s1648 "\"jumps ünïcödé quick brown fox over λ\"")
+(define s1649 "café fox lazy port over\t\x41;")
+(define s1650 "scheme ünïcödé dog lazy lazy lazy\n")
+(define s1651 "scheme quick")
+(define s1652 "scheme ünïcödé dog port over dog scheme")
+(define s1653 "the port λ\t\x41;")
+(define s1654 "ünïcödé over ünïcödé over dog over jumps\n")
+(define s1655 "lazy λ quick dog string over quick")
+(define s1656 "over scheme\n")
+(define s1657 "fox over port café lambda dog λ\n")
+(define s1658 "lambda λ the café string brown dog dog fox\t\x41;")
+(define s1659 "port string dog scheme lazy fox dog brown the fox lambda")
+(define s1660 "lambda string")
+(define s1661 "jumps café quick brown the")
+(define s1662 "café ünïcödé ünïcödé lazy the scheme dog lazy\n")
+(define s1663 "\"ünïcödé brown\"")
+(define s1664 "café lazy fox ünïcödé jumps lambda\n")
+(define s1665 "ünïcödé string café\t\x41;")
+(define s1666 "fox scheme lambda\t\x41;")
+(define s1667 "ünïcödé over λ port fox dog ünïcödé ünïcödé over port brown\n")
+(define s1668 "\"dog λ scheme\"")
+(define s1669 "fox jumps")
+(define s1670 "λ the dog lazy the fox")
+(define s1671 "quick fox brown scheme fox lazy string jumps lambda lazy jumps\t\x41;")
+(define s1672 "ünïcödé dog")
+(define s1673 "\"brown brown jumps\"")
+(define s1674 "λ lambda lazy the over over")
+(define s1675 "café jumps")
+(define s1676 "scheme fox")
+(define s1677 "jumps the port lazy fox lazy the λ brown fox")
+(define s1678 "over dog scheme port the")
+(define s1679 "\"over ünïcödé fox quick dog\"")
+(define s1680 "\"over jumps brown café café quick fox\"")
+(define s1681 "lambda string λ ünïcödé dog λ λ fox quick string jumps\t\x41;")
+(define s1682 "λ lazy brown lazy")
+(define s1683 "λ ünïcödé\t\x41;")
+(define s1684 "café lazy")
+(define s1685 "scheme the\n")
+(define s1686 "lambda jumps jumps café fox jumps dog string fox quick string\t\x41;")
+(define s1687 "dog the brown quick port scheme dog")
+(define s1688 "over brown")
+(define s1689 "brown over\t\x41;")
+(define s1690 "quick λ over quick scheme the fox café jumps λ\n")
+(define s1691 "port port jumps brown quick scheme lazy")
+(define s1692 "port string over scheme string the the port lambda")
+(define s1693 "λ jumps café")
+(define s1694 "\"λ string over dog the ünïcödé café scheme port the over\"")
+(define s1695 "jumps brown λ string\t\x41;")
+(define s1696 "jumps port\t\x41;")
+(define s1697 "port quick quick quick the jumps lazy scheme quick")
+(define s1698 "jumps ünïcödé ünïcödé jumps\t\x41;")
+(define s1699 "\"quick the café ünïcödé port the fox\"")
+(define s1700 "fox scheme brown over string jumps café café jumps café\t\x41;")
+(define s1701 "λ café jumps string")
+(define s1702 "\"the dog λ café fox scheme café lambda\"")
+(define s1703 "the scheme the lambda dog quick fox lambda scheme lambda lambda\t\x41;")
+(define s1704 "quick lazy λ the")
+(define s1705 "the café lambda lambda jumps\n")
+(define s1706 "dog lambda")
+(define s1707 "\"lambda string lazy café café over λ scheme jumps ünïcödé\"")
+(define s1708 "scheme quick string brown quick quick fox the over\t\x41;")
+(define s1709 "\"jumps ünïcödé dog quick quick over lazy dog scheme dog\"")
+(define s1710 "dog ünïcödé port ünïcödé")
+(define s1711 "λ jumps café lambda jumps λ lambda jumps jumps\t\x41;")
+(define s1712 "port lazy quick string fox brown fox scheme lazy string")
+(define s1713 "café the the lambda ünïcödé brown jumps port jumps the quick")
+(define s1714 "λ brown jumps scheme quick lazy string string brown ünïcödé\t\x41;")
+(define s1715 "café dog lazy scheme\n")
+(define s1716 "the string λ lambda quick scheme over ünïcödé jumps fox jumps\t\x41;")
+(define s1717 "\"jumps dog dog λ string ünïcödé port port\"")
+(define s1718 "brown lambda port jumps scheme café")
+(define s1719 "ünïcödé jumps café quick the\n")
+(define s1720 "lambda λ lambda lazy lazy λ brown dog café lazy ünïcödé")
+(define s1721 "café scheme\t\x41;")
+(define s1722 "fox dog ünïcödé fox string café dog lambda lambda λ\n")
+(define s1723 "\"scheme jumps over brown\"")
+(define s1724 "\"ünïcödé the\"")
+(define s1725 "port dog port ünïcödé λ scheme dog lazy\n")
+(define s1726 "the jumps the λ")
+(define s1727 "jumps λ café fox café scheme\t\x41;")
+(define s1728 "λ fox lambda lazy fox\t\x41;")
+(define s1729 "string ünïcödé string dog λ")
+(define s1730 "lazy lambda jumps λ scheme lambda scheme ünïcödé")
+(define s1731 "brown port lazy port")
+(define s1732 "\"lambda brown lambda ünïcödé ünïcödé\"")
+(define s1733 "λ the dog string quick λ ünïcödé fox fox λ\n")
+(define s1734 "port jumps string café lambda")
+(define s1735 "string lazy ünïcödé over café fox brown lazy dog ünïcödé ünïcödé")
+(define s1736 "port dog fox the café the lambda")
+(define s1737 "\"the the string λ lambda fox lambda port\"")
+(define s1738 "lambda lazy ünïcödé quick port")
+(define s1739 "over the port the string\t\x41;")
+(define s1740 "scheme ünïcödé over quick lambda lambda jumps brown λ")
+(define s1741 "lambda jumps jumps quick jumps fox ünïcödé brown")
+(define s1742 "fox the scheme\t\x41;")
+(define s1743 "quick port ünïcödé")
+(define s1744 "\"fox fox scheme ünïcödé the over λ fox jumps\"")
+(define s1745 "λ string scheme brown port over")
+(define s1746 "\"jumps ünïcödé\"")
+(define s1747 "fox dog ünïcödé scheme the dog jumps fox\t\x41;")
+(define s1748 "string brown\n")
+(define s1749 "\"dog lambda λ scheme lazy brown jumps quick string over over\"")
+(define s1750 "lazy the port brown lazy brown λ scheme scheme quick ünïcödé\n")
+(define s1751 "fox the jumps quick string ünïcödé\t\x41;")
+(define s1752 "quick the dog jumps fox scheme")
+(define s1753 "lambda lazy café brown λ café over jumps\n")
+(define s1754 "café quick scheme string fox λ")
+(define s1755 "\"lazy the ünïcödé quick lambda café\"")
+(define s1756 "\"the ünïcödé the λ lazy over port\"")
+(define s1757 "\"café scheme over jumps lambda λ dog λ λ brown café\"")
+(define s1758 "café scheme fox ünïcödé λ ünïcödé café over brown string string")
+(define s1759 "jumps ünïcödé scheme brown over the jumps dog brown")
+(define s1760 "\"jumps lazy lazy scheme café\"")
+(define s1761 "dog ünïcödé lazy ünïcödé brown brown the the the\t\x41;")
+(define s1762 "fox string")
+(define s1763 "jumps lambda lazy scheme lazy café lambda lambda port\n")
+(define s1764 "quick brown\t\x41;")
+(define s1765 "port λ over jumps lambda string port")
+(define s1766 "\"fox jumps λ ünïcödé café scheme lazy\"")
+(define s1767 "the quick string\t\x41;")
+(define s1768 "\"lazy quick fox café café string\"")
+(define s1769 "\"λ dog\"")
+(define s1770 "port port quick dog scheme brown café scheme λ the brown")
+(define s1771 "scheme brown fox\t\x41;")
+(define s1772 "λ fox ünïcödé string")
+(define s1773 "jumps over brown jumps\t\x41;")
+(define s1774 "lambda jumps ünïcödé lazy brown brown")
+(define s1775 "\"scheme the lambda string fox\"")
+(define s1776 "the café")
+(define s1777 "brown string quick dog lazy the")
+(define s1778 "dog café lazy quick port port brown quick lazy")
+(define s1779 "port lambda dog fox ünïcödé port brown lambda café")
+(define s1780 "the café scheme port fox fox dog jumps lambda\t\x41;")
+(define s1781 "port λ scheme lambda λ fox brown over\t\x41;")
+(define s1782 "fox port lazy lazy dog")
+(define s1783 "\"brown ünïcödé lazy lambda fox\"")
+(define s1784 "fox over café fox ünïcödé lambda fox lazy port jumps jumps")
+(define s1785 "quick lambda λ string")
+(define s1786 "over dog")
+(define s1787 "lazy dog over quick over\t\x41;")
+(define s1788 "dog quick the port ünïcödé port lambda")
+(define s1789 "jumps over scheme café lazy fox brown café\n")
+(define s1790 "port jumps scheme lambda fox λ lazy the port fox string")
+(define s1791 "café café port")
+(define s1792 "the brown brown dog\t\x41;")
+(define s1793 "jumps dog café over the café")
+(define s1794 "jumps jumps over dog λ\t\x41;")
+(define s1795 "brown quick ünïcödé scheme jumps lazy over λ quick café\n")
+(define s1796 "string quick ünïcödé brown quick fox fox lambda the")
+(define s1797 "λ string dog jumps quick over lambda quick")
+(define s1798 "the the λ over λ lazy")
+(define s1799 "\"λ lazy café café brown ünïcödé port over dog\"")
+(define s1800 "lambda brown ünïcödé over over over string\n")
+(define s1801 "\"jumps fox\"")
+(define s1802 "fox port fox")
+(define s1803 "scheme brown brown brown string over\t\x41;")
+(define s1804 "quick the dog\t\x41;")
+(define s1805 "string the the fox café over ünïcödé port brown")
+(define s1806 "port string brown\n")
+(define s1807 "lambda string café")
+(define s1808 "the the fox brown scheme café scheme λ brown lambda\t\x41;")
+(define s1809 "port brown quick λ lazy scheme dog λ café string scheme")
+(define s1810 "fox brown brown ünïcödé over fox\t\x41;")
+(define s1811 "quick λ quick string lazy\t\x41;")
+(define s1812 "brown lambda dog jumps ünïcödé port")
+(define s1813 "\"λ quick scheme\"")
+(define s1814 "over the brown brown café fox string\n")
+(define s1815 "ünïcödé over brown")
+(define s1816 "lazy café lazy brown")
+(define s1817 "fox café scheme string string λ dog\n")
+(define s1818 "lazy lazy lambda port fox")
+(define s1819 "jumps jumps string lambda jumps scheme fox")
+(define s1820 "quick quick fox lambda λ string fox string lazy")
+(define s1821 "jumps string café brown dog dog ünïcödé the port")
+(define s1822 "ünïcödé café ünïcödé dog scheme fox over ünïcödé scheme\t\x41;")
+(define s1823 "\"λ ünïcödé string café scheme λ port string jumps scheme λ\"")
+(define s1824 "λ quick scheme ünïcödé dog brown quick brown brown scheme")
+(define s1825 "fox port brown λ scheme the quick")
+(define s1826 "brown dog the lambda string")
+(define s1827 "lazy lambda")
+(define s1828 "dog fox over the dog λ\n")
+(define s1829 "over lambda lazy\t\x41;")
+(define s1830 "over λ jumps scheme brown café dog λ")
+(define s1831 "café lambda brown café jumps\t\x41;")
+(define s1832 "café port lazy")
+(define s1833 "string lazy brown")
+(define s1834 "ünïcödé lazy lazy fox\t\x41;")
+(define s1835 "\"café λ fox scheme\"")
+(define s1836 "port lazy brown lazy port λ lambda lambda λ")
+(define s1837 "\"string fox ünïcödé over café\"")
+(define s1838 "string fox lambda over λ ünïcödé quick café jumps scheme port")
+(define s1839 "jumps dog ünïcödé port")
+(define s1840 "dog port")
+(define s1841 "the café lambda brown quick lazy\t\x41;")
+(define s1842 "ünïcödé the fox λ over dog brown string brown café")
+(define s1843 "brown λ the fox over")
+(define s1844 "λ string ünïcödé")
+(define s1845 "dog quick ünïcödé fox jumps\n")
+(define s1846 "lambda port\t\x41;")
+(define s1847 "lambda lazy fox over lazy quick")
+(define s1848 "\"fox jumps port dog string quick port ünïcödé\"")
+(define s1849 "\"over the\"")
+(define s1850 "jumps string over over\n")
+(define s1851 "\"quick string port string jumps scheme scheme\"")
+(define s1852 "\"quick λ brown over\"")
+(define s1853 "brown ünïcödé lazy fox brown lambda port scheme over fox\n")
+(define s1854 "ünïcödé port the lambda brown scheme port brown")
+(define s1855 "over the jumps string dog\t\x41;")
+(define s1856 "\"string over over over over fox string quick quick dog string\"")
+(define s1857 "\"café fox\"")
+(define s1858 "λ scheme café jumps lazy string brown café port")
+(define s1859 "dog over jumps jumps the string string λ lazy")
+(define s1860 "port lambda brown")
+(define s1861 "\"fox port\"")
+(define s1862 "over brown café scheme λ port λ café port")
+(define s1863 "\"string ünïcödé λ jumps port lambda ünïcödé λ ünïcödé\"")
+(define s1864 "fox string jumps lambda lazy port over brown café brown jumps")
+(define s1865 "brown fox the port café string port dog quick quick\t\x41;")
+(define s1866 "\"scheme quick fox dog lazy jumps over fox scheme lambda\"")
+(define s1867 "brown brown lazy λ quick jumps string")
+(define s1868 "lambda the fox café λ lazy fox scheme fox brown\t\x41;")
+(define s1869 "quick lazy ünïcödé\n")
+(define s1870 "dog λ λ over lazy scheme")
+(define s1871 "dog the the\t\x41;")
+(define s1872 "λ dog quick quick ünïcödé fox the")
+(define s1873 "port lazy the lambda lazy café ünïcödé")
+(define s1874 "brown lazy scheme jumps dog café λ dog lambda ünïcödé fox\n")
+(define s1875 "lazy fox port over the quick lazy\t\x41;")
+(define s1876 "port over quick")
+(define s1877 "quick dog café lambda ünïcödé lazy")
+(define s1878 "scheme jumps λ")
+(define s1879 "\"λ fox ünïcödé quick ünïcödé\"")
+(define s1880 "ünïcödé lazy λ dog")
+(define s1881 "lambda quick port")
+(define s1882 "port port lambda dog lambda lazy the over jumps")
+(define s1883 "jumps brown lazy string brown λ scheme lambda fox lambda")
+(define s1884 "quick café the string lambda fox jumps jumps over\t\x41;")
+(define s1885 "quick over the string")
+(define s1886 "dog the scheme the")
+(define s1887 "\"lambda string over over port lambda the dog fox port dog\"")
+(define s1888 "string dog dog the λ lambda λ")
+(define s1889 "brown the lazy port lazy over λ fox λ fox ünïcödé")
+(define s1890 "λ brown port\n")
+(define s1891 "brown lambda port ünïcödé scheme")
+(define s1892 "jumps scheme port café quick brown port")
+(define s1893 "fox scheme lazy λ over over")
+(define s1894 "café string jumps scheme scheme port dog quick scheme\t\x41;")
+(define s1895 "brown scheme port λ dog scheme ünïcödé over lambda dog string")
+(define s1896 "scheme lazy the the string\t\x41;")
+(define s1897 "lambda lambda port quick")
+(define s1898 "café fox café the over ünïcödé brown\t\x41;")
+(define s1899 "dog quick over brown quick quick jumps λ lambda lazy port\n")
+(define s1900 "\"jumps the the café λ string over café string string\"")
+(define s1901 "dog café λ brown")
+(define s1902 "scheme jumps over fox over dog café jumps")
+(define s1903 "lambda jumps λ port lambda brown dog the brown ünïcödé fox\n")
+(define s1904 "lambda λ lazy lazy string lazy the fox")
+(define s1905 "fox port port λ lazy fox over lambda")
+(define s1906 "jumps fox λ\t\x41;")
+(define s1907 "the string port quick")
+(define s1908 "\"quick port port quick lambda the\"")
+(define s1909 "\"jumps dog quick quick ünïcödé dog quick\"")
+(define s1910 "lambda port jumps lambda string café\t\x41;")
+(define s1911 "string lazy over fox the the over the jumps fox string\n")
+(define s1912 "lazy quick brown the lambda quick λ ünïcödé")
+(define s1913 "the scheme ünïcödé fox scheme")
+(define s1914 "λ quick quick café dog quick quick scheme the fox\t\x41;")
+(define s1915 "port string brown λ scheme lazy lazy jumps lazy ünïcödé port")
+(define s1916 "over lambda port lazy\t\x41;")
+(define s1917 "brown lazy the scheme quick λ fox lazy\t\x41;")
+(define s1918 "λ jumps quick lazy lazy ünïcödé brown")
+(define s1919 "port λ dog λ ünïcödé fox café jumps dog")
+(define s1920 "\"over quick over ünïcödé fox jumps lambda quick scheme café lambda\"")
+(define s1921 "\"jumps quick λ dog lambda string lazy fox port quick\"")
+(define s1922 "\"ünïcödé jumps fox string the café\"")
+(define s1923 "ünïcödé scheme scheme ünïcödé fox café\t\x41;")
+(define s1924 "jumps lazy port λ jumps")
+(define s1925 "scheme the café quick\n")
+(define s1926 "quick lazy dog ünïcödé dog scheme")
+(define s1927 "ünïcödé lazy dog scheme jumps jumps jumps quick the")
+(define s1928 "port dog λ\n")
+(define s1929 "quick jumps brown string ünïcödé\t\x41;")
+(define s1930 "fox café fox brown dog λ jumps fox λ")
+(define s1931 "lambda lazy quick café over café port brown lambda lambda\n")
+(define s1932 "lazy λ scheme\t\x41;")
+(define s1933 "jumps brown λ fox over brown port")
+(define s1934 "\"fox over scheme lazy ünïcödé fox port the quick brown jumps\"")
+(define s1935 "café fox λ ünïcödé brown the the café over\t\x41;")
+(define s1936 "λ fox quick λ port λ ünïcödé scheme\t\x41;")
+(define s1937 "over lambda string")
+(define s1938 "over dog the the café lambda lambda ünïcödé café\n")
+(define s1939 "ünïcödé ünïcödé λ dog the café scheme dog")
+(define s1940 "café quick")
+(define s1941 "brown lazy\n")
+(define s1942 "over fox jumps dog the scheme λ\t\x41;")
+(define s1943 "quick brown port port ünïcödé port port ünïcödé over\t\x41;")
+(define s1944 "\"jumps ünïcödé ünïcödé the brown over over quick\"")
+(define s1945 "\"over lazy brown string lazy jumps over brown brown lambda\"")
+(define s1946 "the lambda quick fox lambda dog port brown\t\x41;")
+(define s1947 "dog jumps brown the over λ λ quick the brown\n")
+(define s1948 "port string café the")
+(define s1949 "\"over dog fox port\"")
+(define s1950 "\"lambda lazy lambda scheme\"")
+(define s1951 "scheme over")
+(define s1952 "λ brown brown fox string over scheme port jumps ünïcödé jumps")
+(define s1953 "\"quick lazy ünïcödé ünïcödé over port port over brown\"")
+(define s1954 "\"lazy lambda dog jumps fox the quick dog port\"")
+(define s1955 "fox string\t\x41;")
+(define s1956 "jumps lazy lazy ünïcödé over the lazy lazy port brown scheme")
+(define s1957 "\"port brown quick dog café café fox café brown lambda\"")
+(define s1958 "λ ünïcödé quick dog port lambda jumps over")
+(define s1959 "quick ünïcödé quick quick ünïcödé")
+(define s1960 "over the lambda\n")
+(define s1961 "\"dog port over lazy\"")
+(define s1962 "brown over lazy")
+(define s1963 "dog lambda jumps")
+(define s1964 "dog port dog ünïcödé port\t\x41;")
+(define s1965 "lazy λ scheme over dog string\n")
+(define s1966 "lazy the lambda string the over")
+(define s1967 "ünïcödé the fox port")
+(define s1968 "string jumps café lazy brown port brown café fox fox jumps\n")
+(define s1969 "jumps scheme brown λ brown")
+(define s1970 "\"dog dog scheme\"")
+(define s1971 "\"port lazy over string quick\"")
+(define s1972 "over ünïcödé")
+(define s1973 "lazy dog jumps café string port port lambda café lambda lambda")
+(define s1974 "quick scheme fox scheme café scheme jumps\n")
+(define s1975 "quick over lazy quick fox over\t\x41;")
+(define s1976 "fox jumps string quick café ünïcödé over lazy fox\n")
+(define s1977 "jumps fox port port over string dog the string over lambda\n")
+(define s1978 "\"dog string dog ünïcödé port lazy λ quick brown dog scheme\"")
+(define s1979 "fox scheme over")
+(define s1980 "λ fox brown café jumps jumps jumps brown over café\n")
+(define s1981 "\"lambda lazy café café jumps the the ünïcödé\"")
+(define s1982 "\"brown scheme port fox dog over jumps\"")
+(define s1983 "scheme string ünïcödé ünïcödé dog")
+(define s1984 "brown lambda the brown lambda quick café lazy string dog")
+(define s1985 "lazy the over scheme")
+(define s1986 "the lazy café fox lambda the the dog the string lambda\n")
+(define s1987 "brown lazy lambda port the brown lazy the")
+(define s1988 "the quick string over brown fox café string ünïcödé\t\x41;")
+(define s1989 "string over over dog λ lambda over scheme string fox")
+(define s1990 "fox ünïcödé fox brown the port dog jumps café port jumps\n")
+(define s1991 "lambda quick port lambda café café port the quick\n")
+(define s1992 "scheme café string café string port fox fox\t\x41;")
+(define s1993 "over lazy brown fox string lazy the λ brown\n")
+(define s1994 "brown scheme lambda ünïcödé ünïcödé lambda jumps brown café ünïcödé port")
+(define s1995 "over quick λ\n")
+(define s1996 "\"dog jumps string\"")
+(define s1997 "string quick lazy port")
+(define s1998 "port lambda port brown jumps lambda")
+(define s1999 "fox brown café λ jumps scheme lambda port lazy\n")
+(define s2000 "\"ünïcödé quick string lambda lambda scheme λ fox lazy port\"")
+(define s2001 "\"port scheme\"")
+(define s2002 "port fox lazy string port")
+(define s2003 "over lambda brown ünïcödé lazy lazy ünïcödé")
+(define s2004 "fox ünïcödé over dog quick the lambda λ\n")
+(define s2005 "string the fox lambda λ port lazy quick\t\x41;")
+(define s2006 "café scheme jumps brown café")
+(define s2007 "over string lazy ünïcödé fox over over fox fox the\n")
+(define s2008 "lambda lazy lazy quick\n")
+(define s2009 "port λ café port quick the")
+(define s2010 "brown dog the λ dog\t\x41;")
+(define s2011 "dog fox string quick café port jumps")
+(define s2012 "the quick quick jumps the quick")
+(define s2013 "over the brown quick scheme scheme brown λ brown jumps\t\x41;")
+(define s2014 "\"café over jumps lazy\"")
+(define s2015 "\"lazy port brown quick ünïcödé\"")
+(define s2016 "the string lambda over lambda fox quick jumps jumps string")
+(define s2017 "string port jumps over café dog lambda scheme\n")
+(define s2018 "\"brown the lambda ünïcödé the over port λ quick\"")
+(define s2019 "the dog lazy café scheme dog ünïcödé string λ over scheme\t\x41;")
+(define s2020 "scheme quick lazy port\n")
+(define s2021 "dog scheme jumps over quick scheme quick brown string")
+(define s2022 "\"lambda café\"")
+(define s2023 "ünïcödé string café lazy dog over the brown")
+(define s2024 "\"string café quick brown lambda λ port brown fox lambda jumps\"")
+(define s2025 "dog jumps the lambda port scheme ünïcödé\n")
+(define s2026 "dog over dog string")
+(define s2027 "\"port quick\"")
+(define s2028 "jumps port scheme scheme λ brown\t\x41;")
+(define s2029 "\"port brown lazy\"")
+(define s2030 "lambda lambda jumps port ünïcödé dog café string brown scheme port\n")
+(define s2031 "\"λ lazy the scheme café jumps port string the\"")
+(define s2032 "jumps fox brown ünïcödé brown quick fox lambda lambda string fox")
+(define s2033 "ünïcödé quick port ünïcödé lazy over brown dog jumps lazy")
+(define s2034 "\"dog jumps\"")
+(define s2035 "\"lazy quick lazy\"")
+(define s2036 "jumps λ jumps brown café ünïcödé quick brown fox")
+(define s2037 "jumps brown")
+(define s2038 "string quick ünïcödé dog lambda")
+(define s2039 "brown lazy over string over scheme fox café brown string")
+(define s2040 "brown λ λ port fox\n")
+(define s2041 "café port")
+(define s2042 "port ünïcödé scheme scheme ünïcödé lazy fox lambda lazy brown the")
+(define s2043 "\"fox ünïcödé brown quick the scheme the ünïcödé port λ\"")
+(define s2044 "quick string lazy the dog\t\x41;")
+(define s2045 "jumps string ünïcödé scheme ünïcödé café λ lazy café scheme")
+(define s2046 "\"dog λ string port jumps lambda fox port scheme quick jumps\"")
+(define s2047 "quick jumps λ port café fox λ lambda fox jumps jumps\t\x41;")
+(define s2048 "jumps fox port quick lazy fox fox\t\x41;")
+(define s2049 "string scheme λ jumps\t\x41;")
+(define s2050 "port λ lambda string λ\n")
+(define s2051 "over λ string ünïcödé")
+(define s2052 "λ dog lazy ünïcödé café lazy over dog café over fox")
+(define s2053 "λ jumps string string λ café string ünïcödé")
+(define s2054 "string quick scheme\t\x41;")
+(define s2055 "\"string port port over the fox café over port\"")
+(define s2056 "scheme fox lazy λ\t\x41;")
+(define s2057 "fox lambda lazy lambda port")
+(define s2058 "string the café jumps the scheme over lambda lambda over brown\n")
+(define s2059 "\"jumps jumps\"")
+(define s2060 "\"quick lazy fox string fox\"")
+(define s2061 "ünïcödé string jumps café over café dog dog")
+(define s2062 "ünïcödé over over fox brown ünïcödé lambda ünïcödé")
+(define s2063 "fox quick the scheme dog string brown the jumps jumps\t\x41;")
+(define s2064 "café café over string ünïcödé")
+(define s2065 "quick λ dog string dog ünïcödé scheme λ fox over")
+(define s2066 "\"fox jumps the\"")
+(define s2067 "λ port string jumps quick λ lazy\t\x41;")
+(define s2068 "ünïcödé port the lambda λ café fox jumps\t\x41;")
+(define s2069 "dog lambda ünïcödé dog string lazy scheme lazy fox\n")
+(define s2070 "ünïcödé scheme quick")
+(define s2071 "string ünïcödé dog lambda quick brown quick dog quick lazy fox")
+(define s2072 "λ ünïcödé fox lazy the")
+(define s2073 "\"lazy ünïcödé string dog jumps over jumps quick over\"")
+(define s2074 "scheme lambda quick lambda fox jumps scheme brown lazy over\n")
+(define s2075 "λ quick string quick\n")
+(define s2076 "brown λ fox ünïcödé string scheme lazy jumps")
+(define s2077 "brown ünïcödé café lazy\t\x41;")
+(define s2078 "lambda dog lambda string brown string\n")
+(define s2079 "\"jumps port\"")
+(define s2080 "\"over scheme brown café quick string over lambda brown ünïcödé the\"")
+(define s2081 "quick lazy jumps dog quick string")
+(define s2082 "jumps café λ scheme brown dog scheme scheme λ brown")
+(define s2083 "jumps lambda port the café\n")
+(define s2084 "fox quick λ the fox lambda port lazy jumps")
+(define s2085 "café string port fox over over λ λ quick")
+(define s2086 "over over λ")
+(define s2087 "jumps scheme fox over λ")
+(define s2088 "λ lambda quick lazy port")
+(define s2089 "quick ünïcödé scheme café\n")
+(define s2090 "lazy dog lambda lambda")
+(define s2091 "dog ünïcödé fox jumps")
+(define s2092 "café lambda jumps quick")
+(define s2093 "quick café")
+(define s2094 "over port lambda port lambda over\n")
+(define s2095 "λ port scheme lambda jumps port café café brown café ünïcödé")
+(define s2096 "string brown ünïcödé ünïcödé ünïcödé scheme over the")
+(define s2097 "dog lambda λ scheme lambda lazy fox string fox")
+(define s2098 "lambda jumps brown fox café string over over fox quick the")
+(define s2099 "brown over λ café lambda lambda fox quick fox the")
+(define s2100 "λ lambda dog")
+(define s2101 "jumps brown λ λ λ lazy the café ünïcödé λ")
+(define s2102 "scheme lambda café lambda")
+(define s2103 "string lambda port dog\t\x41;")
+(define s2104 "jumps string lambda the the dog quick ünïcödé\t\x41;")
+(define s2105 "\"scheme dog lazy café café lambda lazy\"")
+(define s2106 "port fox quick λ jumps dog café\n")
+(define s2107 "\"string café\"")
+(define s2108 "\"λ fox fox ünïcödé over fox jumps café scheme\"")
+(define s2109 "port café quick café string\n")
+(define s2110 "lazy scheme")
+(define s2111 "lambda over\t\x41;")
+(define s2112 "scheme dog jumps jumps fox brown")
+(define s2113 "lambda string port ünïcödé fox string lazy brown jumps")
+(define s2114 "brown over lazy over over")
+(define s2115 "café jumps string the scheme")
+(define s2116 "café lazy brown the string\n")
+(define s2117 "ünïcödé café dog quick lazy brown port quick ünïcödé ünïcödé\n")
+(define s2118 "café dog brown brown quick\t\x41;")
+(define s2119 "port over λ over café λ\t\x41;")
+(define s2120 "ünïcödé dog ünïcödé the brown scheme jumps\n")
+(define s2121 "the jumps jumps port dog quick lambda λ lazy café jumps")
+(define s2122 "scheme ünïcödé quick lazy over fox port dog lazy fox fox\n")
+(define s2123 "lambda quick dog over")
+(define s2124 "scheme λ jumps jumps")
+(define s2125 "λ dog scheme café scheme fox over string jumps\t\x41;")
+(define s2126 "quick quick string quick")
+(define s2127 "café string café port ünïcödé brown\t\x41;")
+(define s2128 "dog quick ünïcödé brown over fox jumps port λ")
+(define s2129 "\"fox λ\"")
+(define s2130 "\"over lazy string\"")
+(define s2131 "port jumps scheme")
+(define s2132 "over fox string over the ünïcödé ünïcödé")
+(define s2133 "\"λ string string\"")
+(define s2134 "port lazy the dog λ")
+(define s2135 "λ brown lazy fox")
+(define s2136 "lazy fox dog λ jumps lambda")
+(define s2137 "\"the jumps lazy the over jumps\"")
+(define s2138 "scheme the over λ lambda dog the\t\x41;")
+(define s2139 "ünïcödé jumps")
+(define s2140 "\"fox brown brown lazy λ fox the lambda over brown scheme\"")
+(define s2141 "ünïcödé port scheme port lambda café the\t\x41;")
+(define s2142 "λ λ brown fox brown\t\x41;")
+(define s2143 "café dog scheme scheme café string fox")
+(define s2144 "quick ünïcödé port the brown")
+(define s2145 "jumps scheme ünïcödé port café\t\x41;")
+(define s2146 "dog lazy ünïcödé over string café quick lazy over")
+(define s2147 "ünïcödé jumps quick jumps over lambda ünïcödé scheme\n")
+(define s2148 "brown jumps brown port brown λ λ")
+(define s2149 "scheme lambda ünïcödé jumps scheme fox ünïcödé")
+(define s2150 "brown ünïcödé scheme jumps café string the ünïcödé quick\n")
+(define s2151 "scheme over string jumps ünïcödé lambda ünïcödé the over ünïcödé")
+(define s2152 "lazy fox dog the over ünïcödé λ")
+(define s2153 "café ünïcödé port λ over string")
+(define s2154 "scheme fox lazy lambda dog café over\n")
+(define s2155 "café string brown scheme λ lambda the brown")
+(define s2156 "\"the over quick over lambda string port\"")
+(define s2157 "scheme fox lambda port ünïcödé jumps fox scheme quick fox\t\x41;")
+(define s2158 "quick dog ünïcödé port brown jumps café jumps λ jumps\t\x41;")
+(define s2159 "dog brown quick port over lazy scheme fox over")
+(define s2160 "dog scheme brown scheme over port jumps lambda jumps")
+(define s2161 "lazy jumps string dog brown port port lazy over over")
+(define s2162 "\"string ünïcödé λ the fox over fox λ\"")
+(define s2163 "string port the port λ\t\x41;")
+(define s2164 "lazy scheme café string jumps the brown\t\x41;")
+(define s2165 "\"fox scheme lazy over\"")
+(define s2166 "\"dog jumps\"")
+(define s2167 "\"brown over\"")
+(define s2168 "fox scheme scheme lambda lazy quick port lambda port λ\n")
+(define s2169 "lambda quick")
+(define s2170 "ünïcödé dog café ünïcödé café fox\t\x41;")
+(define s2171 "quick brown\n")
+(define s2172 "scheme ünïcödé port")
+(define s2173 "dog jumps brown\n")
+(define s2174 "scheme brown brown λ lazy café quick dog the string lambda\n")
+(define s2175 "café fox the café")
+(define s2176 "\"port the scheme λ café lambda café λ λ the port\"")
+(define s2177 "café the the the jumps λ ünïcödé dog dog dog\t\x41;")
+(define s2178 "λ λ port\t\x41;")
+(define s2179 "string lambda lambda the scheme lambda port fox string fox string\n")
+(define s2180 "scheme ünïcödé over lambda port quick string café port\n")
+(define s2181 "the scheme")
+(define s2182 "café lazy lazy port scheme dog lazy dog ünïcödé quick scheme")
+(define s2183 "quick port scheme string")
+(define s2184 "brown λ over string scheme string lambda over\n")
+(define s2185 "port port dog fox scheme scheme quick fox λ dog dog")
+(define s2186 "\"brown port port lazy quick over quick port brown λ scheme\"")
+(define s2187 "over the lambda café string the over brown the ünïcödé")
+(define s2188 "scheme ünïcödé jumps lambda")
+(define s2189 "quick scheme\t\x41;")
+(define s2190 "\"over fox over\"")
+(define s2191 "lazy lazy quick jumps\n")
+(define s2192 "λ dog")
+(define s2193 "λ café the jumps over string λ fox")
+(define s2194 "jumps port the port\t\x41;")
+(define s2195 "café scheme port brown café over dog jumps over fox\n")
+(define s2196 "jumps λ λ fox")
+(define s2197 "jumps scheme lambda port lambda brown lambda ünïcödé")
+(define s2198 "port café over café over ünïcödé quick λ brown lazy over\n")
+(define s2199 "dog café the over lazy café ünïcödé ünïcödé\t\x41;")
+(define s2200 "\"lambda jumps dog λ jumps the jumps scheme port\"")
+(define s2201 "brown lambda scheme ünïcödé")
+(define s2202 "scheme ünïcödé brown ünïcödé jumps port jumps dog")
+(define s2203 "\"fox port lambda café ünïcödé lazy quick\"")
+(define s2204 "quick port λ ünïcödé\n")
+(define s2205 "ünïcödé the")
+(define s2206 "brown lazy quick\n")
+(define s2207 "\"port brown dog port jumps string ünïcödé the over over\"")
+(define s2208 "lazy dog dog ünïcödé brown string string over over brown\n")
+(define s2209 "port dog port jumps café string ünïcödé the\t\x41;")
+(define s2210 "\"lazy café lambda quick fox brown string over café dog jumps\"")
+(define s2211 "café lazy jumps lazy port café scheme λ café\t\x41;")
+(define s2212 "scheme jumps port port string fox λ ünïcödé quick the brown")
+(define s2213 "\"string fox port fox lazy scheme\"")
+(define s2214 "dog λ dog café dog")
+(define s2215 "café café brown jumps string λ scheme λ")
+(define s2216 "lazy lambda fox jumps λ ünïcödé the lambda quick")
+(define s2217 "scheme scheme lazy fox lambda lambda λ")
+(define s2218 "the fox jumps dog ünïcödé λ the λ")
+(define s2219 "\"lambda brown string\"")
+(define s2220 "ünïcödé ünïcödé string port")
+(define s2221 "string brown lambda port the dog ünïcödé fox")
+(define s2222 "scheme brown brown λ ünïcödé string")
+(define s2223 "\"dog fox lazy the\"")
+(define s2224 "\"lambda jumps lazy lazy ünïcödé fox jumps\"")
+(define s2225 "scheme lambda lazy λ\n")
+(define s2226 "scheme lazy dog the the lambda fox\n")
+(define s2227 "fox lambda")
+(define s2228 "brown dog fox fox brown café")
+(define s2229 "café port the scheme dog café brown lazy")
+(define s2230 "café lazy dog dog dog jumps\n")
+(define s2231 "lazy dog jumps string dog quick\n")
+(define s2232 "over string lambda scheme jumps dog lambda quick scheme")
+(define s2233 "string quick lambda")
+(define s2234 "port λ dog over fox port lazy brown lazy ünïcödé scheme\t\x41;")
+(define s2235 "dog dog scheme scheme quick café")
+(define s2236 "ünïcödé quick port\n")
+(define s2237 "brown lazy lazy brown quick quick jumps brown\t\x41;")
+(define s2238 "lambda lambda jumps café")
+(define s2239 "brown brown lazy brown fox brown")
+(define s2240 "fox string\n")
+(define s2241 "scheme dog brown the fox the")
+(define s2242 "λ lazy\t\x41;")
+(define s2243 "port string scheme ünïcödé the scheme fox lazy")
+(define s2244 "ünïcödé dog lambda brown brown the brown port dog scheme\t\x41;")
+(define s2245 "lazy scheme lambda quick over dog string café\n")
+(define s2246 "\"quick port the\"")
+(define s2247 "the port quick\t\x41;")
+(define s2248 "lazy port the over λ over λ fox brown")
+(define s2249 "\"scheme fox string café dog scheme fox\"")
+(define s2250 "fox fox port brown quick café scheme brown dog λ lambda")
+(define s2251 "lambda λ ünïcödé scheme lazy port port the port quick dog")
+(define s2252 "string ünïcödé over over port string jumps lazy dog lazy")
+(define s2253 "quick jumps scheme port fox scheme jumps string\t\x41;")
+(define s2254 "café port café jumps fox quick ünïcödé jumps string port")
+(define s2255 "\"quick string quick\"")
+(define s2256 "\"dog the string string\"")
+(define s2257 "port over the over the scheme café the lazy brown")
+(define s2258 "lambda over lambda fox ünïcödé scheme")
+(define s2259 "jumps quick the café scheme over")
+(define s2260 "the lambda over port dog\t\x41;")
+(define s2261 "\"port string the ünïcödé jumps λ ünïcödé scheme lambda lazy quick\"")
+(define s2262 "brown lazy café ünïcödé café fox\n")
+(define s2263 "quick λ string λ")
+(define s2264 "fox jumps fox port lambda λ")
+(define s2265 "quick fox jumps jumps the fox café fox dog\t\x41;")
+(define s2266 "café fox lambda dog fox brown the port\t\x41;")
+(define s2267 "lambda jumps string fox dog port jumps over")
+(define s2268 "string brown lazy jumps café brown\t\x41;")
+(define s2269 "dog over fox lambda string lazy ünïcödé jumps\n")
+(define s2270 "scheme café ünïcödé brown\t\x41;")
+(define s2271 "port fox café café lazy λ port scheme over\t\x41;")
+(define s2272 "brown quick brown fox quick brown café")
+(define s2273 "jumps λ")
+(define s2274 "\"lambda dog scheme quick string lazy\"")
+(define s2275 "over fox brown scheme\n")
+(define s2276 "jumps λ quick ünïcödé ünïcödé\n")
+(define s2277 "\"port port jumps lazy dog scheme λ brown ünïcödé\"")
+(define s2278 "brown café string brown over quick port string")
+(define s2279 "jumps port port λ café over ünïcödé lambda\t\x41;")
+(define s2280 "port brown brown fox lazy the λ string string\t\x41;")
+(define s2281 "lambda over lambda jumps jumps scheme\n")
+(define s2282 "ünïcödé ünïcödé lambda brown lambda lazy jumps over\t\x41;")
+(define s2283 "λ café brown brown\n")
+(define s2284 "quick dog fox string port string lambda\n")
+(define s2285 "\"dog brown quick\"")
+(define s2286 "the over")
+(define s2287 "fox port fox fox scheme quick λ port over over\t\x41;")
+(define s2288 "scheme dog\t\x41;")
+(define s2289 "string lambda dog\t\x41;")
+(define s2290 "jumps scheme ünïcödé jumps scheme jumps quick")
+(define s2291 "\"café the quick string\"")
+(define s2292 "ünïcödé the scheme quick\t\x41;")
+(define s2293 "\"scheme string brown string the string\"")
+(define s2294 "quick fox lazy ünïcödé port λ scheme the")
+(define s2295 "brown quick ünïcödé lambda jumps")
+(define s2296 "\"quick the the the over lambda the λ scheme\"")
+(define s2297 "port fox\t\x41;")
+(define s2298 "port dog ünïcödé the jumps ünïcödé\t\x41;")
+(define s2299 "ünïcödé ünïcödé ünïcödé\t\x41;")
+(define s2300 "lazy jumps scheme brown fox port\n")
+(define s2301 "over string port λ brown fox dog lazy port ünïcödé\t\x41;")
+(define s2302 "λ lambda lambda the\t\x41;")
+(define s2303 "lazy jumps dog café ünïcödé fox\n")
+(define s2304 "fox over lazy over fox port lazy\t\x41;")
+(define s2305 "scheme lambda ünïcödé over over brown café jumps café lambda lazy")
+(define s2306 "string over the scheme lazy")
+(define s2307 "\"dog scheme brown lazy over over λ\"")
+(define s2308 "dog brown the café café string quick café dog")
+(define s2309 "fox ünïcödé brown\n")
+(define s2310 "dog λ quick the jumps jumps lazy dog jumps café ünïcödé")
+(define s2311 "quick lambda λ")
+(define s2312 "scheme quick\t\x41;")
+(define s2313 "the λ dog dog jumps over the lazy quick scheme quick")
+(define s2314 "jumps jumps port fox lambda")
+(define s2315 "ünïcödé over fox quick")
+(define s2316 "the λ λ over fox")
+(define s2317 "\"over fox λ brown lazy the λ λ lazy\"")
+(define s2318 "λ the brown café")
+(define s2319 "café brown\n")
+(define s2320 "scheme port lazy port jumps brown port\t\x41;")
+(define s2321 "lazy port scheme scheme over lambda ünïcödé brown\t\x41;")
+(define s2322 "quick jumps dog quick café brown")
+(define s2323 "dog port port fox brown lambda scheme")
+(define s2324 "ünïcödé string the dog\t\x41;")
+(define s2325 "\"fox lazy café dog over lambda\"")
+(define s2326 "café string port quick lazy λ port port fox\n")
+(define s2327 "the brown string brown the λ brown fox ünïcödé\t\x41;")
+(define s2328 "port lazy dog brown port quick lazy lazy lambda fox")
+(define s2329 "dog string dog scheme\n")
+(define s2330 "dog dog quick fox ünïcödé string brown café fox λ")
+(define s2331 "port string lambda λ over jumps ünïcödé ünïcödé café café")
+(define s2332 "\"the over quick lambda λ the scheme port string brown\"")
+(define s2333 "café string lambda brown scheme port\n")
+(define s2334 "\"jumps ünïcödé dog string lazy dog the ünïcödé jumps brown\"")
+(define s2335 "café dog dog dog\t\x41;")
+(define s2336 "lazy over café\t\x41;")
+(define s2337 "quick fox λ fox the dog string ünïcödé string jumps")
+(define s2338 "port string string the quick quick the")
+(define s2339 "string quick scheme lambda dog λ brown lambda\n")
+(define s2340 "ünïcödé string string ünïcödé lazy")
+(define s2341 "\"jumps λ lazy string\"")
+(define s2342 "ünïcödé quick the string dog string λ quick port café\n")
+(define s2343 "over ünïcödé jumps dog")
+(define s2344 "\"port string dog café ünïcödé brown string lazy λ λ\"")
+(define s2345 "\"scheme scheme scheme the ünïcödé quick café\"")
+(define s2346 "fox quick quick lambda\n")
+(define s2347 "the port")
+(define s2348 "fox lazy ünïcödé quick\t\x41;")
+(define s2349 "lambda over")
+(define s2350 "café fox quick quick ünïcödé ünïcödé port jumps quick jumps jumps\n")
+(define s2351 "dog the port café jumps jumps string quick the dog over\t\x41;")
+(define s2352 "jumps café fox fox ünïcödé fox fox ünïcödé jumps jumps")
+(define s2353 "café lazy jumps port over lazy brown")
+(define s2354 "λ quick fox scheme string dog dog brown dog")
+(define s2355 "the quick port brown lambda fox\t\x41;")
+(define s2356 "\"dog café jumps dog string ünïcödé lazy\"")
+(define s2357 "\"λ scheme lambda lazy over string quick\"")
+(define s2358 "quick quick over jumps the fox")
+(define s2359 "quick the ünïcödé scheme\n")
+(define s2360 "jumps lambda scheme port the quick quick the scheme\n")
+(define s2361 "lambda ünïcödé café scheme port quick scheme jumps brown scheme string")
+(define s2362 "\"over fox brown lazy the the jumps lambda the over\"")
+(define s2363 "fox brown\t\x41;")
+(define s2364 "the over quick\n")
+(define s2365 "brown scheme port\t\x41;")
+(define s2366 "fox lazy over string ünïcödé lazy")
+(define s2367 "brown lazy lazy lambda port the ünïcödé lazy jumps ünïcödé the\n")
+(define s2368 "ünïcödé scheme scheme port lambda the\n")
+(define s2369 "brown scheme café the\n")
+(define s2370 "\"scheme λ jumps brown port lambda\"")
+(define s2371 "string dog the ünïcödé café jumps ünïcödé dog quick quick")
+(define s2372 "\"ünïcödé café port quick scheme ünïcödé the string the jumps\"")
+(define s2373 "over brown port fox")
+(define s2374 "fox dog jumps quick")
+(define s2375 "brown brown scheme fox λ")
+(define s2376 "port the")
+(define s2377 "port port dog\n")
+(define s2378 "fox brown\t\x41;")
+(define s2379 "\"quick lazy brown port the fox\"")
+(define s2380 "\"λ scheme dog jumps\"")
+(define s2381 "dog brown ünïcödé brown jumps jumps brown ünïcödé")
+(define s2382 "jumps quick scheme over café over λ dog quick quick quick")
+(define s2383 "\"the port fox lambda jumps string lazy\"")
+(define s2384 "string brown dog scheme lambda over\n")
+(define s2385 "\"fox fox quick jumps café fox port\"")
+(define s2386 "jumps fox quick quick lambda\t\x41;")
+(define s2387 "\"fox λ the the lambda brown lambda scheme port the λ\"")
+(define s2388 "fox port\t\x41;")
+(define s2389 "quick string ünïcödé scheme the jumps jumps string the")
+(define s2390 "café jumps the string scheme port dog ünïcödé the λ")
+(define s2391 "over quick the the brown lazy café the dog")
+(define s2392 "the scheme ünïcödé")
+(define s2393 "lambda over dog port\t\x41;")
+(define s2394 "fox lazy quick")
+(define s2395 "\"scheme fox string over lazy over lambda over λ over\"")
+(define s2396 "fox lambda quick lazy over λ lazy λ λ")
+(define s2397 "port jumps the ünïcödé café string")
+(define s2398 "string scheme string scheme string jumps\n")
+(define s2399 "\"jumps λ scheme dog\"")
+(define s2400 "ünïcödé port lazy fox fox over dog scheme scheme café")
+(define s2401 "\"quick port port scheme port brown fox port lambda café\"")
+(define s2402 "café over port jumps the ünïcödé café lazy")
+(define s2403 "over over fox jumps lambda scheme")
+(define s2404 "\"over string quick λ fox jumps quick\"")
+(define s2405 "the dog fox brown λ brown scheme λ scheme jumps ünïcödé\n")
+(define s2406 "lazy port lambda over λ café λ λ scheme jumps\n")
+(define s2407 "quick scheme fox brown string lambda\n")
+(define s2408 "lambda λ brown jumps quick\t\x41;")
+(define s2409 "fox port ünïcödé fox port")
+(define s2410 "quick scheme lambda")
+(define s2411 "string scheme λ dog dog scheme λ café")
+(define s2412 "port port string dog lazy over\n")
+(define s2413 "\"scheme café quick the the over port λ\"")
+(define s2414 "quick jumps lazy\n")
+(define s2415 "\"café λ ünïcödé string jumps quick café café lambda\"")
+(define s2416 "scheme over the dog\t\x41;")
+(define s2417 "\"λ λ scheme\"")
+(define s2418 "brown fox jumps lambda the fox quick ünïcödé brown café over\t\x41;")
+(define s2419 "\"café scheme lazy dog string the\"")
+(define s2420 "\"lambda over over ünïcödé\"")
+(define s2421 "over string scheme fox port port jumps dog port dog over\n")
+(define s2422 "dog the\t\x41;")
+(define s2423 "port over lambda the dog café lambda λ jumps string")
+(define s2424 "café dog jumps dog over lazy λ string dog")
+(define s2425 "fox quick λ jumps lazy string scheme λ café\n")
+(define s2426 "brown fox")
+(define s2427 "café port scheme lambda the brown quick dog dog\t\x41;")
+(define s2428 "the over over fox jumps brown string lambda brown café the\t\x41;")
+(define s2429 "lambda quick")
+(define s2430 "string ünïcödé dog brown string lazy scheme λ the jumps\t\x41;")
+(define s2431 "lazy the ünïcödé lazy λ café over fox the λ\t\x41;")
+(define s2432 "the fox the lambda café")
+(define s2433 "quick lambda\t\x41;")
+(define s2434 "\"scheme ünïcödé over quick\"")
+(define s2435 "the lambda fox lazy port fox dog\t\x41;")
+(define s2436 "port string lazy dog port scheme the lazy\n")
+(define s2437 "lazy lambda string the λ ünïcödé\t\x41;")
+(define s2438 "\"jumps string café\"")
+(define s2439 "brown lambda jumps scheme lazy over over quick dog lazy\n")
+(define s2440 "\"dog ünïcödé fox jumps lazy\"")
+(define s2441 "dog fox string")
+(define s2442 "ünïcödé over\t\x41;")
+(define s2443 "dog quick the lambda ünïcödé\t\x41;")
+(define s2444 "over brown quick lazy")
+(define s2445 "\"jumps lazy lambda café dog scheme the fox brown over\"")
+(define s2446 "lambda λ λ quick the ünïcödé string")
+(define s2447 "\"the string\"")
+(define s2448 "quick fox brown over string fox lazy")
+(define s2449 "quick λ quick jumps the lazy\t\x41;")
+(define s2450 "\"café over over scheme fox\"")
+(define s2451 "café café port string jumps jumps lazy λ the ünïcödé port")
+(define s2452 "quick port fox scheme\t\x41;")
+(define s2453 "brown port fox λ λ\n")
+(define s2454 "port scheme ünïcödé over string brown quick fox brown the\t\x41;")
+(define s2455 "\"over quick lazy ünïcödé dog lazy café string\"")
+(define s2456 "ünïcödé lambda port λ dog\t\x41;")
+(define s2457 "\"port dog quick brown brown café\"")
+(define s2458 "lambda the fox lambda lambda")
+(define s2459 "lazy café brown")
+(define s2460 "port scheme lazy string scheme λ λ string string\t\x41;")
+(define s2461 "the fox λ over lambda dog lazy string port quick λ")
+(define s2462 "\"quick lazy lambda ünïcödé\"")
+(define s2463 "brown lambda port café port over fox jumps fox jumps lazy\n")
+(define s2464 "jumps café string the the")
+(define s2465 "fox fox ünïcödé")
+(define s2466 "ünïcödé café lazy the fox lambda")
+(define s2467 "\"café ünïcödé brown λ jumps quick\"")
+(define s2468 "jumps over")
+(define s2469 "quick quick over λ scheme over café ünïcödé string")
+(define s2470 "\"string fox fox quick dog dog over\"")
+(define s2471 "port ünïcödé string dog lazy café jumps quick jumps ünïcödé\t\x41;")
+(define s2472 "quick quick jumps λ ünïcödé")
+(define s2473 "lambda lambda dog\n")
+(define s2474 "λ dog the scheme port fox fox the quick string over")
+(define s2475 "café dog the quick brown lazy quick café\n")
+(define s2476 "the lazy jumps")
+(define s2477 "over lambda brown lambda")
+(define s2478 "over dog the lambda café fox\t\x41;")
+(define s2479 "fox quick scheme string")
+(define s2480 "λ brown ünïcödé café jumps scheme brown café quick\t\x41;")
+(define s2481 "string lazy ünïcödé ünïcödé the scheme quick quick scheme jumps brown")
+(define s2482 "lazy the port\n")
+(define s2483 "jumps ünïcödé lambda lambda port\t\x41;")
+(define s2484 "café quick café brown café\t\x41;")
+(define s2485 "brown port port scheme jumps string ünïcödé λ lazy jumps over\t\x41;")
+(define s2486 "jumps lazy over the string ünïcödé quick")
+(define s2487 "\"port jumps lambda string fox over port ünïcödé\"")
+(define s2488 "ünïcödé lazy scheme brown ünïcödé brown dog string\t\x41;")
+(define s2489 "over string lambda jumps string quick the ünïcödé the\n")
+(define s2490 "lazy port café string lazy port string scheme dog lambda λ\t\x41;")
+(define s2491 "jumps dog the over over port port")
+(define s2492 "\"string port café string fox ünïcödé port over the café brown\"")
+(define s2493 "brown quick\n")
+(define s2494 "ünïcödé quick quick lazy brown lazy")
+(define s2495 "lazy lambda\t\x41;")
+(define s2496 "dog λ quick jumps dog string lazy\t\x41;")
+(define s2497 "quick over port fox")
+(define s2498 "\"brown the port lazy brown brown\"")
+(define s2499 "dog λ fox ünïcödé")
+(define s2500 "quick over ünïcödé ünïcödé lambda café scheme café")
+(define s2501 "\"λ ünïcödé dog λ quick jumps brown jumps quick ünïcödé string\"")
+(define s2502 "lazy lazy quick scheme quick quick jumps lambda")
+(define s2503 "lambda lazy the the λ café café string jumps\t\x41;")
+(define s2504 "\"over fox\"")
+(define s2505 "the lambda port the lazy λ lazy λ fox over\n")
+(define s2506 "lambda brown scheme the")
+(define s2507 "scheme jumps ünïcödé café string fox")
+(define s2508 "over lambda")
+(define s2509 "dog string fox the brown fox quick lazy port\n")
+(define s2510 "\"over scheme\"")
+(define s2511 "\"string scheme lambda over the fox dog the brown port\"")
+(define s2512 "λ brown over over dog café café\t\x41;")
+(define s2513 "lazy ünïcödé fox quick")
+(define s2514 "quick café string")
+(define s2515 "\"dog café dog ünïcödé café café brown port\"")
+(define s2516 "\"brown fox brown port dog fox dog\"")
+(define s2517 "jumps over quick fox λ λ")
+(define s2518 "over lambda port fox over port brown dog quick lazy jumps\t\x41;")
+(define s2519 "dog café jumps over brown the port")
+(define s2520 "café lambda brown ünïcödé")
+(define s2521 "café scheme dog port")
+(define s2522 "fox jumps quick fox dog scheme over fox quick")
+(define s2523 "\"over over scheme café port\"")
+(define s2524 "λ over jumps string port dog quick fox ünïcödé jumps quick\n")
+(define s2525 "brown brown the café café the")
+(define s2526 "over over\t\x41;")
+(define s2527 "café lazy lazy jumps\t\x41;")
+(define s2528 "\"λ over scheme quick\"")
+(define s2529 "port lambda lambda fox quick lazy ünïcödé fox")
+(define s2530 "the café fox fox quick scheme brown\n")
+(define s2531 "over dog café\n")
+(define s2532 "lambda λ the over string the\t\x41;")
+(define s2533 "\"string lambda over lazy brown λ\"")
+(define s2534 "string string")
+(define s2535 "string café over over the jumps\n")
+(define s2536 "lambda string brown")
+(define s2537 "quick fox string brown port lazy jumps scheme lambda lambda ünïcödé")
+(define s2538 "scheme lambda over string fox lazy fox\n")
+(define s2539 "\"dog brown over brown port\"")
+(define s2540 "the lambda ünïcödé dog lazy ünïcödé\n")
+(define s2541 "port fox ünïcödé scheme fox λ lazy")
+(define s2542 "brown λ dog\n")
+(define s2543 "quick quick\n")
+(define s2544 "\"ünïcödé brown lambda string port\"")
+(define s2545 "jumps quick string scheme brown lazy string café port port\t\x41;")
+(define s2546 "\"quick quick lazy jumps fox λ λ quick scheme\"")
+(define s2547 "\"jumps fox fox λ the the string jumps\"")
+(define s2548 "λ lazy fox\t\x41;")
+(define s2549 "over fox ünïcödé jumps brown over ünïcödé brown\t\x41;")
+(define s2550 "over λ over café fox ünïcödé the lazy lazy λ")
+(define s2551 "the brown fox scheme dog ünïcödé fox string over port scheme\n")
+(define s2552 "jumps ünïcödé string scheme scheme jumps fox jumps λ quick λ")
+(define s2553 "jumps jumps string string dog the string dog the lambda brown\t\x41;")
+(define s2554 "lazy over café port lazy")
+(define s2555 "\"scheme string café over quick λ\"")
+(define s2556 "the port over jumps\n")
+(define s2557 "string jumps quick port lazy\n")
+(define s2558 "string over the dog ünïcödé dog jumps scheme port\n")
+(define s2559 "dog the λ port fox")
+(define s2560 "ünïcödé the jumps jumps λ ünïcödé ünïcödé ünïcödé\t\x41;")
+(define s2561 "lazy over fox string brown the scheme port the ünïcödé\n")
+(define s2562 "\"the lazy the café\"")
+(define s2563 "brown jumps")
+(define s2564 "\"port scheme dog brown lambda\"")
+(define s2565 "\"ünïcödé jumps jumps brown\"")
+(define s2566 "lambda quick ünïcödé scheme\n")
+(define s2567 "over brown λ ünïcödé scheme\n")
+(define s2568 "quick string quick the lazy dog quick scheme brown\t\x41;")
+(define s2569 "café over\t\x41;")
+(define s2570 "lambda lambda\t\x41;")
+(define s2571 "quick string scheme port the port")
+(define s2572 "ünïcödé the λ lambda port lazy the port ünïcödé ünïcödé")
+(define s2573 "jumps string lazy port scheme café the lambda ünïcödé\n")
+(define s2574 "fox dog quick dog port the over\t\x41;")
+(define s2575 "dog fox ünïcödé\t\x41;")
+(define s2576 "fox dog quick port scheme dog scheme the dog")
+(define s2577 "lazy the dog lambda brown café port lambda scheme café\n")
+(define s2578 "over scheme\n")
+(define s2579 "lazy string the fox port string port")
+(define s2580 "quick ünïcödé λ λ")
+(define s2581 "\"lambda port quick over over over over\"")
+(define s2582 "ünïcödé ünïcödé fox fox over quick string ünïcödé brown lambda\t\x41;")
+(define s2583 "quick port lazy the\t\x41;")
+(define s2584 "ünïcödé λ jumps dog λ café lambda\t\x41;")
+(define s2585 "lambda port brown jumps λ café\n")
+(define s2586 "jumps fox lambda λ ünïcödé")
+(define s2587 "the jumps quick brown")
+(define s2588 "quick string jumps lambda fox dog jumps\n")
+(define s2589 "quick ünïcödé over")
+(define s2590 "scheme jumps lazy λ jumps café jumps fox ünïcödé café\n")
+(define s2591 "port fox café quick quick jumps over lazy quick ünïcödé lambda")
+(define s2592 "the port over lazy ünïcödé")
+(define s2593 "string fox lazy quick ünïcödé string\t\x41;")
+(define s2594 "\"the lambda\"")
+(define s2595 "\"lambda dog\"")
+(define s2596 "lambda fox jumps lambda jumps quick café")
+(define s2597 "the fox port")
+(define s2598 "the lazy ünïcödé scheme scheme string dog lambda")
+(define s2599 "\"lazy lazy string café string quick dog\"")
+(define s2600 "λ fox brown café dog dog jumps string brown port\n")
+(define s2601 "dog dog λ café quick λ jumps lambda jumps\n")
+(define s2602 "λ jumps café λ fox λ string lazy fox")
+(define s2603 "scheme the fox string the lambda dog brown scheme\t\x41;")
+(define s2604 "lazy scheme the café string scheme\n")
+(define s2605 "lambda quick fox\t\x41;")
+(define s2606 "\"λ over\"")
+(define s2607 "fox quick over café\t\x41;")
+(define s2608 "ünïcödé dog\t\x41;")
+(define s2609 "over scheme")
+(define s2610 "port scheme lambda port the the string quick café scheme\n")
+(define s2611 "port brown the over brown ünïcödé lazy brown λ\t\x41;")
+(define s2612 "the lazy lazy lambda quick")
+(define s2613 "port over string fox lambda\t\x41;")
+(define s2614 "\"jumps quick port fox λ over jumps the fox\"")
+(define s2615 "λ string port ünïcödé string scheme ünïcödé lazy over")
+(define s2616 "fox café port quick\n")
+(define s2617 "brown the quick ünïcödé ünïcödé the string café lazy λ string")
+(define s2618 "\"fox fox λ scheme\"")
+(define s2619 "lambda brown")
+(define s2620 "scheme ünïcödé ünïcödé lazy scheme λ brown fox dog ünïcödé λ")
+(define s2621 "jumps λ ünïcödé brown quick")
+(define s2622 "quick quick fox over lazy over lazy over\t\x41;")
+(define s2623 "\"dog over string string jumps\"")
+(define s2624 "over jumps fox\t\x41;")
+(define s2625 "jumps port fox brown fox jumps lazy lazy ünïcödé λ\n")
+(define s2626 "\"jumps the café\"")
+(define s2627 "lambda λ string lazy scheme")
+(define s2628 "string string λ lazy dog the")
+(define s2629 "ünïcödé quick\t\x41;")
+(define s2630 "over λ dog quick over lambda quick ünïcödé lazy dog")
+(define s2631 "jumps λ λ quick\n")
+(define s2632 "\"lazy over scheme over brown\"")
+(define s2633 "dog ünïcödé λ string brown café quick λ string ünïcödé jumps\n")
+(define s2634 "\"fox ünïcödé lambda fox dog lambda café\"")
+(define s2635 "brown the café jumps brown port ünïcödé λ dog jumps lazy")
+(define s2636 "\"string brown jumps scheme fox the ünïcödé brown lambda\"")
+(define s2637 "dog quick string")
+(define s2638 "over λ string café quick quick\n")
+(define s2639 "quick over string ünïcödé the\n")
+(define s2640 "ünïcödé jumps")
+(define s2641 "fox café over lambda quick quick brown brown fox\n")
+(define s2642 "\"scheme jumps brown quick brown\"")
+(define s2643 "λ quick scheme café\t\x41;")
+(define s2644 "the ünïcödé")
+(define s2645 "\"over lambda string fox quick scheme dog jumps\"")
+(define s2646 "\"ünïcödé port string brown scheme scheme dog brown fox lambda λ\"")
+(define s2647 "the ünïcödé scheme jumps port λ λ string")
+(define s2648 "\"over scheme λ lambda string λ\"")
+(define s2649 "lazy lambda port scheme string the scheme\t\x41;")
+(define s2650 "fox fox dog the fox λ jumps the brown dog")
+(define s2651 "scheme dog fox lambda over over café brown\n")
+(define s2652 "fox lazy quick lambda dog\t\x41;")
+(define s2653 "brown lazy scheme brown λ λ over brown brown")
+(define s2654 "dog jumps\t\x41;")
+(define s2655 "\"lambda the jumps ünïcödé brown ünïcödé lambda dog\"")
+(define s2656 "\"ünïcödé string λ dog port fox jumps lazy café\"")
+(define s2657 "port port scheme lambda brown fox string ünïcödé")
+(define s2658 "\"quick the over café quick the quick lazy jumps\"")
+(define s2659 "jumps lambda dog ünïcödé lazy jumps port")
+(define s2660 "string λ scheme ünïcödé café café fox string λ")
+(define s2661 "λ ünïcödé café café lambda quick")
+(define s2662 "dog fox fox dog lambda brown fox string brown port jumps")
+(define s2663 "\"string jumps ünïcödé café ünïcödé over brown the\"")
+(define s2664 "jumps the string café dog")
+(define s2665 "port over over over jumps dog ünïcödé\n")
+(define s2666 "scheme brown brown lambda quick lazy fox\t\x41;")
+(define s2667 "dog fox dog ünïcödé port over over dog ünïcödé ünïcödé port\n")
+(define s2668 "port dog string lambda")
+(define s2669 "lambda fox dog café scheme jumps dog the café dog\n")
+(define s2670 "\"over ünïcödé brown jumps the ünïcödé the over\"")
+(define s2671 "lambda the ünïcödé ünïcödé port lazy the fox string\t\x41;")
+(define s2672 "\"λ quick ünïcödé quick quick jumps ünïcödé\"")
+(define s2673 "scheme brown port λ brown café lazy\t\x41;")
+(define s2674 "\"lazy over brown λ λ\"")
+(define s2675 "the jumps string lambda jumps λ")
+(define s2676 "café lazy lambda the\n")
+(define s2677 "quick brown the\n")
+(define s2678 "\"quick lazy the café\"")
+(define s2679 "\"string dog lambda dog quick lambda string over\"")
+(define s2680 "lambda quick café quick jumps ünïcödé lambda dog over lambda\n")
+(define s2681 "jumps lazy lazy fox quick dog string brown ünïcödé over\t\x41;")
+(define s2682 "lazy the port fox")
+(define s2683 "\"brown fox port\"")
+(define s2684 "quick ünïcödé lambda lambda café quick λ λ over\t\x41;")
+(define s2685 "\"over brown port fox over\"")
+(define s2686 "lambda the scheme café over dog dog lazy\n")
+(define s2687 "the quick jumps lazy fox jumps lazy lambda\t\x41;")
+(define s2688 "dog ünïcödé port scheme port scheme dog jumps λ lazy fox")
+(define s2689 "port over port quick port dog lambda")
+(define s2690 "brown scheme lazy lambda brown quick dog dog dog lazy\n")
+(define s2691 "scheme café the port port ünïcödé lazy λ over\t\x41;")
+(define s2692 "brown brown lazy\n")
+(define s2693 "ünïcödé string the brown port jumps jumps ünïcödé port string scheme")
+(define s2694 "lazy string fox λ scheme fox fox the\n")
+(define s2695 "fox dog\n")
+(define s2696 "ünïcödé quick café jumps lazy lazy café quick scheme lazy lambda")
+(define s2697 "port over fox ünïcödé scheme quick brown over\n")
+(define s2698 "the the lambda over the dog")
+(define s2699 "lazy lambda over fox port jumps")
+(define s2700 "quick port scheme λ lazy quick brown jumps")
+(define s2701 "λ dog café lambda jumps quick quick jumps scheme over port")
+(define s2702 "lambda fox lambda quick over jumps the")
+(define s2703 "scheme the fox quick the brown string ünïcödé brown lazy\n")
+(define s2704 "brown dog string port café port lambda port string\t\x41;")
+(define s2705 "λ the string brown string λ")
+(define s2706 "port ünïcödé jumps\t\x41;")
+(define s2707 "quick λ string lazy scheme the lazy ünïcödé\t\x41;")
+(define s2708 "dog jumps ünïcödé brown fox\n")
+(define s2709 "λ fox dog\t\x41;")
+(define s2710 "ünïcödé lambda dog the quick scheme string brown lambda lazy λ")
+(define s2711 "dog fox")
+(define s2712 "port jumps lazy λ scheme café string dog scheme\t\x41;")
+(define s2713 "fox scheme scheme lazy scheme quick scheme café café\t\x41;")
+(define s2714 "port jumps lambda over")
+(define s2715 "\"café fox scheme brown café jumps\"")
+(define s2716 "the fox ünïcödé jumps quick port scheme")
+(define s2717 "\"fox λ café café scheme dog café\"")
+(define s2718 "lambda scheme scheme quick fox over\n")
+(define s2719 "dog lazy fox dog café brown jumps the dog quick scheme\t\x41;")
+(define s2720 "over lazy café lazy port")
+(define s2721 "\"jumps café lazy brown\"")
+(define s2722 "quick quick quick ünïcödé over string ünïcödé λ over\n")
+(define s2723 "the café dog quick\t\x41;")
+(define s2724 "\"ünïcödé string the quick brown scheme fox string jumps jumps\"")
+(define s2725 "\"dog λ the\"")
+(define s2726 "string fox fox brown dog")
+(define s2727 "\"ünïcödé jumps dog ünïcödé λ fox quick\"")
+(define s2728 "dog lazy λ\n")
+(define s2729 "jumps fox ünïcödé lazy dog lambda port fox ünïcödé port λ")
+(define s2730 "scheme fox the")
+(define s2731 "quick quick brown brown scheme\t\x41;")
+(define s2732 "jumps lambda jumps quick fox\n")
+(define s2733 "string string string lambda\t\x41;")
+(define s2734 "λ string dog lazy fox the λ jumps café port scheme")
+(define s2735 "dog port ünïcödé jumps λ")
+(define s2736 "ünïcödé the over lambda dog")
+(define s2737 "\"λ lazy café café ünïcödé ünïcödé dog brown\"")
+(define s2738 "port lazy string the string port port λ jumps ünïcödé port\n")
+(define s2739 "jumps dog lambda ünïcödé lazy fox")
+(define s2740 "scheme port jumps café string brown café ünïcödé the dog")
+(define s2741 "port quick quick café lambda over café lazy string fox\t\x41;")
+(define s2742 "\"scheme dog λ the\"")
+(define s2743 "\"λ the over lazy\"")
+(define s2744 "over λ brown over\n")
+(define s2745 "port port lambda port λ brown over the jumps")
+(define s2746 "port ünïcödé lazy quick port lazy")
+(define s2747 "string lambda over quick string ünïcödé over café over λ over")
+(define s2748 "\"λ port ünïcödé over ünïcödé string café brown\"")
+(define s2749 "\"fox brown λ quick brown\"")
+(define s2750 "fox café λ brown")
+(define s2751 "ünïcödé the jumps café the over lazy dog ünïcödé lambda fox")
+(define s2752 "quick port lambda fox λ jumps dog quick\t\x41;")
+(define s2753 "\"café quick\"")
+(define s2754 "jumps the quick café dog the brown lambda over λ")
+(define s2755 "dog dog fox quick scheme fox λ brown string\t\x41;")
+(define s2756 "\"port ünïcödé café the lazy ünïcödé port lambda brown quick\"")
+(define s2757 "the lambda λ dog lambda jumps dog fox quick the\t\x41;")
+(define s2758 "string fox quick string string quick ünïcödé λ")
+(define s2759 "\"dog café\"")
+(define s2760 "\"lambda scheme jumps over brown port jumps the port over jumps\"")
+(define s2761 "ünïcödé λ λ λ λ quick lazy scheme lambda string lazy\n")
+(define s2762 "lambda lambda lambda jumps the string")
+(define s2763 "dog ünïcödé λ λ dog")
+(define s2764 "\"port lambda ünïcödé dog brown quick port dog lambda dog\"")
+(define s2765 "\"fox lazy string\"")
+(define s2766 "the the brown string\n")
+(define s2767 "scheme brown ünïcödé brown dog\n")
+(define s2768 "the the dog lazy lazy string dog café over over")
+(define s2769 "over jumps")
+(define s2770 "lambda port the scheme ünïcödé string\t\x41;")
+(define s2771 "string the port quick ünïcödé jumps over ünïcödé fox\t\x41;")
+(define s2772 "lazy the λ")
+(define s2773 "\"jumps fox lazy lazy brown café\"")
+(define s2774 "\"the over ünïcödé the λ the ünïcödé café\"")
+(define s2775 "lazy ünïcödé over quick\n")
+(define s2776 "café quick over port lambda café over lambda λ quick λ")
+(define s2777 "over lambda lambda")
+(define s2778 "\"string brown scheme string\"")
+(define s2779 "port the the the lambda lazy string lambda fox lambda jumps")
+(define s2780 "café café λ café")
+(define s2781 "café brown lambda ünïcödé string")
+(define s2782 "port dog brown lazy λ over scheme the lazy ünïcödé")
+(define s2783 "the port port lambda string")
+(define s2784 "lambda scheme scheme\n")
+(define s2785 "dog quick dog λ brown fox lambda café")
+(define s2786 "ünïcödé port lazy lazy λ λ dog ünïcödé brown lambda quick\t\x41;")
+(define s2787 "lazy lazy over λ dog string λ ünïcödé\n")
+(define s2788 "the dog quick\t\x41;")
+(define s2789 "string scheme brown")
+(define s2790 "over over λ fox\n")
+(define s2791 "string scheme jumps over brown lazy lambda quick λ the lambda")
+(define s2792 "λ scheme over port fox\n")
+(define s2793 "port the dog the jumps port fox λ lazy")
+(define s2794 "café λ over port lambda port lazy quick fox fox quick")
+(define s2795 "\"ünïcödé brown the over over\"")
+(define s2796 "\"ünïcödé over port\"")
+(define s2797 "quick port café brown lambda over over jumps port\t\x41;")
+(define s2798 "scheme the string lambda ünïcödé dog")
+(define s2799 "quick jumps café over the dog port fox port scheme scheme")
+(define s2800 "\"lambda scheme café brown\"")
+(define s2801 "the lazy port lazy port brown fox lazy over\n")
+(define s2802 "the lambda port")
+(define s2803 "string fox brown lambda ünïcödé quick λ ünïcödé quick\n")
+(define s2804 "lazy lambda fox ünïcödé the quick jumps string dog brown jumps\t\x41;")
+(define s2805 "\"over ünïcödé lambda fox the fox port dog\"")
+(define s2806 "port λ lazy jumps over dog\t\x41;")
+(define s2807 "scheme fox")
+(define s2808 "\"the the port\"")
+(define s2809 "the over brown port")
+(define s2810 "over fox dog lazy café λ dog over\n")
+(define s2811 "\"port the\"")
+(define s2812 "lazy ünïcödé café lazy string port dog lambda the jumps")
+(define s2813 "\"the the\"")
+(define s2814 "over scheme fox brown jumps lambda λ the λ fox café\t\x41;")
+(define s2815 "lambda lambda quick fox string dog dog brown")
+(define s2816 "string jumps port ünïcödé scheme over café\n")
+(define s2817 "string ünïcödé scheme lambda jumps fox over lambda")
+(define s2818 "string port")
+(define s2819 "lambda quick over over\t\x41;")
+(define s2820 "dog λ ünïcödé ünïcödé port\n")
+(define s2821 "λ scheme lazy ünïcödé the string dog lambda ünïcödé")
+(define s2822 "quick jumps brown quick dog jumps jumps jumps lazy café lazy")
+(define s2823 "port the scheme café brown over")
+(define s2824 "port café fox café")
+(define s2825 "the lazy lambda jumps port jumps lazy\t\x41;")
+(define s2826 "the lambda")
+(define s2827 "ünïcödé the\t\x41;")
+(define s2828 "port port fox café ünïcödé lambda scheme lambda\n")
+(define s2829 "over dog string ünïcödé string ünïcödé port λ the\n")
+(define s2830 "λ quick ünïcödé port the quick")
+(define s2831 "string brown café dog the quick dog brown scheme the λ")
+(define s2832 "over lazy over lambda fox string scheme brown")
+(define s2833 "\"lazy string jumps dog brown brown ünïcödé dog café port fox\"")
+(define s2834 "λ lambda λ lambda café port ünïcödé scheme café port")
+(define s2835 "\"jumps brown jumps string scheme over jumps\"")
+(define s2836 "λ scheme dog over lambda lambda jumps ünïcödé λ\n")
+(define s2837 "dog string brown lambda port fox dog")
+(define s2838 "café over brown port ünïcödé over lazy lazy quick café lambda\t\x41;")
+(define s2839 "fox λ λ over lazy")
+(define s2840 "string quick brown café lambda over café ünïcödé")
+(define s2841 "port brown lambda café brown the fox\n")
+(define s2842 "\"dog fox jumps\"")
+(define s2843 "λ string scheme over")
+(define s2844 "jumps brown café")
+(define s2845 "scheme brown λ ünïcödé café quick\t\x41;")
+(define s2846 "port dog string over")
+(define s2847 "café quick fox scheme brown string jumps over lambda port λ\n")
+(define s2848 "\"ünïcödé fox λ dog\"")
+(define s2849 "λ quick fox λ jumps jumps ünïcödé port ünïcödé port")
+(define s2850 "\"dog lambda lambda lazy lambda over brown dog lazy λ\"")
+(define s2851 "the jumps fox lambda")
+(define s2852 "\"λ café quick café λ over\"")
+(define s2853 "port jumps string scheme quick port lambda quick λ ünïcödé")
+(define s2854 "\"lambda the the\"")
+(define s2855 "jumps ünïcödé quick dog dog the fox dog\n")
+(define s2856 "λ café jumps")
+(define s2857 "lazy quick port quick\n")
+(define s2858 "brown λ over quick port brown port brown λ")
+(define s2859 "dog the")
+(define s2860 "brown café the string jumps")
+(define s2861 "brown quick the lambda dog jumps string over lambda")
+(define s2862 "dog brown port dog scheme lazy")
+(define s2863 "brown jumps brown")
+(define s2864 "\"the café fox λ λ string λ over string string fox\"")
+(define s2865 "\"port λ\"")
+(define s2866 "over the café over lazy\n")
+(define s2867 "scheme scheme brown")
+(define s2868 "dog quick fox café ünïcödé string café string over\n")
+(define s2869 "\"over λ\"")
+(define s2870 "café brown lambda fox over port string brown over the\t\x41;")
+(define s2871 "brown string the scheme quick")
+(define s2872 "ünïcödé café\n")
+(define s2873 "lambda brown the over scheme over café over fox port fox\t\x41;")
+(define s2874 "\"jumps brown brown string lambda over the lambda\"")
+(define s2875 "\"λ fox jumps dog lazy λ fox lazy ünïcödé quick\"")
+(define s2876 "string brown scheme the lambda scheme\t\x41;")
+(define s2877 "the over string")
+(define s2878 "fox port jumps the\n")
+(define s2879 "lazy brown over\t\x41;")
+(define s2880 "string scheme jumps over port jumps over string lazy café quick\t\x41;")
+(define s2881 "quick café café brown string café lambda\t\x41;")
+(define s2882 "quick lazy quick port λ\t\x41;")
+(define s2883 "ünïcödé jumps quick string quick quick brown scheme")
+(define s2884 "over over string string\n")
+(define s2885 "\"quick lambda port the λ lambda lazy quick the string\"")
+(define s2886 "λ jumps λ ünïcödé")
+(define s2887 "lazy lambda brown string scheme jumps λ café scheme lazy lazy\t\x41;")
+(define s2888 "jumps string")
+(define s2889 "port jumps λ jumps string over scheme jumps the\t\x41;")
+(define s2890 "\"lazy λ string the lambda λ λ quick over scheme\"")
+(define s2891 "\"λ fox string port jumps\"")
+(define s2892 "dog the")
+(define s2893 "\"scheme lazy scheme string scheme over brown\"")
+(define s2894 "\"the brown port jumps port over dog dog\"")
+(define s2895 "lazy scheme lambda\n")
+(define s2896 "café the ünïcödé dog lazy λ string\n")
+(define s2897 "quick ünïcödé dog quick lambda brown ünïcödé the")
+(define s2898 "jumps lambda brown λ the café brown scheme over jumps\t\x41;")
+(define s2899 "quick jumps jumps")
+(define s2900 "lambda λ fox ünïcödé λ scheme jumps café jumps port")
+(define s2901 "\"ünïcödé dog port\"")
+(define s2902 "\"dog lazy jumps brown string jumps\"")
+(define s2903 "jumps string café\n")
+(define s2904 "string port ünïcödé brown dog jumps fox brown lambda\n")
+(define s2905 "\"jumps λ\"")
+(define s2906 "quick λ the the lambda over ünïcödé dog over brown")
+(define s2907 "dog over quick lazy port jumps jumps port lazy")
+(define s2908 "dog lambda jumps λ over")
+(define s2909 "brown lazy jumps λ")
+(define s2910 "dog dog dog lazy\t\x41;")
+(define s2911 "dog λ dog string port quick café jumps λ string jumps")
+(define s2912 "café port string dog jumps\n")
+(define s2913 "fox scheme lazy ünïcödé\n")
+(define s2914 "fox lazy λ scheme λ brown the café string\n")
+(define s2915 "λ dog scheme quick port λ port lambda dog quick quick\t\x41;")
+(define s2916 "λ port over brown dog\n")
+(define s2917 "\"λ lambda\"")
+(define s2918 "\"ünïcödé dog brown fox lambda string quick λ\"")
+(define s2919 "\"ünïcödé brown brown\"")
+(define s2920 "dog the\n")
+(define s2921 "\"café fox\"")
+(define s2922 "ünïcödé jumps lazy")
+(define s2923 "port lambda ünïcödé brown fox port the jumps brown quick")
+(define s2924 "café café lazy ünïcödé\n")
+(define s2925 "\"dog port ünïcödé café lambda scheme the lazy quick dog string\"")
+(define s2926 "fox λ lambda the string")
+(define s2927 "lazy λ over λ λ")
+(define s2928 "dog λ λ the ünïcödé dog café")
+(define s2929 "the the over string lambda quick")
+(define s2930 "fox jumps quick scheme lambda λ\n")
+(define s2931 "scheme café the fox over café λ\n")
+(define s2932 "\"scheme scheme lambda\"")
+(define s2933 "lazy dog the café port port over λ quick lazy\n")
+(define s2934 "dog brown λ dog ünïcödé port fox fox")
+(define s2935 "quick over scheme port café quick fox\n")
+(define s2936 "jumps brown fox the")
+(define s2937 "\"port café café the the\"")
+(define s2938 "brown ünïcödé over dog port dog λ jumps lazy scheme fox\n")
+(define s2939 "\"quick string port over café lazy string scheme\"")
+(define s2940 "jumps jumps café scheme scheme lazy ünïcödé lambda scheme quick scheme\n")
+(define s2941 "fox quick café λ port λ quick")
+(define s2942 "brown quick\n")
+(define s2943 "café string lazy lambda")
+(define s2944 "scheme λ")
+(define s2945 "ünïcödé lazy λ")
+(define s2946 "over ünïcödé ünïcödé port café ünïcödé\n")
+(define s2947 "\"the scheme dog quick café over\"")
+(define s2948 "café brown quick fox string jumps café string café lambda")
+(define s2949 "\"ünïcödé café café ünïcödé quick dog λ jumps string over jumps\"")
+(define s2950 "string jumps quick scheme fox scheme λ\t\x41;")
+(define s2951 "scheme fox over dog café the ünïcödé fox")
+(define s2952 "jumps over λ over quick lambda jumps scheme lambda λ\t\x41;")
+(define s2953 "λ the brown ünïcödé jumps fox lazy lambda fox\n")
+(define s2954 "λ the scheme brown ünïcödé lambda the")
+(define s2955 "ünïcödé ünïcödé café café over the lambda lambda\t\x41;")
+(define s2956 "quick string fox brown brown lazy over string quick fox λ\n")
+(define s2957 "fox scheme jumps lazy dog λ fox over\t\x41;")
+(define s2958 "scheme port over λ quick the string port over port café")
+(define s2959 "lambda the café fox over lambda scheme\n")
+(define s2960 "quick fox café lazy λ string lambda lazy\t\x41;")
+(define s2961 "\"scheme port port jumps lambda\"")
+(define s2962 "brown lazy port λ quick port string scheme lambda")
+(define s2963 "\"dog ünïcödé λ café jumps ünïcödé λ port lazy\"")
+(define s2964 "lambda λ port ünïcödé lazy ünïcödé ünïcödé\t\x41;")
+(define s2965 "λ ünïcödé λ over")
+(define s2966 "café lambda ünïcödé")
+(define s2967 "brown λ fox λ lambda\t\x41;")
+(define s2968 "over the ünïcödé\n")
+(define s2969 "string quick lazy jumps lambda lambda\n")
+(define s2970 "scheme brown lambda brown λ ünïcödé jumps port fox over\n")
+(define s2971 "\"λ scheme fox lazy quick café λ café fox dog\"")
+(define s2972 "fox λ over fox ünïcödé the λ")
+(define s2973 "\"fox over quick string jumps the λ lazy lambda dog lambda\"")
+(define s2974 "\"scheme quick over the port λ over dog jumps\"")
+(define s2975 "\"fox string string λ dog lambda the brown port\"")
+(define s2976 "string string λ brown dog dog brown lambda the lambda")
+(define s2977 "\"café lazy café port the lambda ünïcödé port dog\"")
+(define s2978 "lambda brown scheme dog lazy")
+(define s2979 "λ jumps lazy scheme fox scheme λ over fox λ\t\x41;")
+(define s2980 "lambda ünïcödé port scheme dog lambda fox string")
+(define s2981 "fox scheme")
+(define s2982 "dog quick quick λ fox café string\t\x41;")
+(define s2983 "string jumps jumps port café scheme\n")
+(define s2984 "the brown café jumps\t\x41;")
+(define s2985 "dog quick scheme\t\x41;")
+(define s2986 "scheme fox dog λ café λ ünïcödé\n")
+(define s2987 "the over fox string over string over over lazy string ünïcödé")
+(define s2988 "over lazy scheme over quick dog quick string\t\x41;")
+(define s2989 "scheme café fox quick lazy string jumps scheme over lazy\n")
+(define s2990 "jumps the café the")
+(define s2991 "ünïcödé scheme scheme lambda")
+(define s2992 "the quick over café brown port café lambda\n")
+(define s2993 "\"brown café fox quick brown over quick\"")
+(define s2994 "\"dog café café lazy jumps fox scheme fox port\"")
+(define s2995 "\"jumps lazy\"")
+(define s2996 "café quick over string λ λ café\t\x41;")
+(define s2997 "dog brown café string fox brown the jumps dog scheme")
+(define s2998 "\"port dog string brown dog\"")
+(define s2999 "λ brown dog jumps dog lambda lazy lazy brown port\n")
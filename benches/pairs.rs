@@ -0,0 +1,32 @@
+//! Building and walking long lists, which is mostly allocating and freeing
+//! pairs. Run it with and without the `pair-pool` feature to compare.
+
+mod timing;
+
+use mibph::Object;
+use timing::{bench, define, evaluate};
+
+fn main() {
+    let pool = if cfg!(feature = "pair-pool") {
+        "pool"
+    } else {
+        "no pool"
+    };
+    define(
+        "(define (build n)
+           (let loop ((i 0) (list '()))
+             (if (= i n) list (loop (+ i 1) (cons i list)))))
+         (define (walk list)
+           (let loop ((list list) (sum 0))
+             (if (null? list) sum (loop (cdr list) (+ sum (car list))))))",
+    );
+    bench(
+        &format!("build and walk 10000 ({pool})"),
+        None,
+        evaluate("(walk (build 10000))"),
+    );
+    bench(&format!("Object::list of 100000 ({pool})"), None, || {
+        let list = Object::list((0..100_000).map(Object::int));
+        drop(list);
+    });
+}
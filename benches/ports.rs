@@ -0,0 +1,26 @@
+//! Writing to the standard ports in tight loops.
+//!
+//! What's written goes to standard output, and the timings to standard
+//! error, so run it as `cargo bench --bench ports > /dev/null`.
+
+mod timing;
+
+use timing::{bench, define, evaluate};
+
+fn main() {
+    define(
+        "(define (write-chars n)
+           (do ((i 0 (+ i 1))) ((= i n)) (write-char #\\a))
+           (flush-output-port))
+         (define (look-up-port n)
+           (do ((i 0 (+ i 1))) ((= i n)) (current-output-port)))
+         (define long-list (vector->list (make-vector 100000 12345)))
+         (define (write-long-list)
+           (write long-list)
+           (newline)
+           (flush-output-port))",
+    );
+    bench("(current-output-port) x 10000", None, evaluate("(look-up-port 10000)"));
+    bench("write-char to stdout x 10000", None, evaluate("(write-chars 10000)"));
+    bench("write a 100000-long list to stdout", None, evaluate("(write-long-list)"));
+}
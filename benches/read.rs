@@ -0,0 +1,24 @@
+//! Lexing and reading the fixtures: a long flat list of numbers, deeply
+//! nested lists, vectors and quotations, and a file full of strings.
+
+mod timing;
+
+use timing::bench;
+
+const FIXTURES: &[(&str, &str)] = &[
+    ("numbers", include_str!("fixtures/numbers.scm")),
+    ("nested", include_str!("fixtures/nested.scm")),
+    ("strings", include_str!("fixtures/strings.scm")),
+];
+
+fn main() {
+    for (name, text) in FIXTURES {
+        bench(&format!("lex {name}.scm"), Some(text.len()), || {
+            let (rest, _) = mibph::lex(text).expect("the fixture lexes");
+            assert!(rest.is_empty(), "{name}.scm lexes in full");
+        });
+        bench(&format!("read {name}.scm"), Some(text.len()), || {
+            mibph::read_str(text).expect("the fixture reads");
+        });
+    }
+}
@@ -0,0 +1,24 @@
+//! Getting at the characters of a long string in and out of order, which
+//! costs the same either way since strings are kept as characters rather
+//! than as UTF-8.
+
+mod timing;
+
+use timing::{bench, define, evaluate};
+
+fn main() {
+    define(
+        "(define text (make-string 100000 #\\λ))
+         (define (sequential n)
+           (do ((i 0 (+ i 1))) ((= i n)) (string-ref text i)))
+         ;; a stride coprime to the length visits every index once, out of order
+         (define (scattered n)
+           (do ((i 0 (+ i 1)) (k 0 (modulo (+ k 7919) n))) ((= i n)) (string-ref text k)))
+         (define (overwrite n)
+           (do ((i 0 (+ i 1)) (k 0 (modulo (+ k 7919) n))) ((= i n)) (string-set! text k #\\a)))",
+    );
+    bench("string-ref in order x 100000", None, evaluate("(sequential 100000)"));
+    bench("string-ref out of order x 100000", None, evaluate("(scattered 100000)"));
+    bench("string-set! out of order x 100000", None, evaluate("(overwrite 100000)"));
+    bench("string->list of 100000", None, evaluate("(string->list text)"));
+}
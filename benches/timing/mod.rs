@@ -0,0 +1,49 @@
+//! A little timing harness for the benchmarks, which need nothing fetched
+//! from outside.
+
+// each benchmark only uses some of this
+#![allow(dead_code)]
+
+use std::time::{Duration, Instant};
+
+/// Runs `f` until it has taken up about a second, after running it once to
+/// warm up, and reports how long it took each time. If it's working
+/// through `bytes` of input, that's reported as throughput as well.
+///
+/// Reports go to standard error, so that what the benchmarks write to
+/// standard output can be thrown away.
+pub fn bench(name: &str, bytes: Option<usize>, mut f: impl FnMut()) {
+    f();
+    let start = Instant::now();
+    let mut runs = 0u32;
+    while runs == 0 || start.elapsed() < Duration::from_secs(1) {
+        f();
+        runs += 1;
+    }
+    let each = start.elapsed() / runs;
+    match bytes {
+        Some(bytes) => {
+            let throughput = bytes as f64 / each.as_secs_f64() / 1e6;
+            eprintln!("{name:<36} {each:>12.3?} {throughput:>10.1} MB/s");
+        }
+        None => eprintln!("{name:<36} {each:>12.3?}"),
+    }
+}
+
+/// Evaluates all of `program` in the interaction environment, for setting
+/// up what's to be benchmarked.
+pub fn define(program: &str) {
+    evaluate(program)();
+}
+
+/// Something that evaluates `program` in the interaction environment each
+/// time it's called, having read it once and for all.
+pub fn evaluate(program: &str) -> impl FnMut() {
+    let data = mibph::read_str(program).expect("the program reads");
+    let env = mibph::interaction_environment();
+    move || {
+        for datum in &data {
+            mibph::eval(datum.clone(), &env).expect("the program runs");
+        }
+    }
+}
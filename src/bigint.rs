@@ -0,0 +1,540 @@
+use alloc::{string::ToString, vec::Vec};
+use core::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, BitAnd, BitOr, BitXor, Mul, Neg, Sub},
+};
+
+// the tests have std, whose own methods come first
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+
+/// An integer of any size, for the exact integers that don't fit an `i64`
+/// and for the parts of exact fractions.
+///
+/// It's kept as a sign and a magnitude, the magnitude in base 2^32 with
+/// the least significant limb first and no zero limbs at the top. So zero
+/// has no limbs at all, and it is never negative.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    limbs: Vec<u32>,
+}
+
+impl BigInt {
+    fn from_parts(negative: bool, mut limbs: Vec<u32>) -> BigInt {
+        trim(&mut limbs);
+        BigInt {
+            negative: negative && !limbs.is_empty(),
+            limbs,
+        }
+    }
+
+    /// The integer written as `digits` in `radix`, which must all be digits
+    /// in that radix. There's no sign; negate the result for one.
+    pub fn from_digits(digits: &str, radix: u32) -> Option<BigInt> {
+        let mut limbs = Vec::new();
+        for c in digits.chars() {
+            let digit = c.to_digit(radix)?;
+            let mut carry = u64::from(digit);
+            for limb in &mut limbs {
+                let x = u64::from(*limb) * u64::from(radix) + carry;
+                *limb = x as u32;
+                carry = x >> 32;
+            }
+            if carry != 0 {
+                limbs.push(carry as u32);
+            }
+        }
+        (!digits.is_empty()).then(|| BigInt::from_parts(false, limbs))
+    }
+
+    /// The integer `x`, which must be finite and have no fractional part.
+    pub fn from_f64(x: f64) -> BigInt {
+        debug_assert!(x.is_finite() && x.fract() == 0.0);
+        let bits = x.to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as i64;
+        let mantissa = match exponent {
+            0 => (bits & ((1 << 52) - 1)) << 1,
+            _ => (bits & ((1 << 52) - 1)) | (1 << 52),
+        };
+        // x is mantissa * 2^shift
+        let shift = exponent - 1075;
+        let magnitude = BigInt::from(mantissa);
+        let magnitude = match shift {
+            0.. => magnitude.shl(shift as usize),
+            _ => magnitude.shr(shift.unsigned_abs() as usize),
+        };
+        if x < 0.0 {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    pub fn to_i64(&self) -> Option<i64> {
+        if self.limbs.len() > 2 {
+            return None;
+        }
+        let magnitude = self
+            .limbs
+            .iter()
+            .rev()
+            .fold(0u64, |acc, &limb| (acc << 32) | u64::from(limb));
+        match self.negative {
+            false => i64::try_from(magnitude).ok(),
+            true if magnitude == 1 << 63 => Some(i64::MIN),
+            true => i64::try_from(magnitude).ok().map(|m| -m),
+        }
+    }
+
+    /// The nearest `f64`, which is infinite beyond the range of `f64`s.
+    pub fn to_f64(&self) -> f64 {
+        if let Some(i) = self.to_i64() {
+            return i as f64;
+        }
+        // Rust's float parsing rounds correctly, which adding up the limbs
+        // wouldn't
+        self.to_string().parse().unwrap_or(f64::NAN)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn is_even(&self) -> bool {
+        self.limbs.first().is_none_or(|limb| limb % 2 == 0)
+    }
+
+    pub fn abs(&self) -> BigInt {
+        BigInt::from_parts(false, self.limbs.clone())
+    }
+
+    /// How many bits the magnitude takes, without leading zeros.
+    pub fn bits(&self) -> usize {
+        match self.limbs.last() {
+            Some(top) => self.limbs.len() * 32 - top.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// The magnitude multiplied by 2^`n`, keeping the sign.
+    pub fn shl(&self, n: usize) -> BigInt {
+        BigInt::from_parts(self.negative, shl_limbs(&self.limbs, n))
+    }
+
+    /// The magnitude divided by 2^`n`, rounding towards zero and keeping
+    /// the sign.
+    pub fn shr(&self, n: usize) -> BigInt {
+        BigInt::from_parts(self.negative, shr_limbs(&self.limbs, n))
+    }
+
+    /// `self` times 2^`n`, where a negative `n` divides and rounds down, as
+    /// shifting a two's complement integer would.
+    pub fn arithmetic_shift(&self, n: i64) -> BigInt {
+        let bits = n.unsigned_abs() as usize;
+        match n {
+            0.. => self.shl(bits),
+            _ if !self.negative => self.shr(bits),
+            _ => -&(&(&self.abs() - &BigInt::from(1i64)).shr(bits) + &BigInt::from(1i64)),
+        }
+    }
+
+    /// How many 1 bits the magnitude has.
+    pub fn count_ones(&self) -> u64 {
+        self.limbs
+            .iter()
+            .map(|limb| u64::from(limb.count_ones()))
+            .sum()
+    }
+
+    /// `len` limbs of `self` in two's complement, which has to be enough
+    /// of them for the top bit to be the sign.
+    fn twos_complement(&self, len: usize) -> Vec<u32> {
+        let (mut limbs, fill) = match self.negative {
+            // -x is !(x - 1)
+            true => {
+                let less = sub_limbs(&self.limbs, &[1]);
+                (less.iter().map(|limb| !limb).collect::<Vec<_>>(), u32::MAX)
+            }
+            false => (self.limbs.clone(), 0),
+        };
+        limbs.resize(len, fill);
+        limbs
+    }
+
+    fn from_twos_complement(limbs: Vec<u32>) -> BigInt {
+        match limbs.last() {
+            Some(top) if top >> 31 == 1 => {
+                let less: Vec<u32> = limbs.iter().map(|limb| !limb).collect();
+                BigInt::from_parts(true, add_limbs(&less, &[1]))
+            }
+            _ => BigInt::from_parts(false, limbs),
+        }
+    }
+
+    /// `op` applied to each pair of bits of `self` and `other` in two's
+    /// complement, as though they went on forever to the left.
+    fn bitwise(&self, other: &BigInt, op: impl Fn(u32, u32) -> u32) -> BigInt {
+        let len = self.limbs.len().max(other.limbs.len()) + 1;
+        let (a, b) = (self.twos_complement(len), other.twos_complement(len));
+        BigInt::from_twos_complement(a.iter().zip(&b).map(|(&x, &y)| op(x, y)).collect())
+    }
+
+    /// The quotient rounded towards zero and the remainder, which has the
+    /// same sign as `self`. Panics if `divisor` is zero.
+    pub fn div_rem(&self, divisor: &BigInt) -> (BigInt, BigInt) {
+        assert!(!divisor.is_zero(), "BigInt division by zero");
+        let (q, r) = div_rem_limbs(&self.limbs, &divisor.limbs);
+        (
+            BigInt::from_parts(self.negative != divisor.negative, q),
+            BigInt::from_parts(self.negative, r),
+        )
+    }
+
+    /// The greatest integer whose square is at most `self`, which mustn't
+    /// be negative.
+    pub fn isqrt(&self) -> BigInt {
+        debug_assert!(!self.negative);
+        if self.is_zero() {
+            return BigInt::default();
+        }
+        // Newton's method from above: each step is the average of x and
+        // self / x, which only goes down until it reaches the root
+        let mut x = BigInt::from(1i64).shl(self.bits().div_ceil(2));
+        loop {
+            let next = (&x + &self.div_rem(&x).0).shr(1);
+            if next >= x {
+                return x;
+            }
+            x = next;
+        }
+    }
+
+    /// The greatest common divisor of `self` and `other`, which is never
+    /// negative.
+    pub fn gcd(&self, other: &BigInt) -> BigInt {
+        let (mut a, mut b) = (self.abs(), other.abs());
+        while !b.is_zero() {
+            let (_, r) = a.div_rem(&b);
+            (a, b) = (b, r);
+        }
+        a
+    }
+}
+
+fn trim(limbs: &mut Vec<u32>) {
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+}
+
+fn cmp_limbs(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut sum = Vec::with_capacity(long.len() + 1);
+    let mut carry = 0;
+    for (i, &x) in long.iter().enumerate() {
+        let s = u64::from(x) + u64::from(short.get(i).copied().unwrap_or(0)) + carry;
+        sum.push(s as u32);
+        carry = s >> 32;
+    }
+    if carry != 0 {
+        sum.push(carry as u32);
+    }
+    sum
+}
+
+/// `a - b`, where `a` is at least `b`.
+fn sub_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut difference = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+    for (i, &x) in a.iter().enumerate() {
+        let d = i64::from(x) - i64::from(b.get(i).copied().unwrap_or(0)) - borrow;
+        difference.push(d as u32);
+        borrow = i64::from(d < 0);
+    }
+    trim(&mut difference);
+    difference
+}
+
+fn mul_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut product = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0;
+        for (j, &y) in b.iter().enumerate() {
+            let p = u64::from(x) * u64::from(y) + u64::from(product[i + j]) + carry;
+            product[i + j] = p as u32;
+            carry = p >> 32;
+        }
+        product[i + b.len()] = carry as u32;
+    }
+    trim(&mut product);
+    product
+}
+
+fn shl_limbs(limbs: &[u32], n: usize) -> Vec<u32> {
+    if limbs.is_empty() {
+        return Vec::new();
+    }
+    let (whole, bits) = (n / 32, n % 32);
+    let mut shifted = vec![0; whole];
+    let mut carry = 0;
+    for &limb in limbs {
+        let x = (u64::from(limb) << bits) | carry;
+        shifted.push(x as u32);
+        carry = x >> 32;
+    }
+    if carry != 0 {
+        shifted.push(carry as u32);
+    }
+    shifted
+}
+
+fn shr_limbs(limbs: &[u32], n: usize) -> Vec<u32> {
+    let (whole, bits) = (n / 32, n % 32);
+    if whole >= limbs.len() {
+        return Vec::new();
+    }
+    let limbs = &limbs[whole..];
+    let mut shifted: Vec<u32> = (0..limbs.len())
+        .map(|i| {
+            let high = limbs.get(i + 1).map_or(0, |&next| u64::from(next) << 32);
+            ((high | u64::from(limbs[i])) >> bits) as u32
+        })
+        .collect();
+    trim(&mut shifted);
+    shifted
+}
+
+/// The quotient and remainder of magnitudes, by long division as in Knuth's
+/// Algorithm D: each limb of the quotient is estimated from the top limbs,
+/// and corrected if the estimate turns out too big.
+fn div_rem_limbs(u: &[u32], v: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if cmp_limbs(u, v) == Ordering::Less {
+        return (Vec::new(), u.to_vec());
+    }
+    if let [d] = v {
+        let d = u64::from(*d);
+        let mut quotient = vec![0; u.len()];
+        let mut remainder = 0;
+        for (i, &limb) in u.iter().enumerate().rev() {
+            let x = (remainder << 32) | u64::from(limb);
+            quotient[i] = (x / d) as u32;
+            remainder = x % d;
+        }
+        trim(&mut quotient);
+        let mut remainder = vec![remainder as u32];
+        trim(&mut remainder);
+        return (quotient, remainder);
+    }
+    // with the divisor's top bit set, the estimates are off by at most two
+    let shift = v[v.len() - 1].leading_zeros() as usize;
+    let v = shl_limbs(v, shift);
+    let mut u = shl_limbs(u, shift);
+    u.resize(u.len().max(v.len()) + 1, 0);
+    let n = v.len();
+    let base = 1u128 << 32;
+    let mut quotient = vec![0; u.len() - n];
+    for j in (0..u.len() - n).rev() {
+        let top = (u128::from(u[j + n]) << 32) | u128::from(u[j + n - 1]);
+        let mut estimate = top / u128::from(v[n - 1]);
+        let mut rest = top % u128::from(v[n - 1]);
+        while estimate >= base
+            || estimate * u128::from(v[n - 2]) > ((rest << 32) | u128::from(u[j + n - 2]))
+        {
+            estimate -= 1;
+            rest += u128::from(v[n - 1]);
+            if rest >= base {
+                break;
+            }
+        }
+        // subtract estimate * v from the part of u it's under
+        let mut borrow = 0i128;
+        let mut carry = 0u128;
+        for i in 0..n {
+            let p = estimate * u128::from(v[i]) + carry;
+            carry = p >> 32;
+            let t = i128::from(u[i + j]) - borrow - (p & 0xffff_ffff) as i128;
+            u[i + j] = t as u32;
+            borrow = i128::from(t < 0);
+        }
+        let t = i128::from(u[j + n]) - borrow - carry as i128;
+        u[j + n] = t as u32;
+        if t < 0 {
+            // the estimate was one too many, so add v back
+            estimate -= 1;
+            let mut carry = 0;
+            for i in 0..n {
+                let s = u64::from(u[i + j]) + u64::from(v[i]) + carry;
+                u[i + j] = s as u32;
+                carry = s >> 32;
+            }
+            u[j + n] = u[j + n].wrapping_add(carry as u32);
+        }
+        quotient[j] = estimate as u32;
+    }
+    trim(&mut quotient);
+    let remainder = shr_limbs(&u[..n], shift);
+    (quotient, remainder)
+}
+
+impl From<i64> for BigInt {
+    fn from(i: i64) -> BigInt {
+        let mut big = BigInt::from(i.unsigned_abs());
+        big.negative = i < 0;
+        big
+    }
+}
+
+impl From<u64> for BigInt {
+    fn from(u: u64) -> BigInt {
+        BigInt::from_parts(false, vec![u as u32, (u >> 32) as u32])
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(i: i128) -> BigInt {
+        let u = i.unsigned_abs();
+        let limbs = (0..4).map(|k| (u >> (32 * k)) as u32).collect();
+        BigInt::from_parts(i < 0, limbs)
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.limbs)
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::from_parts(self.negative, add_limbs(&self.limbs, &other.limbs));
+        }
+        match cmp_limbs(&self.limbs, &other.limbs) {
+            Ordering::Less => {
+                BigInt::from_parts(other.negative, sub_limbs(&other.limbs, &self.limbs))
+            }
+            _ => BigInt::from_parts(self.negative, sub_limbs(&self.limbs, &other.limbs)),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: &BigInt) -> BigInt {
+        self + &-other
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: &BigInt) -> BigInt {
+        BigInt::from_parts(
+            self.negative != other.negative,
+            mul_limbs(&self.limbs, &other.limbs),
+        )
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        -self.clone()
+    }
+}
+
+/// The bitwise operators work on two's complement.
+impl BitAnd for &BigInt {
+    type Output = BigInt;
+
+    fn bitand(self, other: &BigInt) -> BigInt {
+        self.bitwise(other, |x, y| x & y)
+    }
+}
+
+impl BitOr for &BigInt {
+    type Output = BigInt;
+
+    fn bitor(self, other: &BigInt) -> BigInt {
+        self.bitwise(other, |x, y| x | y)
+    }
+}
+
+impl BitXor for &BigInt {
+    type Output = BigInt;
+
+    fn bitxor(self, other: &BigInt) -> BigInt {
+        self.bitwise(other, |x, y| x ^ y)
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &BigInt) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_limbs(&self.limbs, &other.limbs),
+            (true, true) => cmp_limbs(&other.limbs, &self.limbs),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &BigInt) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // nine decimal digits at a time, least significant first
+        let chunk = vec![1_000_000_000];
+        let mut chunks = Vec::new();
+        let mut rest = self.limbs.clone();
+        while !rest.is_empty() {
+            let (q, r) = div_rem_limbs(&rest, &chunk);
+            chunks.push(r.first().copied().unwrap_or(0));
+            rest = q;
+        }
+        if self.negative {
+            write!(f, "-")?;
+        }
+        match chunks.split_last() {
+            None => write!(f, "0"),
+            Some((first, others)) => {
+                write!(f, "{first}")?;
+                for chunk in others.iter().rev() {
+                    write!(f, "{chunk:09}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl fmt::Debug for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self}")
+    }
+}
@@ -0,0 +1,298 @@
+use alloc::vec::Vec;
+
+use crate::{
+    constant,
+    error::Error,
+    eval::Primitive,
+    number::{self, Number},
+    string,
+    sync::{Rc, RefCell},
+    vector, Object,
+};
+
+/// A new bytevector of `bytes`.
+pub fn make(bytes: Vec<u8>) -> Object {
+    Object::Bytevector(Rc::new(RefCell::new(bytes)))
+}
+
+/// The bytes of `obj`, which `who` needs to be a bytevector.
+fn bytevector<'a>(obj: &'a Object, who: &str) -> Result<&'a RefCell<Vec<u8>>, Error> {
+    match obj {
+        Object::Bytevector(v) => Ok(v),
+        _ => Err(Error::wrong_type(who, "a bytevector", obj)),
+    }
+}
+
+/// The bytes of `obj`, which `who` needs to be a bytevector it can change.
+fn bytevector_mut<'a>(obj: &'a Object, who: &str) -> Result<&'a RefCell<Vec<u8>>, Error> {
+    let v = bytevector(obj, who)?;
+    constant::mutable(obj, who)?;
+    Ok(v)
+}
+
+pub fn byte(obj: &Object, who: &str) -> Result<u8, Error> {
+    match obj {
+        Object::Number(Number::Integer(i)) if (0..=255).contains(i) => Ok(*i as u8),
+        _ => Err(Error::wrong_type(who, "a byte", obj)),
+    }
+}
+
+fn make_bytevector(args: &[Object]) -> Result<Object, Error> {
+    let k = number::index(&args[0], "make-bytevector")?;
+    let fill = match args.get(1) {
+        Some(b) => byte(b, "make-bytevector")?,
+        None => 0,
+    };
+    Ok(make(vector::filled(k, fill, "make-bytevector", &args[0])?))
+}
+
+fn bytevector_u8_ref(args: &[Object]) -> Result<Object, Error> {
+    let v = bytevector(&args[0], "bytevector-u8-ref")?.borrow();
+    let k = number::index_below(&args[1], v.len(), "bytevector-u8-ref")?;
+    Ok(Object::Number(Number::Integer(v[k].into())))
+}
+
+fn bytevector_u8_set(args: &[Object]) -> Result<Object, Error> {
+    let mut v = bytevector_mut(&args[0], "bytevector-u8-set!")?.borrow_mut();
+    let k = number::index_below(&args[1], v.len(), "bytevector-u8-set!")?;
+    v[k] = byte(&args[2], "bytevector-u8-set!")?;
+    Ok(Object::Unspecified)
+}
+
+fn bytevector_copy(args: &[Object]) -> Result<Object, Error> {
+    let v = bytevector(&args[0], "bytevector-copy")?.borrow();
+    let range = number::range(&args[1..], v.len(), "bytevector-copy")?;
+    Ok(make(v[range].to_vec()))
+}
+
+/// `(bytevector-copy! to at from [start [end]])`. `copy_within` takes care
+/// of overlapping ranges when `to` and `from` are the same bytevector.
+fn bytevector_copy_to(args: &[Object]) -> Result<Object, Error> {
+    let to = bytevector_mut(&args[0], "bytevector-copy!")?;
+    let from = bytevector(&args[2], "bytevector-copy!")?;
+    let at = number::index(&args[1], "bytevector-copy!")?;
+    let range = number::range(&args[3..], from.borrow().len(), "bytevector-copy!")?;
+    if at > to.borrow().len() || to.borrow().len() - at < range.len() {
+        return Err(number::out_of_range("bytevector-copy!", &args[1]));
+    }
+    if core::ptr::eq(to, from) {
+        to.borrow_mut().copy_within(range, at);
+    } else {
+        to.borrow_mut()[at..at + range.len()].copy_from_slice(&from.borrow()[range]);
+    }
+    Ok(Object::Unspecified)
+}
+
+fn bytevector_append(args: &[Object]) -> Result<Object, Error> {
+    let mut bytes = Vec::new();
+    for arg in args {
+        bytes.extend_from_slice(&bytevector(arg, "bytevector-append")?.borrow());
+    }
+    Ok(make(bytes))
+}
+
+/// Whether all of `args` have the same bytes. Comparing slices looks at
+/// their lengths before any of their contents.
+fn bytevector_eq(args: &[Object]) -> Result<Object, Error> {
+    let vs = args
+        .iter()
+        .map(|arg| Ok(bytevector(arg, "bytevector=?")?.borrow()))
+        .collect::<Result<Vec<_>, Error>>()?;
+    Ok(Object::Boolean(vs.windows(2).all(|w| *w[0] == *w[1])))
+}
+
+/// `(utf8->string v [start [end]])`. Bytes that aren't UTF-8 are a read
+/// error, saying where in `v` the first of them is.
+fn utf8_to_string(args: &[Object]) -> Result<Object, Error> {
+    let v = bytevector(&args[0], "utf8->string")?.borrow();
+    let range = number::range(&args[1..], v.len(), "utf8->string")?;
+    let start = range.start;
+    match core::str::from_utf8(&v[range]) {
+        Ok(s) => Ok(string::make(s.chars())),
+        Err(e) => {
+            let at = start + e.valid_up_to();
+            // with no length, the sequence was cut short by the end
+            let problem = match e.error_len() {
+                Some(_) => "invalid UTF-8",
+                None => "incomplete UTF-8 sequence",
+            };
+            Err(Error::read(
+                format!("utf8->string: {problem} at byte {at}"),
+                vec![args[0].clone()],
+            ))
+        }
+    }
+}
+
+pub const PRIMITIVES: &[Primitive] = &[
+    Primitive::range("make-bytevector", 1, 2, make_bytevector),
+    Primitive::variadic("bytevector", 0, |args| {
+        let bytes = args
+            .iter()
+            .map(|b| byte(b, "bytevector"))
+            .collect::<Result<_, _>>()?;
+        Ok(make(bytes))
+    }),
+    Primitive::fixed("bytevector-u8-ref", 2, bytevector_u8_ref),
+    Primitive::fixed("bytevector-u8-set!", 3, bytevector_u8_set),
+    Primitive::fixed("bytevector-length", 1, |args| {
+        let len = bytevector(&args[0], "bytevector-length")?.borrow().len();
+        Ok(Object::Number(Number::Integer(len as i64)))
+    }),
+    Primitive::variadic("bytevector-append", 0, bytevector_append),
+    Primitive::range("bytevector-copy", 1, 3, bytevector_copy),
+    Primitive::range("bytevector-copy!", 3, 5, bytevector_copy_to),
+    Primitive::range("utf8->string", 1, 3, utf8_to_string),
+    Primitive::variadic("bytevector=?", 2, bytevector_eq),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{check, check_errors};
+
+    #[test]
+    fn making_getting_and_setting() {
+        check(&[
+            ("(make-bytevector 3 7)", "#u8(7 7 7)"),
+            ("(bytevector-length (make-bytevector 2))", "2"),
+            ("(bytevector 1 2 255)", "#u8(1 2 255)"),
+            ("(bytevector)", "#u8()"),
+            ("(bytevector-u8-ref #u8(5 6) 1)", "6"),
+            (
+                "(define b (make-bytevector 2 0)) (bytevector-u8-set! b 1 200) b",
+                "#u8(0 200)",
+            ),
+        ]);
+        check_errors(&[
+            ("(bytevector 256)", "bytevector: expected a byte 256"),
+            (
+                "(make-bytevector 2 -1)",
+                "make-bytevector: expected a byte -1",
+            ),
+            (
+                "(bytevector-u8-ref #u8(1) 1)",
+                "bytevector-u8-ref: index out of range 1",
+            ),
+            (
+                "(bytevector-u8-set! (make-bytevector 1) 0 300)",
+                "bytevector-u8-set!: expected a byte 300",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn appending_and_copying() {
+        check(&[
+            ("(bytevector-length #u8(1 2 3))", "3"),
+            ("(bytevector-append #u8(1) #u8() #u8(2 3))", "#u8(1 2 3)"),
+            ("(bytevector-append)", "#u8()"),
+            ("(bytevector-copy #u8(1 2 3) 1)", "#u8(2 3)"),
+            ("(bytevector-copy #u8(1 2 3) 1 2)", "#u8(2)"),
+            (
+                "(define b (bytevector 1 2)) (eq? b (bytevector-copy b))",
+                "#f",
+            ),
+            (
+                "(define b (bytevector 1 2 3 4 5)) (bytevector-copy! b 1 b 0 3) b",
+                "#u8(1 1 2 3 5)",
+            ),
+            (
+                "(define b (bytevector 1 2 3 4 5)) (bytevector-copy! b 0 b 2) b",
+                "#u8(3 4 5 4 5)",
+            ),
+        ]);
+        check_errors(&[
+            (
+                "(bytevector-copy! (make-bytevector 1) 0 #u8(1 2))",
+                "bytevector-copy!: index out of range 0",
+            ),
+            (
+                "(bytevector-copy #u8(1) 2)",
+                "bytevector-copy: index out of range 2",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn utf8() {
+        check(&[
+            ("(string->utf8 \"abc\")", "#u8(97 98 99)"),
+            ("(string->utf8 \"λ€\")", "#u8(206 187 226 130 172)"),
+            ("(string->utf8 \"aλb\" 1 2)", "#u8(206 187)"),
+            ("(utf8->string (string->utf8 \"hello\"))", "\"hello\""),
+            ("(utf8->string (string->utf8 \"λ€😀\"))", "\"λ€😀\""),
+            ("(utf8->string #u8(97 206 187 98) 1 3)", "\"λ\""),
+        ]);
+        check_errors(&[("(utf8->string #u8(255))", "utf8->string: invalid UTF-8")]);
+    }
+
+    #[test]
+    fn bytevector_eq() {
+        check(&[
+            (
+                "(bytevector=? #u8(1 2) (bytevector 1 2) (bytevector-copy #u8(1 2)))",
+                "#t",
+            ),
+            ("(bytevector=? #u8(1) #u8(1 2))", "#f"),
+            ("(bytevector=? #u8(1 2) #u8(1 3))", "#f"),
+            ("(bytevector=? #u8() #u8())", "#t"),
+        ]);
+        check_errors(&[(
+            "(bytevector=? #u8(1) 1)",
+            "bytevector=?: expected a bytevector 1",
+        )]);
+    }
+
+    #[test]
+    fn invalid_utf8_is_a_read_error() {
+        check(&[
+            ("(utf8->string #u8(226 130 172))", "\"€\""),
+            ("(utf8->string #u8(240 159 152 128 97))", "\"😀a\""),
+            (
+                "(guard (e ((read-error? e) (error-object-message e)))
+                   (utf8->string #u8(97 128)))",
+                "\"utf8->string: invalid UTF-8 at byte 1\"",
+            ),
+            (
+                "(guard (e ((read-error? e) (error-object-message e)))
+                   (utf8->string #u8(97 98 226 130)))",
+                "\"utf8->string: incomplete UTF-8 sequence at byte 2\"",
+            ),
+        ]);
+        check_errors(&[
+            // a lone continuation byte
+            (
+                "(utf8->string #u8(128))",
+                "utf8->string: invalid UTF-8 at byte 0",
+            ),
+            (
+                "(utf8->string #u8(97 98 99 191 97))",
+                "utf8->string: invalid UTF-8 at byte 3",
+            ),
+            // truncated, by the end of the bytevector or of the range
+            (
+                "(utf8->string #u8(206))",
+                "utf8->string: incomplete UTF-8 sequence at byte 0",
+            ),
+            (
+                "(utf8->string #u8(97 240 159 152 128) 0 4)",
+                "utf8->string: incomplete UTF-8 sequence at byte 1",
+            ),
+            // where it is in the whole bytevector, not in the range
+            (
+                "(utf8->string #u8(97 97 206 97) 2)",
+                "utf8->string: invalid UTF-8 at byte 2",
+            ),
+            // an overlong encoding of `/`
+            (
+                "(utf8->string #u8(192 175))",
+                "utf8->string: invalid UTF-8 at byte 0",
+            ),
+            // a surrogate
+            (
+                "(utf8->string #u8(237 160 128))",
+                "utf8->string: invalid UTF-8 at byte 0",
+            ),
+        ]);
+    }
+}
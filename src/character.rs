@@ -0,0 +1,340 @@
+use alloc::vec::Vec;
+
+use crate::{error::Error, eval::Primitive, number::Number, Object};
+
+fn chars(args: &[Object], who: &str) -> Result<Vec<char>, Error> {
+    args.iter()
+        .map(|arg| match arg {
+            Object::Char(c) => Ok(*c),
+            _ => Err(Error::wrong_type(who, "a character", arg)),
+        })
+        .collect()
+}
+
+/// Whether `holds` is true of every adjacent pair of `args`, which compare
+/// by their Unicode scalar values.
+fn chain(args: &[Object], who: &str, holds: fn(&char, &char) -> bool) -> Result<Object, Error> {
+    let cs = chars(args, who)?;
+    Ok(Object::Boolean(cs.windows(2).all(|w| holds(&w[0], &w[1]))))
+}
+
+/// Like [`chain`], but comparing the characters' case foldings, so that
+/// `#\a` and `#\A` are the same.
+fn chain_ci(args: &[Object], who: &str, holds: fn(&char, &char) -> bool) -> Result<Object, Error> {
+    let cs: Vec<char> = chars(args, who)?.into_iter().map(foldcase).collect();
+    Ok(Object::Boolean(cs.windows(2).all(|w| holds(&w[0], &w[1]))))
+}
+
+/// The one character that `mapped` turns `c` into, or `c` itself if
+/// there isn't just one: a character's simple case mapping only differs
+/// from its full one where the full one is several characters long, and
+/// is usually no mapping at all then, as for `ß`, which has no one-letter
+/// uppercase.
+fn simple(c: char, mapped: impl Iterator<Item = char>) -> char {
+    let mut mapped = mapped;
+    match (mapped.next(), mapped.next()) {
+        (Some(m), None) => m,
+        _ => c,
+    }
+}
+
+/// The simple uppercase of `c`. Characters map to single characters, as
+/// they must for `char-upcase`, where `string-upcase` uses the full
+/// mappings that can turn one into several.
+pub fn upcase(c: char) -> char {
+    let offset = match c {
+        // Greek small letters with ypogegrammeni, the iota written under
+        // them, uppercase in full to a capital and a separate iota, but
+        // simply to the capital with prosgegrammeni, beside it
+        '\u{1F80}'..='\u{1F87}' | '\u{1F90}'..='\u{1F97}' | '\u{1FA0}'..='\u{1FA7}' => 8,
+        '\u{1FB3}' | '\u{1FC3}' | '\u{1FF3}' => 9,
+        _ => return simple(c, c.to_uppercase()),
+    };
+    char::from_u32(c as u32 + offset).unwrap_or(c)
+}
+
+/// The simple lowercase of `c`, as [`upcase`] is the simple uppercase.
+pub fn downcase(c: char) -> char {
+    match c {
+        // lowercases in full to i and a combining dot above
+        'İ' => 'i',
+        _ => simple(c, c.to_lowercase()),
+    }
+}
+
+/// The simple case folding of `c`. Lowercasing its uppercase brings
+/// together characters that only differ in case, like `σ` and final `ς`,
+/// which lowercasing alone doesn't.
+pub fn foldcase(c: char) -> char {
+    match c {
+        // dotless i would come back dotted, and dotted capital I only has
+        // a full folding, to i and a combining dot
+        'ı' | 'İ' => c,
+        // Cherokee folds to uppercase, which it had first
+        '\u{13A0}'..='\u{13FD}' | '\u{AB70}'..='\u{ABBF}' => upcase(c),
+        _ => downcase(upcase(c)),
+    }
+}
+
+/// The zero of every run of decimal digits in Unicode, which is to say
+/// characters of general category Nd. They always come ten at a time, in
+/// order from zero to nine.
+#[rustfmt::skip]
+const DIGIT_ZEROS: &[char] = &[
+    '\u{30}', '\u{660}', '\u{6F0}', '\u{7C0}', '\u{966}', '\u{9E6}', '\u{A66}', '\u{AE6}',
+    '\u{B66}', '\u{BE6}', '\u{C66}', '\u{CE6}', '\u{D66}', '\u{DE6}', '\u{E50}', '\u{ED0}',
+    '\u{F20}', '\u{1040}', '\u{1090}', '\u{17E0}', '\u{1810}', '\u{1946}', '\u{19D0}', '\u{1A80}',
+    '\u{1A90}', '\u{1B50}', '\u{1BB0}', '\u{1C40}', '\u{1C50}', '\u{A620}', '\u{A8D0}', '\u{A900}',
+    '\u{A9D0}', '\u{A9F0}', '\u{AA50}', '\u{ABF0}', '\u{FF10}', '\u{104A0}', '\u{10D30}',
+    '\u{10D40}', '\u{11066}', '\u{110F0}', '\u{11136}', '\u{111D0}', '\u{112F0}', '\u{11450}',
+    '\u{114D0}', '\u{11650}', '\u{116C0}', '\u{116D0}', '\u{116DA}', '\u{11730}', '\u{118E0}',
+    '\u{11950}', '\u{11BF0}', '\u{11C50}', '\u{11D50}', '\u{11DA0}', '\u{11DE0}', '\u{11F50}',
+    '\u{16130}', '\u{16A60}', '\u{16AC0}', '\u{16B50}', '\u{16D70}', '\u{1CCF0}', '\u{1D7CE}',
+    '\u{1D7D8}', '\u{1D7E2}', '\u{1D7EC}', '\u{1D7F6}', '\u{1E140}', '\u{1E2F0}', '\u{1E4F0}',
+    '\u{1E5F1}', '\u{1E950}', '\u{1FBF0}',
+];
+
+/// The value of `c` as a decimal digit, in whichever script it's from.
+pub fn digit_value(c: char) -> Option<u32> {
+    let after = DIGIT_ZEROS.partition_point(|&zero| zero <= c);
+    let zero = DIGIT_ZEROS[after.checked_sub(1)?];
+    let value = c as u32 - zero as u32;
+    (value < 10).then_some(value)
+}
+
+fn char_arg(args: &[Object], who: &str) -> Result<char, Error> {
+    Ok(chars(&args[..1], who)?[0])
+}
+
+fn integer_to_char(args: &[Object]) -> Result<Object, Error> {
+    match &args[0] {
+        Object::Number(Number::Integer(i)) => u32::try_from(*i)
+            .ok()
+            .and_then(char::from_u32)
+            .map(Object::Char)
+            .ok_or_else(|| {
+                Error::new(
+                    "integer->char: not a Unicode scalar value",
+                    vec![args[0].clone()],
+                )
+            }),
+        other => Err(Error::wrong_type(
+            "integer->char",
+            "an exact integer",
+            other,
+        )),
+    }
+}
+
+/// Whether `args[0]` is a character that `holds` is true of.
+fn category(args: &[Object], who: &str, holds: fn(char) -> bool) -> Result<Object, Error> {
+    Ok(Object::Boolean(holds(char_arg(args, who)?)))
+}
+
+// These go by Unicode's properties rather than just ASCII, as the report
+// suggests: Alphabetic, Nd for numeric, White_Space, Uppercase and Lowercase.
+pub const PRIMITIVES: &[Primitive] = &[
+    Primitive::variadic("char=?", 2, |args| chain(args, "char=?", char::eq)),
+    Primitive::variadic("char<?", 2, |args| chain(args, "char<?", char::lt)),
+    Primitive::variadic("char>?", 2, |args| chain(args, "char>?", char::gt)),
+    Primitive::variadic("char<=?", 2, |args| chain(args, "char<=?", char::le)),
+    Primitive::variadic("char>=?", 2, |args| chain(args, "char>=?", char::ge)),
+    Primitive::variadic("char-ci=?", 2, |args| chain_ci(args, "char-ci=?", char::eq)),
+    Primitive::variadic("char-ci<?", 2, |args| chain_ci(args, "char-ci<?", char::lt)),
+    Primitive::variadic("char-ci>?", 2, |args| chain_ci(args, "char-ci>?", char::gt)),
+    Primitive::variadic("char-ci<=?", 2, |args| {
+        chain_ci(args, "char-ci<=?", char::le)
+    }),
+    Primitive::variadic("char-ci>=?", 2, |args| {
+        chain_ci(args, "char-ci>=?", char::ge)
+    }),
+    Primitive::fixed("char-alphabetic?", 1, |args| {
+        category(args, "char-alphabetic?", char::is_alphabetic)
+    }),
+    Primitive::fixed("char-numeric?", 1, |args| {
+        category(args, "char-numeric?", |c| digit_value(c).is_some())
+    }),
+    Primitive::fixed("char-whitespace?", 1, |args| {
+        category(args, "char-whitespace?", char::is_whitespace)
+    }),
+    Primitive::fixed("char-upper-case?", 1, |args| {
+        category(args, "char-upper-case?", char::is_uppercase)
+    }),
+    Primitive::fixed("char-lower-case?", 1, |args| {
+        category(args, "char-lower-case?", char::is_lowercase)
+    }),
+    Primitive::fixed("digit-value", 1, |args| {
+        Ok(match digit_value(char_arg(args, "digit-value")?) {
+            Some(d) => Object::Number(Number::Integer(d.into())),
+            None => Object::Boolean(false),
+        })
+    }),
+    Primitive::fixed("char-upcase", 1, |args| {
+        Ok(Object::Char(upcase(char_arg(args, "char-upcase")?)))
+    }),
+    Primitive::fixed("char-downcase", 1, |args| {
+        Ok(Object::Char(downcase(char_arg(args, "char-downcase")?)))
+    }),
+    Primitive::fixed("char-foldcase", 1, |args| {
+        Ok(Object::Char(foldcase(char_arg(args, "char-foldcase")?)))
+    }),
+    Primitive::fixed("char->integer", 1, |args| {
+        let c = char_arg(args, "char->integer")?;
+        Ok(Object::Number(Number::Integer(u32::from(c).into())))
+    }),
+    Primitive::fixed("integer->char", 1, integer_to_char),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{check, check_errors};
+
+    #[test]
+    fn comparisons() {
+        check(&[
+            ("(char<? #\\a #\\b #\\c)", "#t"),
+            ("(char<? #\\a #\\a)", "#f"),
+            ("(char<? #\\a #\\c #\\b)", "#f"),
+            ("(char<=? #\\a #\\a #\\b)", "#t"),
+            ("(char>? #\\c #\\b #\\a)", "#t"),
+            ("(char>=? #\\b #\\b #\\c)", "#f"),
+            ("(char=? #\\λ #\\x3bb #\\λ)", "#t"),
+            ("(char<? #\\Z #\\a)", "#t"),
+        ]);
+        check_errors(&[
+            ("(char<? #\\a 1)", "char<?: expected a character 1"),
+            ("(char=? 'a #\\a)", "char=?: expected a character a"),
+        ]);
+    }
+
+    #[test]
+    fn case_and_code_points() {
+        check(&[
+            ("(char-upcase #\\a)", "#\\A"),
+            ("(char-downcase #\\A)", "#\\a"),
+            ("(char-upcase #\\λ)", "#\\Λ"),
+            ("(char-foldcase #\\Σ)", "#\\σ"),
+            ("(char-upcase #\\1)", "#\\1"),
+            ("(char->integer #\\A)", "65"),
+            ("(integer->char 955)", "#\\λ"),
+            ("(char->integer (integer->char 1114111))", "1114111"),
+        ]);
+        check_errors(&[
+            (
+                "(integer->char 55296)",
+                "integer->char: not a Unicode scalar value 55296",
+            ),
+            (
+                "(integer->char 1114112)",
+                "integer->char: not a Unicode scalar value 1114112",
+            ),
+            ("(integer->char -1)", "integer->char:"),
+            (
+                "(char->integer 65)",
+                "char->integer: expected a character 65",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn categories() {
+        let categories = "(define (categories c)
+                            (map (lambda (pred) (pred c))
+                                 (list char-alphabetic? char-numeric? char-whitespace?
+                                       char-upper-case? char-lower-case?)))";
+        check(&[
+            (
+                &format!("{categories} (categories #\\a)"),
+                "(#t #f #f #f #t)",
+            ),
+            (
+                &format!("{categories} (categories #\\A)"),
+                "(#t #f #f #t #f)",
+            ),
+            (
+                &format!("{categories} (categories #\\5)"),
+                "(#f #t #f #f #f)",
+            ),
+            (
+                &format!("{categories} (categories #\\space)"),
+                "(#f #f #t #f #f)",
+            ),
+            (
+                &format!("{categories} (categories #\\λ)"),
+                "(#t #f #f #f #t)",
+            ),
+            (
+                &format!("{categories} (categories #\\x0664)"),
+                "(#f #t #f #f #f)",
+            ),
+            (
+                &format!("{categories} (categories #\\x3000)"),
+                "(#f #f #t #f #f)",
+            ),
+            (
+                &format!("{categories} (categories #\\;)"),
+                "(#f #f #f #f #f)",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn digit_values() {
+        check(&[
+            ("(digit-value #\\7)", "7"),
+            ("(digit-value #\\0)", "0"),
+            ("(digit-value #\\x0664)", "4"),
+            ("(digit-value #\\x0e53)", "3"),
+            ("(digit-value #\\a)", "#f"),
+            ("(digit-value #\\x00bd)", "#f"),
+        ]);
+        check_errors(&[("(digit-value 7)", "digit-value: expected a character 7")]);
+    }
+
+    #[test]
+    fn case_insensitive_comparisons() {
+        check(&[
+            ("(char-ci=? #\\A #\\a)", "#t"),
+            ("(char-ci=? #\\A #\\a #\\b)", "#f"),
+            ("(char-ci<? #\\a #\\B #\\c)", "#t"),
+            ("(char-ci<? #\\a #\\B #\\b)", "#f"),
+            // where case matters, Z comes before a
+            ("(list (char<? #\\a #\\Z) (char-ci<? #\\a #\\Z))", "(#f #t)"),
+            ("(char-ci>? #\\C #\\b #\\A)", "#t"),
+            ("(char-ci<=? #\\a #\\A #\\b)", "#t"),
+            ("(char-ci>=? #\\b #\\B #\\c)", "#f"),
+            ("(char-ci=? #\\Σ #\\σ #\\ς)", "#t"),
+        ]);
+        check_errors(&[(
+            "(char-ci=? #\\a \"a\")",
+            "char-ci=?: expected a character \"a\"",
+        )]);
+    }
+
+    #[test]
+    fn simple_and_full_case_mappings() {
+        check(&[
+            // a character maps to one character, or stays as it is where
+            // its mapping would be several, while a string can get longer
+            ("(char-upcase #\\ß)", "#\\ß"),
+            ("(string-upcase \"ß\")", "\"SS\""),
+            (
+                "(list->string (map char-upcase (string->list \"straße\")))",
+                "\"STRAßE\"",
+            ),
+            ("(char-downcase #\\İ)", "#\\i"),
+            ("(string-length (string-downcase \"İ\"))", "2"),
+            ("(char-foldcase #\\İ)", "#\\İ"),
+            ("(char-upcase #\\ı)", "#\\I"),
+            ("(char-foldcase #\\ı)", "#\\ı"),
+            ("(string-foldcase \"ß\")", "\"ss\""),
+            // ᾳ has a capital of its own with the iota beside it, but
+            // uppercases in full to a capital and a separate iota
+            ("(char-upcase #\\ᾳ)", "#\\ᾼ"),
+            ("(string-upcase \"ᾳ\")", "\"ΑΙ\""),
+            // sigma is final at the end of a word, in a string
+            ("(char-downcase #\\Σ)", "#\\σ"),
+            ("(string-downcase \"ΟΔΟΣ\")", "\"οδος\""),
+        ]);
+    }
+}
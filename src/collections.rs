@@ -0,0 +1,41 @@
+//! The maps and sets that everything is kept in: std's hashed ones, or
+//! without the `std` feature, `alloc`'s ordered ones under the same names.
+//! Those have all the methods that are called on them here, and only need
+//! keys that can be put in order, as everything used as a key can.
+
+pub use alloc::collections::VecDeque;
+#[cfg(not(feature = "std"))]
+pub use alloc::collections::{BTreeMap as HashMap, BTreeSet as HashSet};
+use core::hash::Hasher;
+#[cfg(feature = "std")]
+pub use std::{
+    collections::{HashMap, HashSet},
+    hash::DefaultHasher,
+};
+
+/// What hash tables hash their keys with.
+#[cfg(not(feature = "std"))]
+pub type DefaultHasher = Fnv;
+
+/// FNV-1a, which is plenty for names in source code, and a good deal
+/// quicker than the standard hasher for the short ones most names are.
+/// Without std there's no standard hasher, and hash tables use it too.
+pub struct Fnv(u64);
+
+impl Default for Fnv {
+    fn default() -> Fnv {
+        Fnv(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
@@ -0,0 +1,128 @@
+//! Literals in code are constants, as R7RS has them: the pairs, strings,
+//! vectors and bytevectors that a `quote` or a self-evaluating datum
+//! evaluates to, and whatever's in them, can't be changed, so that what a
+//! literal evaluates to is the same every time.
+//!
+//! Objects don't have room for a flag of their own, so the constants are
+//! kept in a table by address, held weakly, for the procedures that change
+//! things to look in. Like the rest of what's kept per thread, a constant
+//! handed over to another thread isn't one there.
+
+use alloc::vec::Vec;
+
+use crate::{
+    collections::HashMap,
+    error::Error,
+    list::Pair,
+    sync::{Rc, RefCell, Weak},
+    Object,
+};
+
+/// A constant, held on to only as long as something else holds on to it,
+/// and for only as long as it keeps its address.
+enum Held {
+    Pair(Weak<Pair>),
+    String(Weak<RefCell<Vec<char>>>),
+    Bytevector(Weak<RefCell<Vec<u8>>>),
+    Vector(Weak<RefCell<Vec<Object>>>),
+}
+
+impl Held {
+    fn is_dropped(&self) -> bool {
+        match self {
+            Held::Pair(p) => p.strong_count() == 0,
+            Held::String(s) => s.strong_count() == 0,
+            Held::Bytevector(v) => v.strong_count() == 0,
+            Held::Vector(v) => v.strong_count() == 0,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Constants {
+    held: HashMap<usize, Held>,
+    /// How many there can be before the dropped ones are cleared out.
+    limit: usize,
+}
+
+thread_local! {
+    static CONSTANTS: RefCell<Constants> = RefCell::new(Constants::default());
+}
+
+/// The address of `obj`, if it's the kind of object that can be changed.
+fn address(obj: &Object) -> Option<usize> {
+    Some(match obj {
+        Object::Pair(p) => Rc::as_ptr(p) as usize,
+        Object::String(s) => Rc::as_ptr(s) as usize,
+        Object::Bytevector(v) => Rc::as_ptr(v) as usize,
+        Object::Vector(v) => Rc::as_ptr(v) as usize,
+        _ => return None,
+    })
+}
+
+fn hold(obj: &Object) -> Option<Held> {
+    Some(match obj {
+        Object::Pair(p) => Held::Pair(Rc::downgrade(p)),
+        Object::String(s) => Held::String(Rc::downgrade(s)),
+        Object::Bytevector(v) => Held::Bytevector(Rc::downgrade(v)),
+        Object::Vector(v) => Held::Vector(Rc::downgrade(v)),
+        _ => return None,
+    })
+}
+
+/// Makes `obj` a constant, and everything in it. What's already constant
+/// is left as it is, along with everything in it, so that evaluating the
+/// same literal again only takes a look.
+pub fn freeze(obj: &Object) {
+    if address(obj).is_none() {
+        return;
+    }
+    CONSTANTS.with(|constants| {
+        let mut constants = constants.borrow_mut();
+        let mut todo = vec![obj.clone()];
+        while let Some(obj) = todo.pop() {
+            let Some(address) = address(&obj) else {
+                continue;
+            };
+            if let Some(held) = constants.held.get(&address) {
+                if !held.is_dropped() {
+                    continue;
+                }
+            }
+            if let Some(held) = hold(&obj) {
+                constants.held.insert(address, held);
+            }
+            match &obj {
+                Object::Pair(p) => todo.extend([p.car(), p.cdr()]),
+                Object::Vector(v) => todo.extend(v.borrow().iter().cloned()),
+                _ => {}
+            }
+        }
+        if constants.held.len() > constants.limit {
+            constants.held.retain(|_, held| !held.is_dropped());
+            constants.limit = constants.held.len() * 2 + 64;
+        }
+    })
+}
+
+/// Whether `obj` is a constant.
+pub fn is_constant(obj: &Object) -> bool {
+    let Some(address) = address(obj) else {
+        return false;
+    };
+    CONSTANTS.with(|constants| match constants.borrow().held.get(&address) {
+        Some(held) => !held.is_dropped(),
+        None => false,
+    })
+}
+
+/// An error unless `obj` can be changed, as `who` would change it.
+pub fn mutable(obj: &Object, who: &str) -> Result<(), Error> {
+    match is_constant(obj) {
+        true => Err(Error::new(
+            format!("{who}: can't change a constant"),
+            vec![obj.clone()],
+        )),
+        false => Ok(()),
+    }
+}
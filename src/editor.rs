@@ -0,0 +1,278 @@
+use std::{
+    env, fs,
+    io::{self, Read, Write},
+    path::PathBuf,
+    process::{Command, Stdio},
+};
+
+/// How many lines of history are kept between sessions.
+const HISTORY_LIMIT: usize = 1000;
+
+/// Reads lines from a terminal, letting the user edit them as they type:
+/// the arrow keys move along the line and back and forth through the
+/// lines entered before, which are saved to `~/.mibph_history` for the
+/// next session.
+///
+/// The terminal is put in raw mode with `stty` while a line is being read,
+/// and back the way it was while anything else happens.
+pub struct Editor {
+    history: Vec<String>,
+    file: Option<PathBuf>,
+}
+
+/// A key, or a key combination, that does something.
+enum Key {
+    Char(char),
+    Enter,
+    Backspace,
+    Delete,
+    Left,
+    Right,
+    Up,
+    Down,
+    Home,
+    End,
+    /// Ctrl-U, deleting everything before the cursor.
+    KillBefore,
+    /// Ctrl-D, ending the input if the line is empty.
+    EndOfInput,
+    /// Ctrl-C, abandoning what's been typed.
+    Interrupt,
+    Ignored,
+}
+
+/// The terminal in raw mode until this is dropped.
+struct RawMode(String);
+
+impl RawMode {
+    fn enter() -> Option<RawMode> {
+        let saved = stty(&["-g"])?;
+        stty(&["-icanon", "-echo", "-isig", "min", "1"])?;
+        Some(RawMode(saved))
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        stty(&[&self.0]);
+    }
+}
+
+/// Runs `stty` on the terminal with `args`, returning what it prints if it
+/// succeeds.
+fn stty(args: &[&str]) -> Option<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+    let printed = String::from_utf8_lossy(&output.stdout).trim().to_owned();
+    output.status.success().then_some(printed)
+}
+
+impl Editor {
+    /// An editor for the terminal on standard input, if it can be
+    /// switched into raw mode.
+    pub fn new() -> Option<Editor> {
+        stty(&["-g"])?;
+        let file = env::var_os("HOME").map(|home| PathBuf::from(home).join(".mibph_history"));
+        let history = file
+            .as_ref()
+            .and_then(|file| fs::read_to_string(file).ok())
+            .map(|text| text.lines().map(str::to_owned).collect())
+            .unwrap_or_default();
+        Some(Editor { history, file })
+    }
+
+    /// The next line the user enters after `prompt`, with its line ending,
+    /// or `None` at the end of the input. Ctrl-C gives an error of kind
+    /// [`io::ErrorKind::Interrupted`].
+    pub fn read_line(&mut self, prompt: &str) -> io::Result<Option<String>> {
+        let _raw = RawMode::enter();
+        let mut stdin = io::stdin().lock();
+        let mut stdout = io::stdout().lock();
+        let mut line: Vec<char> = Vec::new();
+        let mut cursor = 0;
+        // which line of history is showing; one past the end is the line
+        // being typed, which is kept in `draft` meanwhile
+        let mut recalled = self.history.len();
+        let mut draft = Vec::new();
+        loop {
+            write!(
+                stdout,
+                "\r{prompt}{}\x1b[K",
+                line.iter().collect::<String>()
+            )?;
+            if cursor < line.len() {
+                write!(stdout, "\x1b[{}D", line.len() - cursor)?;
+            }
+            stdout.flush()?;
+            let Some(key) = key(&mut stdin)? else {
+                writeln!(stdout)?;
+                return Ok(None);
+            };
+            match key {
+                Key::Char(c) => {
+                    line.insert(cursor, c);
+                    cursor += 1;
+                }
+                Key::Backspace if cursor > 0 => {
+                    cursor -= 1;
+                    line.remove(cursor);
+                }
+                Key::Delete | Key::EndOfInput if cursor < line.len() => {
+                    line.remove(cursor);
+                }
+                Key::Left if cursor > 0 => cursor -= 1,
+                Key::Right if cursor < line.len() => cursor += 1,
+                Key::Home => cursor = 0,
+                Key::End => cursor = line.len(),
+                Key::Up if recalled > 0 => {
+                    if recalled == self.history.len() {
+                        draft = line;
+                    }
+                    recalled -= 1;
+                    line = self.history[recalled].chars().collect();
+                    cursor = line.len();
+                }
+                Key::Down if recalled < self.history.len() => {
+                    recalled += 1;
+                    line = match self.history.get(recalled) {
+                        Some(entry) => entry.chars().collect(),
+                        None => draft.clone(),
+                    };
+                    cursor = line.len();
+                }
+                Key::KillBefore => {
+                    line.drain(..cursor);
+                    cursor = 0;
+                }
+                Key::EndOfInput if line.is_empty() => {
+                    writeln!(stdout)?;
+                    return Ok(None);
+                }
+                Key::Interrupt => {
+                    writeln!(stdout, "^C")?;
+                    return Err(io::ErrorKind::Interrupted.into());
+                }
+                Key::Enter => {
+                    writeln!(stdout)?;
+                    let line: String = line.into_iter().collect();
+                    self.remember(&line);
+                    return Ok(Some(line + "\n"));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn remember(&mut self, line: &str) {
+        if !line.trim().is_empty() && self.history.last().map(String::as_str) != Some(line) {
+            self.history.push(line.to_owned());
+        }
+    }
+}
+
+impl Drop for Editor {
+    fn drop(&mut self) {
+        if let Some(file) = &self.file {
+            let kept = &self.history[self.history.len().saturating_sub(HISTORY_LIMIT)..];
+            fs::write(
+                file,
+                kept.iter()
+                    .map(|line| format!("{line}\n"))
+                    .collect::<String>(),
+            )
+            .ok();
+        }
+    }
+}
+
+fn byte(input: &mut impl Read) -> io::Result<Option<u8>> {
+    let mut buf = [0];
+    match input.read(&mut buf)? {
+        0 => Ok(None),
+        _ => Ok(Some(buf[0])),
+    }
+}
+
+/// The next key pressed, decoding escape sequences and UTF-8, or `None`
+/// if the terminal has gone away.
+fn key(input: &mut impl Read) -> io::Result<Option<Key>> {
+    let Some(b) = byte(input)? else {
+        return Ok(None);
+    };
+    Ok(Some(match b {
+        b'\r' | b'\n' => Key::Enter,
+        0x7f | 0x08 => Key::Backspace,
+        0x01 => Key::Home,
+        0x02 => Key::Left,
+        0x03 => Key::Interrupt,
+        0x04 => Key::EndOfInput,
+        0x05 => Key::End,
+        0x06 => Key::Right,
+        0x0e => Key::Down,
+        0x10 => Key::Up,
+        0x15 => Key::KillBefore,
+        0x1b => escape(input)?,
+        0x00..=0x1f => Key::Ignored,
+        0x20..=0x7e => Key::Char(b as char),
+        _ => {
+            let len = match b {
+                0xc0..=0xdf => 2,
+                0xe0..=0xef => 3,
+                0xf0..=0xf7 => 4,
+                _ => return Ok(Some(Key::Ignored)),
+            };
+            let mut bytes = vec![b];
+            for _ in 1..len {
+                bytes.extend(byte(input)?);
+            }
+            match std::str::from_utf8(&bytes)
+                .ok()
+                .and_then(|s| s.chars().next())
+            {
+                Some(c) => Key::Char(c),
+                None => Key::Ignored,
+            }
+        }
+    }))
+}
+
+/// The key that an escape sequence stands for, once its escape has been
+/// read.
+fn escape(input: &mut impl Read) -> io::Result<Key> {
+    match byte(input)? {
+        Some(b'[') => {}
+        Some(b'O') => {
+            return Ok(match byte(input)? {
+                Some(b'H') => Key::Home,
+                Some(b'F') => Key::End,
+                _ => Key::Ignored,
+            })
+        }
+        _ => return Ok(Key::Ignored),
+    }
+    let mut parameter = Vec::new();
+    loop {
+        match byte(input)? {
+            Some(b @ b'0'..=b'9') => parameter.push(b),
+            Some(b'A') => return Ok(Key::Up),
+            Some(b'B') => return Ok(Key::Down),
+            Some(b'C') => return Ok(Key::Right),
+            Some(b'D') => return Ok(Key::Left),
+            Some(b'H') => return Ok(Key::Home),
+            Some(b'F') => return Ok(Key::End),
+            Some(b'~') => {
+                return Ok(match &parameter[..] {
+                    b"1" | b"7" => Key::Home,
+                    b"3" => Key::Delete,
+                    b"4" | b"8" => Key::End,
+                    _ => Key::Ignored,
+                })
+            }
+            _ => return Ok(Key::Ignored),
+        }
+    }
+}
@@ -0,0 +1,331 @@
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::hash::BuildHasherDefault;
+
+#[cfg(feature = "std")]
+use crate::collections::Fnv;
+use crate::{
+    bytevector, character,
+    collections::HashMap,
+    error::{self, Error},
+    eval::{self, Primitive},
+    features, hashtable, list, load, number, parameter, port, read, string,
+    sync::{Rc, RefCell},
+    syntax, vector, Object,
+};
+
+/// The bindings that symbols are looked up in: a frame of its own, and
+/// the frames of every enclosing scope through `parent`.
+#[derive(Default)]
+pub struct Env {
+    parent: Option<Rc<Env>>,
+    frame: RefCell<Frame>,
+}
+
+/// How many bindings a frame holds in a row before it's worth hashing them.
+const SMALL_FRAME: usize = 8;
+
+/// The bindings of one scope. Most are the few parameters of a procedure
+/// call, which are quicker to look through one by one, and to make afresh
+/// for every call, than a table is. The top level gets a table.
+enum Frame {
+    Small(Vec<(Rc<str>, Object)>),
+    Large(Table),
+}
+
+#[cfg(feature = "std")]
+type Table = HashMap<Rc<str>, Object, BuildHasherDefault<Fnv>>;
+#[cfg(not(feature = "std"))]
+type Table = HashMap<Rc<str>, Object>;
+
+impl Default for Frame {
+    fn default() -> Frame {
+        Frame::Small(Vec::new())
+    }
+}
+
+// plain loops rather than iterator chains, since looking up a variable is
+// most of what evaluating one takes in a debug build
+impl Frame {
+    fn get(&self, name: &Rc<str>) -> Option<&Object> {
+        match self {
+            Frame::Small(bindings) => {
+                for (n, value) in bindings {
+                    if n == name {
+                        return Some(value);
+                    }
+                }
+                None
+            }
+            Frame::Large(bindings) => bindings.get(name),
+        }
+    }
+
+    fn get_mut(&mut self, name: &Rc<str>) -> Option<&mut Object> {
+        match self {
+            Frame::Small(bindings) => {
+                for (n, value) in bindings {
+                    if n == name {
+                        return Some(value);
+                    }
+                }
+                None
+            }
+            Frame::Large(bindings) => bindings.get_mut(name),
+        }
+    }
+
+    fn insert(&mut self, name: Rc<str>, value: Object) {
+        if let Some(slot) = self.get_mut(&name) {
+            *slot = value;
+            return;
+        }
+        match self {
+            Frame::Small(bindings) if bindings.len() < SMALL_FRAME => bindings.push((name, value)),
+            Frame::Small(bindings) => {
+                let mut table: Table = bindings.drain(..).collect();
+                table.insert(name, value);
+                *self = Frame::Large(table);
+            }
+            Frame::Large(bindings) => {
+                bindings.insert(name, value);
+            }
+        }
+    }
+
+    fn names(&self) -> Vec<Rc<str>> {
+        match self {
+            Frame::Small(bindings) => bindings.iter().map(|(n, _)| n.clone()).collect(),
+            Frame::Large(bindings) => bindings.keys().cloned().collect(),
+        }
+    }
+}
+
+impl Env {
+    /// An empty environment, with nothing bound in it.
+    pub fn new() -> Env {
+        Env {
+            parent: None,
+            frame: RefCell::default(),
+        }
+    }
+
+    /// An empty scope nested inside `parent`.
+    pub fn extend(parent: Rc<Env>) -> Env {
+        Env {
+            parent: Some(parent),
+            frame: RefCell::default(),
+        }
+    }
+
+    /// An environment holding the standard procedures.
+    pub fn standard() -> Env {
+        let env = Env::new();
+        for table in [
+            crate::PRIMITIVES,
+            PRIMITIVES,
+            bytevector::PRIMITIVES,
+            character::PRIMITIVES,
+            error::PRIMITIVES,
+            eval::PRIMITIVES,
+            features::PRIMITIVES,
+            hashtable::PRIMITIVES,
+            list::PRIMITIVES,
+            list::ACCESSORS,
+            load::PRIMITIVES,
+            number::PRIMITIVES,
+            parameter::PRIMITIVES,
+            port::PRIMITIVES,
+            read::PRIMITIVES,
+            string::PRIMITIVES,
+            syntax::PRIMITIVES,
+            vector::PRIMITIVES,
+        ] {
+            for p in table {
+                env.define(Rc::from(p.name), Object::Primitive(p));
+            }
+        }
+        env.define(Rc::from("apply"), Object::Primitive(&eval::APPLY));
+        for name in ["call-with-current-continuation", "call/cc"] {
+            env.define(Rc::from(name), Object::Primitive(&eval::CALL_CC));
+        }
+        for (name, parameter) in [
+            ("current-input-port", port::current_input_port_parameter()),
+            ("current-output-port", port::current_output_port_parameter()),
+            ("current-error-port", port::current_error_port_parameter()),
+        ] {
+            env.define(Rc::from(name), Object::Parameter(parameter));
+        }
+        env
+    }
+
+    /// The value of the innermost binding of `name`. An alias introduced
+    /// by a macro that nothing has bound means what it renames did where
+    /// the macro was defined.
+    pub fn lookup(&self, name: &Rc<str>) -> Option<Object> {
+        let mut env = self;
+        loop {
+            if let Some(value) = env.frame.borrow().get(name) {
+                return Some(value.clone());
+            }
+            match env.parent.as_deref() {
+                Some(parent) => env = parent,
+                None => {
+                    let (original, env) = syntax::resolve(name)?;
+                    return env.lookup(&original);
+                }
+            }
+        }
+    }
+
+    /// Whether `name` is bound here or in an enclosing scope, not counting
+    /// what it might be an alias of.
+    pub fn binds(&self, name: &Rc<str>) -> bool {
+        let mut env = self;
+        loop {
+            if env.frame.borrow().get(name).is_some() {
+                return true;
+            }
+            match env.parent.as_deref() {
+                Some(parent) => env = parent,
+                None => return false,
+            }
+        }
+    }
+
+    /// Binds `name` in this frame, replacing any binding it already had here.
+    pub fn define(&self, name: Rc<str>, value: Object) {
+        self.frame.borrow_mut().insert(name, value);
+    }
+
+    /// The names bound in this frame itself.
+    pub fn names(&self) -> Vec<Rc<str>> {
+        self.frame.borrow().names()
+    }
+
+    /// Changes the innermost binding of `name`, which must already exist.
+    pub fn set(&self, name: &Rc<str>, value: Object) -> Result<(), Error> {
+        let mut env = self;
+        loop {
+            if let Some(slot) = env.frame.borrow_mut().get_mut(name) {
+                *slot = value;
+                return Ok(());
+            }
+            match env.parent.as_deref() {
+                Some(parent) => env = parent,
+                None => match syntax::resolve(name) {
+                    Some((original, env)) => return env.set(&original, value),
+                    None => return Err(unbound(name)),
+                },
+            }
+        }
+    }
+}
+
+pub fn unbound(name: &Rc<str>) -> Error {
+    Error::new(
+        "unbound variable",
+        vec![Object::Symbol(syntax::unalias(name))],
+    )
+}
+
+thread_local! {
+    static INTERACTION_ENVIRONMENT: Rc<Env> = Rc::new(Env::standard());
+}
+
+/// The top level that the REPL evaluates in, and that definitions made
+/// there end up in.
+pub fn interaction_environment() -> Rc<Env> {
+    INTERACTION_ENVIRONMENT.with(Rc::clone)
+}
+
+/// The environments of `scheme-report-environment` and `null-environment`
+/// only come in the versions of the report that this implements.
+fn report_version(who: &str, version: &Object) -> Result<(), Error> {
+    match version {
+        Object::Number(number::Number::Integer(5 | 7)) => Ok(()),
+        _ => Err(Error::new(
+            format!("{who}: unsupported version of the report"),
+            vec![version.clone()],
+        )),
+    }
+}
+
+pub const PRIMITIVES: &[Primitive] = &[
+    Primitive::fixed("interaction-environment", 0, |_| {
+        Ok(Object::Environment(interaction_environment()))
+    }),
+    Primitive::fixed("scheme-report-environment", 1, |args| {
+        report_version("scheme-report-environment", &args[0])?;
+        Ok(Object::Environment(Rc::new(Env::standard())))
+    }),
+    // syntactic keywords are recognised by the evaluator without being
+    // bound, so an environment with nothing in it has exactly those
+    Primitive::fixed("null-environment", 1, |args| {
+        report_version("null-environment", &args[0])?;
+        Ok(Object::Environment(Rc::new(Env::new())))
+    }),
+];
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    fn int(i: i64) -> Object {
+        Object::Number(number::Number::Integer(i))
+    }
+
+    #[test]
+    fn child_frames_shadow_their_parents() {
+        let x: Rc<str> = Rc::from("x");
+        let global = Rc::new(Env::new());
+        global.define(x.clone(), int(1));
+        let local = Env::extend(global.clone());
+        local.define(x.clone(), int(2));
+        assert_eq!(local.lookup(&x).unwrap().to_string(), "2");
+        assert_eq!(global.lookup(&x).unwrap().to_string(), "1");
+        local.set(&x, int(3)).unwrap();
+        assert_eq!(global.lookup(&x).unwrap().to_string(), "1");
+        let inner = Env::extend(Rc::new(Env::extend(global.clone())));
+        inner.set(&x, int(4)).unwrap();
+        assert_eq!(global.lookup(&x).unwrap().to_string(), "4");
+    }
+
+    #[test]
+    fn frames_outgrowing_a_few_bindings() {
+        let names: Vec<Rc<str>> = (0..20).map(|i| Rc::from(format!("x{i}"))).collect();
+        let global = Rc::new(Env::new());
+        global.define(names[0].clone(), int(-1));
+        let local = Env::extend(global.clone());
+        for (i, name) in names.iter().enumerate() {
+            local.define(name.clone(), int(i as i64));
+            local.define(name.clone(), int(i as i64 * 10));
+        }
+        local.set(&names[19], int(7)).unwrap();
+        for (i, name) in names[..19].iter().enumerate() {
+            assert_eq!(
+                local.lookup(name).unwrap().to_string(),
+                (i * 10).to_string()
+            );
+        }
+        assert_eq!(local.lookup(&names[19]).unwrap().to_string(), "7");
+        assert_eq!(global.lookup(&names[0]).unwrap().to_string(), "-1");
+        let mut bound = local.names();
+        bound.sort();
+        let mut expected = names.clone();
+        expected.sort();
+        assert_eq!(bound, expected);
+    }
+
+    #[test]
+    fn unbound_variables() {
+        let y: Rc<str> = Rc::from("y");
+        let env = Env::extend(Rc::new(Env::new()));
+        assert!(env.lookup(&y).is_none());
+        let e = env.set(&y, int(1)).unwrap_err();
+        assert_eq!(e.to_string(), "unbound variable y");
+        assert!(!env.binds(&y));
+    }
+}
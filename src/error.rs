@@ -0,0 +1,458 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use crate::{
+    eval::{apply, Continuation, Primitive},
+    is_procedure,
+    lex::Span,
+    string,
+    sync::{Cell, Rc, RefCell},
+    vec_to_list, Object,
+};
+
+thread_local! {
+    /// The handlers installed by `with-exception-handler`, innermost last.
+    static HANDLERS: RefCell<Vec<Object>> = const { RefCell::new(Vec::new()) };
+}
+
+/// What sort of error a condition reports, for `read-error?` and `file-error?`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ConditionKind {
+    Error,
+    Read,
+    File,
+}
+
+/// An error object, as raised by the runtime when something goes wrong.
+pub struct Condition {
+    pub kind: ConditionKind,
+    pub message: String,
+    pub irritants: Vec<Object>,
+    /// Where in the text being read or evaluated the error happened, once
+    /// something that knows has said.
+    pub span: Cell<Option<Span>>,
+}
+
+/// Why a computation stopped before producing a value.
+pub enum Error {
+    /// An object was raised, and no handler has seen it yet. The innermost
+    /// `with-exception-handler` it unwinds through hands it to its handler.
+    Raise(Object),
+    /// An object was raised while the handler with `depth` handlers beneath
+    /// it was running, or because that handler returned from a `raise`. It
+    /// unwinds quietly to the `with-exception-handler` that installed the
+    /// handler and carries on from there as an ordinary [`Error::Raise`].
+    Reraise(usize, Object),
+    /// A continuation was invoked with a value, and everything up to the
+    /// `call/cc` that captured it is being abandoned.
+    Throw(Rc<Continuation>, Object),
+    /// `exit` or `emergency-exit` was called, and the program wants to end
+    /// with `status`. Once this reaches whoever is running the evaluator it
+    /// is up to them to actually exit, or not.
+    Exit { status: i32, emergency: bool },
+}
+
+impl Error {
+    pub fn new(message: impl Into<String>, irritants: Vec<Object>) -> Error {
+        Error::condition(ConditionKind::Error, message, irritants)
+    }
+
+    /// An error in the syntax of the text being read.
+    pub fn read(message: impl Into<String>, irritants: Vec<Object>) -> Error {
+        Error::condition(ConditionKind::Read, message, irritants)
+    }
+
+    /// A failure to open or otherwise deal with a file.
+    pub fn file(message: impl Into<String>, irritants: Vec<Object>) -> Error {
+        Error::condition(ConditionKind::File, message, irritants)
+    }
+
+    /// `who` was given `obj` where it needed something else.
+    pub fn wrong_type(who: &str, expected: &str, obj: &Object) -> Error {
+        Error::new(format!("{who}: expected {expected}"), vec![obj.clone()])
+    }
+
+    /// There isn't the memory for what `obj` asked `who` to make.
+    pub fn out_of_memory(who: &str, obj: &Object) -> Error {
+        Error::new(format!("{who}: out of memory"), vec![obj.clone()])
+    }
+
+    fn condition(kind: ConditionKind, message: impl Into<String>, irritants: Vec<Object>) -> Error {
+        Error::Raise(Object::Condition(Rc::new(Condition {
+            kind,
+            message: message.into(),
+            irritants,
+            span: Cell::new(None),
+        })))
+    }
+
+    /// The same error, saying that it happened at `span` unless it already
+    /// says somewhere more precise. Only error objects can say where they
+    /// happened, so anything else raised is left as it is.
+    pub fn at(self, span: Span) -> Error {
+        if let Error::Raise(Object::Condition(c)) | Error::Reraise(_, Object::Condition(c)) = &self
+        {
+            if c.span.get().is_none() {
+                c.span.set(Some(span));
+            }
+        }
+        self
+    }
+
+    /// Where the error happened, if it says.
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            Error::Raise(Object::Condition(c)) | Error::Reraise(_, Object::Condition(c)) => {
+                c.span.get()
+            }
+            _ => None,
+        }
+    }
+}
+
+/// What went wrong, as the REPL would report it: an error object's message
+/// and irritants, or what was raised, if it wasn't an error object.
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Raise(Object::Condition(c)) | Error::Reraise(_, Object::Condition(c)) => {
+                write!(f, "{}", c.message)?;
+                for irritant in &c.irritants {
+                    write!(f, " {irritant}")?;
+                }
+                Ok(())
+            }
+            Error::Raise(obj) | Error::Reraise(_, obj) => write!(f, "uncaught exception: {obj}"),
+            Error::Throw(..) => write!(f, "continuation called from outside its extent"),
+            Error::Exit { status, .. } => write!(f, "exit with status {status}"),
+        }
+    }
+}
+
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Raise(obj) => f.debug_tuple("Raise").field(obj).finish(),
+            Error::Reraise(depth, obj) => f.debug_tuple("Reraise").field(depth).field(obj).finish(),
+            Error::Throw(_, obj) => f.debug_tuple("Throw").field(obj).finish(),
+            Error::Exit { status, emergency } => f
+                .debug_struct("Exit")
+                .field("status", status)
+                .field("emergency", emergency)
+                .finish(),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Runs `body` with only the first `depth` handlers installed, putting the
+/// others back afterwards.
+fn with_handlers<T>(depth: usize, body: impl FnOnce() -> T) -> T {
+    struct Restore(Vec<Object>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            HANDLERS.with(|handlers| handlers.borrow_mut().append(&mut self.0));
+        }
+    }
+
+    let _restore = Restore(HANDLERS.with(|handlers| handlers.borrow_mut().split_off(depth)));
+    body()
+}
+
+/// Calls `handler` on `obj`. A handler can only return a value for a
+/// continuable raise; returning from any other is an error in its own right.
+fn handle(handler: &Object, obj: Object, continuable: bool) -> Result<Object, Error> {
+    let value = apply(handler, vec![obj.clone()])?;
+    if continuable {
+        Ok(value)
+    } else {
+        Err(Error::new(
+            "handler returned from a non-continuable raise",
+            vec![obj],
+        ))
+    }
+}
+
+/// `(raise obj)` and `(raise-continuable obj)`: calls the current handler
+/// right where `obj` is raised, with the handlers outside it installed.
+pub fn raise(obj: Object, continuable: bool) -> Result<Object, Error> {
+    let Some((depth, handler)) = HANDLERS.with(|handlers| {
+        let handlers = handlers.borrow();
+        let handler = handlers.last()?.clone();
+        Some((handlers.len() - 1, handler))
+    }) else {
+        return Err(Error::Raise(obj));
+    };
+    with_handlers(depth, || handle(&handler, obj, continuable)).map_err(|e| match e {
+        Error::Raise(obj) => Error::Reraise(depth, obj),
+        e => e,
+    })
+}
+
+/// Runs `body` with `handler` installed, as `with-exception-handler` does.
+///
+/// Objects raised with `raise` and `raise-continuable` reach `handler`
+/// before anything unwinds. Errors that the runtime itself signals reach
+/// it once they have unwound out of `body`, which makes no difference to
+/// the handler except that the `after`s of any `dynamic-wind`s in between
+/// have already run.
+pub fn with_handler(
+    handler: &Object,
+    body: impl FnOnce() -> Result<Object, Error>,
+) -> Result<Object, Error> {
+    struct Uninstall(usize);
+    impl Drop for Uninstall {
+        fn drop(&mut self) {
+            HANDLERS.with(|handlers| handlers.borrow_mut().truncate(self.0));
+        }
+    }
+
+    let depth = HANDLERS.with(|handlers| {
+        let mut handlers = handlers.borrow_mut();
+        handlers.push(handler.clone());
+        handlers.len() - 1
+    });
+    let result = {
+        let _uninstall = Uninstall(depth);
+        body()
+    };
+    match result {
+        Err(Error::Raise(obj)) => handle(handler, obj, false),
+        Err(Error::Reraise(d, obj)) if d == depth => Err(Error::Raise(obj)),
+        result => result,
+    }
+}
+
+fn with_exception_handler(args: &[Object]) -> Result<Object, Error> {
+    if !is_procedure(&args[0]) {
+        return Err(Error::wrong_type(
+            "with-exception-handler",
+            "a procedure",
+            &args[0],
+        ));
+    }
+    with_handler(&args[0], || apply(&args[1], vec![]))
+}
+
+/// `(error message irritant...)`: raises a new error object.
+fn error(args: &[Object]) -> Result<Object, Error> {
+    let Object::String(message) = &args[0] else {
+        return Err(Error::wrong_type("error", "a string", &args[0]));
+    };
+    let condition = Condition {
+        kind: ConditionKind::Error,
+        message: message.borrow().iter().collect(),
+        irritants: args[1..].to_vec(),
+        span: Cell::new(None),
+    };
+    raise(Object::Condition(Rc::new(condition)), false)
+}
+
+fn condition<'a>(obj: &'a Object, who: &str) -> Result<&'a Condition, Error> {
+    match obj {
+        Object::Condition(c) => Ok(c),
+        _ => Err(Error::wrong_type(who, "an error object", obj)),
+    }
+}
+
+fn is_read_error(obj: Object) -> Object {
+    Object::Boolean(matches!(obj, Object::Condition(c) if c.kind == ConditionKind::Read))
+}
+
+fn is_file_error(obj: Object) -> Object {
+    Object::Boolean(matches!(obj, Object::Condition(c) if c.kind == ConditionKind::File))
+}
+
+pub const PRIMITIVES: &[Primitive] = &[
+    Primitive::fixed("raise", 1, |args| raise(args[0].clone(), false)),
+    Primitive::fixed("raise-continuable", 1, |args| raise(args[0].clone(), true)),
+    Primitive::fixed("with-exception-handler", 2, with_exception_handler),
+    Primitive::variadic("error", 1, error),
+    Primitive::fixed("error-object?", 1, |args| {
+        Ok(Object::Boolean(matches!(args[0], Object::Condition(_))))
+    }),
+    Primitive::fixed("error-object-message", 1, |args| {
+        let c = condition(&args[0], "error-object-message")?;
+        Ok(string::make(c.message.chars()))
+    }),
+    Primitive::fixed("error-object-irritants", 1, |args| {
+        let c = condition(&args[0], "error-object-irritants")?;
+        Ok(vec_to_list(c.irritants.clone(), Object::Null))
+    }),
+    Primitive::fixed("read-error?", 1, |args| Ok(is_read_error(args[0].clone()))),
+    Primitive::fixed("file-error?", 1, |args| Ok(is_file_error(args[0].clone()))),
+];
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "std")]
+    use crate::testing::temp_path;
+    use crate::testing::{check, check_errors};
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn read_and_file_errors_are_told_apart() {
+        let missing = temp_path("missing");
+        let kind = "(define (kind thunk)
+                      (guard (e ((read-error? e) 'read) ((file-error? e) 'file) (#t 'other))
+                        (thunk)))";
+        check(&[
+            (
+                &format!("{kind} (kind (lambda () (read (open-input-string \"(1 . )\"))))"),
+                "read",
+            ),
+            (
+                &format!("{kind} (kind (lambda () (read (open-input-string \"#\\\\nope\"))))"),
+                "read",
+            ),
+            (
+                &format!("{kind} (kind (lambda () (open-input-file {missing:?})))"),
+                "file",
+            ),
+            (&format!("{kind} (kind (lambda () (car 1)))"), "other"),
+            ("(list (read-error? 'x) (file-error? \"x\"))", "(#f #f)"),
+        ]);
+    }
+
+    #[test]
+    fn raise_reaches_the_installed_handler() {
+        check(&[
+            (
+                "(call/cc (lambda (k)
+                   (with-exception-handler (lambda (e) (k (list 'caught e)))
+                     (lambda () (raise 'oops)))))",
+                "(caught oops)",
+            ),
+            (
+                "(with-exception-handler (lambda (e) (* e 10))
+                   (lambda () (+ 1 (raise-continuable 4))))",
+                "41",
+            ),
+        ]);
+        check_errors(&[
+            ("(raise 'oops)", "uncaught exception: oops"),
+            ("(raise (list 1 2))", "uncaught exception: (1 2)"),
+            (
+                "(with-exception-handler (lambda (e) 0) (lambda () (raise 'oops)))",
+                "handler returned from a non-continuable raise",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn handlers_recover_or_reraise() {
+        check(&[
+            (
+                "(with-exception-handler
+                   (lambda (e) (if (string? e) (string-length e) 0))
+                   (lambda () (list (raise-continuable \"four\") (raise-continuable 'x))))",
+                "(4 0)",
+            ),
+            (
+                "(call/cc (lambda (k)
+                   (with-exception-handler (lambda (outer) (k (list 'outer outer)))
+                     (lambda ()
+                       (with-exception-handler (lambda (inner) (raise (list 'inner inner)))
+                         (lambda () (raise 'oops)))))))",
+                "(outer (inner oops))",
+            ),
+            (
+                "(with-exception-handler (lambda (e) (+ e 1))
+                   (lambda ()
+                     (with-exception-handler (lambda (e) (* 2 (raise-continuable e)))
+                       (lambda () (raise-continuable 5)))))",
+                "12",
+            ),
+        ]);
+        check_errors(&[(
+            "(with-exception-handler (lambda (e) (raise (list 'again e)))
+               (lambda () (raise 'oops)))",
+            "uncaught exception: (again oops)",
+        )]);
+    }
+
+    #[test]
+    fn error_objects_carry_their_message_and_irritants() {
+        check(&[
+            (
+                "(guard (e (#t (error-object-message e))) (error \"boom\" 1 2))",
+                "\"boom\"",
+            ),
+            (
+                "(guard (e (#t (error-object-irritants e))) (error \"boom\" 1 2))",
+                "(1 2)",
+            ),
+            (
+                "(guard (e (#t (error-object-irritants e))) (error \"boom\"))",
+                "()",
+            ),
+            (
+                "(guard (e (#t (list (error-object? e) (error-object? 'x)))) (error \"boom\"))",
+                "(#t #f)",
+            ),
+            (
+                "(guard (e ((error-object? e) 'error) (#t 'other)) (raise 'x))",
+                "other",
+            ),
+            (
+                "(guard (e ((error-object? e) (error-object-message e))) (vector-ref (vector) 0))",
+                "\"vector-ref: index out of range\"",
+            ),
+        ]);
+        check_errors(&[
+            ("(error \"boom\" 'a \"b\")", "boom a \"b\""),
+            (
+                "(error-object-message 'x)",
+                "error-object-message: expected an error object x",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn misuse_is_catchable() {
+        let catch =
+            |src: &str| format!("(guard (e ((error-object? e) (error-object-message e))) {src})");
+        for (src, message) in [
+            ("(car '())", "car: expected a pair"),
+            (
+                "(vector-ref (vector 1 2) 2)",
+                "vector-ref: index out of range",
+            ),
+            ("(bytevector 256)", "bytevector: expected a byte"),
+            (
+                "(write-simple 'x 'not-a-port)",
+                "write-simple: expected a port",
+            ),
+            (
+                "(make-vector 100000000000000)",
+                "make-vector: out of memory",
+            ),
+            (
+                "(make-string 100000000000000 #\\a)",
+                "make-string: out of memory",
+            ),
+            (
+                "(string-pad \"a\" 100000000000000)",
+                "string-pad: out of memory",
+            ),
+            (
+                "(arithmetic-shift 1 100000000000)",
+                "arithmetic-shift: result too big",
+            ),
+            ("(expt 2 100000000000000)", "expt: result too big"),
+        ] {
+            check(&[(&catch(src), &format!("{message:?}"))]);
+        }
+        check(&[
+            ("(vector-length (make-vector 3 0))", "3"),
+            ("(string-pad \"ab\" 4 #\\-)", "\"--ab\""),
+            ("(string-pad-right \"ab\" 4 #\\-)", "\"ab--\""),
+            ("(bit-count (arithmetic-shift 1 1000000))", "1"),
+            ("(expt 1 100000000000000)", "1"),
+            ("(expt -1 100000000000001)", "-1"),
+            ("(expt 0 100000000000000)", "0"),
+            ("(expt 1/2 3)", "1/8"),
+        ]);
+    }
+}
@@ -0,0 +1,2052 @@
+use alloc::vec::Vec;
+
+use crate::{
+    cons, constant,
+    env::{interaction_environment, unbound, Env},
+    eqv,
+    error::{self, Error},
+    features, library, list, list_to_vec,
+    load::include,
+    number::Number,
+    parameter::Parameter,
+    read, symbol,
+    sync::{Cell, Rc},
+    syntax::{self, Macro},
+    vec_to_list, vector, Object,
+};
+
+/// A procedure implemented in Rust.
+///
+/// `f` is only ever called with between `min_args` and `max_args`
+/// arguments, so it can index into them without checking.
+pub struct Primitive {
+    pub name: &'static str,
+    pub min_args: usize,
+    pub max_args: Option<usize>,
+    pub f: fn(&[Object]) -> Result<Object, Error>,
+}
+
+impl Primitive {
+    pub const fn fixed(
+        name: &'static str,
+        args: usize,
+        f: fn(&[Object]) -> Result<Object, Error>,
+    ) -> Primitive {
+        Primitive::range(name, args, args, f)
+    }
+
+    pub const fn range(
+        name: &'static str,
+        min_args: usize,
+        max_args: usize,
+        f: fn(&[Object]) -> Result<Object, Error>,
+    ) -> Primitive {
+        Primitive {
+            name,
+            min_args,
+            max_args: Some(max_args),
+            f,
+        }
+    }
+
+    pub const fn variadic(
+        name: &'static str,
+        min_args: usize,
+        f: fn(&[Object]) -> Result<Object, Error>,
+    ) -> Primitive {
+        Primitive {
+            name,
+            min_args,
+            max_args: None,
+            f,
+        }
+    }
+}
+
+/// A procedure made by `lambda`, or by `case-lambda` with several cases.
+pub struct Procedure {
+    cases: Vec<Case>,
+    env: Rc<Env>,
+}
+
+/// One of the ways to call a procedure, for the argument counts that its
+/// formals accept.
+struct Case {
+    formals: Formals,
+    /// The names defined at the start of `body`.
+    definitions: Vec<Rc<str>>,
+    body: Vec<Object>,
+}
+
+impl Case {
+    fn new(expr: &Object, formals: Formals, body: &[Object]) -> Result<Case, Error> {
+        if body.is_empty() {
+            return Err(bad_syntax(expr));
+        }
+        Ok(Case {
+            formals,
+            definitions: definitions(body)?,
+            body: body.to_vec(),
+        })
+    }
+}
+
+/// A continuation captured by `call/cc`.
+///
+/// Continuations only escape: invoking one unwinds the Rust stack back to
+/// the [`run`] that the `call/cc` was evaluated in, as an [`Error::Throw`],
+/// so it stops working once that has returned. Every `call/cc` in the
+/// same `run` is in tail position there and shares its continuation.
+pub struct Continuation {
+    active: Cell<bool>,
+}
+
+/// What's left of an evaluation once a form has done its own part.
+///
+/// Forms hand their tail expressions and calls back to [`run`] rather
+/// than evaluating them themselves, so that tail calls don't grow the Rust
+/// stack.
+enum Tail {
+    Value(Object),
+    Eval(Object, Rc<Env>),
+    Apply(Object, Vec<Object>),
+}
+
+pub fn eval(expr: Object, env: &Rc<Env>) -> Result<Object, Error> {
+    match expr {
+        // a variable or a constant is done with in a single step, and
+        // they're most of what gets evaluated
+        Object::Pair(_) => run(Tail::Eval(expr, env.clone())),
+        _ => match step(expr, env)? {
+            Tail::Value(value) => Ok(value),
+            tail => run(tail),
+        },
+    }
+}
+
+pub fn apply(operator: &Object, args: Vec<Object>) -> Result<Object, Error> {
+    run(Tail::Apply(operator.clone(), args))
+}
+
+thread_local! {
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+    static MAX_DEPTH: Cell<usize> = const { Cell::new(DEFAULT_MAX_DEPTH) };
+    #[cfg(feature = "metrics")]
+    static PEAK_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// How much Rust stack a level of nested evaluation can take, at most.
+/// The most measured is about 8 KiB in a debug build and 1.3 KiB in a
+/// release one, for a `let` in a procedure that recurses through another,
+/// and this is twice that, to leave room for whatever wasn't measured.
+const LEVEL_STACK: usize = if cfg!(debug_assertions) {
+    16 << 10
+} else {
+    4 << 10
+};
+
+/// How deeply evaluations can nest before giving up, by default: as deeply
+/// as fits in the 8 MiB stack that a main thread usually has. Threads with
+/// other stacks should set their own limit with [`set_max_depth`].
+pub const DEFAULT_MAX_DEPTH: usize = (8 << 20) / LEVEL_STACK;
+
+/// The size of the stack that `with_stack` runs things on, which is only
+/// reserved, not used up, until evaluations nest that deeply.
+pub const STACK_SIZE: usize = 256 << 20;
+
+/// Limits how deeply evaluations on this thread can nest: going deeper
+/// raises an error rather than overflowing the stack. Hosts running the
+/// evaluator on a bigger or smaller stack than [`DEFAULT_MAX_DEPTH`] is
+/// meant for can change it accordingly.
+pub fn set_max_depth(depth: usize) {
+    MAX_DEPTH.with(|max| max.set(depth));
+}
+
+pub fn max_depth() -> usize {
+    MAX_DEPTH.with(Cell::get)
+}
+
+/// Runs `f` on a thread of its own with a [`STACK_SIZE`] stack, and with
+/// evaluations allowed to nest as deeply as fits in it, for recursion far
+/// deeper than an ordinary thread leaves room for. It's only there with the
+/// `std` feature, or in the tests, which have std either way.
+#[cfg(any(feature = "std", test))]
+pub fn with_stack<T: Send>(f: impl FnOnce() -> T + Send) -> T {
+    std::thread::scope(|scope| {
+        let thread = std::thread::Builder::new()
+            .stack_size(STACK_SIZE)
+            .spawn_scoped(scope, || {
+                set_max_depth(STACK_SIZE / LEVEL_STACK);
+                f()
+            })
+            .expect("can't start a thread to evaluate on");
+        match thread.join() {
+            Ok(result) => result,
+            Err(panic) => std::panic::resume_unwind(panic),
+        }
+    })
+}
+
+/// How deeply evaluations are nested right now.
+#[cfg(feature = "metrics")]
+pub fn current_depth() -> usize {
+    DEPTH.with(Cell::get)
+}
+
+/// The deepest evaluations have nested since the last
+/// [`reset_peak_depth`], e.g. to check that a loop of tail calls runs in
+/// constant space.
+#[cfg(feature = "metrics")]
+pub fn peak_depth() -> usize {
+    PEAK_DEPTH.with(Cell::get)
+}
+
+/// Starts measuring [`peak_depth`] afresh from the current depth.
+#[cfg(feature = "metrics")]
+pub fn reset_peak_depth() {
+    PEAK_DEPTH.with(|peak| peak.set(current_depth()));
+}
+
+/// Counts a nested evaluation for as long as it's alive.
+struct Depth;
+
+impl Depth {
+    fn enter() -> Result<Depth, Error> {
+        let depth = DEPTH.with(Cell::get);
+        if depth >= max_depth() {
+            return Err(Error::new("recursion too deep", vec![]));
+        }
+        DEPTH.with(|d| d.set(depth + 1));
+        #[cfg(feature = "metrics")]
+        PEAK_DEPTH.with(|peak| peak.set(peak.get().max(depth + 1)));
+        Ok(Depth)
+    }
+}
+
+impl Drop for Depth {
+    fn drop(&mut self) {
+        DEPTH.with(|d| d.set(d.get() - 1));
+    }
+}
+
+/// The evaluator's trampoline.
+///
+/// Everything Scheme considers a tail position comes back through here
+/// instead of recursing: the last expression of a body or `begin`, the
+/// branches of `if`, `cond`, `case`, `when` and `unless`, the last operand
+/// of `and` and `or`, `cond`'s `=>` receivers, `do`'s results, and the
+/// call of a named `let`. Calling a procedure then replaces the current
+/// frame, so only the operator and operands of a call need Rust stack.
+fn run(tail: Tail) -> Result<Object, Error> {
+    let _depth = Depth::enter()?;
+    let mut k = None;
+    let result = trampoline(tail, &mut k);
+    let Some(k) = k else {
+        return result;
+    };
+    k.active.set(false);
+    match result {
+        Err(Error::Throw(target, value)) if Rc::ptr_eq(&target, &k) => Ok(value),
+        result => result,
+    }
+}
+
+/// The loop of [`run`], which makes `k` the first time `call/cc` is called.
+///
+/// An error says it happened in the form being evaluated, if that was read
+/// with its span kept, or else in the form the loop started with.
+fn trampoline(tail: Tail, k: &mut Option<Rc<Continuation>>) -> Result<Object, Error> {
+    // the forms by the addresses of their first pairs
+    let mut form = 0;
+    let start = match &tail {
+        Tail::Eval(Object::Pair(pair), _) => Rc::as_ptr(pair) as usize,
+        _ => 0,
+    };
+    bounce(tail, k, &mut form).map_err(|error| {
+        match read::span_of(form).or_else(|| read::span_of(start)) {
+            Some(span) => error.at(span),
+            None => error,
+        }
+    })
+}
+
+fn bounce(
+    mut tail: Tail,
+    k: &mut Option<Rc<Continuation>>,
+    form: &mut usize,
+) -> Result<Object, Error> {
+    loop {
+        tail = match tail {
+            Tail::Value(value) => return Ok(value),
+            Tail::Eval(expr, env) => {
+                if let Object::Pair(pair) = &expr {
+                    *form = Rc::as_ptr(pair) as usize;
+                }
+                step(expr, &env)?
+            }
+            Tail::Apply(Object::Procedure(p), args) => {
+                let Some(case) = p.cases.iter().find(|c| c.formals.accepts(args.len())) else {
+                    return Err(arity_error("procedure", args));
+                };
+                let env = Rc::new(Env::extend(p.env.clone()));
+                case.formals
+                    .bind(&env, args)
+                    .map_err(|args| arity_error("procedure", args))?;
+                for name in &case.definitions {
+                    env.define(name.clone(), Object::Uninitialized);
+                }
+                sequence(&case.body, &env)?
+            }
+            Tail::Apply(Object::Primitive(p), args)
+                if core::ptr::eq(p, &APPLY) && args.len() >= APPLY.min_args =>
+            {
+                let (operator, args) = spread(&args)?;
+                Tail::Apply(operator, args)
+            }
+            Tail::Apply(Object::Primitive(p), mut args)
+                if core::ptr::eq(p, &CALL_CC) && args.len() == 1 =>
+            {
+                let k = k.get_or_insert_with(|| {
+                    Rc::new(Continuation {
+                        active: Cell::new(true),
+                    })
+                });
+                Tail::Apply(args.remove(0), vec![Object::Continuation(k.clone())])
+            }
+            Tail::Apply(operator, args) => Tail::Value(call(&operator, args)?),
+        }
+    }
+}
+
+fn step(expr: Object, env: &Rc<Env>) -> Result<Tail, Error> {
+    match expr {
+        Object::Symbol(name) => match env.lookup(&name) {
+            Some(Object::Uninitialized) => Err(Error::new(
+                "variable used before it was initialized",
+                vec![Object::Symbol(name)],
+            )),
+            Some(Object::Macro(_)) => Err(Error::new(
+                "syntactic keyword used as a variable",
+                vec![Object::Symbol(syntax::unalias(&name))],
+            )),
+            Some(value) => Ok(Tail::Value(value)),
+            None => Err(unbound(&name)),
+        },
+        Object::Pair(ref pair) => {
+            let Some(operands) = list_to_vec(&pair.cdr()) else {
+                return Err(bad_syntax(&expr));
+            };
+            let operator = pair.car();
+            // special forms come first, so their keywords can't be
+            // redefined, and then macros, so that a macro keyword is never
+            // looked at as a variable
+            let operator = match &operator {
+                Object::Symbol(name) => {
+                    let keyword = syntax::keyword(name, env);
+                    if let Some(tail) = special_form(&keyword, &expr, &operands, env) {
+                        return tail;
+                    }
+                    match env.lookup(name) {
+                        Some(Object::Macro(m)) => {
+                            return Ok(Tail::Eval(m.expand(&expr)?, env.clone()))
+                        }
+                        Some(Object::Uninitialized) | None => eval(operator, env)?,
+                        Some(value) => value,
+                    }
+                }
+                _ => eval(operator, env)?,
+            };
+            // a plain loop rather than an iterator chain keeps the stack
+            // frames of deep recursion small in debug builds
+            let mut args = Vec::with_capacity(operands.len());
+            for arg in operands {
+                args.push(eval(arg, env)?);
+            }
+            Ok(Tail::Apply(operator, args))
+        }
+        Object::Null => Err(Error::new("can't evaluate the empty list", vec![])),
+        // everything else, and vectors and bytevectors in particular,
+        // evaluates to itself, elements and all, which are constants
+        _ => {
+            constant::freeze(&expr);
+            Ok(Tail::Value(expr))
+        }
+    }
+}
+
+/// Evaluates `expr` if it's a special form introduced by `keyword`.
+fn special_form(
+    keyword: &str,
+    expr: &Object,
+    operands: &[Object],
+    env: &Rc<Env>,
+) -> Option<Result<Tail, Error>> {
+    Some(match keyword {
+        "quote" => quote(expr, operands),
+        "quasiquote" => quasiquote(expr, operands, env),
+        "define" => define(expr, operands, env),
+        "lambda" => lambda(expr, operands, env).map(Tail::Value),
+        "case-lambda" => case_lambda(expr, operands, env).map(Tail::Value),
+        "if" => if_(expr, operands, env),
+        "cond" => cond(expr, operands, env),
+        "case" => case(expr, operands, env),
+        "let" => let_(expr, operands, env),
+        "let*" => let_star(expr, operands, env),
+        "letrec" => letrec(expr, operands, env, false),
+        "letrec*" => letrec(expr, operands, env, true),
+        "let-values" => let_values(expr, operands, env, false),
+        "let*-values" => let_values(expr, operands, env, true),
+        "define-values" => define_values(expr, operands, env),
+        "set!" => set(expr, operands, env),
+        // evaluating in the same frame is what splices the
+        // definitions of a top-level `begin` into the top level
+        "begin" => sequence(operands, env),
+        "and" => and(operands, env),
+        "or" => or(operands, env),
+        "do" => do_(expr, operands, env),
+        "when" => when(expr, operands, env, true),
+        "unless" => when(expr, operands, env, false),
+        "include" => include(operands, false, env).map(Tail::Value),
+        "include-ci" => include(operands, true, env).map(Tail::Value),
+        "guard" => guard(expr, operands, env),
+        "parameterize" => parameterize(expr, operands, env),
+        "cond-expand" => cond_expand(expr, operands, env),
+        "define-library" => library::define_library(expr, operands).map(Tail::Value),
+        "import" => library::import(operands, env).map(Tail::Value),
+        "define-syntax" => define_syntax(expr, operands, env),
+        "let-syntax" => let_syntax(expr, operands, env, false),
+        "letrec-syntax" => let_syntax(expr, operands, env, true),
+        _ => return None,
+    })
+}
+
+/// Evaluates all but the last of `exprs`, leaving the last one as the tail.
+fn sequence(exprs: &[Object], env: &Rc<Env>) -> Result<Tail, Error> {
+    let Some((last, init)) = exprs.split_last() else {
+        return Ok(Tail::Value(Object::Unspecified));
+    };
+    for expr in init {
+        eval(expr.clone(), env)?;
+    }
+    Ok(Tail::Eval(last.clone(), env.clone()))
+}
+
+fn bad_syntax(expr: &Object) -> Error {
+    Error::new("bad syntax", vec![expr.clone()])
+}
+
+fn quote(expr: &Object, operands: &[Object]) -> Result<Tail, Error> {
+    match operands {
+        [datum] => {
+            constant::freeze(datum);
+            Ok(Tail::Value(datum.clone()))
+        }
+        _ => Err(bad_syntax(expr)),
+    }
+}
+
+fn quasiquote(expr: &Object, operands: &[Object], env: &Rc<Env>) -> Result<Tail, Error> {
+    match operands {
+        [template] => Ok(Tail::Value(quasi(template, 1, env)?)),
+        _ => Err(bad_syntax(expr)),
+    }
+}
+
+/// The operand of `obj` if it's a `(keyword operand)` form.
+fn form(obj: &Object, keyword: &str) -> Option<Object> {
+    let Object::Pair(pair) = obj else {
+        return None;
+    };
+    match pair.cdr() {
+        Object::Pair(rest)
+            if is_keyword(&pair.car(), keyword) && matches!(rest.cdr(), Object::Null) =>
+        {
+            Some(rest.car())
+        }
+        _ => None,
+    }
+}
+
+fn list2(keyword: &str, operand: Object) -> Object {
+    list![symbol(keyword), operand]
+}
+
+/// Fills in a quasiquote template. `depth` counts the quasiquotes around
+/// `template` that haven't been cancelled out by unquotes yet; only the
+/// unquotes that bring it down to zero get evaluated.
+fn quasi(template: &Object, depth: usize, env: &Rc<Env>) -> Result<Object, Error> {
+    if let Some(operand) = form(template, "unquote") {
+        return match depth {
+            1 => eval(operand, env),
+            _ => Ok(list2("unquote", quasi(&operand, depth - 1, env)?)),
+        };
+    }
+    if let Some(operand) = form(template, "unquote-splicing") {
+        return match depth {
+            // only the list or vector it's in can take what it splices
+            1 => Err(Error::new(
+                "unquote-splicing: not inside a list or vector",
+                vec![template.clone()],
+            )),
+            _ => Ok(list2("unquote-splicing", quasi(&operand, depth - 1, env)?)),
+        };
+    }
+    if let Some(operand) = form(template, "quasiquote") {
+        return Ok(list2("quasiquote", quasi(&operand, depth + 1, env)?));
+    }
+    match template {
+        // the spine is walked rather than recursed down, so that a long
+        // list doesn't run out of stack and its elements are filled in
+        // from left to right, up to a tail that's more than a pair: an
+        // `unquote` there, say, since `(a . ,b)` is `(a unquote b)`
+        Object::Pair(_) => {
+            let mut filled = Vec::new();
+            let mut rest = template.clone();
+            while let Object::Pair(pair) = &rest {
+                if is_quasi_form(&rest) {
+                    break;
+                }
+                fill(&pair.car(), depth, env, &mut filled)?;
+                rest = pair.cdr();
+            }
+            Ok(vec_to_list(filled, quasi(&rest, depth, env)?))
+        }
+        // the elements are filled in one at a time rather than as a list,
+        // where an `unquote` among them would read as the tail of the list
+        Object::Vector(items) => {
+            let items = items.borrow().clone();
+            let mut filled = Vec::with_capacity(items.len());
+            for item in &items {
+                fill(item, depth, env, &mut filled)?;
+            }
+            Ok(vector::make(filled))
+        }
+        _ => Ok(template.clone()),
+    }
+}
+
+/// Whether `obj` is one of the forms that [`quasi`] treats specially.
+fn is_quasi_form(obj: &Object) -> bool {
+    ["unquote", "unquote-splicing", "quasiquote"]
+        .iter()
+        .any(|keyword| form(obj, keyword).is_some())
+}
+
+/// Fills in `item`, an element of a list or vector in a quasiquote
+/// template, onto the end of `filled`.
+fn fill(item: &Object, depth: usize, env: &Rc<Env>, filled: &mut Vec<Object>) -> Result<(), Error> {
+    match form(item, "unquote-splicing") {
+        Some(operand) if depth == 1 => filled.extend(splice(operand, env)?),
+        _ => filled.push(quasi(item, depth, env)?),
+    }
+    Ok(())
+}
+
+/// The elements of the list that `operand` of an `unquote-splicing`
+/// evaluates to.
+fn splice(operand: Object, env: &Rc<Env>) -> Result<Vec<Object>, Error> {
+    let spliced = eval(operand, env)?;
+    list_to_vec(&spliced)
+        .ok_or_else(|| Error::new("unquote-splicing: expected a list", vec![spliced]))
+}
+
+/// `(define name value)`, or `(define (name . params) body...)` for
+/// `(define name (lambda params body...))`.
+fn define(expr: &Object, operands: &[Object], env: &Rc<Env>) -> Result<Tail, Error> {
+    match operands {
+        [Object::Symbol(name), value] => {
+            let value = eval(value.clone(), env)?;
+            env.define(name.clone(), value);
+        }
+        [Object::Pair(signature), body @ ..] if !body.is_empty() => {
+            let Object::Symbol(name) = signature.car() else {
+                return Err(bad_syntax(expr));
+            };
+            let lambda = cons(
+                symbol("lambda"),
+                cons(signature.cdr(), vec_to_list(body.to_vec(), Object::Null)),
+            );
+            let value = eval(lambda, env)?;
+            env.define(name.clone(), value);
+        }
+        _ => return Err(bad_syntax(expr)),
+    }
+    Ok(Tail::Value(Object::Unspecified))
+}
+
+/// `(define-syntax keyword (syntax-rules ...))` binds `keyword` to a macro.
+fn define_syntax(expr: &Object, operands: &[Object], env: &Rc<Env>) -> Result<Tail, Error> {
+    let [Object::Symbol(name), spec] = operands else {
+        return Err(bad_syntax(expr));
+    };
+    let value = transformer(expr, name, spec, env)?;
+    env.define(name.clone(), value);
+    Ok(Tail::Value(Object::Unspecified))
+}
+
+/// The macro that the transformer spec `(syntax-rules ...)` in `expr`
+/// makes for `name`, with its templates meaning what they do in `env`.
+fn transformer(
+    expr: &Object,
+    name: &Rc<str>,
+    spec: &Object,
+    env: &Rc<Env>,
+) -> Result<Object, Error> {
+    let parts = list_to_vec(spec).unwrap_or_default();
+    let [rules, rest @ ..] = &parts[..] else {
+        return Err(bad_syntax(expr));
+    };
+    if !is_keyword(rules, "syntax-rules") {
+        return Err(bad_syntax(expr));
+    }
+    let value = Macro::new(name.clone(), spec, rest, env)?;
+    Ok(Object::Macro(Rc::new(value)))
+}
+
+/// `(let-syntax ((keyword spec)...) body...)` and `letrec-syntax`, which
+/// bind macros for the body alone. Only the macros of `letrec-syntax` can
+/// use each other, since theirs are the templates that mean something in
+/// the body's scope rather than the enclosing one.
+fn let_syntax(
+    expr: &Object,
+    operands: &[Object],
+    env: &Rc<Env>,
+    recursive: bool,
+) -> Result<Tail, Error> {
+    let (bindings, body) = binding_form(expr, operands)?;
+    let inner = Rc::new(Env::extend(env.clone()));
+    let scope = if recursive { &inner } else { env };
+    for (name, spec) in &bindings {
+        let value = transformer(expr, name, spec, scope)?;
+        inner.define(name.clone(), value);
+    }
+    eval_body(body, &inner)
+}
+
+/// `(and expr...)`, stopping at the first false value.
+fn and(operands: &[Object], env: &Rc<Env>) -> Result<Tail, Error> {
+    let Some((last, init)) = operands.split_last() else {
+        return Ok(Tail::Value(Object::Boolean(true)));
+    };
+    for expr in init {
+        let value = eval(expr.clone(), env)?;
+        if !value.is_truthy() {
+            return Ok(Tail::Value(value));
+        }
+    }
+    Ok(Tail::Eval(last.clone(), env.clone()))
+}
+
+/// `(or expr...)`, stopping at the first true value.
+fn or(operands: &[Object], env: &Rc<Env>) -> Result<Tail, Error> {
+    let Some((last, init)) = operands.split_last() else {
+        return Ok(Tail::Value(Object::Boolean(false)));
+    };
+    for expr in init {
+        let value = eval(expr.clone(), env)?;
+        if value.is_truthy() {
+            return Ok(Tail::Value(value));
+        }
+    }
+    Ok(Tail::Eval(last.clone(), env.clone()))
+}
+
+/// `when`, or `unless` if `wanted` is false: the body runs only if the
+/// test comes out as `wanted`.
+fn when(expr: &Object, operands: &[Object], env: &Rc<Env>, wanted: bool) -> Result<Tail, Error> {
+    let [test, body @ ..] = operands else {
+        return Err(bad_syntax(expr));
+    };
+    if eval(test.clone(), env)?.is_truthy() == wanted {
+        sequence(body, env)
+    } else {
+        Ok(Tail::Value(Object::Unspecified))
+    }
+}
+
+/// `(do ((var init step)...) (test result...) command...)`.
+///
+/// Each iteration gets a fresh frame, so closures made in the body keep
+/// the values of that iteration.
+fn do_(expr: &Object, operands: &[Object], env: &Rc<Env>) -> Result<Tail, Error> {
+    let [specs, exit, commands @ ..] = operands else {
+        return Err(bad_syntax(expr));
+    };
+    let Some(specs) = list_to_vec(specs) else {
+        return Err(bad_syntax(expr));
+    };
+    let mut vars = Vec::new();
+    for spec in &specs {
+        match list_to_vec(spec).as_deref() {
+            Some([Object::Symbol(name), init]) => vars.push((name.clone(), init.clone(), None)),
+            Some([Object::Symbol(name), init, step]) => {
+                vars.push((name.clone(), init.clone(), Some(step.clone())))
+            }
+            _ => return Err(bad_syntax(expr)),
+        }
+    }
+    let exit = list_to_vec(exit).unwrap_or_default();
+    let [test, results @ ..] = &exit[..] else {
+        return Err(bad_syntax(expr));
+    };
+
+    let mut frame = Rc::new(Env::extend(env.clone()));
+    for (name, init, _) in &vars {
+        frame.define(name.clone(), eval(init.clone(), env)?);
+    }
+    while !eval(test.clone(), &frame)?.is_truthy() {
+        for command in commands {
+            eval(command.clone(), &frame)?;
+        }
+        let next = Rc::new(Env::extend(env.clone()));
+        for (name, _, step) in &vars {
+            let value = match step {
+                Some(step) => eval(step.clone(), &frame)?,
+                None => frame.lookup(name).expect("bound above"),
+            };
+            next.define(name.clone(), value);
+        }
+        frame = next;
+    }
+    sequence(results, &frame)
+}
+
+fn set(expr: &Object, operands: &[Object], env: &Rc<Env>) -> Result<Tail, Error> {
+    let [Object::Symbol(name), value] = operands else {
+        return Err(bad_syntax(expr));
+    };
+    let value = eval(value.clone(), env)?;
+    env.set(name, value)?;
+    Ok(Tail::Value(Object::Unspecified))
+}
+
+/// The variables of a `lambda` or `let-values`: a list of names that may
+/// end in a dotted rest name, or a single name that takes everything.
+struct Formals {
+    params: Vec<Rc<str>>,
+    /// Where any values beyond `params` go, as a list.
+    rest: Option<Rc<str>>,
+}
+
+impl Formals {
+    fn parse(expr: &Object, formals: &Object) -> Result<Formals, Error> {
+        let mut params = Vec::new();
+        let mut formals = formals.clone();
+        let rest = loop {
+            formals = match &formals {
+                Object::Null => break None,
+                Object::Symbol(name) => break Some(name.clone()),
+                Object::Pair(pair) => {
+                    let Object::Symbol(name) = pair.car() else {
+                        return Err(bad_syntax(expr));
+                    };
+                    params.push(name);
+                    pair.cdr()
+                }
+                _ => return Err(bad_syntax(expr)),
+            }
+        };
+        Ok(Formals { params, rest })
+    }
+
+    fn names(&self) -> impl Iterator<Item = &Rc<str>> {
+        self.params.iter().chain(&self.rest)
+    }
+
+    fn accepts(&self, count: usize) -> bool {
+        match self.rest {
+            None => count == self.params.len(),
+            Some(_) => count >= self.params.len(),
+        }
+    }
+
+    /// Binds the variables to `values` in `env`, or gives `values` back if
+    /// there are too many or too few of them.
+    fn bind(&self, env: &Env, values: Vec<Object>) -> Result<(), Vec<Object>> {
+        if !self.accepts(values.len()) {
+            return Err(values);
+        }
+        let mut values = values.into_iter();
+        for (name, value) in self.params.iter().zip(&mut values) {
+            env.define(name.clone(), value);
+        }
+        if let Some(rest) = &self.rest {
+            env.define(rest.clone(), vec_to_list(values.collect(), Object::Null));
+        }
+        Ok(())
+    }
+}
+
+/// `(define-values formals expr)`.
+fn define_values(expr: &Object, operands: &[Object], env: &Rc<Env>) -> Result<Tail, Error> {
+    let [formals, init] = operands else {
+        return Err(bad_syntax(expr));
+    };
+    let formals = Formals::parse(expr, formals)?;
+    let vals = value_list(eval(init.clone(), env)?);
+    formals
+        .bind(env, vals)
+        .map_err(|vals| values_error("define-values", vals))?;
+    Ok(Tail::Value(Object::Unspecified))
+}
+
+/// `(lambda formals body...)`.
+fn lambda(expr: &Object, operands: &[Object], env: &Rc<Env>) -> Result<Object, Error> {
+    let [formals, body @ ..] = operands else {
+        return Err(bad_syntax(expr));
+    };
+    let case = Case::new(expr, Formals::parse(expr, formals)?, body)?;
+    Ok(Object::Procedure(Rc::new(Procedure {
+        cases: vec![case],
+        env: env.clone(),
+    })))
+}
+
+/// `(case-lambda (formals body...) ...)`, a procedure that runs the first
+/// clause whose formals accept the arguments it was called with.
+fn case_lambda(expr: &Object, clauses: &[Object], env: &Rc<Env>) -> Result<Object, Error> {
+    let cases = clauses
+        .iter()
+        .map(|clause| {
+            let clause = list_to_vec(clause).unwrap_or_default();
+            let [formals, body @ ..] = &clause[..] else {
+                return Err(bad_syntax(expr));
+            };
+            Case::new(expr, Formals::parse(expr, formals)?, body)
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Object::Procedure(Rc::new(Procedure {
+        cases,
+        env: env.clone(),
+    })))
+}
+
+/// The names a form defines, if it's a definition.
+fn defined_names(form: &Object) -> Option<Vec<Rc<str>>> {
+    let Object::Pair(pair) = form else {
+        return None;
+    };
+    let Object::Pair(operands) = pair.cdr() else {
+        return None;
+    };
+    let keyword = pair.car();
+    match operands.car() {
+        Object::Symbol(name)
+            if is_keyword(&keyword, "define") || is_keyword(&keyword, "define-syntax") =>
+        {
+            Some(vec![name])
+        }
+        Object::Pair(signature) if is_keyword(&keyword, "define") => match signature.car() {
+            Object::Symbol(name) => Some(vec![name]),
+            _ => None,
+        },
+        formals if is_keyword(&keyword, "define-values") => {
+            let formals = Formals::parse(form, &formals).ok()?;
+            Some(formals.names().cloned().collect())
+        }
+        _ => None,
+    }
+}
+
+/// The names defined at the start of a body. Definitions can't come after
+/// the body's first expression.
+fn definitions(body: &[Object]) -> Result<Vec<Rc<str>>, Error> {
+    let mut names = Vec::new();
+    let mut forms = body.iter();
+    for form in forms.by_ref() {
+        match defined_names(form) {
+            Some(defined) => names.extend(defined),
+            None => break,
+        }
+    }
+    match forms.find(|form| defined_names(form).is_some()) {
+        Some(form) => Err(Error::new(
+            "definitions must come before the expressions of a body",
+            vec![form.clone()],
+        )),
+        None => Ok(names),
+    }
+}
+
+/// Evaluates a body in `env`, a fresh frame. Its definitions work like
+/// `letrec*`: they all go in that frame, which has them from the start.
+fn eval_body(body: &[Object], env: &Rc<Env>) -> Result<Tail, Error> {
+    for name in definitions(body)? {
+        env.define(name, Object::Uninitialized);
+    }
+    sequence(body, env)
+}
+
+fn if_(expr: &Object, operands: &[Object], env: &Rc<Env>) -> Result<Tail, Error> {
+    let (test, consequent, alternative) = match operands {
+        [test, consequent] => (test, consequent, None),
+        [test, consequent, alternative] => (test, consequent, Some(alternative)),
+        _ => return Err(bad_syntax(expr)),
+    };
+    if eval(test.clone(), env)?.is_truthy() {
+        Ok(Tail::Eval(consequent.clone(), env.clone()))
+    } else if let Some(alternative) = alternative {
+        Ok(Tail::Eval(alternative.clone(), env.clone()))
+    } else {
+        Ok(Tail::Value(Object::Unspecified))
+    }
+}
+
+/// `(cond (test body...) ...)`. A clause without a body gives the value
+/// of its test, `(test => receiver)` passes that value to `receiver`, and
+/// a final `(else body...)` catches everything else.
+fn cond(expr: &Object, clauses: &[Object], env: &Rc<Env>) -> Result<Tail, Error> {
+    Ok(cond_clauses(expr, clauses, env)?.unwrap_or(Tail::Value(Object::Unspecified)))
+}
+
+/// The result of the first of the `cond` clauses that applies, if any.
+fn cond_clauses(expr: &Object, clauses: &[Object], env: &Rc<Env>) -> Result<Option<Tail>, Error> {
+    for (i, clause) in clauses.iter().enumerate() {
+        let clause = list_to_vec(clause).unwrap_or_default();
+        let [test, body @ ..] = &clause[..] else {
+            return Err(bad_syntax(expr));
+        };
+        let value = if is_keyword(test, "else") {
+            if i + 1 != clauses.len() || body.is_empty() {
+                return Err(bad_syntax(expr));
+            }
+            return sequence(body, env).map(Some);
+        } else {
+            eval(test.clone(), env)?
+        };
+        if value.is_truthy() {
+            return clause_body(expr, body, value, env).map(Some);
+        }
+    }
+    Ok(None)
+}
+
+/// `(cond-expand (requirement body...) ...)` stands for the body of the
+/// first clause whose feature requirement is met, or of a final `(else
+/// body...)`, spliced in place as with `begin`.
+fn cond_expand(expr: &Object, clauses: &[Object], env: &Rc<Env>) -> Result<Tail, Error> {
+    for (i, clause) in clauses.iter().enumerate() {
+        let clause = list_to_vec(clause).unwrap_or_default();
+        let [requirement, body @ ..] = &clause[..] else {
+            return Err(bad_syntax(expr));
+        };
+        let met = if is_keyword(requirement, "else") {
+            if i + 1 != clauses.len() {
+                return Err(bad_syntax(expr));
+            }
+            true
+        } else {
+            features::satisfied(requirement, "cond-expand")?
+        };
+        if met {
+            return sequence(body, env);
+        }
+    }
+    Ok(Tail::Value(Object::Unspecified))
+}
+
+/// `(guard (var clause...) body...)`.
+///
+/// The body runs with a handler that escapes back here with whatever was
+/// raised. The clauses are then tried as in `cond`, with `var` bound to
+/// it, and if none of them applies it is raised again. That happens from
+/// here rather than from where it was first raised, since continuations
+/// can't go back there, and so a handler can't return from it.
+fn guard(expr: &Object, operands: &[Object], env: &Rc<Env>) -> Result<Tail, Error> {
+    let [spec, body @ ..] = operands else {
+        return Err(bad_syntax(expr));
+    };
+    let spec = list_to_vec(spec).unwrap_or_default();
+    let [Object::Symbol(var), clauses @ ..] = &spec[..] else {
+        return Err(bad_syntax(expr));
+    };
+    let k = Rc::new(Continuation {
+        active: Cell::new(true),
+    });
+    let result = error::with_handler(&Object::Continuation(k.clone()), || {
+        run(eval_body(body, &Rc::new(Env::extend(env.clone())))?)
+    });
+    k.active.set(false);
+    let raised = match result {
+        Err(Error::Throw(target, raised)) if Rc::ptr_eq(&target, &k) => raised,
+        result => return result.map(Tail::Value),
+    };
+    let inner = Rc::new(Env::extend(env.clone()));
+    inner.define(var.clone(), raised.clone());
+    match cond_clauses(expr, clauses, &inner)? {
+        Some(tail) => Ok(tail),
+        None => error::raise(raised, false).map(Tail::Value),
+    }
+}
+
+/// `(parameterize ((param value)...) body...)`: the body, with each
+/// parameter bound to its value until the body returns or is escaped from.
+/// All the values are converted before any of them are bound.
+fn parameterize(expr: &Object, operands: &[Object], env: &Rc<Env>) -> Result<Tail, Error> {
+    let [bindings, body @ ..] = operands else {
+        return Err(bad_syntax(expr));
+    };
+    let bindings = list_to_vec(bindings).ok_or_else(|| bad_syntax(expr))?;
+    if body.is_empty() {
+        return Err(bad_syntax(expr));
+    }
+    let mut params = Vec::with_capacity(bindings.len());
+    for binding in &bindings {
+        let binding = list_to_vec(binding).unwrap_or_default();
+        let [param, value] = &binding[..] else {
+            return Err(bad_syntax(expr));
+        };
+        match eval(param.clone(), env)? {
+            Object::Parameter(param) => {
+                let value = param.convert(eval(value.clone(), env)?)?;
+                params.push((param, value));
+            }
+            other => return Err(Error::wrong_type("parameterize", "a parameter", &other)),
+        }
+    }
+    let env = Rc::new(Env::extend(env.clone()));
+    rebind(&params, &|| run(eval_body(body, &env)?)).map(Tail::Value)
+}
+
+/// Runs `body` with each of `params` bound to its value.
+fn rebind(
+    params: &[(Rc<Parameter>, Object)],
+    body: &dyn Fn() -> Result<Object, Error>,
+) -> Result<Object, Error> {
+    match params.split_first() {
+        None => body(),
+        Some(((param, value), rest)) => param.parameterize(value.clone(), || rebind(rest, body)),
+    }
+}
+
+/// The result of a `cond` or `case` clause that was chosen because of
+/// `value`.
+fn clause_body(
+    expr: &Object,
+    body: &[Object],
+    value: Object,
+    env: &Rc<Env>,
+) -> Result<Tail, Error> {
+    match body {
+        [] => Ok(Tail::Value(value)),
+        [arrow, receiver] if is_keyword(arrow, "=>") => {
+            let receiver = eval(receiver.clone(), env)?;
+            Ok(Tail::Apply(receiver, vec![value]))
+        }
+        [arrow, ..] if is_keyword(arrow, "=>") => Err(bad_syntax(expr)),
+        _ => sequence(body, env),
+    }
+}
+
+fn is_keyword(obj: &Object, keyword: &str) -> bool {
+    matches!(obj, Object::Symbol(s) if &*syntax::unalias(s) == keyword)
+}
+
+/// `(case key ((datum...) body...) ...)`, picking the first clause with a
+/// datum that is `eqv?` to the key, or a final `(else body...)`. Either
+/// kind of clause can be `(... => receiver)` to pass the key on instead.
+fn case(expr: &Object, operands: &[Object], env: &Rc<Env>) -> Result<Tail, Error> {
+    let [key, clauses @ ..] = operands else {
+        return Err(bad_syntax(expr));
+    };
+    let key = eval(key.clone(), env)?;
+    for (i, clause) in clauses.iter().enumerate() {
+        let clause = list_to_vec(clause).unwrap_or_default();
+        let [data, body @ ..] = &clause[..] else {
+            return Err(bad_syntax(expr));
+        };
+        if body.is_empty() {
+            return Err(bad_syntax(expr));
+        }
+        let matched = if is_keyword(data, "else") {
+            if i + 1 != clauses.len() {
+                return Err(bad_syntax(expr));
+            }
+            true
+        } else {
+            let Some(data) = list_to_vec(data) else {
+                return Err(bad_syntax(expr));
+            };
+            // a macro's template can have put aliases in the data
+            data.iter().any(|datum| eqv(&syntax::strip(datum), &key))
+        };
+        if matched {
+            return clause_body(expr, body, key, env);
+        }
+    }
+    Ok(Tail::Value(Object::Unspecified))
+}
+
+/// The names and init expressions of a binding list `((name init) ...)`.
+type Bindings = Vec<(Rc<str>, Object)>;
+
+fn bindings(expr: &Object, list: &Object) -> Result<Bindings, Error> {
+    let Some(list) = list_to_vec(list) else {
+        return Err(bad_syntax(expr));
+    };
+    list.iter()
+        .map(|binding| match list_to_vec(binding).as_deref() {
+            Some([Object::Symbol(name), init]) => Ok((name.clone(), init.clone())),
+            _ => Err(bad_syntax(expr)),
+        })
+        .collect()
+}
+
+/// Splits the operands of a binding form into its bindings and body.
+fn binding_form<'a>(
+    expr: &Object,
+    operands: &'a [Object],
+) -> Result<(Bindings, &'a [Object]), Error> {
+    match operands {
+        [list, body @ ..] if !body.is_empty() => Ok((bindings(expr, list)?, body)),
+        _ => Err(bad_syntax(expr)),
+    }
+}
+
+/// `let`, or named `let` when the bindings are preceded by a name: that
+/// name is bound to a procedure taking the variables as arguments and
+/// evaluating the body, which is then called with the inits.
+fn let_(expr: &Object, operands: &[Object], env: &Rc<Env>) -> Result<Tail, Error> {
+    if let [Object::Symbol(name), operands @ ..] = operands {
+        let (bindings, body) = binding_form(expr, operands)?;
+        let (params, inits): (Vec<_>, Vec<_>) = bindings.into_iter().unzip();
+        let inner = Rc::new(Env::extend(env.clone()));
+        let case = Case::new(expr, Formals { params, rest: None }, body)?;
+        let procedure = Object::Procedure(Rc::new(Procedure {
+            cases: vec![case],
+            env: inner.clone(),
+        }));
+        inner.define(name.clone(), procedure.clone());
+        let args = inits
+            .into_iter()
+            .map(|init| eval(init, env))
+            .collect::<Result<_, _>>()?;
+        return Ok(Tail::Apply(procedure, args));
+    }
+    let (bindings, body) = binding_form(expr, operands)?;
+    let inner = Rc::new(Env::extend(env.clone()));
+    for (name, init) in bindings {
+        inner.define(name, eval(init, env)?);
+    }
+    eval_body(body, &inner)
+}
+
+/// Like `let`, but each init can see the bindings before it.
+fn let_star(expr: &Object, operands: &[Object], env: &Rc<Env>) -> Result<Tail, Error> {
+    let (bindings, body) = binding_form(expr, operands)?;
+    let mut env = env.clone();
+    for (name, init) in bindings {
+        let value = eval(init, &env)?;
+        env = Rc::new(Env::extend(env));
+        env.define(name, value);
+    }
+    // the body's definitions need a frame of their own even without bindings
+    eval_body(body, &Rc::new(Env::extend(env)))
+}
+
+/// `let-values`, or with `sequential`, `let*-values`, whose inits can
+/// each see the bindings made before them.
+fn let_values(
+    expr: &Object,
+    operands: &[Object],
+    env: &Rc<Env>,
+    sequential: bool,
+) -> Result<Tail, Error> {
+    let [list, body @ ..] = operands else {
+        return Err(bad_syntax(expr));
+    };
+    let Some(specs) = list_to_vec(list) else {
+        return Err(bad_syntax(expr));
+    };
+    if body.is_empty() {
+        return Err(bad_syntax(expr));
+    }
+    let mut inner = Rc::new(Env::extend(env.clone()));
+    for spec in &specs {
+        let spec = list_to_vec(spec).unwrap_or_default();
+        let [formals, init] = &spec[..] else {
+            return Err(bad_syntax(expr));
+        };
+        let formals = Formals::parse(expr, formals)?;
+        let vals = value_list(eval(init.clone(), if sequential { &inner } else { env })?);
+        if sequential {
+            inner = Rc::new(Env::extend(inner));
+        }
+        formals
+            .bind(&inner, vals)
+            .map_err(|vals| values_error("let-values", vals))?;
+    }
+    eval_body(body, &inner)
+}
+
+/// `letrec` and `letrec*`, whose inits all see each other's bindings.
+///
+/// `letrec*` assigns each variable as soon as its init is evaluated, so
+/// later inits can use its value; plain `letrec` assigns them all at the
+/// end. Using a variable before it has been assigned is an error.
+fn letrec(
+    expr: &Object,
+    operands: &[Object],
+    env: &Rc<Env>,
+    sequential: bool,
+) -> Result<Tail, Error> {
+    let (bindings, body) = binding_form(expr, operands)?;
+    let env = Rc::new(Env::extend(env.clone()));
+    for (name, _) in &bindings {
+        env.define(name.clone(), Object::Uninitialized);
+    }
+    let mut values = Vec::new();
+    for (name, init) in &bindings {
+        let value = eval(init.clone(), &env)?;
+        if sequential {
+            env.define(name.clone(), value);
+        } else {
+            values.push(value);
+        }
+    }
+    for ((name, _), value) in bindings.into_iter().zip(values) {
+        env.define(name, value);
+    }
+    eval_body(body, &env)
+}
+
+/// What `(values vals...)` returns: a single value stands for itself.
+pub fn values(mut vals: Vec<Object>) -> Object {
+    match vals.len() {
+        1 => vals.pop().expect("just checked"),
+        _ => Object::Values(Rc::new(vals)),
+    }
+}
+
+/// The values that `obj` stands for, undoing [`values`].
+fn value_list(obj: Object) -> Vec<Object> {
+    match obj {
+        Object::Values(vals) => vals.to_vec(),
+        obj => vec![obj],
+    }
+}
+
+/// `apply` is a primitive like any other so that it can be passed around,
+/// but [`run`] recognises it so that the call it makes is a tail call.
+pub static APPLY: Primitive = Primitive::variadic("apply", 2, |args| {
+    let (operator, args) = spread(args)?;
+    apply(&operator, args)
+});
+
+/// The procedure and arguments of `(apply proc arg... list)`.
+fn spread(args: &[Object]) -> Result<(Object, Vec<Object>), Error> {
+    let (last, init) = args
+        .split_last()
+        .expect("apply takes at least two arguments");
+    let list = list::elements("apply", last)?;
+    let mut spread = init[1..].to_vec();
+    spread.extend(list);
+    Ok((init[0].clone(), spread))
+}
+
+/// Calls anything applicable that isn't a `lambda` procedure.
+fn call(operator: &Object, args: Vec<Object>) -> Result<Object, Error> {
+    match operator {
+        Object::Primitive(p) => {
+            if args.len() < p.min_args || p.max_args.is_some_and(|max| args.len() > max) {
+                return Err(arity_error(p.name, args));
+            }
+            (p.f)(&args)
+        }
+        Object::Continuation(k) if k.active.get() => Err(Error::Throw(k.clone(), values(args))),
+        Object::Continuation(_) => Err(Error::new(
+            "continuation can't be resumed once its call/cc has returned",
+            vec![],
+        )),
+        Object::Parameter(p) => match <[_; 1]>::try_from(args) {
+            Err(args) if args.is_empty() => Ok(p.get()),
+            Err(args) => Err(arity_error("parameter", args)),
+            Ok([value]) => {
+                p.set(p.convert(value)?);
+                Ok(Object::Unspecified)
+            }
+        },
+        _ => Err(Error::new("not a procedure", vec![operator.clone()])),
+    }
+}
+
+fn values_error(who: &str, vals: Vec<Object>) -> Error {
+    Error::new(format!("{who}: wrong number of values"), vals)
+}
+
+fn arity_error(name: &str, args: Vec<Object>) -> Error {
+    Error::new(format!("{name}: wrong number of arguments"), args)
+}
+
+/// `call/cc` is a primitive like any other so that it can be passed
+/// around, but only [`run`] ever calls it, so that the call it makes is a
+/// tail call and `run` can catch what's thrown to the continuation.
+pub static CALL_CC: Primitive = Primitive::fixed("call-with-current-continuation", 1, |_| {
+    unreachable!("run applies call/cc itself")
+});
+
+/// `(dynamic-wind before thunk after)`.
+///
+/// Since continuations only ever escape, leaving the extent of `thunk` is
+/// always a return from it, normal or otherwise, and there is no need for
+/// a stack of winders: `after` just runs on the way out, unless that's
+/// because of an `emergency-exit`. An error in `after` takes precedence
+/// over how `thunk` finished.
+fn dynamic_wind(args: &[Object]) -> Result<Object, Error> {
+    apply(&args[0], vec![])?;
+    let result = apply(&args[1], vec![]);
+    if !matches!(
+        result,
+        Err(Error::Exit {
+            emergency: true,
+            ..
+        })
+    ) {
+        apply(&args[2], vec![])?;
+    }
+    result
+}
+
+/// `(exit [status])` and `(emergency-exit [status])`. A status of `#t`, or
+/// none at all, means success and `#f` failure; an exact integer is passed
+/// on as it is, and anything else counts as a failure too.
+fn exit(args: &[Object], emergency: bool) -> Result<Object, Error> {
+    let status = match args.first() {
+        None | Some(Object::Boolean(true)) => 0,
+        Some(Object::Number(Number::Integer(i))) => i32::try_from(*i).unwrap_or(1),
+        Some(_) => 1,
+    };
+    Err(Error::Exit { status, emergency })
+}
+
+/// `(eval expr [env])`, evaluating in the interaction environment if no
+/// other is given.
+fn eval_procedure(args: &[Object]) -> Result<Object, Error> {
+    let env = match args.get(1) {
+        None => interaction_environment(),
+        Some(Object::Environment(env)) => env.clone(),
+        Some(other) => return Err(Error::wrong_type("eval", "an environment", other)),
+    };
+    eval(args[0].clone(), &env)
+}
+
+pub const PRIMITIVES: &[Primitive] = &[
+    Primitive::range("eval", 1, 2, eval_procedure),
+    Primitive::fixed("dynamic-wind", 3, dynamic_wind),
+    Primitive::range("exit", 0, 1, |args| exit(args, false)),
+    Primitive::range("emergency-exit", 0, 1, |args| exit(args, true)),
+    Primitive::variadic("values", 0, |args| Ok(values(args.to_vec()))),
+    Primitive::fixed("call-with-values", 2, |args| {
+        let vals = apply(&args[0], vec![])?;
+        apply(&args[1], value_list(vals))
+    }),
+];
+
+#[cfg(test)]
+mod tests {
+    use alloc::{borrow::ToOwned, string::ToString};
+
+    use super::*;
+    use crate::{
+        read_str,
+        testing::{check, check_errors, output},
+    };
+
+    #[test]
+    fn literals_and_application() {
+        check(&[
+            ("42", "42"),
+            ("\"hi\"", "\"hi\""),
+            ("#t", "#t"),
+            ("#\\a", "#\\a"),
+            ("#u8(1 2)", "#u8(1 2)"),
+            ("(+ 1 2)", "3"),
+            ("((if #t + -) 5 (* 2 3))", "11"),
+        ]);
+        check_errors(&[
+            ("(1 2)", "not a procedure"),
+            ("nowhere", "unbound variable nowhere"),
+        ]);
+    }
+
+    #[test]
+    fn define() {
+        check(&[
+            ("(define x (* 6 7)) x", "42"),
+            ("(define (f a b) (- a b)) (f 5 3)", "2"),
+            ("(define (g . rest) rest) (g 1 2)", "(1 2)"),
+            ("(define x 1) (define x 2) x", "2"),
+            ("(define (f) 1) (define (f) 2) (f)", "2"),
+            (
+                "(define x 1) (define (f) (define x 2) x) (list (f) x)",
+                "(2 1)",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn lambda() {
+        check(&[
+            ("((lambda (a b) (list b a)) 1 2)", "(2 1)"),
+            ("((lambda (a . rest) (list a rest)) 1 2 3)", "(1 (2 3))"),
+            ("((lambda (a . rest) rest) 1)", "()"),
+            ("((lambda args args))", "()"),
+            ("(define (adder n) (lambda (x) (+ x n))) ((adder 3) 4)", "7"),
+            ("((lambda () 1 2 3))", "3"),
+        ]);
+        check_errors(&[
+            (
+                "((lambda (a b) a) 1)",
+                "procedure: wrong number of arguments",
+            ),
+            (
+                "((lambda (a b) a) 1 2 3)",
+                "procedure: wrong number of arguments",
+            ),
+            (
+                "((lambda (a . rest) a))",
+                "procedure: wrong number of arguments",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn conditionals() {
+        check(&[
+            ("(if #t 1 2)", "1"),
+            ("(if #f 1 2)", "2"),
+            ("(if '() 1 2)", "1"),
+            ("(list (if #f #f))", "(<unspecified>)"),
+            ("(cond (#f 1) ((= 1 1) 2 3) (#t 4))", "3"),
+            ("(cond (#f 1) (2))", "2"),
+            ("(list (cond (#f 1)))", "(<unspecified>)"),
+            (
+                "(case (* 2 3) ((2 3 5 7) 'prime) ((1 4 6 8 9) 'composite))",
+                "composite",
+            ),
+            ("(list (case 'x ((a) 1) ((b c) 2)))", "(<unspecified>)"),
+            ("(case #\\a ((#\\a) 'char))", "char"),
+        ]);
+        assert_eq!(
+            output("(if (begin (display 1) #f) (display 2) (display 3))"),
+            "13"
+        );
+    }
+
+    #[test]
+    fn arrow_and_else_clauses() {
+        check(&[
+            (
+                "(cond ((assv 'b '((a 1) (b 2))) => cadr) (else 'nope))",
+                "2",
+            ),
+            ("(cond ((memv 9 '(1 2)) => car) (else 'nope))", "nope"),
+            ("(case 5 ((1) 'one) (else => (lambda (x) (* x 2))))", "10"),
+            (
+                "(case 1 ((1) => (lambda (x) (list x 'one))) (else 'other))",
+                "(1 one)",
+            ),
+            ("(case 3 ((1) 'one) ((2) 'two) (else 'many))", "many"),
+        ]);
+    }
+
+    #[test]
+    fn binding_forms() {
+        check(&[
+            ("(define x 1) (let ((x 2) (y x)) (list x y))", "(2 1)"),
+            (
+                "(let* ((x 1) (y (+ x 1)) (z (* y 2))) (list x y z))",
+                "(1 2 4)",
+            ),
+            (
+                "(letrec ((even? (lambda (n) (if (= n 0) #t (odd? (- n 1)))))
+                          (odd? (lambda (n) (if (= n 0) #f (even? (- n 1))))))
+                   (list (even? 10) (odd? 7)))",
+                "(#t #t)",
+            ),
+            ("(letrec* ((a 1) (b (+ a 1))) b)", "2"),
+            ("(let () 5)", "5"),
+        ]);
+        check_errors(&[(
+            "(letrec ((a b) (b 1)) a)",
+            "variable used before it was initialized b",
+        )]);
+    }
+
+    #[test]
+    fn named_let() {
+        check(&[
+            ("(let loop ((i 0)) (if (= i 1000000) i (loop (+ i 1))))", "1000000"),
+            (
+                "(let loop ((xs '(1 2 3)) (acc '())) (if (null? xs) acc (loop (cdr xs) (cons (car xs) acc))))",
+                "(3 2 1)",
+            ),
+            ("(define loop 'outer) (let loop ((i 0)) i) loop", "outer"),
+        ]);
+    }
+
+    #[test]
+    fn set() {
+        check(&[
+            (
+                "(define (make-counter)
+                   (let ((n 0)) (lambda () (set! n (+ n 1)) n)))
+                 (define c (make-counter))
+                 (define d (make-counter))
+                 (c) (c) (d)
+                 (list (c) (d))",
+                "(3 2)",
+            ),
+            ("(define x 1) (let ((x 2)) (set! x 3)) x", "1"),
+            ("(define x 1) (set! x 5) x", "5"),
+        ]);
+        check_errors(&[
+            ("(set! never-defined 1)", "unbound variable never-defined"),
+            (
+                "(let () (set! never-defined 1))",
+                "unbound variable never-defined",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn begin() {
+        assert_eq!(output("(begin (display 1) (display 2) (display 3))"), "123");
+        check(&[
+            ("(begin 1 2 3)", "3"),
+            ("(list (begin))", "(<unspecified>)"),
+            ("(begin (define a 1) (define b 2)) (list a b)", "(1 2)"),
+            ("(begin (begin (define c 3))) c", "3"),
+        ]);
+    }
+
+    #[test]
+    fn and_or() {
+        check(&[
+            ("(and)", "#t"),
+            ("(or)", "#f"),
+            ("(and 1 2 'last)", "last"),
+            ("(and 1 #f 3)", "#f"),
+            ("(or #f '(a) 3)", "(a)"),
+            ("(or #f #f)", "#f"),
+            ("(define (loop n) (and #t (or #f (if (= n 0) 'done (loop (- n 1)))))) (loop 100000)", "done"),
+        ]);
+        assert_eq!(output("(and (display 1) #f (display 2))"), "1");
+        assert_eq!(output("(or (begin (display 1) #f) 'x (display 2))"), "1");
+    }
+
+    #[test]
+    fn when_unless() {
+        check(&[
+            ("(when (= 1 1) 'a 'b)", "b"),
+            ("(list (when #f 'a))", "(<unspecified>)"),
+            ("(unless #f 'a 'b)", "b"),
+            ("(list (unless 0 'a))", "(<unspecified>)"),
+            ("(list (when #t))", "(<unspecified>)"),
+            ("(list (unless #f))", "(<unspecified>)"),
+        ]);
+        assert_eq!(output("(when #f (display 1)) (unless #t (display 2))"), "");
+    }
+
+    #[test]
+    fn do_loops() {
+        check(&[
+            ("(do ((i 0 (+ i 1)) (sum 0 (+ sum i))) ((= i 5) sum))", "10"),
+            ("(do ((i 0 (+ i 1)) (k 'same)) ((= i 3) k))", "same"),
+            (
+                "(define v (make-vector 3 0))
+                 (do ((i 0 (+ i 1))) ((= i 3) v) (vector-set! v i (* i i)))",
+                "#(0 1 4)",
+            ),
+            (
+                "(list (do ((i 0 (+ i 1))) ((= i 100000))))",
+                "(<unspecified>)",
+            ),
+            (
+                "(do ((a 1 b) (b 2 a) (n 0 (+ n 1))) ((= n 3) (list a b)))",
+                "(2 1)",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn quasiquote() {
+        check(&[
+            ("`(1 ,(+ 1 1) ,@(list 3 4) 5)", "(1 2 3 4 5)"),
+            ("`(a `(b ,(c)))", "(a (quasiquote (b (unquote (c)))))"),
+            ("`#(1 ,(+ 1 1) ,@(list 3))", "#(1 2 3)"),
+            ("`(1 . ,(+ 1 1))", "(1 . 2)"),
+            ("`(,@'() . x)", "x"),
+            ("`x", "x"),
+            (
+                "`(a `(b ,(+ 1 2) ,,(+ 3 4)))",
+                "(a (quasiquote (b (unquote (+ 1 2)) (unquote 7))))",
+            ),
+            (
+                "`(1 `(2 ,(3 ,@(list 4 5))))",
+                "(1 (quasiquote (2 (unquote (3 4 5)))))",
+            ),
+            // a long template doesn't run out of stack
+            (
+                "(length (eval (list 'quasiquote (make-list 100000 '(unquote (+ 1 1))))
+                               (interaction-environment)))",
+                "100000",
+            ),
+        ]);
+        // the unquotes are evaluated in the order they're written
+        assert_eq!(
+            output("`(,(display \"a\") ,(display \"b\") . ,(display \"c\"))"),
+            "abc"
+        );
+        assert_eq!(
+            output("`#(,(display \"a\") ,@(begin (display \"b\") '()))"),
+            "ab"
+        );
+        check_errors(&[("`(1 ,@2 3)", "unquote-splicing: expected a list 2")]);
+    }
+
+    #[test]
+    fn tail_calls() {
+        check(&[
+            (
+                "(define (count n acc) (if (= n 0) acc (count (- n 1) (+ acc 1))))
+                 (count 1000000 0)",
+                "1000000",
+            ),
+            (
+                "(define (ping n) (cond ((= n 0) 'done) (else (pong (- n 1)))))
+                 (define (pong n) (let ((m n)) (when #t (case 1 ((1) (ping m))))))
+                 (ping 100000)",
+                "done",
+            ),
+            (
+                "(define (loop n) (begin (let* ((x n)) (letrec ((y x)) (if (= y 0) 'ok (loop (- y 1)))))))
+                 (loop 100000)",
+                "ok",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn apply() {
+        check(&[
+            ("(apply + 1 2 '(3 4))", "10"),
+            ("(apply + '(1 2 3))", "6"),
+            ("(apply list '())", "()"),
+            ("(apply apply list '((1 2)))", "(1 2)"),
+            (
+                "(define (loop n) (if (= n 0) 'done (apply loop (list (- n 1))))) (loop 100000)",
+                "done",
+            ),
+        ]);
+        check_errors(&[
+            ("(apply + 1 2)", "apply: expected a list 2"),
+            ("(apply + '(1 . 2))", "apply: expected a list"),
+            (
+                "(define circular (list 1 2))
+                 (set-cdr! (cdr circular) circular)
+                 (apply + circular)",
+                "apply: expected a list #0=(1 2 . #0#)",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn call_cc() {
+        check(&[
+            (
+                "(define (find-first pred xs)
+                   (call/cc (lambda (return)
+                     (for-each (lambda (x) (when (pred x) (return x))) xs)
+                     #f)))
+                 (list (find-first even? '(1 3 4 5 6)) (find-first even? '(1 3)))",
+                "(4 #f)",
+            ),
+            ("(+ 1 (call/cc (lambda (k) (+ 10 (k 1)))))", "2"),
+            ("(call-with-current-continuation (lambda (k) 5))", "5"),
+            ("(apply call/cc (list (lambda (k) (k 9))))", "9"),
+            (
+                "(define (f n) (call/cc (lambda (k) (if (= n 0) 0 (f (- n 1))))))
+                 (f 100000)",
+                "0",
+            ),
+        ]);
+        check_errors(&[(
+            "(define saved #f) (call/cc (lambda (k) (set! saved k))) (saved 1)",
+            "continuation can't be resumed once its call/cc has returned",
+        )]);
+    }
+
+    #[test]
+    fn dynamic_wind() {
+        let wind = "(define log '())
+                    (define (note x) (set! log (cons x log)))
+                    (define (wind thunk)
+                      (dynamic-wind (lambda () (note 'before)) thunk (lambda () (note 'after))))";
+        check(&[
+            (
+                &format!("{wind} (define r (wind (lambda () (note 'during) 1))) (list r (reverse log))"),
+                "(1 (before during after))",
+            ),
+            (
+                &format!(
+                    "{wind} (define r (call/cc (lambda (k) (wind (lambda () (k 'escaped) (note 'no))))))
+                     (list r (reverse log))"
+                ),
+                "(escaped (before after))",
+            ),
+            (
+                &format!(
+                    "{wind} (define r (guard (e (#t e)) (wind (lambda () (raise 'oops)))))
+                     (list r (reverse log))"
+                ),
+                "(oops (before after))",
+            ),
+        ]);
+        // continuations only escape, so there's no way back in to run
+        // `before` again
+        check_errors(&[(
+            &format!(
+                "{wind} (define k #f)
+                 (wind (lambda () (call/cc (lambda (c) (set! k c)))))
+                 (k 1)"
+            ),
+            "continuation can't be resumed",
+        )]);
+    }
+
+    #[test]
+    fn internal_definitions() {
+        check(&[
+            (
+                "(define (parity n)
+                   (define (ev? n) (if (= n 0) #t (od? (- n 1))))
+                   (define (od? n) (if (= n 0) #f (ev? (- n 1))))
+                   (if (ev? n) 'even 'odd))
+                 (list (parity 10) (parity 7))",
+                "(even odd)",
+            ),
+            (
+                "(let () (define a 1) (define b (+ a 1)) (list a b))",
+                "(1 2)",
+            ),
+            (
+                "(define x 'outer) (define (f) (define x 'inner) x) (list (f) x)",
+                "(inner outer)",
+            ),
+            ("(let () (begin (define a 1) (define b 2)) (+ a b))", "3"),
+        ]);
+        check_errors(&[
+            (
+                "(define (f) (display 1) (define x 2) x)",
+                "definitions must come before the expressions of a body (define x 2)",
+            ),
+            (
+                "(let () 1 (define y 2) y)",
+                "definitions must come before the expressions of a body",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn multiple_value_bindings() {
+        check(&[
+            ("(let-values (((q r) (floor/ 7 2))) (list q r))", "(3 1)"),
+            (
+                "(let-values (((a . rest) (values 1 2)) (all (values 3 4))) (list a rest all))",
+                "(1 (2) (3 4))",
+            ),
+            (
+                "(let*-values (((a) (values 1)) ((b) (values (+ a 1)))) (list a b))",
+                "(1 2)",
+            ),
+            (
+                "(define-values (x . y) (values 1 2 3)) (list x y)",
+                "(1 (2 3))",
+            ),
+            (
+                "(define (f) (define-values (a b) (values 1 2)) (+ a b)) (f)",
+                "3",
+            ),
+        ]);
+        check_errors(&[
+            (
+                "(let-values (((a b) (values 1 2 3))) a)",
+                "let-values: wrong number of values 1 2 3",
+            ),
+            (
+                "(define-values (a b) (values 1))",
+                "define-values: wrong number of values 1",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn case_lambda() {
+        let area = "(define area
+                      (case-lambda
+                        ((r) (list 'circle r))
+                        ((w h) (list 'rect w h))
+                        ((w h . more) (list 'many (length more)))))";
+        check(&[
+            (&format!("{area} (area 1)"), "(circle 1)"),
+            (&format!("{area} (area 1 2)"), "(rect 1 2)"),
+            (&format!("{area} (area 1 2 3 4)"), "(many 2)"),
+        ]);
+        check_errors(&[
+            (
+                &format!("{area} (area)"),
+                "procedure: wrong number of arguments",
+            ),
+            (
+                "((case-lambda ((a) a) ((a b c) a)) 1 2)",
+                "procedure: wrong number of arguments 1 2",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn eval_procedure() {
+        check(&[
+            ("(eval '(+ 1 2) (interaction-environment))", "3"),
+            ("(eval '(* 2 3))", "6"),
+            (
+                "(eval (list 'define 'made-at-runtime 4)) made-at-runtime",
+                "4",
+            ),
+            ("(eval ''quoted (interaction-environment))", "quoted"),
+        ]);
+        check_errors(&[("(eval 1 2)", "eval: expected an environment 2")]);
+    }
+
+    #[test]
+    fn report_environments() {
+        check(&[
+            ("(eval '(car '(1 2)) (scheme-report-environment 7))", "1"),
+            ("(eval '(if #t 'yes 'no) (null-environment 5))", "yes"),
+            (
+                "(eval '(define report-only 1) (scheme-report-environment 7))
+                 (guard (e (#t 'unbound)) report-only)",
+                "unbound",
+            ),
+        ]);
+        check_errors(&[
+            (
+                "(eval '(car '(1 2)) (null-environment 7))",
+                "unbound variable car",
+            ),
+            (
+                "(scheme-report-environment 6)",
+                "scheme-report-environment:",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn runaway_recursion_raises() {
+        check_errors(&[
+            ("(define (f n) (+ 1 (f n))) (f 0)", "recursion too deep"),
+            ("(define (g) (list (g))) (g)", "recursion too deep"),
+        ]);
+        check(&[
+            (
+                "(define (f n) (+ 1 (f n))) (guard (e (#t (error-object-message e))) (f 0))",
+                "\"recursion too deep\"",
+            ),
+            (
+                "(define (deep n) (if (= n 0) 0 (+ 1 (deep (- n 1))))) (deep 10000)",
+                "10000",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn the_depth_limit_can_be_changed() {
+        let (shallow, deep) = with_stack(|| {
+            let env = crate::interaction_environment();
+            let program = "(define (deep n) (if (= n 0) 0 (+ 1 (deep (- n 1))))) (deep 100)";
+            let [define, call] = <[_; 2]>::try_from(read_str(program).unwrap()).unwrap();
+            eval(define, &env).unwrap();
+            set_max_depth(50);
+            let shallow = eval(call.clone(), &env).map_err(|e| e.to_string());
+            set_max_depth(DEFAULT_MAX_DEPTH);
+            let deep = eval(call, &env).map(|value| value.to_string());
+            (shallow.err(), deep.ok())
+        });
+        assert_eq!(shallow.as_deref(), Some("recursion too deep"));
+        assert_eq!(deep.as_deref(), Some("100"));
+    }
+
+    #[test]
+    fn guard_catches_by_predicate_or_reraises() {
+        check(&[
+            (
+                "(guard (e ((symbol? e) (list 'symbol e)) ((string? e) (list 'string e)))
+                   (raise \"oops\"))",
+                "(string \"oops\")",
+            ),
+            (
+                "(guard (e ((assq 'a e) => cdr) ((assq 'b e))) (raise (list (cons 'a 42))))",
+                "42",
+            ),
+            (
+                "(guard (e ((assq 'a e) => cdr) ((assq 'b e))) (raise (list (cons 'b 23))))",
+                "(b . 23)",
+            ),
+            ("(guard (e (else 'other)) (+ 1 (raise 'oops)))", "other"),
+            ("(guard (e (#f 'never)) 'fine)", "fine"),
+            (
+                "(guard (outer (#t (list 'outer outer)))
+                   (guard (inner ((string? inner) 'inner))
+                     (raise 'oops)))",
+                "(outer oops)",
+            ),
+        ]);
+        check_errors(&[(
+            "(guard (e ((string? e) 'string)) (raise 'oops))",
+            "uncaught exception: oops",
+        )]);
+    }
+
+    #[test]
+    fn exit_runs_the_pending_afters() {
+        let run = |exit: &'static str| {
+            with_stack(move || {
+                let env = crate::interaction_environment();
+                let program = format!(
+                    "(define log '())
+                     (dynamic-wind
+                       (lambda () #f)
+                       (lambda ()
+                         (dynamic-wind
+                           (lambda () #f)
+                           (lambda () ({exit} 3))
+                           (lambda () (set! log (cons 'inner log)))))
+                       (lambda () (set! log (cons 'outer log))))"
+                );
+                let [define, wind] = <[_; 2]>::try_from(read_str(&program).unwrap()).unwrap();
+                eval(define, &env).unwrap();
+                let exited = matches!(eval(wind, &env), Err(Error::Exit { status: 3, .. }));
+                let log = eval(Object::Symbol("log".into()), &env).unwrap();
+                (exited, log.to_string())
+            })
+        };
+        assert_eq!(run("exit"), (true, "(outer inner)".to_owned()));
+        assert_eq!(run("emergency-exit"), (true, "()".to_owned()));
+        check_errors(&[
+            ("(exit)", "exit with status 0"),
+            ("(exit #t)", "exit with status 0"),
+            ("(exit #f)", "exit with status 1"),
+            ("(exit 7)", "exit with status 7"),
+            ("(guard (e (#t 'caught)) (exit 7))", "exit with status 7"),
+        ]);
+    }
+
+    #[test]
+    #[cfg(feature = "metrics")]
+    fn tail_calls_keep_the_depth_flat() {
+        let peak = |src: &'static str| {
+            with_stack(move || {
+                let env = interaction_environment();
+                let mut data = read_str(src).unwrap();
+                let last = data.pop().unwrap();
+                for datum in data {
+                    eval(datum, &env).unwrap();
+                }
+                reset_peak_depth();
+                eval(last, &env).unwrap();
+                peak_depth() - current_depth()
+            })
+        };
+        let loops = [
+            "(let loop ((i 0)) (if (< i 1000000) (loop (+ i 1)) i))",
+            "(define (even? n) (if (= n 0) #t (odd? (- n 1))))
+             (define (odd? n) (if (= n 0) #f (even? (- n 1))))
+             (even? 100000)",
+            "(define (f n) (cond ((= n 0) 'done) (else (and #t (or #f (let () (f (- n 1))))))))
+             (f 100000)",
+            "(define (g n) (when (> n 0) (apply g (list (- n 1)))))
+             (g 100000)",
+        ];
+        for src in loops {
+            let peak = peak(src);
+            assert!(peak < 10, "{src} nested {peak} deep");
+        }
+        // and calls that aren't in tail position do nest
+        let peak = peak("(define (sum n) (if (= n 0) 0 (+ n (sum (- n 1))))) (sum 1000)");
+        assert!(peak > 1000, "{peak}");
+    }
+
+    #[test]
+    fn literals_are_constants() {
+        check(&[
+            ("#(1 (+ 1 1) x)", "#(1 (+ 1 1) x)"),
+            ("#u8(1 2 3)", "#u8(1 2 3)"),
+            ("(equal? #(1 2 3) (vector 1 2 3))", "#t"),
+            // copies of them can be changed, and so can what `read` gives
+            (
+                "(define v (vector-copy #(1 2 3)))
+                 (vector-set! v 0 9)
+                 v",
+                "#(9 2 3)",
+            ),
+            (
+                "(define s (string-copy \"abc\"))
+                 (string-fill! s #\\z)
+                 s",
+                "\"zzz\"",
+            ),
+            (
+                "(define l (read (open-input-string \"(1 #(2))\")))
+                 (set-car! l 0)
+                 (vector-set! (cadr l) 0 0)
+                 l",
+                "(0 #(0))",
+            ),
+            ("(define l `(1 ,(+ 1 1))) (set-car! l 0) l", "(0 2)"),
+            // so a literal is the same every time it's evaluated
+            (
+                "(define (f) '(1 2))
+                 (guard (e (#t #f)) (set-car! (f) 0))
+                 (f)",
+                "(1 2)",
+            ),
+        ]);
+        check_errors(&[
+            (
+                "(vector-set! #(1 2 3) 0 9)",
+                "vector-set!: can't change a constant #(1 2 3)",
+            ),
+            (
+                "(vector-set! '#(1 2 3) 0 9)",
+                "vector-set!: can't change a constant",
+            ),
+            (
+                "(string-fill! \"abc\" #\\z)",
+                "string-fill!: can't change a constant \"abc\"",
+            ),
+            (
+                "(bytevector-u8-set! #u8(1 2 3) 0 9)",
+                "bytevector-u8-set!: can't change a constant",
+            ),
+            (
+                "(set-car! '(1 2) 3)",
+                "set-car!: can't change a constant (1 2)",
+            ),
+            (
+                "(list-set! '(1 2) 1 3)",
+                "list-set!: can't change a constant (2)",
+            ),
+            // and whatever's in them
+            (
+                "(vector-fill! (car '(#(1))) 0)",
+                "vector-fill!: can't change a constant #(1)",
+            ),
+            (
+                "(string-copy! (vector-ref #(\"ab\") 0) 0 \"z\")",
+                "string-copy!: can't change a constant",
+            ),
+            ("(sort! < #(3 2 1))", "sort!: can't change a constant"),
+        ]);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{error::Error, eval::Primitive, library, list_to_vec, symbol, vec_to_list, Object};
+
+/// The feature identifiers that `cond-expand` recognises and `features`
+/// lists. Exact arithmetic falls back to reals when it overflows, so this
+/// doesn't claim `exact-closed`.
+const FEATURES: &[&str] = &[
+    "r7rs",
+    "ratios",
+    "full-unicode",
+    "mibph",
+    #[cfg(unix)]
+    "unix",
+    #[cfg(windows)]
+    "windows",
+    #[cfg(target_endian = "little")]
+    "little-endian",
+    #[cfg(target_endian = "big")]
+    "big-endian",
+];
+
+/// Whether the feature requirement `requirement` is met, which `who`
+/// needs to be well-formed.
+pub fn satisfied(requirement: &Object, who: &str) -> Result<bool, Error> {
+    let bad = || {
+        Error::new(
+            format!("{who}: bad feature requirement"),
+            vec![requirement.clone()],
+        )
+    };
+    let parts = match requirement {
+        Object::Symbol(feature) => return Ok(FEATURES.contains(&&**feature)),
+        Object::Pair(_) => list_to_vec(requirement).ok_or_else(bad)?,
+        _ => return Err(bad()),
+    };
+    let Some((Object::Symbol(operator), operands)) = parts.split_first() else {
+        return Err(bad());
+    };
+    match (&**operator, operands) {
+        ("and", _) => {
+            for operand in operands {
+                if !satisfied(operand, who)? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+        ("or", _) => {
+            for operand in operands {
+                if satisfied(operand, who)? {
+                    return Ok(true);
+                }
+            }
+            Ok(false)
+        }
+        ("not", [operand]) => Ok(!satisfied(operand, who)?),
+        ("library", [name]) => Ok(library::exists(name)),
+        _ => Err(bad()),
+    }
+}
+
+pub const PRIMITIVES: &[Primitive] = &[Primitive::fixed("features", 0, |_| {
+    Ok(vec_to_list(
+        FEATURES.iter().map(|f| symbol(f)).collect(),
+        Object::Null,
+    ))
+})];
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{check, check_errors};
+
+    #[test]
+    fn cond_expand_picks_the_first_satisfied_clause() {
+        check(&[
+            ("(cond-expand (r7rs 1) (else 2))", "1"),
+            ("(cond-expand (no-such-feature 1) (else 2))", "2"),
+            (
+                "(cond-expand ((and r7rs (not no-such-feature)) 'both) (else 'neither))",
+                "both",
+            ),
+            (
+                "(cond-expand ((or no-such-feature mibph) 'one) (else 'none))",
+                "one",
+            ),
+            ("(cond-expand ((and) 'empty-and))", "empty-and"),
+            ("(cond-expand ((or) 'never) (else 'empty-or))", "empty-or"),
+            (
+                "(cond-expand ((library (scheme base)) 'base) (else 'none))",
+                "base",
+            ),
+            (
+                "(cond-expand ((library (no such library)) 'some) (else 'none))",
+                "none",
+            ),
+            ("(cond-expand (r7rs (define x 1) (+ x 1)))", "2"),
+            (
+                "(map (lambda (f) (and (memq f (features)) #t)) '(r7rs exact-closed))",
+                "(#t #f)",
+            ),
+            (
+                "(list (cond-expand (no-such-feature 1)))",
+                "(<unspecified>)",
+            ),
+        ]);
+        check_errors(&[(
+            "(cond-expand ((nor r7rs) 1))",
+            "cond-expand: bad feature requirement",
+        )]);
+    }
+}
@@ -0,0 +1,92 @@
+//! What `f64` is missing without std: core leaves the roundings and powers
+//! to the platform's maths library, so they're worked out here instead,
+//! the roundings from the integer part and powers by repeated squaring.
+
+/// The few float methods the numbers need that only std has.
+pub trait Float {
+    fn trunc(self) -> f64;
+    fn fract(self) -> f64;
+    fn floor(self) -> f64;
+    fn round(self) -> f64;
+    fn powi(self, n: i32) -> f64;
+}
+
+/// From 2^52 up every float is an integer already.
+const ALL_INTEGERS: f64 = 4503599627370496.0;
+
+impl Float for f64 {
+    fn trunc(self) -> f64 {
+        if self.is_nan() || self.abs() >= ALL_INTEGERS {
+            return self;
+        }
+        // keeping the sign, so that -0.5 truncates to -0.
+        (self as i64 as f64).copysign(self)
+    }
+
+    fn fract(self) -> f64 {
+        self - self.trunc()
+    }
+
+    fn floor(self) -> f64 {
+        let t = self.trunc();
+        if t > self {
+            t - 1.0
+        } else {
+            t
+        }
+    }
+
+    /// Halfway cases round away from zero, as with std.
+    fn round(self) -> f64 {
+        let t = self.trunc();
+        if (self - t).abs() >= 0.5 {
+            t + 1f64.copysign(self)
+        } else {
+            t
+        }
+    }
+
+    fn powi(self, n: i32) -> f64 {
+        let (mut base, mut k, mut product) = (self, n.unsigned_abs(), 1.0);
+        while k > 0 {
+            if k & 1 == 1 {
+                product *= base;
+            }
+            base *= base;
+            k >>= 1;
+        }
+        if n < 0 {
+            1.0 / product
+        } else {
+            product
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Float;
+
+    #[test]
+    fn rounding_without_std() {
+        for x in [
+            0.0,
+            -0.0,
+            0.5,
+            -0.5,
+            1.5,
+            -2.5,
+            2.4999999999999996,
+            1e300,
+            -7.75,
+            f64::INFINITY,
+        ] {
+            assert_eq!(Float::trunc(x).to_bits(), x.trunc().to_bits(), "trunc {x}");
+            assert_eq!(Float::floor(x).to_bits(), x.floor().to_bits(), "floor {x}");
+            assert_eq!(Float::round(x).to_bits(), x.round().to_bits(), "round {x}");
+        }
+        assert!(Float::fract(f64::NAN).is_nan());
+        assert_eq!(Float::powi(2.0, -1000), 2f64.powi(-1000));
+        assert_eq!(Float::powi(-3.0, 5), -243.0);
+    }
+}
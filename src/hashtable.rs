@@ -0,0 +1,771 @@
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+
+use crate::{
+    collections::{DefaultHasher, HashMap, HashSet},
+    cons, equal, eqv,
+    error::Error,
+    eval::{apply, Primitive, Procedure},
+    list::Pair,
+    number::Number,
+    sync::{Cell, Rc, RefCell, Weak},
+    vec_to_list, Object,
+};
+
+/// How many objects [`hash`] looks at, at most, inside a key made of
+/// pairs and vectors. Keys that only differ further in than that hash the
+/// same, which is slower but still right, and cyclic keys hash at all.
+const HASH_DEPTH: usize = 32;
+
+/// How big a weak table can get before it's pruned as a whole, at least.
+const MIN_PRUNE_AT: usize = 64;
+
+/// Which keys a table takes to be the same.
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub enum Equivalence {
+    /// `eqv?`, which `eq?` is the same as here.
+    Eqv,
+    #[default]
+    Equal,
+    /// `string=?`, with only strings for keys.
+    String,
+}
+
+/// A key of a table, which a weak table only holds on to weakly if it's
+/// an object with an identity of its own, the kind that can be dropped.
+#[derive(Clone)]
+enum Key {
+    Strong(Object),
+    Pair(Weak<Pair>),
+    Procedure(Weak<Procedure>),
+    String(Weak<RefCell<Vec<char>>>),
+    Bytevector(Weak<RefCell<Vec<u8>>>),
+    Vector(Weak<RefCell<Vec<Object>>>),
+    HashTable(Weak<HashTable>),
+}
+
+impl Key {
+    fn new(obj: Object, weak: bool) -> Key {
+        match obj {
+            Object::Pair(p) if weak => Key::Pair(Rc::downgrade(&p)),
+            Object::Procedure(p) if weak => Key::Procedure(Rc::downgrade(&p)),
+            Object::String(s) if weak => Key::String(Rc::downgrade(&s)),
+            Object::Bytevector(v) if weak => Key::Bytevector(Rc::downgrade(&v)),
+            Object::Vector(v) if weak => Key::Vector(Rc::downgrade(&v)),
+            Object::HashTable(t) if weak => Key::HashTable(Rc::downgrade(&t)),
+            obj => Key::Strong(obj),
+        }
+    }
+
+    /// The key, unless it's been dropped.
+    fn get(&self) -> Option<Object> {
+        match self {
+            Key::Strong(obj) => Some(obj.clone()),
+            Key::Pair(p) => p.upgrade().map(Object::Pair),
+            Key::Procedure(p) => p.upgrade().map(Object::Procedure),
+            Key::String(s) => s.upgrade().map(Object::String),
+            Key::Bytevector(v) => v.upgrade().map(Object::Bytevector),
+            Key::Vector(v) => v.upgrade().map(Object::Vector),
+            Key::HashTable(t) => t.upgrade().map(Object::HashTable),
+        }
+    }
+
+    fn is_dropped(&self) -> bool {
+        match self {
+            Key::Strong(_) => false,
+            Key::Pair(p) => p.strong_count() == 0,
+            Key::Procedure(p) => p.strong_count() == 0,
+            Key::String(s) => s.strong_count() == 0,
+            Key::Bytevector(v) => v.strong_count() == 0,
+            Key::Vector(v) => v.strong_count() == 0,
+            Key::HashTable(t) => t.strong_count() == 0,
+        }
+    }
+}
+
+/// A mutable table from keys to values.
+///
+/// A weak table doesn't keep its keys alive: once nothing else refers to
+/// a key its entry is as good as gone, and it's pruned when its bucket is
+/// next added to, when the table is gone through, or when the table has
+/// grown enough since it was last pruned. That's only as prompt as reference
+/// counting, though, so a key in a cycle of references is never dropped,
+/// and neither is one that its own value refers to, since values are held
+/// on to as usual.
+#[derive(Default)]
+pub struct HashTable {
+    equivalence: Equivalence,
+    weak: bool,
+    /// The entries, in buckets by the hashes of their keys, so that a key
+    /// only needs comparing with the others that hash the same.
+    buckets: RefCell<HashMap<u64, Vec<(Key, Object)>>>,
+    /// How many entries there are, counting any with dropped keys that
+    /// haven't been pruned yet.
+    size: Cell<usize>,
+    /// How big a weak table can get before the whole of it is pruned,
+    /// which is twice what was left the last time, so that pruning takes
+    /// no more than a constant time per entry added.
+    prune_at: Cell<usize>,
+}
+
+impl HashTable {
+    pub fn new(equivalence: Equivalence, weak: bool) -> HashTable {
+        HashTable {
+            equivalence,
+            weak,
+            buckets: RefCell::default(),
+            size: Cell::new(0),
+            prune_at: Cell::new(MIN_PRUNE_AT),
+        }
+    }
+
+    /// A hash of `key` that's the same for any two keys the table takes to
+    /// be the same.
+    fn hash(&self, key: &Object) -> u64 {
+        let mut hasher = DefaultHasher::default();
+        let mut budget = HASH_DEPTH;
+        let deep = self.equivalence != Equivalence::Eqv;
+        hash_into(key, &mut hasher, deep, &mut budget);
+        hasher.finish()
+    }
+
+    fn same(&self, a: &Object, b: &Object) -> bool {
+        match self.equivalence {
+            Equivalence::Eqv => eqv(a, b),
+            Equivalence::Equal | Equivalence::String => equal(a, b),
+        }
+    }
+
+    /// Checks that `key` can be a key of the table, for `who`. Keys of
+    /// `equal?` tables can't contain themselves, since `equal?` would go
+    /// round them forever comparing them with each other.
+    fn check(&self, key: &Object, who: &str) -> Result<(), Error> {
+        match (self.equivalence, key) {
+            (Equivalence::String, Object::String(_)) => Ok(()),
+            (Equivalence::String, _) => Err(Error::wrong_type(who, "a string key", key)),
+            (Equivalence::Equal, _) if is_cyclic(key, &mut HashSet::new()) => Err(Error::new(
+                format!("{who}: a cyclic key can't be compared with equal?"),
+                vec![key.clone()],
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Where `key`'s entry is in `bucket`, if it has one.
+    fn find(&self, bucket: &[(Key, Object)], key: &Object) -> Option<usize> {
+        bucket
+            .iter()
+            .position(|(k, _)| k.get().is_some_and(|k| self.same(&k, key)))
+    }
+
+    pub fn get(&self, key: &Object) -> Option<Object> {
+        let buckets = self.buckets.borrow();
+        let bucket = buckets.get(&self.hash(key))?;
+        let i = self.find(bucket, key)?;
+        Some(bucket[i].1.clone())
+    }
+
+    pub fn set(&self, key: Object, value: Object) {
+        let h = self.hash(&key);
+        let mut buckets = self.buckets.borrow_mut();
+        let bucket = buckets.entry(h).or_default();
+        if self.weak {
+            let before = bucket.len();
+            bucket.retain(|(k, _)| !k.is_dropped());
+            self.size.set(self.size.get() - (before - bucket.len()));
+        }
+        match self.find(bucket, &key) {
+            Some(i) => bucket[i].1 = value,
+            None => {
+                bucket.push((Key::new(key, self.weak), value));
+                self.size.set(self.size.get() + 1);
+            }
+        }
+        drop(buckets);
+        if self.size.get() > self.prune_at.get() {
+            self.prune();
+        }
+    }
+
+    /// Removes `key`'s entry, saying whether there was one.
+    pub fn delete(&self, key: &Object) -> bool {
+        let mut buckets = self.buckets.borrow_mut();
+        let h = self.hash(key);
+        let Some(bucket) = buckets.get_mut(&h) else {
+            return false;
+        };
+        let Some(i) = self.find(bucket, key) else {
+            return false;
+        };
+        bucket.swap_remove(i);
+        self.size.set(self.size.get() - 1);
+        if bucket.is_empty() {
+            buckets.remove(&h);
+        }
+        true
+    }
+
+    /// Removes the entries of a weak table whose keys have been dropped.
+    fn prune(&self) {
+        if !self.weak {
+            return;
+        }
+        let mut buckets = self.buckets.borrow_mut();
+        buckets.retain(|_, bucket| {
+            bucket.retain(|(k, _)| !k.is_dropped());
+            !bucket.is_empty()
+        });
+        let size = buckets.values().map(Vec::len).sum();
+        self.size.set(size);
+        self.prune_at.set((size * 2).max(MIN_PRUNE_AT));
+    }
+
+    /// How many entries there are. A weak table is pruned first, to leave
+    /// out the ones whose keys have been dropped.
+    pub fn len(&self) -> usize {
+        self.prune();
+        self.size.get()
+    }
+
+    pub fn clear(&self) {
+        self.buckets.borrow_mut().clear();
+        self.size.set(0);
+    }
+
+    /// A new table with the same entries, which can change independently.
+    pub fn copy(&self) -> HashTable {
+        HashTable {
+            equivalence: self.equivalence,
+            weak: self.weak,
+            buckets: RefCell::new(self.buckets.borrow().clone()),
+            size: Cell::new(self.size.get()),
+            prune_at: Cell::new(self.prune_at.get()),
+        }
+    }
+
+    /// Every key and value, in no particular order.
+    pub fn entries(&self) -> Vec<(Object, Object)> {
+        self.prune();
+        let buckets = self.buckets.borrow();
+        let entries = buckets.values().flatten();
+        entries
+            .filter_map(|(k, v)| Some((k.get()?, v.clone())))
+            .collect()
+    }
+}
+
+/// Feeds `obj` to `hasher`, along with up to `budget` of the objects
+/// inside it if `deep`, for `equal?`, or else going by which string, pair
+/// or vector it is rather than what's in it, for `eqv?`.
+fn hash_into(obj: &Object, hasher: &mut DefaultHasher, deep: bool, budget: &mut usize) {
+    if *budget == 0 {
+        return;
+    }
+    *budget -= 1;
+    core::mem::discriminant(obj).hash(hasher);
+    match obj {
+        Object::Boolean(b) => b.hash(hasher),
+        Object::Char(c) => c.hash(hasher),
+        Object::Number(n) => n.hash_eqv(hasher),
+        Object::Symbol(s) => s.hash(hasher),
+        Object::String(s) if deep => s.borrow().hash(hasher),
+        Object::Bytevector(v) if deep => v.borrow().hash(hasher),
+        Object::Pair(pair) if deep => {
+            hash_into(&pair.car(), hasher, deep, budget);
+            hash_into(&pair.cdr(), hasher, deep, budget);
+        }
+        Object::Vector(v) if deep => {
+            let items = v.borrow().clone();
+            for item in &items {
+                hash_into(item, hasher, deep, budget);
+            }
+        }
+        Object::String(s) => Rc::as_ptr(s).hash(hasher),
+        Object::Bytevector(v) => Rc::as_ptr(v).hash(hasher),
+        Object::Pair(pair) => Rc::as_ptr(pair).hash(hasher),
+        Object::Vector(v) => Rc::as_ptr(v).hash(hasher),
+        // everything else is only ever the same as itself
+        Object::Procedure(p) => Rc::as_ptr(p).hash(hasher),
+        Object::Primitive(p) => core::ptr::from_ref(*p).hash(hasher),
+        Object::Condition(c) => Rc::as_ptr(c).hash(hasher),
+        Object::Continuation(c) => Rc::as_ptr(c).hash(hasher),
+        Object::Environment(e) => Rc::as_ptr(e).hash(hasher),
+        Object::HashTable(t) => Rc::as_ptr(t).hash(hasher),
+        Object::Macro(m) => Rc::as_ptr(m).hash(hasher),
+        Object::Parameter(p) => Rc::as_ptr(p).hash(hasher),
+        Object::Port(p) => Rc::as_ptr(p).hash(hasher),
+        Object::Null
+        | Object::EofObject
+        | Object::Record()
+        | Object::Values(_)
+        | Object::Unspecified
+        | Object::Uninitialized => {}
+    }
+}
+
+/// Whether `obj` contains itself through pairs and vectors, given the
+/// pairs and vectors on `path` that it's already inside of.
+fn is_cyclic(obj: &Object, path: &mut HashSet<*const ()>) -> bool {
+    match obj {
+        Object::Pair(_) => {
+            // each pair of a list stays on the path until the end of the
+            // list, which is gone through in a loop rather than recursion
+            // so that long lists don't run out of stack
+            let mut entered = Vec::new();
+            let mut obj = obj.clone();
+            let cyclic = loop {
+                match &obj {
+                    Object::Pair(pair) => {
+                        let ptr = Rc::as_ptr(pair).cast();
+                        if !path.insert(ptr) {
+                            break true;
+                        }
+                        entered.push(ptr);
+                        if is_cyclic(&pair.car(), path) {
+                            break true;
+                        }
+                        obj = pair.cdr();
+                    }
+                    tail => break is_cyclic(tail, path),
+                }
+            };
+            for ptr in entered {
+                path.remove(&ptr);
+            }
+            cyclic
+        }
+        Object::Vector(v) => {
+            let ptr = Rc::as_ptr(v).cast();
+            if !path.insert(ptr) {
+                return true;
+            }
+            let items = v.borrow().clone();
+            let cyclic = items.iter().any(|item| is_cyclic(item, path));
+            path.remove(&ptr);
+            cyclic
+        }
+        _ => false,
+    }
+}
+
+/// The table `obj`, which `who` needs to be a hash table.
+fn table<'a>(obj: &'a Object, who: &str) -> Result<&'a HashTable, Error> {
+    match obj {
+        Object::HashTable(t) => Ok(t),
+        _ => Err(Error::wrong_type(who, "a hash table", obj)),
+    }
+}
+
+/// The table `args[0]`, which `who` needs `args[1]` to be a key of.
+fn table_for_key<'a>(args: &'a [Object], who: &str) -> Result<&'a HashTable, Error> {
+    let t = table(&args[0], who)?;
+    t.check(&args[1], who)?;
+    Ok(t)
+}
+
+/// `(make-hash-table [same? [hash]])`: a table whose keys are the same by
+/// `same?`, which is one of `eq?`, `eqv?`, `equal?` and `string=?`, and
+/// `equal?` by default. The table makes its own hashes to match, so any
+/// hash function is ignored.
+fn make_hash_table(args: &[Object]) -> Result<Object, Error> {
+    let equivalence = match args.first() {
+        None => Equivalence::Equal,
+        Some(Object::Primitive(p)) if p.name == "eq?" || p.name == "eqv?" => Equivalence::Eqv,
+        Some(Object::Primitive(p)) if p.name == "equal?" => Equivalence::Equal,
+        Some(Object::Primitive(p)) if p.name == "string=?" => Equivalence::String,
+        Some(other) => {
+            return Err(Error::wrong_type(
+                "make-hash-table",
+                "one of eq?, eqv?, equal? and string=?",
+                other,
+            ))
+        }
+    };
+    Ok(make(equivalence, false))
+}
+
+fn make(equivalence: Equivalence, weak: bool) -> Object {
+    Object::HashTable(Rc::new(HashTable::new(equivalence, weak)))
+}
+
+fn missing(who: &str, key: &Object) -> Error {
+    Error::new(format!("{who}: no such key"), vec![key.clone()])
+}
+
+/// `(hash-table-ref table key [failure [success]])`: the value for `key`,
+/// passed to `success` if there is one, or else what `failure` returns,
+/// called with no arguments.
+fn hash_table_ref(args: &[Object]) -> Result<Object, Error> {
+    let value = table_for_key(args, "hash-table-ref")?.get(&args[1]);
+    match (value, args.get(2), args.get(3)) {
+        (Some(value), _, Some(success)) => apply(success, vec![value]),
+        (Some(value), _, None) => Ok(value),
+        (None, Some(failure), _) => apply(failure, vec![]),
+        (None, None, _) => Err(missing("hash-table-ref", &args[1])),
+    }
+}
+
+/// `(hash-table-update! table key update [failure])`: sets `key`'s value
+/// to what `update` makes of the one it has, or of what `failure` returns
+/// if it hasn't one.
+fn hash_table_update(args: &[Object]) -> Result<Object, Error> {
+    let t = table_for_key(args, "hash-table-update!")?;
+    let value = match (t.get(&args[1]), args.get(3)) {
+        (Some(value), _) => value,
+        (None, Some(failure)) => apply(failure, vec![])?,
+        (None, None) => return Err(missing("hash-table-update!", &args[1])),
+    };
+    let updated = apply(&args[2], vec![value])?;
+    t.set(args[1].clone(), updated);
+    Ok(Object::Unspecified)
+}
+
+/// `(hash-table-update!/default table key update default)`: like
+/// `hash-table-update!`, with `default` in place of a missing value.
+fn hash_table_update_default(args: &[Object]) -> Result<Object, Error> {
+    let t = table_for_key(args, "hash-table-update!/default")?;
+    let value = t.get(&args[1]).unwrap_or_else(|| args[3].clone());
+    let updated = apply(&args[2], vec![value])?;
+    t.set(args[1].clone(), updated);
+    Ok(Object::Unspecified)
+}
+
+/// `(hash-table-walk table proc)`: calls `proc` with each key and its
+/// value. The entries are the ones there were when it started, even if
+/// `proc` changes the table.
+fn hash_table_walk(args: &[Object]) -> Result<Object, Error> {
+    for (key, value) in table(&args[0], "hash-table-walk")?.entries() {
+        apply(&args[1], vec![key, value])?;
+    }
+    Ok(Object::Unspecified)
+}
+
+/// `(hash-table-fold table kons knil)`: `kons` applied to each key, its
+/// value and what `kons` last returned, starting with `knil`. SRFI 125's
+/// order of arguments, `(hash-table-fold kons knil table)`, works too.
+fn hash_table_fold(args: &[Object]) -> Result<Object, Error> {
+    let (t, kons, knil) = match &args[0] {
+        Object::HashTable(_) => (&args[0], &args[1], &args[2]),
+        _ => (&args[2], &args[0], &args[1]),
+    };
+    let entries = table(t, "hash-table-fold")?.entries();
+    entries
+        .into_iter()
+        .try_fold(knil.clone(), |acc, (key, value)| {
+            apply(kons, vec![key, value, acc])
+        })
+}
+
+/// A list made of something from each entry of the table `args[0]`.
+fn collect(args: &[Object], who: &str, f: fn((Object, Object)) -> Object) -> Result<Object, Error> {
+    let entries = table(&args[0], who)?.entries();
+    Ok(vec_to_list(
+        entries.into_iter().map(f).collect(),
+        Object::Null,
+    ))
+}
+
+// these follow SRFI 69, which most Schemes with hash tables provide
+pub const PRIMITIVES: &[Primitive] = &[
+    Primitive::range("make-hash-table", 0, 2, make_hash_table),
+    // after MIT Scheme
+    Primitive::fixed("make-eq-hash-table", 0, |_| {
+        Ok(make(Equivalence::Eqv, false))
+    }),
+    Primitive::fixed("make-eqv-hash-table", 0, |_| {
+        Ok(make(Equivalence::Eqv, false))
+    }),
+    Primitive::fixed("make-equal-hash-table", 0, |_| {
+        Ok(make(Equivalence::Equal, false))
+    }),
+    Primitive::fixed("make-string-hash-table", 0, |_| {
+        Ok(make(Equivalence::String, false))
+    }),
+    // only by identity, since there's no telling when another key that's
+    // `equal?` to a dropped one might turn up
+    Primitive::fixed("make-key-weak-eq-hash-table", 0, |_| {
+        Ok(make(Equivalence::Eqv, true))
+    }),
+    Primitive::fixed("make-key-weak-eqv-hash-table", 0, |_| {
+        Ok(make(Equivalence::Eqv, true))
+    }),
+    Primitive::fixed("hash-table?", 1, |args| {
+        Ok(Object::Boolean(matches!(args[0], Object::HashTable(_))))
+    }),
+    Primitive::range("hash-table-ref", 2, 4, hash_table_ref),
+    Primitive::fixed("hash-table-ref/default", 3, |args| {
+        let value = table_for_key(args, "hash-table-ref/default")?.get(&args[1]);
+        Ok(value.unwrap_or_else(|| args[2].clone()))
+    }),
+    Primitive::fixed("hash-table-set!", 3, |args| {
+        table_for_key(args, "hash-table-set!")?.set(args[1].clone(), args[2].clone());
+        Ok(Object::Unspecified)
+    }),
+    Primitive::fixed("hash-table-delete!", 2, |args| {
+        let deleted = table_for_key(args, "hash-table-delete!")?.delete(&args[1]);
+        Ok(Object::Boolean(deleted))
+    }),
+    Primitive::fixed("hash-table-contains?", 2, |args| {
+        let value = table_for_key(args, "hash-table-contains?")?.get(&args[1]);
+        Ok(Object::Boolean(value.is_some()))
+    }),
+    Primitive::range("hash-table-update!", 3, 4, hash_table_update),
+    Primitive::fixed("hash-table-update!/default", 4, hash_table_update_default),
+    Primitive::fixed("hash-table-size", 1, |args| {
+        let len = table(&args[0], "hash-table-size")?.len();
+        Ok(Object::Number(Number::Integer(len as i64)))
+    }),
+    Primitive::fixed("hash-table-walk", 2, hash_table_walk),
+    Primitive::fixed("hash-table-fold", 3, hash_table_fold),
+    // tables are always mutable, so there's nothing for SRFI 69's second
+    // argument to say
+    Primitive::range("hash-table-copy", 1, 2, |args| {
+        let copy = table(&args[0], "hash-table-copy")?.copy();
+        Ok(Object::HashTable(Rc::new(copy)))
+    }),
+    Primitive::fixed("hash-table-clear!", 1, |args| {
+        table(&args[0], "hash-table-clear!")?.clear();
+        Ok(Object::Unspecified)
+    }),
+    Primitive::fixed("hash-table-keys", 1, |args| {
+        collect(args, "hash-table-keys", |(k, _)| k)
+    }),
+    Primitive::fixed("hash-table-values", 1, |args| {
+        collect(args, "hash-table-values", |(_, v)| v)
+    }),
+    Primitive::fixed("hash-table->alist", 1, |args| {
+        collect(args, "hash-table->alist", |(k, v)| cons(k, v))
+    }),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{check, check_errors};
+
+    #[test]
+    fn ref_set_update_and_delete() {
+        check(&[
+            // counting up from the default for a missing key, and then
+            // from the value that's there
+            (
+                "(define t (make-hash-table))
+                 (define (count! key)
+                   (hash-table-update! t key (lambda (n) (+ n 1)) (lambda () 0)))
+                 (for-each count! '(a a b a))
+                 (list (hash-table-ref t 'a) (hash-table-ref t 'b) (hash-table-size t))",
+                "(3 1 2)",
+            ),
+            (
+                "(define t (make-hash-table))
+                 (hash-table-update!/default t 'n (lambda (n) (* n 2)) 21)
+                 (hash-table-ref/default t 'n 0)",
+                "42",
+            ),
+            (
+                "(hash-table-ref/default (make-hash-table) 'missing 'default)",
+                "default",
+            ),
+            (
+                "(define t (make-hash-table))
+                 (hash-table-set! t 'k 1)
+                 (list (hash-table-ref t 'k (lambda () 'none) (lambda (v) (* v 10)))
+                       (hash-table-ref t 'j (lambda () 'none) (lambda (v) (* v 10))))",
+                "(10 none)",
+            ),
+            (
+                "(define t (make-hash-table))
+                 (hash-table-set! t 'k 1)
+                 (hash-table-set! t 'k 2)
+                 (list (hash-table-size t) (hash-table-ref t 'k))",
+                "(1 2)",
+            ),
+            // deleting says whether the key was there
+            (
+                "(define t (make-hash-table))
+                 (hash-table-set! t 'k 1)
+                 (let* ((first (hash-table-delete! t 'k)) (second (hash-table-delete! t 'k)))
+                   (list first second (hash-table-contains? t 'k) (hash-table-size t)))",
+                "(#t #f #f 0)",
+            ),
+        ]);
+        check_errors(&[
+            (
+                "(hash-table-ref (make-hash-table) 'k)",
+                "hash-table-ref: no such key k",
+            ),
+            (
+                "(hash-table-update! (make-hash-table) 'k (lambda (n) n))",
+                "hash-table-update!: no such key k",
+            ),
+            (
+                "(hash-table-ref '() 'k)",
+                "hash-table-ref: expected a hash table ()",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn walking_folding_copying_and_clearing() {
+        let table = "(define t (make-hash-table))
+                     (for-each (lambda (k v) (hash-table-set! t k v)) '(a b c d) '(1 2 3 4))";
+        check(&[
+            (&format!("{table} (hash-table-fold t (lambda (k v sum) (+ v sum)) 0)"), "10"),
+            // the SRFI 69 order of arguments too
+            (&format!("{table} (hash-table-fold (lambda (k v sum) (+ v sum)) 0 t)"), "10"),
+            // every entry exactly once
+            (
+                &format!(
+                    "{table} (define seen '())
+                     (hash-table-walk t (lambda (k v) (set! seen (cons k seen))))
+                     (list-sort (lambda (a b) (string<? (symbol->string a) (symbol->string b))) seen)"
+                ),
+                "(a b c d)",
+            ),
+            (
+                &format!(
+                    "{table} (define c (hash-table-copy t #t))
+                     (hash-table-set! c 'a 100)
+                     (hash-table-delete! c 'b)
+                     (hash-table-set! t 'e 5)
+                     (list (hash-table-ref t 'a) (hash-table-contains? t 'b) (hash-table-size t)
+                           (hash-table-ref c 'a) (hash-table-contains? c 'e) (hash-table-size c))"
+                ),
+                "(1 #t 5 100 #f 3)",
+            ),
+            (
+                &format!(
+                    "{table} (hash-table-clear! t)
+                     (list (hash-table-size t) (hash-table-keys t) (hash-table-ref/default t 'a #f))"
+                ),
+                "(0 () #f)",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn tables_keyed_by_each_equivalence() {
+        let lookup = |make: &str| {
+            format!(
+                "(define t ({make}))
+                 (define key (list 1 2))
+                 (hash-table-set! t key 'found)
+                 (list (hash-table-ref/default t (list 1 2) 'missing)
+                       (hash-table-ref/default t key 'missing))"
+            )
+        };
+        check(&[
+            (&lookup("make-equal-hash-table"), "(found found)"),
+            (&lookup("make-eq-hash-table"), "(missing found)"),
+            (&lookup("make-eqv-hash-table"), "(missing found)"),
+            (&lookup("make-hash-table equal?"), "(found found)"),
+            (&lookup("make-hash-table eq?"), "(missing found)"),
+            (
+                "(define t (make-equal-hash-table))
+                 (hash-table-set! t \"ab\" 1)
+                 (hash-table-set! t (vector 1 \"ab\" #\\c) 2)
+                 (list (hash-table-ref t (string-copy \"ab\")) (hash-table-ref t (vector 1 \"ab\" #\\c)))",
+                "(1 2)",
+            ),
+            (
+                "(define t (make-eqv-hash-table))
+                 (hash-table-set! t 100000000000000000000 'big)
+                 (hash-table-set! t 1.5 'float)
+                 (list (hash-table-ref t (* 10000000000 10000000000)) (hash-table-ref t 1.5)
+                       (hash-table-ref/default t 3/2 #f))",
+                "(big float #f)",
+            ),
+            (
+                "(define t (make-string-hash-table))
+                 (hash-table-set! t (string-copy \"key\") 1)
+                 (hash-table-ref t \"key\")",
+                "1",
+            ),
+        ]);
+        check_errors(&[
+            (
+                "(define key (list 1))
+                 (set-cdr! key key)
+                 (hash-table-set! (make-equal-hash-table) key 1)",
+                "hash-table-set!: a cyclic key can't be compared with equal?",
+            ),
+            (
+                "(hash-table-set! (make-string-hash-table) 'key 1)",
+                "hash-table-set!: expected a string key key",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn weak_tables_let_go_of_keys() {
+        check(&[
+            (
+                "(define t (make-key-weak-eqv-hash-table))
+                 (define kept (list 'kept))
+                 (let ((dropped (list 'dropped)))
+                   (hash-table-set! t dropped 1))
+                 (hash-table-set! t kept 2)
+                 (list (hash-table-size t) (hash-table-ref t kept) (hash-table-values t))",
+                "(1 2 (2))",
+            ),
+            // keys that can't be held weakly are held on to
+            (
+                "(define t (make-key-weak-eq-hash-table))
+                 (hash-table-set! t 'symbol 1)
+                 (hash-table-set! t 42 2)
+                 (hash-table-size t)",
+                "2",
+            ),
+            // a strong table keeps them
+            (
+                "(define t (make-eqv-hash-table))
+                 (let ((key (list 'key)))
+                   (hash-table-set! t key 1))
+                 (hash-table-size t)",
+                "1",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn pruned_after_the_last_reference_goes() {
+        use super::{Equivalence, HashTable};
+        use crate::{cons, Object};
+
+        let table = HashTable::new(Equivalence::Eqv, true);
+        let key = cons(Object::int(1), Object::Null);
+        table.set(key.clone(), Object::int(2));
+        assert_eq!(table.len(), 1);
+        drop(key);
+        assert_eq!(table.len(), 0);
+    }
+
+    #[test]
+    fn filling_a_weak_table_takes_linear_time() {
+        use super::{Equivalence, HashTable};
+        use crate::{cons, Object};
+        use alloc::vec::Vec;
+        use std::time::{Duration, Instant};
+
+        // how long filling a table takes, keeping every key alive
+        let fill = |weak: bool| {
+            let table = HashTable::new(Equivalence::Eqv, weak);
+            let keys: Vec<_> = (0..20_000)
+                .map(|i| cons(Object::int(i), Object::Null))
+                .collect();
+            let start = Instant::now();
+            for key in &keys {
+                table.set(key.clone(), Object::Boolean(true));
+            }
+            assert_eq!(table.len(), keys.len());
+            start.elapsed()
+        };
+        let strong = fill(false);
+        let weak = fill(true);
+        // pruning the whole table on every insert took hundreds of times
+        // as long
+        assert!(
+            weak < strong * 10 + Duration::from_millis(100),
+            "{weak:?} to fill a weak table, against {strong:?} for a strong one"
+        );
+    }
+}
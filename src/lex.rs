@@ -1,22 +1,21 @@
-use std::num::TryFromIntError;
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
 
 use nom::{
     self,
     branch::{alt, permutation},
-    bytes::complete::{is_not, tag, take_until},
-    character::complete::{anychar, char, none_of, one_of, satisfy},
-    combinator::{map, map_opt, map_res, recognize, value},
-    multi::{fold_many0, fold_many1, many0, many0_count, many1_count, separated_list0},
-    number::complete::hex_u32,
-    sequence::{delimited, pair, preceded, separated_pair, tuple},
+    bytes::complete::{is_not, tag},
+    character::complete::{anychar, char, digit1, none_of, one_of, satisfy},
+    combinator::{eof, map, map_opt, map_res, opt, peek, recognize, value},
+    multi::{fold_many0, fold_many1, many0, many0_count, many1_count},
+    sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
     AsChar, IResult,
 };
 
 use crate::number::Number;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Token {
-    Identifier,
+    Identifier(String),
     Boolean(bool),
     Number(Number),
     Character(char),
@@ -30,32 +29,112 @@ pub enum Token {
     Comma,
     CommaAt,
     Period,
+    /// `#n=`, labelling the datum after it.
+    Label(u64),
+    /// `#n#`, standing for the datum labelled `n`.
+    LabelRef(u64),
+}
+
+/// Where a token or datum is in the text it was read from, as the byte
+/// offsets of where it starts and of just past where it ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    /// The line and column that the span starts at in `text`, both
+    /// counting from 1, with columns counted in characters.
+    pub fn line_and_column(self, text: &str) -> (usize, usize) {
+        let before = &text[..self.start.min(text.len())];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        (line, before[line_start..].chars().count() + 1)
+    }
 }
+
 // TODO: handle errors
 pub fn lex(i: &str) -> IResult<&str, Vec<Token>> {
-    many0(delimited(intertoken_space, token, intertoken_space))(i)
+    let (rest, tokens) = lex_spans(i)?;
+    Ok((rest, tokens.into_iter().map(|(t, _)| t).collect()))
+}
+
+/// Like [`lex`], with where in `i` each token is.
+pub fn lex_spans(i: &str) -> IResult<&str, Vec<(Token, Span)>> {
+    let mut tokens = Tokens::new(i);
+    let lexed = tokens.by_ref().collect();
+    Ok((tokens.rest(), lexed))
+}
+
+/// The tokens of some text, each with where it is, lexed one at a time as
+/// they're asked for. They end where the text does or where it stops
+/// making sense as tokens, and then [`Tokens::rest`] says which.
+pub struct Tokens<'a> {
+    input: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Tokens<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Tokens { input, rest: input }
+    }
+
+    /// What of the text hasn't been lexed yet, skipping any whitespace
+    /// and comments at the start once the tokens have run out.
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+}
+
+impl Iterator for Tokens<'_> {
+    type Item = (Token, Span);
+
+    fn next(&mut self) -> Option<(Token, Span)> {
+        (self.rest, _) = intertoken_space(self.rest).ok()?;
+        let (after, token) = token(self.rest).ok()?;
+        let offset = |rest: &str| self.input.len() - rest.len();
+        let span = Span { start: offset(self.rest), end: offset(after) };
+        self.rest = after;
+        Some((token, span))
+    }
 }
 
 pub fn token(i: &str) -> IResult<&str, Token> {
     use Token::*;
     alt((
-        map(boolean, Boolean),
-        map(number, Number),
-        value(Identifier, identifier),
+        map(delimited_by(boolean), Boolean),
+        map(delimited_by(number), Number),
+        map(identifier, Identifier),
         map(character, Character),
         map(string, String),
         value(OpenParen, tag("(")),
         value(CloseParen, tag(")")),
         value(OpenVec, tag("#(")),
         value(OpenByteVec, tag("#u8(")),
+        label,
         value(Quote, tag("'")),
         value(BackQuote, tag("`")),
         value(CommaAt, tag(",@")),
         value(Comma, tag(",")),
-        value(Period, tag(",")),
+        map(delimited_by(tag(".")), |_| Period),
     ))(i)
 }
 
+fn label(i: &str) -> IResult<&str, Token> {
+    let (rest, n) = preceded(char('#'), map_res(digit1, str::parse))(i)?;
+    alt((value(Token::Label(n), char('=')), value(Token::LabelRef(n), char('#'))))(rest)
+}
+
+/// `f`, as long as a delimiter or the end of the input comes after it, so
+/// that `1+2i` doesn't lex as `1` followed by something else, or `abc#t`
+/// as `abc` followed by `#t`.
+fn delimited_by<'a, T>(
+    f: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, T> {
+    terminated(f, peek(alt((delimiter, eof))))
+}
+
 fn delimiter(i: &str) -> IResult<&str, &str> {
     alt((
         whitespace,
@@ -80,28 +159,72 @@ fn line_ending(i: &str) -> IResult<&str, &str> {
 }
 
 fn comment(i: &str) -> IResult<&str, &str> {
-    alt((
-        preceded(char(';'), is_not("\n\r")),
-        nested_comment,
-        preceded(pair(tag("#;"), intertoken_space), datum),
-    ))(i)
+    alt((line_comment, nested_comment, datum_comment))(i)
 }
 
-fn nested_comment(i: &str) -> IResult<&str, &str> {
-    delimited(
-        tag("#|"),
-        recognize(pair(comment_text, many0_count(comment_cont))),
-        tag("|#"),
-    )(i)
+fn line_comment(i: &str) -> IResult<&str, &str> {
+    recognize(pair(char(';'), opt(is_not("\n\r"))))(i)
 }
 
-fn comment_text(i: &str) -> IResult<&str, &str> {
-    // TODO: support nesting
-    take_until("|#")(i)
+/// A `#| ... |#` comment, which can have others inside it. This counts
+/// how deeply they go rather than recursing, so no input is too deep.
+fn nested_comment(i: &str) -> IResult<&str, &str> {
+    let (mut rest, _) = tag("#|")(i)?;
+    let mut depth = 1;
+    while depth > 0 {
+        if let Some(after) = rest.strip_prefix("|#") {
+            depth -= 1;
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("#|") {
+            depth += 1;
+            rest = after;
+        } else {
+            let mut chars = rest.chars();
+            if chars.next().is_none() {
+                return nom::combinator::fail(i);
+            }
+            rest = chars.as_str();
+        }
+    }
+    Ok((rest, &i[..i.len() - rest.len()]))
 }
 
-fn comment_cont(i: &str) -> IResult<&str, &str> {
-    recognize(pair(nested_comment, comment_text))(i)
+/// A `#;` comment and the datum it comments out. The datum is skipped a
+/// token at a time, counting the parentheses, so however deeply it nests
+/// this doesn't recurse. More `#;` before it each want a datum of their
+/// own, so `#;#;a b` skips both `a` and `b`.
+fn datum_comment(i: &str) -> IResult<&str, &str> {
+    use Token::*;
+    let (mut rest, _) = tag("#;")(i)?;
+    let mut wanted = 1;
+    let mut depth = 0;
+    while wanted > 0 {
+        (rest, _) = many0_count(alt((whitespace, line_comment, nested_comment, directive)))(rest)?;
+        if let Some(after) = rest.strip_prefix("#;") {
+            // inside a datum being skipped, what it comments out is
+            // skipped anyway
+            if depth == 0 {
+                wanted += 1;
+            }
+            rest = after;
+            continue;
+        }
+        let token;
+        (rest, token) = self::token(rest)?;
+        match token {
+            OpenParen | OpenVec | OpenByteVec => depth += 1,
+            CloseParen if depth > 0 => depth -= 1,
+            CloseParen => return nom::combinator::fail(i),
+            Period if depth == 0 => return nom::combinator::fail(i),
+            // the datum these abbreviate or label is still to come
+            Quote | BackQuote | Comma | CommaAt | Label(_) => continue,
+            _ => {}
+        }
+        if depth == 0 {
+            wanted -= 1;
+        }
+    }
+    Ok((rest, &i[..i.len() - rest.len()]))
 }
 
 fn directive(i: &str) -> IResult<&str, &str> {
@@ -116,11 +239,18 @@ fn intertoken_space(i: &str) -> IResult<&str, &str> {
     recognize(many0_count(atmosphere))(i)
 }
 
-fn identifier(i: &str) -> IResult<&str, &str> {
+fn identifier(i: &str) -> IResult<&str, String> {
     alt((
-        recognize(pair(initial, many0_count(subsequent))),
-        delimited(tag("|"), recognize(many0_count(symbol_element)), tag("|")),
-        peculiar_identifier,
+        map(delimited_by(recognize(pair(initial, many0_count(subsequent)))), str::to_owned),
+        delimited(
+            tag("|"),
+            fold_many0(symbol_element, String::new, |mut acc, c| {
+                acc.push(c);
+                acc
+            }),
+            tag("|"),
+        ),
+        map(delimited_by(peculiar_identifier), str::to_owned),
     ))(i)
 }
 
@@ -155,13 +285,17 @@ fn special_subsequent(i: &str) -> IResult<&str, char> {
 }
 
 fn inline_hex_escape(i: &str) -> IResult<&str, char> {
-    preceded(tag(r"\x"), hex_scalar_value)(i)
+    delimited(tag(r"\x"), hex_scalar_value, char(';'))(i)
 }
 
 fn hex_scalar_value(i: &str) -> IResult<&str, char> {
     map_opt(
-        fold_many1(digit::<16>, || 0u32, |acc, dig| acc * 16 + dig as u32),
-        char::from_u32,
+        fold_many1(
+            digit::<16>,
+            || Some(0u32),
+            |acc, dig| acc?.checked_mul(16)?.checked_add(dig as u32),
+        ),
+        |x| char::from_u32(x?),
     )(i)
 }
 
@@ -177,13 +311,14 @@ fn mnemonic_escape(i: &str) -> IResult<&str, char> {
 
 fn peculiar_identifier(i: &str) -> IResult<&str, &str> {
     alt((
-        recognize(explicit_sign),
+        recognize(tuple((explicit_sign, sign_subsequent, many0(subsequent)))),
         recognize(tuple((
             explicit_sign,
             tag("."),
             dot_subsequent,
             many0(subsequent),
         ))),
+        recognize(explicit_sign),
         recognize(tuple((tag("."), dot_subsequent, many0(subsequent)))),
     ))(i)
 }
@@ -216,9 +351,9 @@ fn boolean(i: &str) -> IResult<&str, bool> {
 
 fn character(i: &str) -> IResult<&str, char> {
     alt((
-        preceded(tag(r"#\"), anychar),
-        preceded(tag(r"#\"), character_name),
-        preceded(tag(r"#\x"), hex_scalar_value),
+        delimited_by(preceded(tag(r"#\"), character_name)),
+        delimited_by(preceded(tag(r"#\x"), hex_scalar_value)),
+        delimited_by(preceded(tag(r"#\"), anychar)),
     ))(i)
 }
 
@@ -226,7 +361,8 @@ fn character_name(i: &str) -> IResult<&str, char> {
     alt((
         value('\x07', tag("alarm")),
         value('\x08', tag("backspace")),
-        value('\x1B', tag("delete")),
+        value('\x7F', tag("delete")),
+        value('\x1B', tag("escape")),
         value('\n', tag("newline")),
         value('\0', tag("null")),
         value('\r', tag("return")),
@@ -269,19 +405,6 @@ fn string_element(i: &str) -> IResult<&str, Option<char>> {
     ))(i)
 }
 
-fn bytevector(i: &str) -> IResult<&str, &str> {
-    delimited(tag("#u8("), recognize(many0_count(byte)), tag(")"))(i)
-}
-
-fn byte(i: &str) -> IResult<&str, u8> {
-    map_res(number, |x| match x {
-        Number::Integer(i) => u8::try_from(i).map_err(|e| format!("can't cast to u8: {e}")),
-        _ => Err(format!(
-            "{x:?} is not an int and can't go in a byte vector!"
-        )),
-    })(i)
-}
-
 fn number(i: &str) -> IResult<&str, Number> {
     // this is quite a silly way to parse it,
     // because we end up recognizing the radix only to discard it later,
@@ -290,22 +413,11 @@ fn number(i: &str) -> IResult<&str, Number> {
 }
 
 fn num<const R: u8>(i: &str) -> IResult<&str, Number> {
-    map(pair(prefix::<R>, complex::<R>), |(exactness, num)| {
-        use Number::*;
-        match (exactness, num) {
-            (Inexact, Integer(i)) => Real(i as f64),
-            (Inexact, Real(x)) => Real(x),
-            (Inexact, Rational{num, den}) => Real(num as f64 / den as f64),
-            (Exact, Integer(x)) => Integer(x),
-            (Exact, Real(x)) => {
-                if x as i64 as f64 == x {
-                    Integer(x as i64)
-                } else {
-                    todo!("idk")
-                }
-            }
-            (Exact, Rational{num, den}) => Rational{num, den},
-            (Unspecified, x) => x,
+    map_opt(pair(prefix::<R>, complex::<R>), |(exactness, num)| {
+        match exactness {
+            Inexact => Some(num.to_inexact()),
+            Exact => num.to_exact(),
+            Unspecified => Some(num),
         }
     })(i)
 }
@@ -327,58 +439,44 @@ fn real<const R: u8>(i: &str) -> IResult<&str, Number> {
 
 fn ureal<const R: u8>(i: &str) -> IResult<&str, Number> {
     alt((
-        map_res(
+        map_opt(
             separated_pair(uinteger::<R>, tag("/"), uinteger::<R>),
-            |(num, den)| u32::try_from(den).map(|den| Number::Rational { num, den })
+            |(num, den)| (!den.is_zero()).then(|| num / den)
         ),
         decimal::<R>,
-        map(uinteger::<R>, Number::Integer),
+        uinteger::<R>,
     ))(i)
 }
 
 fn decimal<const R: u8>(i: &str) -> IResult<&str, Number> {
     match R {
-        10 => alt((
-            map_res (
-                pair(
-                    recognize(delimited(
-                        many1_count(digit::<10>),
-                        tag("."),
-                        many0_count(digit::<10>),
+        // a decimal point or an exponent makes it inexact, so `1e3` is
+        // `1000.` and it's only without either that it's an integer
+        10 => map_res(
+            alt((
+                recognize(pair(
+                    alt((
+                        recognize(tuple((
+                            many1_count(digit::<10>),
+                            tag("."),
+                            many0_count(digit::<10>),
+                        ))),
+                        recognize(pair(tag("."), many1_count(digit::<10>))),
                     )),
-                    suffix,
-                ),
-                |(d,s)| {
-                    d.parse::<f64>().map(|f| Number::Real(f * s as f64))
-                }
-            ),
-            map_res(
-                recognize(preceded(tag("."), pair(many1_count(digit::<10>), suffix))),
-                |d| d.parse::<f64>().map(Number::Real)
-            ),
-            map_opt (
-                pair(uinteger::<10>, suffix),
-                |(i, s)| {
-                    Some(match i.checked_mul(s) {
-                        Some(n) => Number::Integer(n),
-                        None => Number::Real(i as f64 * s as f64),
-                    })
-                }
-            ),
-        ))(i),
+                    opt(suffix),
+                )),
+                recognize(pair(many1_count(digit::<10>), suffix)),
+            )),
+            |d: &str| d.parse::<f64>().map(Number::Real)
+        )(i),
         _ => nom::combinator::fail(i),
     }
 }
 
-fn uinteger<const R: u8>(i: &str) -> IResult<&str, i64> {
-    map_res (
-        fold_many1(
-            digit::<R>,
-            || Some(0i64),
-            |acc, dig| acc?.checked_mul(R as i64)?.checked_add(dig as i64)
-        ),
-        |o| o.ok_or_else(|| format!("integer literal {i} too large for i64!"))
-    )(i)
+fn uinteger<const R: u8>(i: &str) -> IResult<&str, Number> {
+    map_opt(recognize(many1_count(digit::<R>)), |digits| {
+        Number::from_digits(digits, R.into())
+    })(i)
 }
 
 fn prefix<const R: u8>(i: &str) -> IResult<&str, Exactness> {
@@ -399,17 +497,8 @@ fn infnan(i: &str) -> IResult<&str, Number> {
     )(i)
 }
 
-fn suffix(i: &str) -> IResult<&str, i64> {
-    alt((
-        preceded(
-            tag("e"),
-            map_opt (
-                recognize(pair(sign, many1_count(digit::<10>))),
-                |s| 10i64.checked_pow(s.parse::<u32>().ok()?)
-            )
-        ),
-        value(1, tag("")),
-    ))(i)
+fn suffix(i: &str) -> IResult<&str, &str> {
+    recognize(tuple((tag("e"), sign, many1_count(digit::<10>))))(i)
 }
 
 fn sign(i: &str) -> IResult<&str, &str> {
@@ -426,9 +515,9 @@ use Exactness::*;
 
 fn exactness(i: &str) -> IResult<&str, Exactness> {
     alt((
-        value(Unspecified, tag("")),
         value(Inexact, tag("#i")),
         value(Exact, tag("#e")),
+        value(Unspecified, tag("")),
     ))(i)
 }
 
@@ -443,19 +532,251 @@ fn radix<const R: u8>(i: &str) -> IResult<&str, ()> {
 }
 
 fn digit<const R: u8>(i: &str) -> IResult<&str, u8> {
-    for (x, &c) in [
-        '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f',
-    ][0..(R as usize)]
-        .into_iter()
-        .enumerate()
-    {
-        if i.chars().next() == Some(c) {
-            return Ok((i.split_at(1).1, x as u8));
-        }
+    match i.chars().next().and_then(|c| c.to_digit(R as u32)) {
+        Some(x) => Ok((&i[1..], x as u8)),
+        None => nom::combinator::fail(i),
     }
-    nom::combinator::fail(i)
 }
 
-fn datum(i: &str) -> IResult<&str, &str> {
-    nom::combinator::fail(i)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Token::*;
+
+    /// The tokens of all of `input`, which has to lex in full.
+    fn tokens(input: &str) -> Vec<Token> {
+        let (rest, tokens) = lex(input).unwrap();
+        assert_eq!(rest, "", "{input:?} didn't lex in full");
+        tokens
+    }
+
+    fn int(n: i64) -> Token {
+        Number(crate::Number::Integer(n))
+    }
+
+    fn int_ratio(num: i64, den: i64) -> crate::Number {
+        crate::Number::Integer(num) / crate::Number::Integer(den)
+    }
+
+    fn ident(name: &str) -> Token {
+        Identifier(name.to_owned())
+    }
+
+    #[test]
+    fn corpus() {
+        let corpus: &[(&str, Vec<Token>)] = &[
+            (
+                "(define (f x) (* x 2))",
+                vec![
+                    OpenParen,
+                    ident("define"),
+                    OpenParen,
+                    ident("f"),
+                    ident("x"),
+                    CloseParen,
+                    OpenParen,
+                    ident("*"),
+                    ident("x"),
+                    int(2),
+                    CloseParen,
+                    CloseParen,
+                ],
+            ),
+            ("#;#;1 2 3", vec![int(3)]),
+            ("#;(a b) c", vec![ident("c")]),
+            ("1e3", vec![Number(crate::Number::Real(1000.0))]),
+            ("-2.5e-1", vec![Number(crate::Number::Real(-0.25))]),
+            (".5", vec![Number(crate::Number::Real(0.5))]),
+            ("#e1.5", vec![Number(int_ratio(3, 2))]),
+            ("-1/2", vec![Number(int_ratio(-1, 2))]),
+            (
+                "#b101 #o17 #xff #d10",
+                vec![int(5), int(15), int(255), int(10)],
+            ),
+            ("+inf.0", vec![Number(crate::Number::Real(f64::INFINITY))]),
+            ("#\\newline", vec![Character('\n')]),
+            (
+                "#\\space #\\a #\\x41 #\\(",
+                vec![
+                    Character(' '),
+                    Character('a'),
+                    Character('A'),
+                    Character('('),
+                ],
+            ),
+            ("#\\x", vec![Character('x')]),
+            ("|a b|", vec![ident("a b")]),
+            (
+                "+ - ... ->x",
+                vec![ident("+"), ident("-"), ident("..."), ident("->x")],
+            ),
+            ("#t #false", vec![Boolean(true), Boolean(false)]),
+            (r#""a\nb\"c\\""#, vec![String("a\nb\"c\\".to_owned())]),
+            ("\"a\\   \n  b\"", vec![String("ab".to_owned())]),
+            (
+                "'a `(b ,c ,@d)",
+                vec![
+                    Quote,
+                    ident("a"),
+                    BackQuote,
+                    OpenParen,
+                    ident("b"),
+                    Comma,
+                    ident("c"),
+                    CommaAt,
+                    ident("d"),
+                    CloseParen,
+                ],
+            ),
+            (
+                "#(1) #u8(2)",
+                vec![OpenVec, int(1), CloseParen, OpenByteVec, int(2), CloseParen],
+            ),
+            (
+                "(a . b)",
+                vec![OpenParen, ident("a"), Period, ident("b"), CloseParen],
+            ),
+            (
+                "#0=(#0#)",
+                vec![Label(0), OpenParen, LabelRef(0), CloseParen],
+            ),
+            ("; a comment\n1 #!fold-case 2", vec![int(1), int(2)]),
+        ];
+        for (input, expected) in corpus {
+            assert_eq!(&tokens(input), expected, "lexing {input:?}");
+        }
+    }
+
+    #[test]
+    fn exponents_make_numbers_inexact() {
+        // numbers compare by value, so it's the exactness that's checked
+        for input in ["1e3", "1.", "1.5e+2"] {
+            let tokens = tokens(input);
+            assert!(
+                matches!(tokens[..], [Number(crate::Number::Real(_))]),
+                "lexing {input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn hex_digits_can_be_uppercase() {
+        assert_eq!(tokens("#xFF #xfF"), [int(255), int(255)]);
+        assert_eq!(tokens("#\\x4A"), [Character('J')]);
+    }
+
+    #[test]
+    fn inline_hex_escapes_consume_their_semicolon() {
+        assert_eq!(tokens(r#""\x41;b""#), [String("Ab".to_owned())]);
+        assert_eq!(tokens(r"|\x41;b|"), [ident("Ab")]);
+    }
+
+    #[test]
+    fn block_comments_nest() {
+        assert_eq!(tokens("#| a #| b |# c |# 1"), [int(1)]);
+    }
+
+    #[test]
+    fn tokens_need_a_delimiter() {
+        let inputs = [
+            "1+2i", "#\\xD800", "#\\ab", "#tr", "1e", "1/", "12abc", "abc#t", "+a#f", "...#\\a", ".#t",
+        ];
+        for input in inputs {
+            let (rest, _) = lex(input).unwrap();
+            assert_ne!(rest, "", "{input:?} shouldn't lex in full");
+        }
+        assert_eq!(
+            tokens("(1)\"a\"#t;"),
+            [
+                OpenParen,
+                int(1),
+                CloseParen,
+                String("a".to_owned()),
+                Boolean(true)
+            ]
+        );
+    }
+
+    /// A smoke test standing in for a fuzzer: lexing and reading inputs
+    /// made by cutting up and splicing a small corpus, and by putting
+    /// random bytes together, must neither panic nor take long. Set
+    /// `MIBPH_FUZZ_RUNS` to try more of them than the 1000 it tries by
+    /// default, e.g. `MIBPH_FUZZ_RUNS=1000000 cargo test --release fuzz`.
+    #[test]
+    fn fuzz_lex_and_read() {
+        use crate::testing::Rng;
+        use std::time::{Duration, Instant};
+
+        let corpus = [
+            "(define (f x) (* x 2))",
+            "#;#;1 2 3",
+            "#| a #| nested |# comment |#",
+            "\"a\\nb\\x41;\\\"\"",
+            "|sym\\x3bbol|",
+            "#\\x41 #\\space #\\(",
+            "#e1.5 -1/2 +inf.0 #x-ff 1e-3",
+            "'a `(b ,c ,@d)",
+            "#(1 #u8(2 3) . x)",
+            "#0=(a . #0#)",
+            "#!fold-case ABC",
+            "; comment\n",
+        ];
+        let runs = std::env::var("MIBPH_FUZZ_RUNS")
+            .ok()
+            .and_then(|runs| runs.parse().ok())
+            .unwrap_or(1000);
+        // on a stack like the REPL's, since the reader recurses on nested data
+        crate::with_stack(move || {
+            let mut rng = Rng::new(0xf022);
+            let mut bytes = Vec::new();
+            for _ in 0..runs {
+                bytes.clear();
+                for _ in 0..rng.below(8) {
+                    match rng.below(3) {
+                        0 => {
+                            let piece = rng.pick(&corpus).as_bytes();
+                            let from = rng.below(piece.len());
+                            let to = from + rng.below(piece.len() - from + 1);
+                            bytes.extend_from_slice(&piece[from..to]);
+                        }
+                        1 => bytes.extend((0..rng.below(8)).map(|_| rng.next() as u8)),
+                        _ => {
+                            let byte = *rng.pick(b"()#|;\"\\'`,@. \nx1");
+                            bytes.extend(std::iter::repeat_n(byte, rng.below(1200)));
+                        }
+                    }
+                }
+                let input = std::string::String::from_utf8_lossy(&bytes);
+                let start = Instant::now();
+                let _ = lex(&input);
+                let _ = crate::read_str(&input);
+                assert!(start.elapsed() < Duration::from_secs(1), "{input:?} took too long");
+            }
+        });
+    }
+
+    #[test]
+    fn tokens_one_at_a_time() {
+        for input in [
+            "(define (f x) (* x 2))",
+            " #;(a b) 'c `(,d ,@e) ; the end\n",
+            "#(1 #u8(2) \"three\" #\\4 5.0 #t . #0=x #0#) #| x |#",
+            "a b ]c",
+        ] {
+            let mut tokens = Tokens::new(input);
+            let mut lexed = Vec::new();
+            for (token, span) in tokens.by_ref() {
+                // each token spans the text it was lexed from
+                assert_eq!(super::token(&input[span.start..span.end]).unwrap().1, token);
+                lexed.push(token);
+            }
+            let (rest, expected) = lex(input).unwrap();
+            assert_eq!(lexed, expected, "lexing {input:?}");
+            assert_eq!(tokens.rest(), rest);
+            // and once they've run out they stay run out
+            assert_eq!(tokens.next(), None);
+        }
+        assert_eq!(Tokens::new("a b ]c").rest(), "a b ]c");
+        assert_eq!(Tokens::new("a b ]c").nth(2), None);
+    }
 }
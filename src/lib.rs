@@ -0,0 +1,1178 @@
+//! A Scheme interpreter, aiming at R7RS-small.
+//!
+//! Source text is [`lex`](lex())ed into [`Token`]s, which [`read_str`] turns into
+//! data, which [`eval`] evaluates in an [`Env`]:
+//!
+//! ```
+//! use mibph::{eval, interaction_environment, read_str, Object, Token};
+//!
+//! let (rest, tokens) = mibph::lex("(+ 1 2)").unwrap();
+//! assert_eq!(rest, "");
+//! assert_eq!(tokens.first(), Some(&Token::OpenParen));
+//!
+//! let env = interaction_environment();
+//! let mut values = Vec::new();
+//! for datum in read_str("(define x 20) (+ x 1 2)").unwrap() {
+//!     values.push(eval(datum, &env).unwrap());
+//! }
+//! assert_eq!(format!("{:?}", values[1]), format!("{:?}", Object::int(23)));
+//! ```
+//!
+//! Without the default `std` feature the crate is `no_std` and only needs
+//! `alloc`. There are no file or standard stream ports then, nor `load` or
+//! the REPL, and the state otherwise kept per thread is kept once, so such
+//! a build must only be used from one thread.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// the tables are `BTreeMap`s there, which could be made in a `const`,
+// unlike the `HashMap`s they are otherwise
+#![cfg_attr(
+    all(test, not(feature = "std")),
+    allow(clippy::missing_const_for_thread_local)
+)]
+
+#[macro_use]
+extern crate alloc;
+#[cfg(all(test, not(feature = "std")))]
+#[macro_use(thread_local)]
+extern crate std;
+
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use core::fmt;
+
+use collections::{HashMap, HashSet};
+use io::Write;
+
+/// A proper list of the given objects, like `(list ...)`.
+macro_rules! list {
+    ($($item:expr),* $(,)?) => {
+        $crate::Object::list([$($item),*])
+    };
+}
+
+/// Without std, a [`sync::Local`] for each of the statics, in place of
+/// std's macro of the same name.
+#[cfg(not(any(feature = "std", test)))]
+macro_rules! thread_local {
+    () => {};
+    ($(#[$attr:meta])* static $name:ident: $t:ty = const { $init:expr }; $($rest:tt)*) => {
+        thread_local!($(#[$attr])* static $name: $t = $init; $($rest)*);
+    };
+    ($(#[$attr:meta])* static $name:ident: $t:ty = $init:expr; $($rest:tt)*) => {
+        $(#[$attr])*
+        static $name: $crate::sync::Local<$t> = $crate::sync::Local::new(|| $init);
+        thread_local!($($rest)*);
+    };
+}
+
+mod bigint;
+
+mod bytevector;
+
+mod character;
+
+mod collections;
+
+mod constant;
+
+#[cfg(feature = "std")]
+mod editor;
+
+mod env;
+pub use env::{interaction_environment, Env};
+
+mod error;
+use error::Condition;
+pub use error::Error;
+
+mod eval;
+#[cfg(any(feature = "std", test))]
+pub use eval::with_stack;
+pub use eval::{eval, max_depth, set_max_depth, DEFAULT_MAX_DEPTH, STACK_SIZE};
+#[cfg(feature = "metrics")]
+pub use eval::{current_depth, peak_depth, reset_peak_depth};
+use eval::{Continuation, Primitive, Procedure};
+
+mod features;
+
+#[cfg(not(feature = "std"))]
+mod float;
+
+mod hashtable;
+
+pub mod io;
+
+pub mod lex;
+pub use lex::{lex, Token};
+
+mod library;
+
+mod list;
+
+mod load;
+
+mod normalize;
+
+mod number;
+pub use number::Number;
+
+mod parameter;
+use parameter::Parameter;
+
+mod port;
+use port::current_output_port;
+pub use port::{CallbackPort, Port, PortBackend};
+
+pub mod read;
+pub use read::read_str;
+
+#[cfg(feature = "std")]
+pub mod repl;
+
+mod string;
+
+mod syntax;
+
+mod sync;
+use sync::{Rc, RefCell};
+
+#[cfg(test)]
+mod testing;
+
+mod vector;
+
+#[derive(Clone)]
+pub enum Object {
+    Boolean(bool),
+    Char(char),
+    Null,
+    Pair(Rc<list::Pair>),
+    Procedure(Rc<Procedure>),
+    Symbol(Rc<str>),
+    Bytevector(Rc<RefCell<Vec<u8>>>),
+    Condition(Rc<Condition>),
+    Continuation(Rc<Continuation>),
+    Environment(Rc<Env>),
+    EofObject,
+    HashTable(Rc<hashtable::HashTable>),
+    Macro(Rc<syntax::Macro>),
+    Number(Number),
+    Parameter(Rc<Parameter>),
+    Port(Rc<RefCell<Port>>),
+    Primitive(&'static Primitive),
+    /// A string as its characters rather than as UTF-8, at four bytes a
+    /// character. That keeps `string-ref` and `string-set!` constant-time,
+    /// and lets `string-set!` swap in a character of any width where UTF-8
+    /// would have to shift the rest of the string; the cost is encoding
+    /// whenever a string is written out or turned into a Rust `String`.
+    String(Rc<RefCell<Vec<char>>>),
+    Vector(Rc<RefCell<Vec<Object>>>),
+    Record(/* TODO */),
+    /// Zero or several values at once, as returned by `values`.
+    Values(Rc<Vec<Object>>),
+    /// What forms like `define` evaluate to, having no useful value.
+    Unspecified,
+    /// What `letrec` binds its variables to until their inits have run.
+    /// Looking up a variable bound to this is an error, so Scheme code
+    /// never gets hold of it.
+    Uninitialized,
+}
+use Object::*;
+
+// what the `sync` feature is for
+#[cfg(feature = "sync")]
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Object>();
+};
+
+impl Object {
+    /// Whether this counts as true in a conditional, which everything but
+    /// `#f` does, even `()` and `0`.
+    pub fn is_truthy(&self) -> bool {
+        !matches!(self, Boolean(false))
+    }
+
+    // constructors for building data on the Rust side, as hosts and tests
+    // need to
+
+    pub fn int(i: i64) -> Object {
+        Number(number::Number::Integer(i))
+    }
+
+    pub fn sym(name: &str) -> Object {
+        symbol(name)
+    }
+
+    /// A new, mutable string.
+    pub fn str(s: &str) -> Object {
+        string::make(s.chars())
+    }
+
+    /// A proper list of `items`.
+    pub fn list(items: impl IntoIterator<Item = Object>) -> Object {
+        vec_to_list(items.into_iter().collect(), Null)
+    }
+}
+
+impl From<Vec<Object>> for Object {
+    /// The proper list of `items`.
+    fn from(items: Vec<Object>) -> Object {
+        vec_to_list(items, Null)
+    }
+}
+
+fn cons(car: Object, cdr: Object) -> Object {
+    Pair(list::alloc(car, cdr))
+}
+
+fn symbol(name: &str) -> Object {
+    Symbol(Rc::from(name))
+}
+
+/// A list of `items` ending in `tail`, which is `Null` for a proper list.
+fn vec_to_list(items: Vec<Object>, tail: Object) -> Object {
+    items.into_iter().rev().fold(tail, |acc, x| cons(x, acc))
+}
+
+fn is_procedure(obj: &Object) -> bool {
+    matches!(obj, Primitive(_) | Procedure(_) | Continuation(_) | Parameter(_))
+}
+
+/// Whether `a` and `b` are the same object, in the sense of `eqv?`.
+/// Inexact numbers are only the same if their bits are, so `0.0` and
+/// `-0.0` aren't, though they're `=`.
+fn eqv(a: &Object, b: &Object) -> bool {
+    match (a, b) {
+        (Boolean(a), Boolean(b)) => a == b,
+        (Char(a), Char(b)) => a == b,
+        (Null, Null) | (EofObject, EofObject) | (Unspecified, Unspecified) => true,
+        (Number(number::Number::Real(a)), Number(number::Number::Real(b))) => {
+            a.to_bits() == b.to_bits()
+        }
+        (Number(a), Number(b)) => a.is_exact() && b.is_exact() && a == b,
+        // fresh symbols aren't interned, so they're only the same as themselves
+        (Symbol(a), Symbol(b)) => {
+            Rc::ptr_eq(a, b) || (a == b && !syntax::is_fresh(a) && !syntax::is_fresh(b))
+        }
+        (Pair(a), Pair(b)) => Rc::ptr_eq(a, b),
+        (Procedure(a), Procedure(b)) => Rc::ptr_eq(a, b),
+        (Primitive(a), Primitive(b)) => core::ptr::eq(*a, *b),
+        (Bytevector(a), Bytevector(b)) => Rc::ptr_eq(a, b),
+        (Condition(a), Condition(b)) => Rc::ptr_eq(a, b),
+        (Continuation(a), Continuation(b)) => Rc::ptr_eq(a, b),
+        (Environment(a), Environment(b)) => Rc::ptr_eq(a, b),
+        (Macro(a), Macro(b)) => Rc::ptr_eq(a, b),
+        (Parameter(a), Parameter(b)) => Rc::ptr_eq(a, b),
+        (HashTable(a), HashTable(b)) => Rc::ptr_eq(a, b),
+        (Port(a), Port(b)) => Rc::ptr_eq(a, b),
+        (String(a), String(b)) => Rc::ptr_eq(a, b),
+        (Vector(a), Vector(b)) => Rc::ptr_eq(a, b),
+        _ => false,
+    }
+}
+
+/// How many pairs and vectors `equal?` compares before it starts keeping
+/// track of which it has compared, in case they're cyclic.
+const UNTRACKED_COMPARISONS: usize = 1000;
+
+/// Whether `a` and `b` have the same structure and contents, in the sense
+/// of `equal?`.
+///
+/// The parts still to compare are kept on a stack of our own, so deeply
+/// nested structure doesn't overflow Rust's. In case the structure is
+/// cyclic, which would have this going round forever, it remembers which
+/// pairs and vectors it has compared once it has compared a lot of them,
+/// and takes any it comes back to as equal: whatever could make them
+/// differ is already being compared.
+fn equal(a: &Object, b: &Object) -> bool {
+    let mut todo = vec![(a.clone(), b.clone())];
+    let mut untracked = UNTRACKED_COMPARISONS;
+    let mut compared = HashSet::new();
+    let mut first_time = |x: *const (), y: *const ()| match untracked {
+        0 => compared.insert((x, y)),
+        _ => {
+            untracked -= 1;
+            true
+        }
+    };
+    while let Some((a, b)) = todo.pop() {
+        match (&a, &b) {
+            (Pair(x), Pair(y))
+                if !Rc::ptr_eq(x, y) && first_time(Rc::as_ptr(x).cast(), Rc::as_ptr(y).cast()) =>
+            {
+                todo.push((x.cdr(), y.cdr()));
+                todo.push((x.car(), y.car()));
+            }
+            (Vector(x), Vector(y))
+                if !Rc::ptr_eq(x, y) && first_time(Rc::as_ptr(x).cast(), Rc::as_ptr(y).cast()) =>
+            {
+                let (x, y) = (x.borrow(), y.borrow());
+                if x.len() != y.len() {
+                    return false;
+                }
+                todo.extend(x.iter().cloned().zip(y.iter().cloned()).rev());
+            }
+            (String(x), String(y)) if *x.borrow() != *y.borrow() => return false,
+            (Bytevector(x), Bytevector(y)) if *x.borrow() != *y.borrow() => return false,
+            (Pair(_), Pair(_))
+            | (Vector(_), Vector(_))
+            | (String(_), String(_))
+            | (Bytevector(_), Bytevector(_)) => {}
+            _ if !eqv(&a, &b) => return false,
+            _ => {}
+        }
+    }
+    true
+}
+
+/// The elements of `list`, or `None` if it isn't a proper list.
+fn list_to_vec(list: &Object) -> Option<Vec<Object>> {
+    let mut items = Vec::new();
+    let mut list = list.clone();
+    loop {
+        list = match &list {
+            Null => return Some(items),
+            Pair(rc) => {
+                items.push(rc.car());
+                rc.cdr()
+            }
+            _ => return None,
+        }
+    }
+}
+
+fn write1(obj: Object) -> Result<Object, Error> {
+    write2(obj, current_output_port())
+}
+
+fn write2(obj: Object, port: Object) -> Result<Object, Error> {
+    write_to(&obj, &port, Style::Write, Sharing::Cycles, "write")
+}
+
+fn write_shared1(obj: Object) -> Result<Object, Error> {
+    write_shared2(obj, current_output_port())
+}
+
+fn write_shared2(obj: Object, port: Object) -> Result<Object, Error> {
+    write_to(&obj, &port, Style::Write, Sharing::All, "write-shared")
+}
+
+fn write_simple1(obj: Object) -> Result<Object, Error> {
+    write_simple2(obj, current_output_port())
+}
+
+fn write_simple2(obj: Object, port: Object) -> Result<Object, Error> {
+    write_to(&obj, &port, Style::Write, Sharing::None, "write-simple")
+}
+
+fn display1(obj: Object) -> Result<Object, Error> {
+    display2(obj, current_output_port())
+}
+
+fn display2(obj: Object, port: Object) -> Result<Object, Error> {
+    write_to(&obj, &port, Style::Display, Sharing::Cycles, "display")
+}
+
+fn display_shared1(obj: Object) -> Result<Object, Error> {
+    display_shared2(obj, current_output_port())
+}
+
+fn display_shared2(obj: Object, port: Object) -> Result<Object, Error> {
+    write_to(&obj, &port, Style::Display, Sharing::All, "display-shared")
+}
+
+fn write_to(
+    obj: &Object,
+    port: &Object,
+    style: Style,
+    sharing: Sharing,
+    who: &str,
+) -> Result<Object, Error> {
+    port::port_arg(port, who)?;
+    // rendering it all first means the port sees a single write, which
+    // for the standard streams is a single lock of the stream
+    let mut text = Vec::new();
+    let mut labels = Labels::new(obj, sharing);
+    write_impl(obj, &mut text, style, &mut labels).expect("writing to a Vec can't fail");
+    port::write_text(port, &text, who)?;
+    Ok(Unspecified)
+}
+
+/// `write` renders strings and chars as they would be read back in,
+/// `display` renders them as raw text.
+#[derive(Clone, Copy, PartialEq)]
+enum Style {
+    Write,
+    Display,
+}
+
+/// Which pairs and vectors are written with datum labels: none of them,
+/// as `write-simple` does, never mind that it won't finish writing those
+/// that contain themselves, only those, as `write` does, or every one of
+/// them that's in more than one place, as `write-shared` does. So a list
+/// with the same list in it twice is written by `write` as if they were
+/// two lists that are `equal?`, and by `write-shared` as the one list.
+/// `display` and `display-shared` are the same as `write` and
+/// `write-shared` in this.
+#[derive(Clone, Copy, PartialEq)]
+enum Sharing {
+    None,
+    Cycles,
+    All,
+}
+
+/// The datum labels to write an object with: the pairs and vectors that
+/// need them, and the numbers of those that have been given theirs.
+#[derive(Default)]
+struct Labels {
+    needed: HashSet<*const ()>,
+    given: HashMap<*const (), usize>,
+}
+
+impl Labels {
+    /// The labels that `obj` needs written with `sharing`.
+    fn new(obj: &Object, sharing: Sharing) -> Labels {
+        let mut labels = Labels::default();
+        if sharing != Sharing::None {
+            let (mut path, mut seen) = (HashSet::new(), HashSet::new());
+            find_labels(obj, sharing, &mut path, &mut seen, &mut labels.needed);
+        }
+        labels
+    }
+
+    /// Writes the label of the pair or vector at `ptr`, if it needs one:
+    /// `#n=` before the first time it's written, or `#n#` in place of it
+    /// after that, in which case that's all there is to write and this
+    /// says so.
+    fn write_label(&mut self, ptr: *const (), p: &mut impl Write) -> Result<bool, io::Error> {
+        if !self.needed.contains(&ptr) {
+            return Ok(false);
+        }
+        if let Some(n) = self.given.get(&ptr) {
+            write!(p, "#{n}#")?;
+            return Ok(true);
+        }
+        let n = self.given.len();
+        self.given.insert(ptr, n);
+        write!(p, "#{n}=")?;
+        Ok(false)
+    }
+}
+
+/// Adds the pairs and vectors in `obj` that need labels with `sharing` to
+/// `needed`, given the ones on `path` that it's inside of and the ones
+/// `seen` already.
+fn find_labels(
+    obj: &Object,
+    sharing: Sharing,
+    path: &mut HashSet<*const ()>,
+    seen: &mut HashSet<*const ()>,
+    needed: &mut HashSet<*const ()>,
+) {
+    match obj {
+        Pair(_) => {
+            // the pairs of a list stay on the path until the end of it,
+            // which is gone through in a loop rather than by recursion so
+            // that long lists don't run out of stack
+            let mut entered = Vec::new();
+            let mut obj = obj.clone();
+            while let Pair(pair) = &obj {
+                let ptr = Rc::as_ptr(pair).cast();
+                if !seen.insert(ptr) {
+                    if path.contains(&ptr) || sharing == Sharing::All {
+                        needed.insert(ptr);
+                    }
+                    break;
+                }
+                path.insert(ptr);
+                entered.push(ptr);
+                find_labels(&pair.car(), sharing, path, seen, needed);
+                obj = pair.cdr();
+            }
+            if let Vector(_) = obj {
+                find_labels(&obj, sharing, path, seen, needed);
+            }
+            for ptr in entered {
+                path.remove(&ptr);
+            }
+        }
+        Vector(v) => {
+            let ptr = Rc::as_ptr(v).cast();
+            if !seen.insert(ptr) {
+                if path.contains(&ptr) || sharing == Sharing::All {
+                    needed.insert(ptr);
+                }
+                return;
+            }
+            path.insert(ptr);
+            let items = v.borrow().clone();
+            for item in &items {
+                find_labels(item, sharing, path, seen, needed);
+            }
+            path.remove(&ptr);
+        }
+        _ => {}
+    }
+}
+
+fn write_impl(
+    obj: &Object,
+    p: &mut impl Write,
+    style: Style,
+    labels: &mut Labels,
+) -> Result<(), io::Error> {
+    match obj {
+        Char(c) if style == Style::Display => write!(p, "{c}")?,
+        Boolean(true) => write!(p, "#t")?,
+        Boolean(false) => write!(p, "#f")?,
+        Char('\x07') => write!(p, r"#\alarm")?,
+        Char('\x08') => write!(p, r"#\backspace")?,
+        Char('\x7F') => write!(p, r"#\delete")?,
+        Char('\x1B') => write!(p, r"#\escape")?,
+        Char('\n') => write!(p, r"#\newline")?,
+        Char('\0') => write!(p, r"#\null")?,
+        Char('\r') => write!(p, r"#\return")?,
+        Char(' ') => write!(p, r"#\space")?,
+        Char('\t') => write!(p, r"#\tab")?,
+        Char(c) => write!(p, r"#\{c}")?,
+        Null => write!(p, "()")?,
+        Pair(rc) => {
+            if labels.write_label(Rc::as_ptr(rc).cast(), p)? {
+                return Ok(());
+            }
+            write!(p, "(")?;
+            write_impl(&rc.car(), p, style, labels)?;
+            write_cdr(&rc.cdr(), p, style, labels)?;
+            write!(p, ")")?;
+        }
+        Procedure(_) => write!(p, "<procedure>")?,
+        Symbol(s) if style == Style::Write && !reads_as_symbol(s) => {
+            write!(p, "|")?;
+            for c in s.chars() {
+                match c {
+                    '|' => write!(p, r"\|")?,
+                    '\\' => write!(p, r"\x5c;")?,
+                    c => write!(p, "{c}")?,
+                }
+            }
+            write!(p, "|")?;
+        }
+        Symbol(s) => write!(p, "{}", s)?,
+        Bytevector(v) => {
+            let v = v.borrow();
+            write!(p, "#u8(")?;
+            if !v.is_empty() {
+                write!(p, "{}", v[0])?;
+                for b in &v[1..] {
+                    write!(p, " {}", b)?;
+                }
+            }
+            write!(p, ")")?;
+        }
+        Condition(_) => write!(p, "<error>")?,
+        Continuation(_) => write!(p, "<procedure>")?,
+        Environment(_) => write!(p, "<environment>")?,
+        Macro(_) => write!(p, "<macro>")?,
+        EofObject => write!(p, "<eof>")?,
+        HashTable(_) => write!(p, "<hash-table>")?,
+        Number(x) => write!(p, "{}", x)?,
+        Parameter(_) => write!(p, "<parameter>")?,
+        Port(_) => write!(p, "<port>")?,
+        Primitive(_) => write!(p, "<procedure>")?,
+        String(s) if style == Style::Display => {
+            for c in s.borrow().iter() {
+                write!(p, "{}", c)?;
+            }
+        }
+        String(s) => {
+            write!(p, "\"")?;
+            for c in s.borrow().iter() {
+                match c {
+                    '"' => write!(p, r#"\""#)?,
+                    '\\' => write!(p, r"\\")?,
+                    '\x07' => write!(p, r"\a")?,
+                    '\x08' => write!(p, r"\b")?,
+                    '\t' => write!(p, r"\t")?,
+                    '\n' => write!(p, r"\n")?,
+                    '\r' => write!(p, r"\r")?,
+                    c => write!(p, "{}", c)?,
+                }
+            }
+            write!(p, "\"")?;
+        }
+        Vector(v) => {
+            if labels.write_label(Rc::as_ptr(v).cast(), p)? {
+                return Ok(());
+            }
+            let v = v.borrow().clone();
+            write!(p, "#(")?;
+            if !v.is_empty() {
+                write_impl(&v[0], p, style, labels)?;
+                for x in &v[1..] {
+                    write!(p, " ")?;
+                    write_impl(x, p, style, labels)?;
+                }
+            }
+            write!(p, ")")?;
+        }
+        Record() => write!(p, "<record>")?,
+        Values(v) => {
+            if !v.is_empty() {
+                write_impl(&v[0], p, style, labels)?;
+                for x in &v[1..] {
+                    write!(p, " ")?;
+                    write_impl(x, p, style, labels)?;
+                }
+            }
+        }
+        Unspecified => write!(p, "<unspecified>")?,
+        Uninitialized => write!(p, "<uninitialized>")?,
+    };
+    Ok(())
+}
+
+/// Whether `name` written out as it is would be read back in as the symbol
+/// it names, rather than as something else or as several things.
+fn reads_as_symbol(name: &str) -> bool {
+    let symbol = [lex::Token::Identifier(name.to_owned())];
+    matches!(lex::lex(name), Ok(("", tokens)) if tokens == symbol)
+}
+
+fn write_cdr(
+    cdr: &Object,
+    p: &mut impl Write,
+    style: Style,
+    labels: &mut Labels,
+) -> Result<(), io::Error> {
+    match cdr {
+        Null => {}
+        // a labelled pair is written as a list of its own after a `.`, for
+        // the label to go in front of
+        Pair(rc) if !labels.needed.contains(&Rc::as_ptr(rc).cast()) => {
+            write!(p, " ")?;
+            write_impl(&rc.car(), p, style, labels)?;
+            write_cdr(&rc.cdr(), p, style, labels)?;
+        }
+        _ => {
+            write!(p, " . ")?;
+            write_impl(cdr, p, style, labels)?;
+        }
+    };
+    Ok(())
+}
+
+/// The `write` representation, with datum labels for any lists and vectors
+/// that contain themselves.
+impl fmt::Display for Object {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut text = Vec::new();
+        let mut labels = Labels::new(self, Sharing::Cycles);
+        write_impl(self, &mut text, Style::Write, &mut labels).map_err(|_| fmt::Error)?;
+        f.write_str(&String::from_utf8_lossy(&text))
+    }
+}
+
+/// For Rust code, such as failing assertions: the `write` representation
+/// where there is one that says what the object is, and the variant where
+/// there isn't. Lists and vectors that contain themselves are cut short
+/// with `#<cycle>` instead of going round forever.
+impl fmt::Debug for Object {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug(self, f, &mut Vec::new())
+    }
+}
+
+/// `obj` as `Debug` shows it, inside the lists and vectors on `path`.
+fn debug(obj: &Object, f: &mut fmt::Formatter, path: &mut Vec<*const ()>) -> fmt::Result {
+    match obj {
+        Pair(pair) => {
+            let depth = path.len();
+            let mut pair = pair.clone();
+            write!(f, "(")?;
+            let result = loop {
+                let ptr = Rc::as_ptr(&pair).cast();
+                if path.contains(&ptr) {
+                    break write!(f, "#<cycle>");
+                }
+                path.push(ptr);
+                debug(&pair.car(), f, path)?;
+                match pair.cdr() {
+                    Null => break Ok(()),
+                    Pair(next) if path.contains(&Rc::as_ptr(&next).cast()) => {
+                        break write!(f, " . #<cycle>");
+                    }
+                    Pair(next) => {
+                        write!(f, " ")?;
+                        pair = next;
+                    }
+                    cdr => {
+                        write!(f, " . ")?;
+                        break debug(&cdr, f, path);
+                    }
+                }
+            };
+            path.truncate(depth);
+            result?;
+            write!(f, ")")
+        }
+        Vector(v) => {
+            let ptr = Rc::as_ptr(v).cast();
+            if path.contains(&ptr) {
+                return write!(f, "#<cycle>");
+            }
+            path.push(ptr);
+            write!(f, "#(")?;
+            for (i, item) in v.borrow().iter().enumerate() {
+                if i > 0 {
+                    write!(f, " ")?;
+                }
+                debug(item, f, path)?;
+            }
+            path.pop();
+            write!(f, ")")
+        }
+        Values(v) => {
+            write!(f, "Values(")?;
+            for (i, item) in v.iter().enumerate() {
+                if i > 0 {
+                    write!(f, " ")?;
+                }
+                debug(item, f, path)?;
+            }
+            write!(f, ")")
+        }
+        Condition(c) => {
+            write!(f, "Condition({:?}", c.message)?;
+            for irritant in &c.irritants {
+                write!(f, " ")?;
+                debug(irritant, f, path)?;
+            }
+            write!(f, ")")
+        }
+        Procedure(_) | Primitive(_) => write!(f, "Procedure"),
+        Continuation(_) => write!(f, "Continuation"),
+        Unspecified => write!(f, "Unspecified"),
+        Uninitialized => write!(f, "Uninitialized"),
+        _ => {
+            let mut text = Vec::new();
+            let written = write_impl(obj, &mut text, Style::Write, &mut Labels::default());
+            written.map_err(|_| fmt::Error)?;
+            f.write_str(&String::from_utf8_lossy(&text))
+        }
+    }
+}
+
+fn boolean_eq(args: &[Object]) -> Result<Object, Error> {
+    let mut bools = Vec::with_capacity(args.len());
+    for arg in args {
+        match arg {
+            Boolean(b) => bools.push(*b),
+            _ => return Err(Error::wrong_type("boolean=?", "a boolean", arg)),
+        }
+    }
+    Ok(Boolean(bools.windows(2).all(|w| w[0] == w[1])))
+}
+
+fn symbol_eq(args: &[Object]) -> Result<Object, Error> {
+    if let Some(arg) = args.iter().find(|arg| !matches!(arg, Symbol(_))) {
+        return Err(Error::wrong_type("symbol=?", "a symbol", arg));
+    }
+    Ok(Boolean(args.windows(2).all(|w| eqv(&w[0], &w[1]))))
+}
+
+const PRIMITIVES: &[Primitive] = &[
+    Primitive::range("write", 1, 2, |args| match args {
+        [obj] => write1(obj.clone()),
+        _ => write2(args[0].clone(), args[1].clone()),
+    }),
+    Primitive::range("write-shared", 1, 2, |args| match args {
+        [obj] => write_shared1(obj.clone()),
+        _ => write_shared2(args[0].clone(), args[1].clone()),
+    }),
+    Primitive::range("write-simple", 1, 2, |args| match args {
+        [obj] => write_simple1(obj.clone()),
+        _ => write_simple2(args[0].clone(), args[1].clone()),
+    }),
+    Primitive::range("display", 1, 2, |args| match args {
+        [obj] => display1(obj.clone()),
+        _ => display2(args[0].clone(), args[1].clone()),
+    }),
+    // beyond R7RS
+    Primitive::range("display-shared", 1, 2, |args| match args {
+        [obj] => display_shared1(obj.clone()),
+        _ => display_shared2(args[0].clone(), args[1].clone()),
+    }),
+    Primitive::fixed("boolean?", 1, |args| Ok(Boolean(matches!(args[0], Boolean(_))))),
+    Primitive::fixed("not", 1, |args| Ok(Boolean(!args[0].is_truthy()))),
+    Primitive::variadic("boolean=?", 2, boolean_eq),
+    // `eqv?` is already as cheap as comparing pointers for everything but
+    // numbers and characters, so `eq?` might as well be the same. Booleans,
+    // `()`, the EOF object and numbers are held inline in `Object` with no
+    // allocation, so there's nothing to intern for them to be `eq?`
+    Primitive::fixed("eq?", 2, |args| Ok(Boolean(eqv(&args[0], &args[1])))),
+    Primitive::fixed("eqv?", 2, |args| Ok(Boolean(eqv(&args[0], &args[1])))),
+    Primitive::fixed("equal?", 2, |args| Ok(Boolean(equal(&args[0], &args[1])))),
+    Primitive::variadic("symbol=?", 2, symbol_eq),
+    Primitive::fixed("char?", 1, |args| Ok(Boolean(matches!(args[0], Char(_))))),
+    Primitive::fixed("symbol?", 1, |args| Ok(Boolean(matches!(args[0], Symbol(_))))),
+    Primitive::fixed("string?", 1, |args| Ok(Boolean(matches!(args[0], String(_))))),
+    Primitive::fixed("vector?", 1, |args| Ok(Boolean(matches!(args[0], Vector(_))))),
+    Primitive::fixed("bytevector?", 1, |args| Ok(Boolean(matches!(args[0], Bytevector(_))))),
+    Primitive::fixed("eof-object", 0, |_| Ok(EofObject)),
+    Primitive::fixed("eof-object?", 1, |args| Ok(Boolean(matches!(args[0], EofObject)))),
+    // parameter objects can be called for their values, so they count too
+    Primitive::fixed("procedure?", 1, |args| Ok(Boolean(is_procedure(&args[0])))),
+];
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{
+        equal, read_str,
+        sync::Rc,
+        testing::{check, check_errors, output},
+        Object,
+    };
+
+    #[test]
+    fn each_value_satisfies_exactly_one_type_predicate() {
+        check(&[(
+            "(define predicates
+               (list symbol? string? vector? bytevector? procedure? boolean? char? port?
+                     eof-object? pair? null? number?))
+             (define values
+               (list 'a \"s\" #(1) #u8(1) car #t #\\a (current-input-port) (eof-object)
+                     '(1) '() 1))
+             (map (lambda (value)
+                    (let loop ((ps predicates) (i 0) (hits '()))
+                      (cond ((null? ps) (reverse hits))
+                            (((car ps) value) (loop (cdr ps) (+ i 1) (cons i hits)))
+                            (else (loop (cdr ps) (+ i 1) hits)))))
+                  values)",
+            "((0) (1) (2) (3) (4) (5) (6) (7) (8) (9) (10) (11))",
+        )]);
+        check(&[
+            ("(procedure? (lambda () 1))", "#t"),
+            ("(procedure? (make-parameter 1))", "#t"),
+            ("(call/cc procedure?)", "#t"),
+            ("(procedure? 'car)", "#f"),
+        ]);
+    }
+
+    #[test]
+    fn not_and_boolean_eq() {
+        check(&[
+            ("(not '())", "#f"),
+            ("(not #f)", "#t"),
+            ("(not 0)", "#f"),
+            ("(boolean=? #t #t #t)", "#t"),
+            ("(boolean=? #f #f)", "#t"),
+            ("(boolean=? #t #t #f)", "#f"),
+        ]);
+        check_errors(&[("(boolean=? #t 1)", "boolean=?: expected a boolean 1")]);
+    }
+
+    #[test]
+    fn equivalence_predicates() {
+        check(&[
+            ("(eq? 'a 'a)", "#t"),
+            ("(eq? (string->symbol \"a\") 'a)", "#t"),
+            ("(define p (list 1)) (list (eq? p p) (eq? p (list 1)))", "(#t #f)"),
+            ("(eqv? 1 1)", "#t"),
+            ("(eqv? 1 1.0)", "#f"),
+            ("(eqv? 0.0 -0.0)", "#f"),
+            ("(eqv? 2.5 2.5)", "#t"),
+            ("(eqv? 100000000000000000000 100000000000000000000)", "#t"),
+            ("(eqv? 1/2 1/2)", "#t"),
+            ("(eqv? \"a\" \"a\")", "#f"),
+            ("(define s \"a\") (eqv? s s)", "#t"),
+            ("(equal? (list 1 (vector 2 \"x\") #u8(3)) '(1 #(2 \"x\") #u8(3)))", "#t"),
+            ("(equal? '(1 #(2 \"x\")) '(1 #(2 \"y\")))", "#f"),
+            ("(equal? '(1 2) '(1 2 3))", "#f"),
+            ("(equal? 2 2.0)", "#f"),
+            ("(symbol=? 'a 'a 'a)", "#t"),
+            ("(symbol=? 'a 'a 'b)", "#f"),
+        ]);
+        check_errors(&[("(symbol=? 'a \"a\")", "symbol=?: expected a symbol")]);
+    }
+
+    #[test]
+    fn equal_terminates_on_cycles() {
+        check(&[
+            (
+                "(define a (list 1 2)) (set-cdr! (cdr a) a)
+                 (define b (list 1 2 1 2)) (set-cdr! (cdddr b) b)
+                 (define c (list 1 3)) (set-cdr! (cdr c) c)
+                 (list (equal? a b) (equal? a c))",
+                "(#t #f)",
+            ),
+            (
+                "(define v (vector 1 #f)) (vector-set! v 1 v)
+                 (define w (vector 1 #f)) (vector-set! w 1 w)
+                 (define u (vector 2 #f)) (vector-set! u 1 u)
+                 (list (equal? v w) (equal? v u))",
+                "(#t #f)",
+            ),
+            (
+                "(define x (list 'x)) (set-car! x x)
+                 (define y (list 'y)) (set-car! y y)
+                 (equal? x y)",
+                "#t",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn equal_handles_deep_nesting() {
+        use crate::{cons, equal, Object::Null};
+
+        let nest = |innermost| (0..1_000_000).fold(innermost, |inner, _| cons(inner, Null));
+        let (a, b) = (nest(Object::int(1)), nest(Object::int(1)));
+        assert!(equal(&a, &b));
+        assert!(!equal(&a, &nest(Object::int(2))));
+        let long = |last| {
+            (0..1_000_000).fold(cons(last, Null), |rest, i| cons(Object::int(i), rest))
+        };
+        assert!(equal(&long(Object::int(1)), &long(Object::int(1))));
+        assert!(!equal(&long(Object::int(1)), &long(Object::int(2))));
+    }
+
+    #[test]
+    fn only_false_is_false() {
+        assert!(!Object::Boolean(false).is_truthy());
+        for value in [Object::Boolean(true), Object::Null, Object::int(0), Object::str("")] {
+            assert!(value.is_truthy(), "{value}");
+        }
+        check(&[
+            ("(if '() 1 2)", "1"),
+            ("(if 0 1 2)", "1"),
+            ("(if \"\" 1 2)", "1"),
+            ("(if #f 1 2)", "2"),
+            ("(cond (#f 'no) ('() 'yes))", "yes"),
+            ("(list (and 1 '() 0) (and 1 #f 0) (or #f '()) (or #f #f))", "(0 #f () #f)"),
+            ("(list (when 0 'ran) (unless '() 'ran))", "(ran <unspecified>)"),
+            ("(map not (list #f #t '() 0 \"\"))", "(#t #f #f #f #f)"),
+            ("(do ((i 0 (+ i 1))) ('() i))", "0"),
+        ]);
+    }
+
+    #[test]
+    fn symbols_are_shared_and_immutable() {
+        let Object::Symbol(name) = Object::sym("lambda") else { unreachable!() };
+        let copy = Object::Symbol(name.clone());
+        let Object::Symbol(copied) = &copy else { unreachable!() };
+        assert!(Rc::ptr_eq(&name, copied));
+        assert_eq!(Rc::strong_count(&name), 2);
+        check(&[
+            (
+                "(define s 'abc)
+                 (define t (symbol->string s))
+                 (string-fill! t #\\z)
+                 (list s t (symbol->string s))",
+                "(abc \"zzz\" \"abc\")",
+            ),
+            (
+                "(define t (string-copy \"abc\"))
+                 (define s (string->symbol t))
+                 (string-fill! t #\\z)
+                 (list s (eq? s 'abc))",
+                "(abc #t)",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn small_values_are_eq() {
+        check(&[
+            ("(list (eq? #t #t) (eq? #f (not 1)) (eq? '() (cdr '(1))))", "(#t #t #t)"),
+            ("(eq? (eof-object) (read (open-input-string \"\")))", "#t"),
+            ("(list (eq? 5 5) (eq? 255 (+ 250 5)) (eq? -128 (- 128)))", "(#t #t #t)"),
+            ("(eq? #\\a (string-ref \"a\" 0))", "#t"),
+        ]);
+    }
+
+    #[test]
+    fn building_objects_in_rust() {
+        let read = |src| read_str(src).unwrap().remove(0);
+        let built = list![Object::sym("a"), Object::int(1), Object::str("x")];
+        assert!(equal(&built, &read("(a 1 \"x\")")));
+        assert!(!equal(&built, &read("(a 1 \"y\")")));
+        assert!(equal(&Object::list([]), &read("()")));
+        let nested = Object::from(vec![list![Object::int(1)], Object::list([Object::int(2)])]);
+        assert!(equal(&nested, &read("((1) (2))")));
+        assert_eq!(built.to_string(), "(a 1 \"x\")");
+    }
+
+    #[test]
+    fn debug_shows_nested_and_cyclic_data() {
+        let read = |src| read_str(src).unwrap().remove(0);
+        let nested = read("(1 (a \"s\" #\\c) #(x (y)) . z)");
+        assert_eq!(format!("{nested:?}"), "(1 (a \"s\" #\\c) #(x (y)) . z)");
+        let Object::Pair(pair) = list![Object::int(1), Object::int(2)] else { unreachable!() };
+        let Object::Pair(last) = pair.cdr() else { unreachable!() };
+        last.set_cdr(Object::Pair(pair.clone()));
+        assert_eq!(format!("{:?}", Object::Pair(pair.clone())), "(1 2 . #<cycle>)");
+        // break the cycle so that the pairs are freed
+        last.set_cdr(Object::Null);
+        assert_eq!(format!("{:?}", Object::Unspecified), "Unspecified");
+    }
+
+    #[test]
+    #[cfg(not(feature = "std"))]
+    fn without_std_ports() {
+        use crate::{lex, Token};
+
+        let (rest, tokens) = lex("(car '(1 . 2))").unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(tokens.first(), Some(&Token::OpenParen));
+        check(&[
+            ("(car '(1 . 2))", "1"),
+            ("(read (open-input-string \"#(a \\\"b\\\")\"))", "#(a \"b\")"),
+            // the current ports start out as string ports
+            ("(read-char)", "<eof>"),
+            ("(write 'x) (output-port? (current-output-port))", "#t"),
+            // floats get by without std's maths library
+            ("(list (floor-quotient -7. 2) (integer? 2.) (expt 2. 10))", "(-4. #t 1024.)"),
+            ("(= 2.75 11/4)", "#t"),
+        ]);
+        check_errors(&[
+            ("(open-input-file \"f\")", "unbound variable"),
+            ("(load \"f\")", "unbound variable"),
+            ("(include \"f\")", "can't read files without the std feature"),
+        ]);
+    }
+
+    #[test]
+    #[cfg(feature = "sync")]
+    fn objects_can_be_shared_between_threads() {
+        let data = read_str("(a #(1 \"two\") 3.5)").unwrap().remove(0);
+        let written = std::thread::scope(|scope| {
+            let data = &data;
+            let reader = scope.spawn(move || {
+                let Object::Pair(pair) = data else { unreachable!() };
+                // changed on this thread, and seen on the other
+                pair.set_car(crate::symbol("b"));
+                data.to_string()
+            });
+            reader.join().unwrap()
+        });
+        assert_eq!(written, "(b #(1 \"two\") 3.5)");
+        assert!(equal(&data, &read_str("(b #(1 \"two\") 3.5)").unwrap()[0]));
+
+        // evaluated on another thread, with an environment of its own
+        let shared = data.clone();
+        let length = std::thread::spawn(move || {
+            let env = crate::interaction_environment();
+            let quoted = Object::list([crate::symbol("quote"), shared]);
+            let call = Object::list([crate::symbol("length"), quoted]);
+            crate::eval(call, &env).unwrap().to_string()
+        });
+        assert_eq!(length.join().unwrap(), "3");
+    }
+
+    #[test]
+    fn writing_cycles_through_vectors() {
+        let write = |how: &str, setup: &str| {
+            format!(
+                "{setup}
+                 (let ((port (open-output-string)))
+                   ({how} x port)
+                   (get-output-string port))"
+            )
+        };
+        let itself = "(define x (vector 1 2))
+                      (vector-set! x 1 x)";
+        let through_a_pair = "(define x (list 'a (vector 'b #f)))
+                              (vector-set! (cadr x) 1 x)";
+        let nested = "(define x (vector (vector #f)))
+                      (vector-set! (vector-ref x 0) 0 x)";
+        let shared = "(define v (vector 1))
+                      (define x (vector v v))";
+        check(&[
+            (&write("write", itself), "\"#0=#(1 #0#)\""),
+            (&write("write", through_a_pair), "\"#0=(a #(b #0#))\""),
+            (&write("write", nested), "\"#0=#(#(#0#))\""),
+            (&write("display", itself), "\"#0=#(1 #0#)\""),
+            // only what's in a cycle is labelled by `write`, and anything
+            // in more than one place by `write-shared`
+            (&write("write", shared), "\"#(#(1) #(1))\""),
+            (&write("write-shared", shared), "\"#(#0=#(1) #0#)\""),
+            // what's written reads back as the same shape
+            (
+                "(define y (read (open-input-string \"#0=#(1 #0#)\")))
+                 (eq? y (vector-ref y 1))",
+                "#t",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn writing_shared_structure() {
+        let written = |how: &str| {
+            format!(
+                "(define shared (list 'x))
+                 (define diamond (list shared shared))
+                 (let ((port (open-output-string)))
+                   ({how} diamond port)
+                   (get-output-string port))"
+            )
+        };
+        check(&[
+            // a diamond has no cycle, so only `write-shared` labels it
+            (&written("write-simple"), "\"((x) (x))\""),
+            (&written("write"), "\"((x) (x))\""),
+            (&written("write-shared"), "\"(#0=(x) #0#)\""),
+            // the labels are numbered in the order they're written
+            (
+                "(define a (list 1))
+                 (define b (vector 2))
+                 (let ((port (open-output-string)))
+                   (write-shared (list b a b a) port)
+                   (get-output-string port))",
+                "\"(#0=#(2) #1=(1) #0# #1#)\"",
+            ),
+            // strings are written without labels, even when shared
+            (
+                "(define s \"s\")
+                 (let ((port (open-output-string)))
+                   (write-shared (list s s) port)
+                   (get-output-string port))",
+                "\"(\\\"s\\\" \\\"s\\\")\"",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn displaying_cycles() {
+        let shown = |how: &str, setup: &str| {
+            format!(
+                "{setup}
+                 (let ((port (open-output-string)))
+                   ({how} x port)
+                   (get-output-string port))"
+            )
+        };
+        let cyclic = "(define x (list \"a\" #\\b))
+                      (set-cdr! (cdr x) x)";
+        let shared = "(define s (list \"a\"))
+                      (define x (list s s))";
+        check(&[
+            (&shown("display", cyclic), "\"#0=(a b . #0#)\""),
+            (&shown("write", cyclic), "\"#0=(\\\"a\\\" #\\\\b . #0#)\""),
+            // a list that's its own car
+            (
+                &shown("display", "(define x (list 1)) (set-car! x x)"),
+                "\"#0=(#0#)\"",
+            ),
+            (&shown("display", shared), "\"((a) (a))\""),
+            (&shown("display-shared", shared), "\"(#0=(a) #0#)\""),
+            (&shown("display-shared", cyclic), "\"#0=(a b . #0#)\""),
+        ]);
+        assert_eq!(
+            output("(define x (list 1 2)) (set-cdr! (cdr x) x) (display x)"),
+            "#0=(1 2 . #0#)"
+        );
+    }
+}
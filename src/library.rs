@@ -0,0 +1,306 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    collections::HashMap,
+    env::Env,
+    error::Error,
+    eval::eval,
+    features, list_to_vec,
+    load::include,
+    number::Number,
+    sync::{Rc, RefCell},
+    Object,
+};
+
+/// A library made by `define-library`: the environment its body ran in,
+/// and the names it exports, each with the name it has inside.
+struct Library {
+    env: Rc<Env>,
+    exports: Vec<(Rc<str>, Rc<str>)>,
+}
+
+thread_local! {
+    /// The libraries defined so far, by name.
+    static LIBRARIES: RefCell<HashMap<Vec<String>, Rc<Library>>> = RefCell::new(HashMap::new());
+    /// What the standard libraries all import from: everything there is.
+    static STANDARD: Rc<Env> = Rc::new(Env::standard());
+}
+
+/// The standard libraries. They aren't told apart, so importing any one of
+/// them brings in the whole of the standard environment.
+const STANDARD_LIBRARIES: &[&str] = &[
+    "base",
+    "case-lambda",
+    "char",
+    "cxr",
+    "eval",
+    "file",
+    "inexact",
+    "load",
+    "process-context",
+    "read",
+    "write",
+    "r5rs",
+];
+
+/// The parts of a library name like `(srfi 1)`, as text.
+fn name_parts(name: &Object) -> Option<Vec<String>> {
+    list_to_vec(name)?
+        .iter()
+        .map(|part| match part {
+            Object::Symbol(s) => Some(s.to_string()),
+            Object::Number(Number::Integer(i)) if *i >= 0 => Some(i.to_string()),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()
+        .filter(|parts| !parts.is_empty())
+}
+
+fn is_standard(parts: &[String]) -> bool {
+    matches!(parts, [scheme, library]
+        if scheme == "scheme" && STANDARD_LIBRARIES.contains(&library.as_str()))
+}
+
+/// Whether there is a library called `name`, for `(library name)` in
+/// feature requirements.
+pub fn exists(name: &Object) -> bool {
+    name_parts(name).is_some_and(|parts| {
+        is_standard(&parts) || LIBRARIES.with(|libraries| libraries.borrow().contains_key(&parts))
+    })
+}
+
+/// The bindings an import set brings in, with the names they come in as.
+fn import_set(set: &Object) -> Result<Vec<(Rc<str>, Object)>, Error> {
+    let bad = || Error::new("import: bad import set", vec![set.clone()]);
+    let parts = list_to_vec(set).ok_or_else(bad)?;
+    let modifier = match parts.first() {
+        Some(Object::Symbol(s)) if parts.len() >= 2 => &**s,
+        _ => "",
+    };
+    let names = |ids: &[Object]| {
+        ids.iter()
+            .map(|id| match id {
+                Object::Symbol(s) => Ok(s.clone()),
+                _ => Err(bad()),
+            })
+            .collect::<Result<Vec<_>, _>>()
+    };
+    match (modifier, &parts[..]) {
+        ("only", [_, inner, ids @ ..]) => {
+            let ids = names(ids)?;
+            let mut bindings = import_set(inner)?;
+            bindings.retain(|(name, _)| ids.contains(name));
+            Ok(bindings)
+        }
+        ("except", [_, inner, ids @ ..]) => {
+            let ids = names(ids)?;
+            let mut bindings = import_set(inner)?;
+            bindings.retain(|(name, _)| !ids.contains(name));
+            Ok(bindings)
+        }
+        ("prefix", [_, inner, Object::Symbol(prefix)]) => Ok(import_set(inner)?
+            .into_iter()
+            .map(|(name, value)| (Rc::from(format!("{prefix}{name}")), value))
+            .collect()),
+        ("rename", [_, inner, renames @ ..]) => {
+            let mut pairs = Vec::new();
+            for rename in renames {
+                match &list_to_vec(rename).unwrap_or_default()[..] {
+                    [Object::Symbol(from), Object::Symbol(to)] => {
+                        pairs.push((from.clone(), to.clone()))
+                    }
+                    _ => return Err(bad()),
+                }
+            }
+            let mut bindings = import_set(inner)?;
+            for (name, _) in &mut bindings {
+                if let Some((_, to)) = pairs.iter().find(|(from, _)| from == name) {
+                    *name = to.clone();
+                }
+            }
+            Ok(bindings)
+        }
+        _ => library_bindings(set),
+    }
+}
+
+/// Everything exported by the library called `name`.
+fn library_bindings(name: &Object) -> Result<Vec<(Rc<str>, Object)>, Error> {
+    let unknown = || Error::new("import: unknown library", vec![name.clone()]);
+    let parts = name_parts(name).ok_or_else(unknown)?;
+    if is_standard(&parts) {
+        return Ok(STANDARD.with(|env| {
+            env.names()
+                .into_iter()
+                .filter_map(|name| Some((name.clone(), env.lookup(&name)?)))
+                .collect()
+        }));
+    }
+    let library = LIBRARIES
+        .with(|libraries| libraries.borrow().get(&parts).cloned())
+        .ok_or_else(unknown)?;
+    library
+        .exports
+        .iter()
+        .map(|(external, internal)| match library.env.lookup(internal) {
+            Some(value) => Ok((external.clone(), value)),
+            None => Err(Error::new(
+                "import: exported but never defined",
+                vec![Object::Symbol(internal.clone())],
+            )),
+        })
+        .collect()
+}
+
+/// `(import import-set...)` defines what the import sets bring in in
+/// `env`. That's the values the library had for them at the time, so
+/// later assignments inside the library aren't seen through them.
+pub fn import(operands: &[Object], env: &Rc<Env>) -> Result<Object, Error> {
+    for set in operands {
+        for (name, value) in import_set(set)? {
+            env.define(name, value);
+        }
+    }
+    Ok(Object::Unspecified)
+}
+
+/// `(define-library name declaration...)`, where each declaration is an
+/// `export`, `import`, `begin`, `include`, `include-ci` or `cond-expand`.
+/// The library starts out with nothing but the syntactic keywords, which
+/// the evaluator always recognises.
+pub fn define_library(expr: &Object, operands: &[Object]) -> Result<Object, Error> {
+    let bad = || Error::new("bad syntax", vec![expr.clone()]);
+    let [name, declarations @ ..] = operands else {
+        return Err(bad());
+    };
+    let parts = name_parts(name).ok_or_else(bad)?;
+    let mut library = Library {
+        env: Rc::new(Env::new()),
+        exports: Vec::new(),
+    };
+    declare(&mut library, declarations, expr)?;
+    LIBRARIES.with(|libraries| libraries.borrow_mut().insert(parts, Rc::new(library)));
+    Ok(Object::Unspecified)
+}
+
+fn declare(library: &mut Library, declarations: &[Object], expr: &Object) -> Result<(), Error> {
+    let bad = || Error::new("bad syntax", vec![expr.clone()]);
+    for declaration in declarations {
+        let declaration = list_to_vec(declaration).ok_or_else(bad)?;
+        let Some((Object::Symbol(keyword), rest)) = declaration.split_first() else {
+            return Err(bad());
+        };
+        match &**keyword {
+            "export" => {
+                for spec in rest {
+                    library.exports.push(export_spec(spec).ok_or_else(bad)?);
+                }
+            }
+            "import" => {
+                import(rest, &library.env)?;
+            }
+            "begin" => {
+                for form in rest {
+                    eval(form.clone(), &library.env)?;
+                }
+            }
+            "include" => {
+                include(rest, false, &library.env)?;
+            }
+            "include-ci" => {
+                include(rest, true, &library.env)?;
+            }
+            "cond-expand" => {
+                for clause in rest {
+                    let clause = list_to_vec(clause).ok_or_else(bad)?;
+                    let [requirement, body @ ..] = &clause[..] else {
+                        return Err(bad());
+                    };
+                    let met = matches!(requirement, Object::Symbol(s) if &**s == "else")
+                        || features::satisfied(requirement, "define-library")?;
+                    if met {
+                        declare(library, body, expr)?;
+                        break;
+                    }
+                }
+            }
+            _ => return Err(bad()),
+        }
+    }
+    Ok(())
+}
+
+/// An export spec, `name` or `(rename internal external)`, as the names it
+/// has outside and inside.
+fn export_spec(spec: &Object) -> Option<(Rc<str>, Rc<str>)> {
+    match spec {
+        Object::Symbol(name) => Some((name.clone(), name.clone())),
+        _ => match &list_to_vec(spec)?[..] {
+            [Object::Symbol(rename), Object::Symbol(internal), Object::Symbol(external)]
+                if &**rename == "rename" =>
+            {
+                Some((external.clone(), internal.clone()))
+            }
+            _ => None,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{check, check_errors};
+
+    const LIBRARY: &str = "(define-library (tiny counter)
+                             (export next (rename peek current))
+                             (import (scheme base))
+                             (begin
+                               (define count 0)
+                               (define (next) (set! count (+ count 1)) count)
+                               (define (peek) count)))";
+
+    #[test]
+    fn libraries_are_imported_through_import_sets() {
+        let program = |rest: &str| format!("{LIBRARY} {rest}");
+        check(&[
+            (
+                &program("(import (prefix (tiny counter) c:)) (c:next) (c:next)"),
+                "2",
+            ),
+            (&program("(import (only (tiny counter) next)) (next)"), "1"),
+            (
+                &program("(import (rename (tiny counter) (next bump))) (bump) (current)"),
+                "1",
+            ),
+            (
+                &program(
+                    "(define current 'mine)
+                     (import (except (tiny counter) current))
+                     (next)
+                     current",
+                ),
+                "mine",
+            ),
+        ]);
+        check_errors(&[
+            (
+                &program("(import (prefix (tiny counter) c:)) (next)"),
+                "unbound variable next",
+            ),
+            (
+                &program("(import (tiny counter)) count"),
+                "unbound variable count",
+            ),
+            (
+                "(import (no such library))",
+                "import: unknown library (no such library)",
+            ),
+            (
+                "(define-library (broken) (export missing)) (import (broken))",
+                "import: exported but never defined missing",
+            ),
+        ]);
+    }
+}
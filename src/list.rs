@@ -0,0 +1,849 @@
+use alloc::vec::Vec;
+
+use crate::{
+    cons, constant, equal, eqv,
+    error::Error,
+    eval::{apply, values, Primitive},
+    list_to_vec,
+    number::{self, out_of_range, Number},
+    sync::{Rc, RefCell},
+    vec_to_list, vector, Object,
+};
+
+/// How many pairs the pool keeps for reuse, at most. Any more than that
+/// are freed as usual.
+#[cfg(feature = "pair-pool")]
+const POOL_LIMIT: usize = 1 << 16;
+
+#[cfg(feature = "pair-pool")]
+thread_local! {
+    /// Pairs that nothing refers to any more, emptied out, for [`alloc`] to
+    /// hand out again instead of allocating. A pair only ends up here when
+    /// it's let go of by the pair before it, as all but the first pair of
+    /// a list are, and only if nothing refers to it weakly either.
+    static POOL: core::cell::RefCell<Vec<Rc<Pair>>> =
+        const { core::cell::RefCell::new(Vec::new()) };
+}
+
+/// A new pair, reused from the pool if the `pair-pool` feature is on and
+/// there's one there.
+pub fn alloc(car: Object, cdr: Object) -> Rc<Pair> {
+    #[cfg(feature = "pair-pool")]
+    if let Ok(Some(pair)) = POOL.try_with(|pool| pool.borrow_mut().pop()) {
+        pair.set_car(car);
+        pair.set_cdr(cdr);
+        return pair;
+    }
+    Rc::new(Pair::new(car, cdr))
+}
+
+/// Keeps `pair`, which has been emptied out and which nothing else refers
+/// to, in the pool if there's room, and otherwise frees it. Pairs let go of
+/// while the thread is exiting, when the pool is gone, are freed too.
+#[cfg(feature = "pair-pool")]
+fn recycle(pair: Rc<Pair>) {
+    let _ = POOL.try_with(|pool| {
+        let mut pool = pool.borrow_mut();
+        if pool.len() < POOL_LIMIT {
+            pool.push(pair);
+        }
+    });
+}
+
+/// A pair, whose car and cdr can both be changed in place.
+pub struct Pair {
+    car: RefCell<Object>,
+    cdr: RefCell<Object>,
+}
+
+impl Pair {
+    pub fn new(car: Object, cdr: Object) -> Pair {
+        Pair {
+            car: RefCell::new(car),
+            cdr: RefCell::new(cdr),
+        }
+    }
+
+    pub fn car(&self) -> Object {
+        self.car.borrow().clone()
+    }
+
+    pub fn cdr(&self) -> Object {
+        self.cdr.borrow().clone()
+    }
+
+    pub fn set_car(&self, car: Object) {
+        self.car.replace(car);
+    }
+
+    pub fn set_cdr(&self, cdr: Object) {
+        self.cdr.replace(cdr);
+    }
+
+    /// Empties out the car and cdr, adding the pairs that were only
+    /// referred to from them to `orphans`.
+    fn orphan_fields(&self, orphans: &mut Vec<Rc<Pair>>) {
+        for field in [&self.car, &self.cdr] {
+            if let Object::Pair(pair) = field.replace(Object::Null) {
+                if Rc::strong_count(&pair) == 1 && Rc::weak_count(&pair) == 0 {
+                    orphans.push(pair);
+                }
+            }
+        }
+    }
+}
+
+impl Drop for Pair {
+    /// Lets go of the pairs that nothing else refers to one at a time,
+    /// where the default would recurse all the way down the cdrs of a long
+    /// list, or the cars of a deeply nested one, and overflow the stack.
+    fn drop(&mut self) {
+        let mut orphans = Vec::new();
+        self.orphan_fields(&mut orphans);
+        while let Some(pair) = orphans.pop() {
+            pair.orphan_fields(&mut orphans);
+            #[cfg(feature = "pair-pool")]
+            recycle(pair);
+        }
+    }
+}
+
+fn car(who: &str, obj: &Object) -> Result<Object, Error> {
+    match obj {
+        Object::Pair(pair) => Ok(pair.car()),
+        _ => Err(Error::wrong_type(who, "a pair", obj)),
+    }
+}
+
+fn cdr(who: &str, obj: &Object) -> Result<Object, Error> {
+    match obj {
+        Object::Pair(pair) => Ok(pair.cdr()),
+        _ => Err(Error::wrong_type(who, "a pair", obj)),
+    }
+}
+
+/// The number of elements in `list`, or `None` if it isn't a proper list.
+///
+/// A second cursor follows at half speed, so that a cyclic list is caught
+/// when the first one laps it rather than walked forever.
+fn proper_length(list: &Object) -> Option<usize> {
+    let (mut fast, mut slow) = (list.clone(), list.clone());
+    let mut len = 0;
+    loop {
+        fast = match &fast {
+            Object::Null => return Some(len),
+            Object::Pair(pair) => pair.cdr(),
+            _ => return None,
+        };
+        len += 1;
+        if len % 2 == 0 {
+            if let Object::Pair(pair) = &slow {
+                slow = pair.cdr();
+            }
+            if let (Object::Pair(a), Object::Pair(b)) = (&fast, &slow) {
+                if Rc::ptr_eq(a, b) {
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+/// The half-speed cursor of [`proper_length`], for walks down lists that
+/// can stop partway, or that take lists that aren't proper, to tell when
+/// they've gone round a cycle.
+struct Laps {
+    slow: Object,
+    steps: usize,
+}
+
+impl Laps {
+    fn new(list: &Object) -> Laps {
+        Laps {
+            slow: list.clone(),
+            steps: 0,
+        }
+    }
+
+    /// Whether `next`, where the walk has got to after another step, is
+    /// somewhere it's already been.
+    fn lapped(&mut self, next: &Object) -> bool {
+        self.steps += 1;
+        if !self.steps.is_multiple_of(2) {
+            return false;
+        }
+        if let Object::Pair(pair) = &self.slow {
+            self.slow = pair.cdr();
+        }
+        matches!((next, &self.slow), (Object::Pair(a), Object::Pair(b)) if Rc::ptr_eq(a, b))
+    }
+}
+
+/// The elements of `list`, which `who` needs to be a proper list.
+pub fn elements(who: &str, list: &Object) -> Result<Vec<Object>, Error> {
+    match proper_length(list) {
+        Some(_) => Ok(list_to_vec(list).unwrap_or_default()),
+        None => Err(Error::wrong_type(who, "a list", list)),
+    }
+}
+
+fn length(args: &[Object]) -> Result<Object, Error> {
+    match proper_length(&args[0]) {
+        Some(len) => Ok(Object::Number(Number::Integer(len as i64))),
+        None => Err(Error::wrong_type("length", "a list", &args[0])),
+    }
+}
+
+/// The elements of every list but the last are copied, and the last
+/// becomes the tail of the result as it is, whatever it is.
+fn append(args: &[Object]) -> Result<Object, Error> {
+    let Some((last, init)) = args.split_last() else {
+        return Ok(Object::Null);
+    };
+    let mut result = last.clone();
+    for list in init.iter().rev() {
+        result = vec_to_list(elements("append", list)?, result);
+    }
+    Ok(result)
+}
+
+fn reverse(args: &[Object]) -> Result<Object, Error> {
+    let items = elements("reverse", &args[0])?;
+    Ok(items.into_iter().fold(Object::Null, |acc, x| cons(x, acc)))
+}
+
+/// Calls `f` with the elements at the same position in each of `lists`,
+/// from the first position up to the end of the shortest of them.
+fn lockstep(
+    who: &str,
+    lists: &[Object],
+    mut f: impl FnMut(Vec<Object>) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let mut cursors = lists.to_vec();
+    loop {
+        let mut args = Vec::with_capacity(cursors.len());
+        for cursor in &mut cursors {
+            let pair = match cursor {
+                Object::Pair(pair) => pair.clone(),
+                Object::Null => return Ok(()),
+                _ => return Err(Error::wrong_type(who, "a list", cursor)),
+            };
+            args.push(pair.car());
+            *cursor = pair.cdr();
+        }
+        f(args)?;
+    }
+}
+
+fn map(args: &[Object]) -> Result<Object, Error> {
+    let mut results = Vec::new();
+    lockstep("map", &args[1..], |elements| {
+        results.push(apply(&args[0], elements)?);
+        Ok(())
+    })?;
+    Ok(vec_to_list(results, Object::Null))
+}
+
+fn for_each(args: &[Object]) -> Result<Object, Error> {
+    lockstep("for-each", &args[1..], |elements| {
+        apply(&args[0], elements).map(drop)
+    })?;
+    Ok(Object::Unspecified)
+}
+
+/// `(fold-left f init list ...)`: `f` applied to what it last returned,
+/// starting with `init`, and the elements of each list, from first to last.
+fn fold_left(args: &[Object]) -> Result<Object, Error> {
+    let mut acc = args[1].clone();
+    lockstep("fold-left", &args[2..], |mut elements| {
+        elements.insert(0, acc.clone());
+        acc = apply(&args[0], elements)?;
+        Ok(())
+    })?;
+    Ok(acc)
+}
+
+/// `(fold-right f init list ...)`: like `fold-left`, but from last to
+/// first, and with what `f` last returned after the elements.
+fn fold_right(args: &[Object]) -> Result<Object, Error> {
+    let mut rows = Vec::new();
+    lockstep("fold-right", &args[2..], |elements| {
+        rows.push(elements);
+        Ok(())
+    })?;
+    rows.into_iter()
+        .rev()
+        .try_fold(args[1].clone(), |acc, mut elements| {
+            elements.push(acc);
+            apply(&args[0], elements)
+        })
+}
+
+/// `(reduce f identity list)`: `f` applied to each element after the first
+/// and what it last returned, starting with the first element, or
+/// `identity` if there are none.
+fn reduce(args: &[Object]) -> Result<Object, Error> {
+    let mut elements = elements("reduce", &args[2])?.into_iter();
+    let Some(first) = elements.next() else {
+        return Ok(args[1].clone());
+    };
+    elements.try_fold(first, |acc, element| apply(&args[0], vec![element, acc]))
+}
+
+/// The elements of the list `args[1]` that the predicate `args[0]` is
+/// true of, and then the others, each in the order they were in.
+fn partition_by(args: &[Object], who: &str) -> Result<(Vec<Object>, Vec<Object>), Error> {
+    let (mut kept, mut removed) = (Vec::new(), Vec::new());
+    for element in elements(who, &args[1])? {
+        match apply(&args[0], vec![element.clone()])?.is_truthy() {
+            true => kept.push(element),
+            false => removed.push(element),
+        }
+    }
+    Ok((kept, removed))
+}
+
+/// `(list-sort less? list)` as `who`: a sorted copy of `list`, which is
+/// left as it was.
+fn list_sort(args: &[Object], who: &str) -> Result<Object, Error> {
+    let items = elements(who, &args[1])?;
+    Ok(vec_to_list(vector::sort(items, &args[0])?, Object::Null))
+}
+
+/// A copy of the pairs of `list`, sharing its elements and whatever it
+/// ends in.
+fn list_copy(args: &[Object]) -> Result<Object, Error> {
+    let mut items = Vec::new();
+    let mut list = args[0].clone();
+    let mut laps = Laps::new(&list);
+    while let Object::Pair(pair) = &list {
+        items.push(pair.car());
+        list = pair.cdr();
+        if laps.lapped(&list) {
+            return Err(Error::wrong_type("list-copy", "a list", &args[0]));
+        }
+    }
+    Ok(vec_to_list(items, list))
+}
+
+fn make_list(args: &[Object]) -> Result<Object, Error> {
+    let k = number::index(&args[0], "make-list")?;
+    let fill = args.get(1).cloned().unwrap_or(Object::Unspecified);
+    Ok((0..k).fold(Object::Null, |acc, _| cons(fill.clone(), acc)))
+}
+
+/// `obj`, which `who` needs to be a pair it can change.
+fn pair_mut<'a>(obj: &'a Object, who: &str) -> Result<&'a Pair, Error> {
+    let Object::Pair(pair) = obj else {
+        return Err(Error::wrong_type(who, "a pair", obj));
+    };
+    constant::mutable(obj, who)?;
+    Ok(pair)
+}
+
+fn set_car(args: &[Object]) -> Result<Object, Error> {
+    pair_mut(&args[0], "set-car!")?.set_car(args[1].clone());
+    Ok(Object::Unspecified)
+}
+
+fn set_cdr(args: &[Object]) -> Result<Object, Error> {
+    pair_mut(&args[0], "set-cdr!")?.set_cdr(args[1].clone());
+    Ok(Object::Unspecified)
+}
+
+/// What's left of `list` after dropping the number of pairs given by `k`.
+fn tail(who: &str, list: &Object, k: &Object) -> Result<Object, Error> {
+    let mut list = list.clone();
+    for _ in 0..number::index(k, who)? {
+        list = match &list {
+            Object::Pair(pair) => pair.cdr(),
+            _ => return Err(out_of_range(who, k)),
+        };
+    }
+    Ok(list)
+}
+
+/// The pair of `list` at index `k`.
+fn nth_pair(who: &str, list: &Object, k: &Object) -> Result<Rc<Pair>, Error> {
+    match tail(who, list, k)? {
+        Object::Pair(pair) => Ok(pair),
+        _ => Err(out_of_range(who, k)),
+    }
+}
+
+/// How `member` and `assoc` compare objects, if they aren't given a
+/// procedure to do it with.
+#[derive(Clone, Copy)]
+enum Same {
+    Eq,
+    Eqv,
+    Equal,
+}
+
+/// Whether `x` and `y` count as the same, by `same` or else by calling
+/// `compare`.
+fn same(same: Same, compare: Option<&Object>, x: &Object, y: &Object) -> Result<bool, Error> {
+    Ok(match (compare, same) {
+        (Some(compare), _) => !matches!(
+            apply(compare, vec![x.clone(), y.clone()])?,
+            Object::Boolean(false)
+        ),
+        // there's nothing cheaper that `eq?` could get away with than `eqv?`
+        (None, Same::Eq | Same::Eqv) => eqv(x, y),
+        (None, Same::Equal) => equal(x, y),
+    })
+}
+
+/// `(memq obj list)` and friends: the first tail of `list` whose car is
+/// the same as `obj`, or `#f`.
+fn member(who: &str, by: Same, args: &[Object]) -> Result<Object, Error> {
+    let mut list = args[1].clone();
+    let mut laps = Laps::new(&list);
+    loop {
+        list = match &list {
+            Object::Pair(pair) if same(by, args.get(2), &args[0], &pair.car())? => return Ok(list),
+            Object::Pair(pair) => pair.cdr(),
+            Object::Null => return Ok(Object::Boolean(false)),
+            _ => return Err(Error::wrong_type(who, "a list", &args[1])),
+        };
+        if laps.lapped(&list) {
+            return Err(Error::wrong_type(who, "a list", &args[1]));
+        }
+    }
+}
+
+/// `(assq obj alist)` and friends: the first pair of the association list
+/// `alist` whose car is the same as `obj`, or `#f`.
+fn assoc(who: &str, by: Same, args: &[Object]) -> Result<Object, Error> {
+    let mut list = args[1].clone();
+    let mut laps = Laps::new(&list);
+    loop {
+        list = match &list {
+            Object::Pair(pair) => match pair.car() {
+                Object::Pair(entry) if same(by, args.get(2), &args[0], &entry.car())? => {
+                    return Ok(Object::Pair(entry));
+                }
+                Object::Pair(_) => pair.cdr(),
+                entry => return Err(Error::wrong_type(who, "a pair", &entry)),
+            },
+            Object::Null => return Ok(Object::Boolean(false)),
+            _ => return Err(Error::wrong_type(who, "a list", &args[1])),
+        };
+        if laps.lapped(&list) {
+            return Err(Error::wrong_type(who, "a list", &args[1]));
+        }
+    }
+}
+
+pub const PRIMITIVES: &[Primitive] = &[
+    Primitive::fixed("car", 1, |args| car("car", &args[0])),
+    Primitive::fixed("cdr", 1, |args| cdr("cdr", &args[0])),
+    Primitive::fixed("cons", 2, |args| Ok(cons(args[0].clone(), args[1].clone()))),
+    Primitive::fixed("pair?", 1, |args| {
+        Ok(Object::Boolean(matches!(args[0], Object::Pair(_))))
+    }),
+    Primitive::fixed("null?", 1, |args| {
+        Ok(Object::Boolean(matches!(args[0], Object::Null)))
+    }),
+    Primitive::fixed("list?", 1, |args| {
+        Ok(Object::Boolean(proper_length(&args[0]).is_some()))
+    }),
+    Primitive::variadic("list", 0, |args| {
+        Ok(vec_to_list(args.to_vec(), Object::Null))
+    }),
+    Primitive::fixed("length", 1, length),
+    Primitive::variadic("append", 0, append),
+    Primitive::fixed("reverse", 1, reverse),
+    Primitive::fixed("list-copy", 1, list_copy),
+    Primitive::range("make-list", 1, 2, make_list),
+    Primitive::fixed("set-car!", 2, set_car),
+    Primitive::fixed("set-cdr!", 2, set_cdr),
+    Primitive::fixed("list-tail", 2, |args| tail("list-tail", &args[0], &args[1])),
+    Primitive::fixed("list-ref", 2, |args| {
+        Ok(nth_pair("list-ref", &args[0], &args[1])?.car())
+    }),
+    Primitive::fixed("list-set!", 3, |args| {
+        let pair = Object::Pair(nth_pair("list-set!", &args[0], &args[1])?);
+        pair_mut(&pair, "list-set!")?.set_car(args[2].clone());
+        Ok(Object::Unspecified)
+    }),
+    Primitive::fixed("memq", 2, |args| member("memq", Same::Eq, args)),
+    Primitive::fixed("memv", 2, |args| member("memv", Same::Eqv, args)),
+    Primitive::range("member", 2, 3, |args| member("member", Same::Equal, args)),
+    Primitive::fixed("assq", 2, |args| assoc("assq", Same::Eq, args)),
+    Primitive::fixed("assv", 2, |args| assoc("assv", Same::Eqv, args)),
+    Primitive::range("assoc", 2, 3, |args| assoc("assoc", Same::Equal, args)),
+    Primitive::variadic("map", 2, map),
+    Primitive::variadic("for-each", 2, for_each),
+    Primitive::variadic("fold-left", 3, fold_left),
+    Primitive::variadic("fold-right", 3, fold_right),
+    Primitive::fixed("reduce", 3, reduce),
+    Primitive::fixed("filter", 2, |args| {
+        let (kept, _) = partition_by(args, "filter")?;
+        Ok(vec_to_list(kept, Object::Null))
+    }),
+    Primitive::fixed("remove", 2, |args| {
+        let (_, removed) = partition_by(args, "remove")?;
+        Ok(vec_to_list(removed, Object::Null))
+    }),
+    Primitive::fixed("partition", 2, |args| {
+        let (kept, removed) = partition_by(args, "partition")?;
+        Ok(values(vec![
+            vec_to_list(kept, Object::Null),
+            vec_to_list(removed, Object::Null),
+        ]))
+    }),
+    Primitive::fixed("list-sort", 2, |args| list_sort(args, "list-sort")),
+    Primitive::fixed("sort", 2, |args| list_sort(args, "sort")),
+];
+
+/// Defines `ACCESSORS`, the compositions of `car` and `cdr`, each given as
+/// the accessors it applies in order: `cadr` takes the `cdr` first.
+macro_rules! accessors {
+    ($($name:literal => $($op:ident)+;)*) => {
+        pub const ACCESSORS: &[Primitive] = &[$(
+            Primitive::fixed($name, 1, |args| {
+                let obj = args[0].clone();
+                $(let obj = $op($name, &obj)?;)+
+                Ok(obj)
+            }),
+        )*];
+    };
+}
+
+accessors! {
+    "caar" => car car;
+    "cadr" => cdr car;
+    "cdar" => car cdr;
+    "cddr" => cdr cdr;
+    "caaar" => car car car;
+    "caadr" => cdr car car;
+    "cadar" => car cdr car;
+    "caddr" => cdr cdr car;
+    "cdaar" => car car cdr;
+    "cdadr" => cdr car cdr;
+    "cddar" => car cdr cdr;
+    "cdddr" => cdr cdr cdr;
+    "caaaar" => car car car car;
+    "caaadr" => cdr car car car;
+    "caadar" => car cdr car car;
+    "caaddr" => cdr cdr car car;
+    "cadaar" => car car cdr car;
+    "cadadr" => cdr car cdr car;
+    "caddar" => car cdr cdr car;
+    "cadddr" => cdr cdr cdr car;
+    "cdaaar" => car car car cdr;
+    "cdaadr" => cdr car car cdr;
+    "cdadar" => car cdr car cdr;
+    "cdaddr" => cdr cdr car cdr;
+    "cddaar" => car car cdr cdr;
+    "cddadr" => cdr car cdr cdr;
+    "cdddar" => car cdr cdr cdr;
+    "cddddr" => cdr cdr cdr cdr;
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use crate::testing::{check, check_errors, output};
+
+    #[test]
+    fn pairs() {
+        check(&[
+            ("(cons 1 2)", "(1 . 2)"),
+            ("(cons 1 '(2))", "(1 2)"),
+            ("(car '(a b))", "a"),
+            ("(cdr '(a b))", "(b)"),
+            (
+                "(map pair? (list '(1) (cons 1 2) '() #(1)))",
+                "(#t #t #f #f)",
+            ),
+            ("(map null? (list '() '(1) #f))", "(#t #f #f)"),
+            (
+                "(define p (cons 1 2)) (set-car! p 3) (set-cdr! p '()) p",
+                "(3)",
+            ),
+        ]);
+        check_errors(&[
+            ("(car '())", "car: expected a pair ()"),
+            ("(cdr 5)", "cdr: expected a pair 5"),
+            (
+                "(guard (e ((string? e) 'no)) (car '()))",
+                "car: expected a pair ()",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn composed_accessors() {
+        check(&[
+            ("(cadr '(1 2 3))", "2"),
+            ("(caddr '(1 2 3))", "3"),
+            ("(caar '((a) b))", "a"),
+            ("(cdar '((a b) c))", "(b)"),
+            ("(cddr '(1 2 3))", "(3)"),
+            ("(cadddr '(1 2 3 4))", "4"),
+            ("(cddddr '(1 2 3 4 5))", "(5)"),
+            ("(caaaar '((((x)))))", "x"),
+        ]);
+        check_errors(&[
+            ("(cadr '(1))", "cadr: expected a pair ()"),
+            ("(caar '(1))", "caar: expected a pair 1"),
+        ]);
+    }
+
+    #[test]
+    fn list_length_append_reverse() {
+        check(&[
+            ("(list 1 (+ 1 1) 'c)", "(1 2 c)"),
+            ("(list)", "()"),
+            ("(length '(a b c))", "3"),
+            ("(length '())", "0"),
+            ("(append '(1 2) '(3) 4)", "(1 2 3 . 4)"),
+            ("(append)", "()"),
+            ("(append '() 'x)", "x"),
+            (
+                "(define tail '(3)) (eq? (cddr (append '(1 2) tail)) tail)",
+                "#t",
+            ),
+            ("(reverse '(1 (2 3) 4))", "(4 (2 3) 1)"),
+            ("(reverse '())", "()"),
+        ]);
+        check_errors(&[
+            ("(length '(1 . 2))", "length: expected a list (1 . 2)"),
+            (
+                "(define c (list 1 2)) (set-cdr! (cdr c) c) (length c)",
+                "length: expected a list",
+            ),
+            ("(append '(1) 2 '(3))", "append: expected a list 2"),
+            ("(reverse '(1 . 2))", "reverse: expected a list"),
+        ]);
+    }
+
+    #[test]
+    fn map_and_for_each() {
+        check(&[
+            ("(map (lambda (x) (* x x)) '(1 2 3))", "(1 4 9)"),
+            ("(map + '(1 2) '(10 20))", "(11 22)"),
+            ("(map + '(1 2 3) '(10 20))", "(11 22)"),
+            ("(map car '())", "()"),
+            (
+                "(define seen '())
+                 (for-each (lambda (x y) (set! seen (cons (list x y) seen))) '(a b c) '(1 2))
+                 (reverse seen)",
+                "((a 1) (b 2))",
+            ),
+        ]);
+        assert_eq!(output("(for-each display '(1 2 3))"), "123");
+        check_errors(&[("(map car '(1))", "car: expected a pair 1")]);
+    }
+
+    #[test]
+    fn indexing() {
+        check(&[
+            ("(list-ref '(a b c) 1)", "b"),
+            ("(list-tail '(a b c) 2)", "(c)"),
+            ("(list-tail '(a b c) 3)", "()"),
+            (
+                "(define shared (list 1 2 3))
+                 (define alias shared)
+                 (list-set! alias 1 'two)
+                 shared",
+                "(1 two 3)",
+            ),
+        ]);
+        check_errors(&[
+            ("(list-ref '(a b c) 3)", "list-ref: index out of range 3"),
+            ("(list-ref '(a b c) -1)", "list-ref:"),
+            ("(list-tail '(a b) 3)", "list-tail: index out of range 3"),
+            (
+                "(list-set! (list 'a) 2 'x)",
+                "list-set!: index out of range 2",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn searching() {
+        check(&[
+            ("(memq 'c '(a b c d))", "(c d)"),
+            ("(memq 'z '(a b))", "#f"),
+            ("(memq (list 1) '((1)))", "#f"),
+            ("(memv 1.5 '(1 1.5 2))", "(1.5 2)"),
+            ("(memv 1 '(1.0 2))", "#f"),
+            ("(member (list 1) '(0 (1) 2))", "((1) 2)"),
+            ("(member 2.0 '(1 2 3) =)", "(2 3)"),
+            ("(member \"b\" '(\"a\"))", "#f"),
+            ("(assq 'b '((a 1) (b 2)))", "(b 2)"),
+            ("(assq (list 'a) '(((a)) (b)))", "#f"),
+            ("(assv 2 '((1 one) (2 two)))", "(2 two)"),
+            ("(assv 5 '((1 one)))", "#f"),
+            ("(assoc '(a) '(((a) x) (b y)))", "((a) x)"),
+            ("(assoc 2.0 '((1 one) (2 two)) =)", "(2 two)"),
+            ("(assoc \"z\" '((\"a\" 1)))", "#f"),
+        ]);
+    }
+
+    #[test]
+    fn copying_and_making_lists() {
+        check(&[
+            (
+                "(define a (list 1 2 3)) (define b (list-copy a)) (list (equal? a b) (eq? a b))",
+                "(#t #f)",
+            ),
+            (
+                "(define a (list 1 2)) (define b (list-copy a)) (set-car! b 9) a",
+                "(1 2)",
+            ),
+            ("(list-copy '(1 2 . 3))", "(1 2 . 3)"),
+            ("(list-copy 5)", "5"),
+            ("(make-list 3 'x)", "(x x x)"),
+            ("(make-list 0)", "()"),
+            ("(length (make-list 3))", "3"),
+            ("(length (make-list 1000000 0))", "1000000"),
+        ]);
+        check_errors(&[(
+            "(make-list -1)",
+            "make-list: expected an exact non-negative integer -1",
+        )]);
+    }
+
+    #[test]
+    fn proper_lists() {
+        check(&[
+            ("(list? '(1 2 3))", "#t"),
+            ("(list? '())", "#t"),
+            ("(list? '(1 . 2))", "#f"),
+            ("(list? 'a)", "#f"),
+            (
+                "(define c (list 1 2 3)) (set-cdr! (cddr c) c) (list? c)",
+                "#f",
+            ),
+            ("(define c (list 1)) (set-cdr! c c) (list? c)", "#f"),
+        ]);
+    }
+
+    #[test]
+    #[cfg(feature = "pair-pool")]
+    fn let_go_pairs_are_reused() {
+        use super::{alloc, POOL};
+        use crate::{sync::Rc, Object};
+
+        // on a thread of its own, for a pool of its own
+        crate::with_stack(|| {
+            let list = Object::list((0..10).map(Object::int));
+            let Object::Pair(first) = &list else {
+                unreachable!()
+            };
+            let Object::Pair(second) = first.cdr() else {
+                unreachable!()
+            };
+            let address = Rc::as_ptr(&second);
+            drop(second);
+            drop(list);
+            // all but the first pair went to the pool, emptied out
+            assert_eq!(POOL.with(|pool| pool.borrow().len()), 9);
+            let reused: Vec<_> = (0..9)
+                .map(|i| alloc(Object::int(i), Object::Null))
+                .collect();
+            assert!(reused.iter().any(|pair| Rc::as_ptr(pair) == address));
+            assert_eq!(POOL.with(|pool| pool.borrow().len()), 0);
+            // and they're as good as new
+            for (i, pair) in reused.iter().enumerate() {
+                assert!(crate::eqv(&pair.car(), &Object::int(i as i64)));
+                assert!(matches!(pair.cdr(), Object::Null));
+            }
+        });
+    }
+
+    #[test]
+    fn folds() {
+        check(&[
+            // the accumulator comes first from the left and last from the right
+            ("(fold-left cons '() '(1 2 3))", "(((() . 1) . 2) . 3)"),
+            ("(fold-right cons '() '(1 2 3))", "(1 2 3)"),
+            ("(fold-left list '() '(1 2) '(a b c))", "((() 1 a) 2 b)"),
+            ("(fold-right list 'end '(1 2) '(a b))", "(1 a (2 b end))"),
+            ("(fold-left + 0 '())", "0"),
+            ("(fold-left - 0 '(1 2 3))", "-6"),
+            ("(fold-right - 0 '(1 2 3))", "2"),
+            ("(reduce + 0 '(1 2 3 4))", "10"),
+            ("(reduce + 0 '())", "0"),
+            ("(reduce list 'none '(a))", "a"),
+            // each element goes before what was returned last
+            ("(reduce list 'none '(1 2 3))", "(3 (2 1))"),
+        ]);
+        check_errors(&[
+            ("(fold-left cons '() 'a)", "fold-left: expected a list a"),
+            ("(reduce + 0 '(1 . 2))", "reduce: expected a list (1 . 2)"),
+        ]);
+    }
+
+    #[test]
+    fn filtering() {
+        check(&[
+            ("(filter odd? '(1 2 3 4))", "(1 3)"),
+            ("(remove odd? '(1 2 3 4))", "(2 4)"),
+            ("(filter odd? '())", "()"),
+            ("(filter (lambda (x) #t) '(a b))", "(a b)"),
+            (
+                "(call-with-values (lambda () (partition odd? '(1 2 3 4))) list)",
+                "((1 3) (2 4))",
+            ),
+            (
+                "(call-with-values (lambda () (partition odd? '())) list)",
+                "(() ())",
+            ),
+            // anything but #f counts as kept
+            ("(filter (lambda (x) (memv x '(2 3))) '(1 2 3 4))", "(2 3)"),
+            ("(let ((l (list 1 2 3))) (filter odd? l) l)", "(1 2 3)"),
+        ]);
+        check_errors(&[
+            ("(filter odd? 'a)", "filter: expected a list a"),
+            ("(partition odd? '(1 a))", "odd?: expected a number a"),
+        ]);
+    }
+
+    #[test]
+    fn circular_lists_are_caught() {
+        // a list of `len` of `fill` whose last pair leads back to its first
+        let circular = |len: usize, fill: &str| {
+            format!(
+                "(define c (make-list {len} '{fill}))
+                 (set-cdr! (list-tail c {}) c)",
+                len - 1
+            )
+        };
+        check(&[
+            // what's found before going round is still found
+            (&format!("{} (car (memq 'x c))", circular(3, "x")), "x"),
+            (
+                &format!("{} (assq 'k c)", circular(3, "(k . v)")),
+                "(k . v)",
+            ),
+        ]);
+        let mut cases = Vec::new();
+        for len in [1, 2, 3, 10] {
+            for call in [
+                "(list-copy c)",
+                "(memq 'y c)",
+                "(memv 1 c)",
+                "(member \"y\" c)",
+                "(member 'y c eq?)",
+                "(assq 'y c)",
+                "(assv 1 c)",
+                "(assoc \"y\" c)",
+            ] {
+                let who = &call[1..call.find(' ').unwrap()];
+                let src = format!("{} {call}", circular(len, "(x . x)"));
+                cases.push((src, format!("{who}: expected a list")));
+            }
+        }
+        let cases: Vec<_> = cases
+            .iter()
+            .map(|(a, b)| (a.as_str(), b.as_str()))
+            .collect();
+        check_errors(&cases);
+    }
+}
@@ -0,0 +1,203 @@
+#[cfg(feature = "std")]
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[cfg(feature = "std")]
+use crate::{cons, env::interaction_environment, eval::eval, read, string, sync::RefCell, vector};
+use crate::{env::Env, error::Error, eval::Primitive, sync::Rc, Object};
+
+#[cfg(feature = "std")]
+thread_local! {
+    /// The files being loaded, innermost last.
+    static LOADING: RefCell<Vec<PathBuf>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Where `name` refers to: relative names are taken relative to the
+/// directory of the file being loaded, if there is one.
+#[cfg(feature = "std")]
+fn resolve(name: &Path) -> PathBuf {
+    LOADING.with(
+        |loading| match loading.borrow().last().and_then(|f| f.parent()) {
+            Some(dir) if name.is_relative() => dir.join(name),
+            _ => name.to_owned(),
+        },
+    )
+}
+
+#[cfg(feature = "std")]
+fn file_name(who: &str, obj: &Object) -> Result<PathBuf, Error> {
+    match obj {
+        Object::String(s) => Ok(resolve(Path::new(&s.borrow().iter().collect::<String>()))),
+        _ => Err(Error::wrong_type(who, "a file name", obj)),
+    }
+}
+
+/// Every datum in the file `path`.
+#[cfg(feature = "std")]
+fn read_file(path: &Path) -> Result<Vec<Object>, Error> {
+    match fs::read_to_string(path) {
+        Ok(text) => read::read_str(&text).map_err(|e| in_file(e, path, &text)),
+        Err(e) => Err(Error::file(
+            format!("can't read file: {e}"),
+            vec![string::make(path.display().to_string().chars())],
+        )),
+    }
+}
+
+/// A read error from the file `path`, whose contents are `text`, saying
+/// in its message where in the file it was. Left with a span, it would be
+/// taken to be somewhere in whatever text loaded the file.
+#[cfg(feature = "std")]
+fn in_file(error: Error, path: &Path, text: &str) -> Error {
+    let Error::Raise(Object::Condition(c)) = &error else {
+        return error;
+    };
+    let Some(span) = c.span.get() else {
+        return error;
+    };
+    let (line, column) = span.line_and_column(text);
+    Error::read(
+        format!("{}:{line}:{column}: {}", path.display(), c.message),
+        c.irritants.clone(),
+    )
+}
+
+/// Evaluates the datums of the file `path` in `env`, with relative names
+/// resolved against its directory meanwhile, and returns the value of the
+/// last one.
+#[cfg(feature = "std")]
+fn eval_file(path: PathBuf, fold_case: bool, env: &Rc<Env>) -> Result<Object, Error> {
+    struct Loading;
+    impl Drop for Loading {
+        fn drop(&mut self) {
+            LOADING.with(|loading| loading.borrow_mut().pop());
+        }
+    }
+
+    let mut datums = read_file(&path)?;
+    if fold_case {
+        datums = datums.iter().map(fold).collect();
+    }
+    LOADING.with(|loading| loading.borrow_mut().push(path));
+    let _loading = Loading;
+    let mut value = Object::Unspecified;
+    for datum in datums {
+        value = eval(datum, env)?;
+    }
+    Ok(value)
+}
+
+/// `(load filename [env])`: evaluates the contents of a file one datum at
+/// a time, in the interaction environment unless given another.
+#[cfg(feature = "std")]
+fn load(args: &[Object]) -> Result<Object, Error> {
+    let path = file_name("load", &args[0])?;
+    let env = match args.get(1) {
+        None => interaction_environment(),
+        Some(Object::Environment(env)) => env.clone(),
+        Some(other) => return Err(Error::wrong_type("load", "an environment", other)),
+    };
+    eval_file(path, false, &env)?;
+    Ok(Object::Unspecified)
+}
+
+/// Evaluates an `include` or `include-ci` form, whose operands name files.
+///
+/// The files' contents are evaluated in `env` as if they had been written
+/// in place of the form, so definitions in them end up wherever the form
+/// is. Unlike spliced-in code, the last of them isn't in tail position.
+#[cfg(feature = "std")]
+pub fn include(operands: &[Object], fold_case: bool, env: &Rc<Env>) -> Result<Object, Error> {
+    let who = if fold_case { "include-ci" } else { "include" };
+    let paths = operands
+        .iter()
+        .map(|operand| file_name(who, operand))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut value = Object::Unspecified;
+    for path in paths {
+        value = eval_file(path, fold_case, env)?;
+    }
+    Ok(value)
+}
+
+/// `datum` as if it had been read with `#!fold-case` in effect.
+#[cfg(feature = "std")]
+fn fold(datum: &Object) -> Object {
+    match datum {
+        Object::Symbol(name) => Object::Symbol(Rc::from(name.to_lowercase())),
+        Object::Pair(pair) => cons(fold(&pair.car()), fold(&pair.cdr())),
+        Object::Vector(items) => vector::make(items.borrow().iter().map(fold).collect()),
+        _ => datum.clone(),
+    }
+}
+
+/// Without the `std` feature there are no files to read, and `include`
+/// can only say so.
+#[cfg(not(feature = "std"))]
+pub fn include(operands: &[Object], fold_case: bool, _env: &Rc<Env>) -> Result<Object, Error> {
+    let who = if fold_case { "include-ci" } else { "include" };
+    if let Some(operand) = operands
+        .iter()
+        .find(|operand| !matches!(operand, Object::String(_)))
+    {
+        return Err(Error::wrong_type(who, "a file name", operand));
+    }
+    match operands.first() {
+        Some(name) => Err(Error::file(
+            "can't read files without the std feature",
+            vec![name.clone()],
+        )),
+        None => Ok(Object::Unspecified),
+    }
+}
+
+pub const PRIMITIVES: &[Primitive] = &[
+    #[cfg(feature = "std")]
+    Primitive::range("load", 1, 2, load),
+];
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::testing::{check, check_errors, temp_path};
+    use std::fs;
+
+    #[test]
+    fn load_and_include() {
+        let dir = temp_path("load");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            format!("{dir}/main.scm"),
+            "(define (double x) (* 2 x))\n(include \"part.scm\")\n",
+        )
+        .unwrap();
+        fs::write(
+            format!("{dir}/part.scm"),
+            "(define (quadruple x) (double (double x)))",
+        )
+        .unwrap();
+        fs::write(format!("{dir}/shout.scm"), "(DEFINE LOUD 'YES)").unwrap();
+        check(&[
+            (
+                &format!(
+                    "(load {:?}) (list (double 4) (quadruple 1))",
+                    format!("{dir}/main.scm")
+                ),
+                "(8 4)",
+            ),
+            (
+                &format!(
+                    "(let () (include-ci {:?}) loud)",
+                    format!("{dir}/shout.scm")
+                ),
+                "yes",
+            ),
+        ]);
+        check_errors(&[(
+            &format!("(load {:?})", format!("{dir}/missing.scm")),
+            "can't read file",
+        )]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,141 @@
+//! Unicode normalization, which brings the different ways there can be of
+//! writing the same text to one: `é` as one character or as `e` and a
+//! combining acute accent, say. The decomposed forms, NFD and NFKD, break
+//! characters down as far as they go, and the composed forms, NFC and
+//! NFKC, then put them back together as far as they can. The K forms also
+//! break down characters that are only compatible with others, like the
+//! ligature `ﬁ` with `f` and `i`.
+
+use alloc::vec::Vec;
+
+mod tables;
+
+/// Which normal form to put text in.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Form {
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+// the Hangul syllables, which are made of a leading consonant, a vowel and
+// an optional trailing consonant, in order
+const S_BASE: u32 = 0xAC00;
+const L_BASE: u32 = 0x1100;
+const V_BASE: u32 = 0x1161;
+const T_BASE: u32 = 0x11A7;
+const L_COUNT: u32 = 19;
+const V_COUNT: u32 = 21;
+const T_COUNT: u32 = 28;
+const N_COUNT: u32 = V_COUNT * T_COUNT;
+const S_COUNT: u32 = L_COUNT * N_COUNT;
+
+/// `chars` in the normal form `form`.
+pub fn normalize(chars: &[char], form: Form) -> Vec<char> {
+    let compatibility = matches!(form, Form::Nfkc | Form::Nfkd);
+    let decomposed = decompose(chars, compatibility);
+    match form {
+        Form::Nfc | Form::Nfkc => compose(decomposed),
+        Form::Nfd | Form::Nfkd => decomposed,
+    }
+}
+
+fn combining_class(c: char) -> u8 {
+    let classes = tables::COMBINING_CLASSES;
+    match classes.binary_search_by_key(&c, |&(first, _, _)| first) {
+        Ok(i) => classes[i].2,
+        Err(0) => 0,
+        Err(i) if c <= classes[i - 1].1 => classes[i - 1].2,
+        Err(_) => 0,
+    }
+}
+
+fn lookup(table: &[(char, &'static str)], c: char) -> Option<&'static str> {
+    let i = table.binary_search_by_key(&c, |&(c, _)| c).ok()?;
+    Some(table[i].1)
+}
+
+/// `chars` broken down as far as they go, with the combining marks after
+/// each character in canonical order.
+fn decompose(chars: &[char], compatibility: bool) -> Vec<char> {
+    let mut decomposed = Vec::with_capacity(chars.len());
+    for &c in chars {
+        let s = (c as u32).wrapping_sub(S_BASE);
+        if s < S_COUNT {
+            decomposed.extend(char::from_u32(L_BASE + s / N_COUNT));
+            decomposed.extend(char::from_u32(V_BASE + s % N_COUNT / T_COUNT));
+            if !s.is_multiple_of(T_COUNT) {
+                decomposed.extend(char::from_u32(T_BASE + s % T_COUNT));
+            }
+            continue;
+        }
+        let compatible = compatibility
+            .then(|| lookup(tables::COMPATIBILITY, c))
+            .flatten();
+        match compatible.or_else(|| lookup(tables::CANONICAL, c)) {
+            Some(parts) => decomposed.extend(parts.chars()),
+            None => decomposed.push(c),
+        }
+    }
+    // each run of combining marks is sorted by class, stably, so that
+    // marks of the same class stay in the order they were in
+    let mut start = 0;
+    while start < decomposed.len() {
+        let len = decomposed[start..]
+            .iter()
+            .take_while(|&&c| combining_class(c) != 0)
+            .count();
+        decomposed[start..start + len].sort_by_key(|&c| combining_class(c));
+        start += len.max(1);
+    }
+    decomposed
+}
+
+/// What `a` and `b` compose to together, if anything.
+fn compose_pair(a: char, b: char) -> Option<char> {
+    let (a, b) = (a as u32, b as u32);
+    if (L_BASE..L_BASE + L_COUNT).contains(&a) && (V_BASE..V_BASE + V_COUNT).contains(&b) {
+        let lv = (a - L_BASE) * N_COUNT + (b - V_BASE) * T_COUNT;
+        return char::from_u32(S_BASE + lv);
+    }
+    let s = a.wrapping_sub(S_BASE);
+    if s < S_COUNT && s.is_multiple_of(T_COUNT) && (T_BASE + 1..T_BASE + T_COUNT).contains(&b) {
+        return char::from_u32(a + b - T_BASE);
+    }
+    let (a, b) = (char::from_u32(a)?, char::from_u32(b)?);
+    let compositions = tables::COMPOSITIONS;
+    let i = compositions
+        .binary_search_by_key(&(a, b), |&(a, b, _)| (a, b))
+        .ok()?;
+    Some(compositions[i].2)
+}
+
+/// Decomposed `chars` put back together as far as they go. A combining
+/// mark composes with the last character before it that isn't one, as
+/// long as nothing between them has a class of its own or higher, which
+/// would be blocking it.
+fn compose(chars: Vec<char>) -> Vec<char> {
+    let mut composed: Vec<char> = Vec::with_capacity(chars.len());
+    let mut starter = None;
+    // the class of the last character kept, if it isn't the starter
+    let mut last_class = None;
+    for c in chars {
+        let class = combining_class(c);
+        if let Some(i) = starter {
+            let blocked = matches!(last_class, Some(last) if last >= class);
+            if let Some(pair) = compose_pair(composed[i], c).filter(|_| !blocked) {
+                composed[i] = pair;
+                continue;
+            }
+        }
+        if class == 0 {
+            starter = Some(composed.len());
+            last_class = None;
+        } else {
+            last_class = Some(class);
+        }
+        composed.push(c);
+    }
+    composed
+}
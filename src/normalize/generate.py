@@ -0,0 +1,79 @@
+# Writes out tables.rs, the Unicode data that normalization needs, from
+# the version of the Unicode Character Database that Python's unicodedata
+# has:
+#
+#     python3 generate.py > tables.rs
+
+import unicodedata as u
+
+def lit(s):
+    return ''.join(ch if 0x20 <= ord(ch) < 0x7f and ch not in '\\"\'' else '\\u{%X}' % ord(ch) for ch in s)
+
+def clit(ch):
+    return "'%s'" % lit(ch)
+
+def is_hangul(cp):
+    return 0xAC00 <= cp <= 0xD7A3
+
+canonical, compatibility, compositions, classes = [], [], [], []
+run = None
+for cp in range(0x110000):
+    if 0xD800 <= cp <= 0xDFFF:
+        continue
+    c = chr(cp)
+    nfd, nfkd = u.normalize('NFD', c), u.normalize('NFKD', c)
+    if not is_hangul(cp):
+        if nfd != c:
+            canonical.append((c, nfd))
+        if nfkd != nfd:
+            compatibility.append((c, nfkd))
+        d = u.decomposition(c)
+        if d and not d.startswith('<'):
+            parts = [chr(int(x, 16)) for x in d.split()]
+            if len(parts) == 2 and u.normalize('NFC', c) == c:
+                compositions.append((parts[0], parts[1], c))
+    k = u.combining(c)
+    if run and run[2] == k and ord(run[1]) == cp - 1:
+        run[1] = c
+    else:
+        if run and run[2]:
+            classes.append(tuple(run))
+        run = [c, c, k]
+if run[2]:
+    classes.append(tuple(run))
+compositions.sort()
+
+out = []
+w = out.append
+w('//! Unicode %s data for normalization, generated from Python\'s' % u.unidata_version)
+w('//! `unicodedata` by `generate.py`. Hangul syllables are left out, since')
+w('//! they decompose and compose by arithmetic instead.')
+w('')
+w('/// The canonical combining classes other than zero, as ranges of')
+w('/// characters in order that all have the same class.')
+w('pub const COMBINING_CLASSES: &[(char, char, u8)] = &[')
+for a, b, k in classes:
+    w('    (%s, %s, %d),' % (clit(a), clit(b), k))
+w('];')
+w('')
+w('/// The full canonical decompositions of the characters that have one,')
+w('/// in order, already in canonical order themselves.')
+w('pub const CANONICAL: &[(char, &str)] = &[')
+for c, d in canonical:
+    w('    (%s, "%s"),' % (clit(c), lit(d)))
+w('];')
+w('')
+w('/// The full compatibility decompositions of the characters whose')
+w('/// compatibility decomposition isn\'t their canonical one, in order.')
+w('pub const COMPATIBILITY: &[(char, &str)] = &[')
+for c, d in compatibility:
+    w('    (%s, "%s"),' % (clit(c), lit(d)))
+w('];')
+w('')
+w('/// The pairs of characters that compose canonically, in order, with')
+w('/// what they compose to.')
+w('pub const COMPOSITIONS: &[(char, char, char)] = &[')
+for a, b, c in compositions:
+    w('    (%s, %s, %s),' % (clit(a), clit(b), clit(c)))
+w('];')
+print('\n'.join(out))
@@ -0,0 +1,1004 @@
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{Add, Div, Mul, Neg, Range, Sub},
+};
+
+// the tests have std, whose own methods come first
+#[cfg(not(any(feature = "std", test)))]
+use crate::float::Float;
+use crate::{
+    bigint::BigInt,
+    error::Error,
+    eval::{values, Primitive},
+    sync::Rc,
+    Object,
+};
+
+/// Numbers compare with `==` and `<` by value, so `1`, `1.` and `2/2` are
+/// all equal; telling exact from inexact is up to `eqv?`.
+///
+/// Exact numbers never overflow: integers too big for an `i64` become
+/// `Big`, and fractions keep their parts as big integers throughout.
+#[derive(Debug, Clone)]
+pub enum Number {
+    Integer(i64),
+    /// An exact integer that doesn't fit an `i64`. Ones that do are always
+    /// `Integer`s, so that the common case stays cheap.
+    Big(Rc<BigInt>),
+    Rational(Rc<Ratio>),
+    Real(f64),
+}
+
+/// An exact fraction in lowest terms, with a denominator above 1.
+#[derive(Debug)]
+pub struct Ratio {
+    num: BigInt,
+    den: BigInt,
+}
+
+impl Number {
+    /// The exact integer `i`, as an `Integer` if it fits one.
+    pub fn integer(i: BigInt) -> Number {
+        match i.to_i64() {
+            Some(i) => Number::Integer(i),
+            None => Number::Big(Rc::new(i)),
+        }
+    }
+
+    fn from_i128(i: i128) -> Number {
+        match i64::try_from(i) {
+            Ok(i) => Number::Integer(i),
+            Err(_) => Number::integer(BigInt::from(i)),
+        }
+    }
+
+    /// The exact integer written as `digits` in `radix`, or `None` if they
+    /// aren't all digits in that radix.
+    pub fn from_digits(digits: &str, radix: u32) -> Option<Number> {
+        match i64::from_str_radix(digits, radix) {
+            Ok(i) if digits.chars().all(|c| c.is_digit(radix)) => Some(Number::Integer(i)),
+            _ => BigInt::from_digits(digits, radix).map(Number::integer),
+        }
+    }
+
+    /// `num/den` in lowest terms, or `None` if `den` is zero.
+    pub fn rational(num: BigInt, den: BigInt) -> Option<Number> {
+        if den.is_zero() {
+            return None;
+        }
+        let g = num.gcd(&den);
+        let (mut num, mut den) = (num.div_rem(&g).0, den.div_rem(&g).0);
+        if den.is_negative() {
+            (num, den) = (-num, -den);
+        }
+        match den.to_i64() {
+            Some(1) => Some(Number::integer(num)),
+            _ => Some(Number::Rational(Rc::new(Ratio { num, den }))),
+        }
+    }
+
+    pub fn to_inexact(&self) -> Number {
+        Number::Real(self.to_f64())
+    }
+
+    /// The exact number equal to `self`, or `None` for infinities and NaNs.
+    ///
+    /// Reals are tried against decimal denominators first, so that `#e0.1`
+    /// comes out as `1/10` rather than the binary fraction nearest to it.
+    pub fn to_exact(&self) -> Option<Number> {
+        let x = match self {
+            Number::Real(x) => *x,
+            exact => return Some(exact.clone()),
+        };
+        if !x.is_finite() {
+            return None;
+        }
+        if x.fract() == 0.0 {
+            return Some(Number::integer(BigInt::from_f64(x)));
+        }
+        let decimal = (1..=9).map(|k| 10i64.pow(k)).find_map(|den| {
+            let num = (x * den as f64).round();
+            if num / den as f64 == x && num.abs() < i64::MAX as f64 {
+                Number::rational(BigInt::from(num as i64), BigInt::from(den))
+            } else {
+                None
+            }
+        });
+        decimal.or_else(|| Some(binary_exact(x)))
+    }
+
+    pub fn is_exact(&self) -> bool {
+        !matches!(self, Number::Real(_))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        match self {
+            Number::Integer(i) => *i == 0,
+            Number::Big(_) | Number::Rational(_) => false,
+            Number::Real(x) => *x == 0.0,
+        }
+    }
+
+    pub fn is_integer(&self) -> bool {
+        match self {
+            Number::Integer(_) | Number::Big(_) => true,
+            Number::Rational(_) => false,
+            Number::Real(x) => x.is_finite() && x.fract() == 0.0,
+        }
+    }
+
+    fn to_f64(&self) -> f64 {
+        match self {
+            Number::Integer(i) => *i as f64,
+            Number::Big(i) => i.to_f64(),
+            Number::Rational(r) => ratio_to_f64(&r.num, &r.den),
+            Number::Real(x) => *x,
+        }
+    }
+
+    /// Feeds `self` to `hasher` so that numbers that are `eqv?` hash the
+    /// same. Each exact number has just the one representation, so that's
+    /// what gets hashed.
+    pub fn hash_eqv(&self, hasher: &mut impl Hasher) {
+        match self {
+            Number::Integer(i) => i.hash(hasher),
+            Number::Big(i) => i.hash(hasher),
+            Number::Rational(r) => (&r.num, &r.den).hash(hasher),
+            // `0.` and `-0.` aren't `eqv?`, but they can share a bucket
+            Number::Real(x) if *x == 0.0 => 0u64.hash(hasher),
+            Number::Real(x) => x.to_bits().hash(hasher),
+        }
+    }
+
+    /// An exact integer as a big integer, or `None` for other numbers.
+    fn to_bigint(&self) -> Option<BigInt> {
+        match self {
+            Number::Integer(i) => Some(BigInt::from(*i)),
+            Number::Big(i) => Some(BigInt::clone(i)),
+            Number::Rational(_) | Number::Real(_) => None,
+        }
+    }
+
+    /// Numerator and denominator of an exact number, or `None` for reals.
+    fn parts(&self) -> Option<(BigInt, BigInt)> {
+        match self {
+            Number::Integer(i) => Some((BigInt::from(*i), BigInt::from(1i64))),
+            Number::Big(i) => Some((BigInt::clone(i), BigInt::from(1i64))),
+            Number::Rational(r) => Some((r.num.clone(), r.den.clone())),
+            Number::Real(_) => None,
+        }
+    }
+
+    /// Applies `small` to two `Integer`s, or if it can't say, `exact` to
+    /// the parts of two exact numbers, or `inexact` to their values as
+    /// reals if either of them is inexact.
+    fn combine(
+        &self,
+        other: &Number,
+        small: impl FnOnce(i64, i64) -> Option<i64>,
+        exact: impl FnOnce((BigInt, BigInt), (BigInt, BigInt)) -> Number,
+        inexact: impl FnOnce(f64, f64) -> f64,
+    ) -> Number {
+        if let (Number::Integer(a), Number::Integer(b)) = (self, other) {
+            if let Some(n) = small(*a, *b) {
+                return Number::Integer(n);
+            }
+        }
+        match (self.parts(), other.parts()) {
+            (Some(a), Some(b)) => exact(a, b),
+            _ => Number::Real(inexact(self.to_f64(), other.to_f64())),
+        }
+    }
+}
+
+/// `num/den` as the nearest `f64`, even when neither part fits one.
+fn ratio_to_f64(num: &BigInt, den: &BigInt) -> f64 {
+    // scale the quotient to 65 bits or so, which is plenty for the 53 an
+    // f64 holds, and then scale it back in two steps in case 2^shift alone
+    // isn't a finite f64 when the result is
+    let shift = den.bits() as i64 - num.bits() as i64 + 65;
+    let quotient = match shift {
+        0.. => num.shl(shift as usize).div_rem(den).0,
+        _ => num.div_rem(&den.shl(shift.unsigned_abs() as usize)).0,
+    };
+    let half = (shift / 2) as i32;
+    quotient.to_f64() / 2f64.powi(half) / 2f64.powi(shift as i32 - half)
+}
+
+/// `num/den` in lowest terms, where `den` isn't zero.
+fn from_parts(num: BigInt, den: BigInt) -> Number {
+    Number::rational(num, den).expect("denominator is never zero")
+}
+
+impl Add for Number {
+    type Output = Number;
+
+    fn add(self, other: Number) -> Number {
+        self.combine(
+            &other,
+            i64::checked_add,
+            |(an, ad), (bn, bd)| from_parts(&(&an * &bd) + &(&bn * &ad), &ad * &bd),
+            |a, b| a + b,
+        )
+    }
+}
+
+impl Sub for Number {
+    type Output = Number;
+
+    fn sub(self, other: Number) -> Number {
+        self.combine(
+            &other,
+            i64::checked_sub,
+            |(an, ad), (bn, bd)| from_parts(&(&an * &bd) - &(&bn * &ad), &ad * &bd),
+            |a, b| a - b,
+        )
+    }
+}
+
+impl Mul for Number {
+    type Output = Number;
+
+    fn mul(self, other: Number) -> Number {
+        self.combine(
+            &other,
+            i64::checked_mul,
+            |(an, ad), (bn, bd)| from_parts(&an * &bn, &ad * &bd),
+            |a, b| a * b,
+        )
+    }
+}
+
+/// Dividing by an exact zero is an error that callers must check for
+/// beforehand; this panics on it the way integer division does.
+impl Div for Number {
+    type Output = Number;
+
+    fn div(self, other: Number) -> Number {
+        self.combine(
+            &other,
+            |a, b| a.checked_rem(b).filter(|&r| r == 0).and_then(|_| a.checked_div(b)),
+            |(an, ad), (bn, bd)| {
+                assert!(!bn.is_zero(), "exact division by zero");
+                from_parts(&an * &bd, &ad * &bn)
+            },
+            |a, b| a / b,
+        )
+    }
+}
+
+impl PartialEq for Number {
+    fn eq(&self, other: &Number) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for Number {
+    fn partial_cmp(&self, other: &Number) -> Option<Ordering> {
+        if let (Number::Integer(a), Number::Integer(b)) = (self, other) {
+            return Some(a.cmp(b));
+        }
+        match (self, other) {
+            (Number::Real(a), Number::Real(b)) => a.partial_cmp(b),
+            (Number::Real(x), exact) => cmp_real(*x, exact),
+            (exact, Number::Real(x)) => cmp_real(*x, exact).map(Ordering::reverse),
+            _ => {
+                let ((an, ad), (bn, bd)) = (self.parts()?, other.parts()?);
+                Some((&an * &bd).cmp(&(&bn * &ad)))
+            }
+        }
+    }
+}
+
+/// How the real `x` compares with the exact number `exact`, going by the
+/// exact value of `x` rather than rounding `exact` to a float, which could
+/// make it equal to floats either side of it, or overflow to an infinity.
+fn cmp_real(x: f64, exact: &Number) -> Option<Ordering> {
+    match exact {
+        _ if x.is_nan() => None,
+        _ if x.is_infinite() => Some(if x > 0.0 {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        }),
+        // small enough integers are floats exactly
+        Number::Integer(i) if i.unsigned_abs() <= 1 << f64::MANTISSA_DIGITS => {
+            x.partial_cmp(&(*i as f64))
+        }
+        _ => binary_exact(x).partial_cmp(exact),
+    }
+}
+
+/// The finite float `x` exactly, as the binary fraction that it is.
+fn binary_exact(x: f64) -> Number {
+    // doubling a float with a fractional part is always exact, and it
+    // takes at most 1074 doublings to get rid of the fraction
+    let (mut scaled, mut shift) = (x, 0);
+    while scaled.fract() != 0.0 {
+        scaled *= 2.0;
+        shift += 1;
+    }
+    match shift {
+        0 => Number::integer(BigInt::from_f64(scaled)),
+        _ => Number::rational(BigInt::from_f64(scaled), BigInt::from(1i64).shl(shift))
+            .expect("a power of two isn't zero"),
+    }
+}
+
+impl Neg for Number {
+    type Output = Number;
+
+    fn neg(self) -> Number {
+        match self {
+            Number::Integer(i) => match i.checked_neg() {
+                Some(n) => Number::Integer(n),
+                None => Number::integer(-BigInt::from(i)),
+            },
+            Number::Big(i) => Number::integer(-BigInt::clone(&i)),
+            Number::Rational(r) => Number::Rational(Rc::new(Ratio {
+                num: -r.num.clone(),
+                den: r.den.clone(),
+            })),
+            Number::Real(x) => Number::Real(-x),
+        }
+    }
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Number::Integer(i) => write!(f, "{i}"),
+            Number::Big(i) => write!(f, "{i}"),
+            Number::Rational(r) => write!(f, "{}/{}", r.num, r.den),
+            Number::Real(x) if x.is_nan() => write!(f, "+nan.0"),
+            Number::Real(x) if x.is_infinite() => {
+                write!(f, "{}inf.0", if *x > 0.0 { "+" } else { "-" })
+            }
+            // a trailing dot keeps integral reals distinguishable from exact integers
+            Number::Real(x) if x.fract() == 0.0 && x.abs() < 1e16 => write!(f, "{x}."),
+            // and beyond that, Rust would spell them out as integers
+            Number::Real(x) if x.fract() == 0.0 => write!(f, "{x:e}"),
+            Number::Real(x) => write!(f, "{x}"),
+        }
+    }
+}
+
+fn numbers(args: &[Object], who: &str) -> Result<Vec<Number>, Error> {
+    // a plain loop, since collecting into a `Result` takes most of what a
+    // call of `+` does in a debug build
+    let mut ns = Vec::with_capacity(args.len());
+    for arg in args {
+        match arg {
+            Object::Number(n) => ns.push(n.clone()),
+            _ => return Err(Error::wrong_type(who, "a number", arg)),
+        }
+    }
+    Ok(ns)
+}
+
+/// `obj` as an index or a count, which `who` needs to be an exact
+/// non-negative integer.
+pub fn index(obj: &Object, who: &str) -> Result<usize, Error> {
+    match obj {
+        Object::Number(Number::Integer(i)) if *i >= 0 => Ok(*i as usize),
+        _ => Err(Error::wrong_type(who, "an exact non-negative integer", obj)),
+    }
+}
+
+pub fn out_of_range(who: &str, k: &Object) -> Error {
+    Error::new(format!("{who}: index out of range"), vec![k.clone()])
+}
+
+/// `obj` as an index into something `len` long.
+pub fn index_below(obj: &Object, len: usize, who: &str) -> Result<usize, Error> {
+    match index(obj, who)? {
+        k if k < len => Ok(k),
+        _ => Err(out_of_range(who, obj)),
+    }
+}
+
+/// The indices into something `len` long that `bounds` picks out: an
+/// optional start, and an optional end after that.
+pub fn range(bounds: &[Object], len: usize, who: &str) -> Result<Range<usize>, Error> {
+    let start = match bounds.first() {
+        Some(obj) => index(obj, who)?,
+        None => 0,
+    };
+    let end = match bounds.get(1) {
+        Some(obj) => index(obj, who)?,
+        None => len,
+    };
+    if end > len {
+        return Err(out_of_range(who, &bounds[1]));
+    }
+    if start > end {
+        return Err(out_of_range(who, &bounds[0]));
+    }
+    Ok(start..end)
+}
+
+fn add(args: &[Object]) -> Result<Object, Error> {
+    let sum = numbers(args, "+")?.into_iter().fold(Number::Integer(0), Add::add);
+    Ok(Object::Number(sum))
+}
+
+fn mul(args: &[Object]) -> Result<Object, Error> {
+    let product = numbers(args, "*")?.into_iter().fold(Number::Integer(1), Mul::mul);
+    Ok(Object::Number(product))
+}
+
+fn sub(args: &[Object]) -> Result<Object, Error> {
+    let ns = numbers(args, "-")?;
+    Ok(Object::Number(match &ns[..] {
+        [n] => -n.clone(),
+        _ => ns[1..].iter().fold(ns[0].clone(), |a, b| a - b.clone()),
+    }))
+}
+
+fn div(args: &[Object]) -> Result<Object, Error> {
+    let ns = numbers(args, "/")?;
+    let (first, divisors) = match &ns[..] {
+        [_] => (Number::Integer(1), &ns[..]),
+        _ => (ns[0].clone(), &ns[1..]),
+    };
+    if divisors.iter().any(|d| d.is_exact() && d.is_zero()) {
+        return Err(Error::new("/: division by zero", args.to_vec()));
+    }
+    Ok(Object::Number(divisors.iter().fold(first, |a, b| a / b.clone())))
+}
+
+fn abs(args: &[Object]) -> Result<Object, Error> {
+    Ok(Object::Number(match numbers(args, "abs")?.remove(0) {
+        Number::Real(x) => Number::Real(x.abs()),
+        n if n < Number::Integer(0) => -n,
+        n => n,
+    }))
+}
+
+/// The greatest of `args` if `greatest`, or else the least, which is
+/// inexact if any of them are.
+fn extremum(args: &[Object], who: &str, greatest: bool) -> Result<Object, Error> {
+    let ns = numbers(args, who)?;
+    let mut best = &ns[0];
+    for n in &ns[1..] {
+        if (greatest && n > best) || (!greatest && n < best) {
+            best = n;
+        }
+    }
+    if ns.iter().any(|n| !n.is_exact()) {
+        return Ok(Object::Number(best.to_inexact()));
+    }
+    Ok(Object::Number(best.clone()))
+}
+
+/// Whether `holds` is true of every adjacent pair of `args`.
+fn chain(args: &[Object], who: &str, holds: fn(&Number, &Number) -> bool) -> Result<Object, Error> {
+    let ns = numbers(args, who)?;
+    Ok(Object::Boolean(ns.windows(2).all(|w| holds(&w[0], &w[1]))))
+}
+
+/// The quotient and remainder of two integers, with the quotient rounded
+/// towards negative infinity for `floor`, or towards zero otherwise.
+fn integer_division(args: &[Object], who: &str, floor: bool) -> Result<(Number, Number), Error> {
+    let ns = numbers(args, who)?;
+    if let Some(i) = ns.iter().position(|n| !n.is_integer()) {
+        return Err(Error::wrong_type(who, "an integer", &args[i]));
+    }
+    let (a, b) = (&ns[0], &ns[1]);
+    if b.is_zero() {
+        return Err(Error::new(format!("{who}: division by zero"), args.to_vec()));
+    }
+    match (a, b) {
+        (Number::Integer(a), Number::Integer(b)) => {
+            let (a, b) = (i128::from(*a), i128::from(*b));
+            let mut q = a / b;
+            if floor && a % b != 0 && (a < 0) != (b < 0) {
+                q -= 1;
+            }
+            Ok((Number::from_i128(q), Number::from_i128(a - q * b)))
+        }
+        (Number::Integer(_) | Number::Big(_), Number::Integer(_) | Number::Big(_)) => {
+            let (a, b) = (a.to_bigint().unwrap(), b.to_bigint().unwrap());
+            let (mut q, mut r) = a.div_rem(&b);
+            if floor && !r.is_zero() && r.is_negative() != b.is_negative() {
+                q = &q - &BigInt::from(1i64);
+                r = &r + &b;
+            }
+            Ok((Number::integer(q), Number::integer(r)))
+        }
+        _ => {
+            let (a, b) = (a.to_f64(), b.to_f64());
+            let q = if floor { (a / b).floor() } else { (a / b).trunc() };
+            Ok((Number::Real(q), Number::Real(a - q * b)))
+        }
+    }
+}
+
+/// Whether `args[0]` is a number that `holds` is true of.
+fn classify(args: &[Object], holds: fn(&Number) -> bool) -> Result<Object, Error> {
+    Ok(Object::Boolean(matches!(&args[0], Object::Number(n) if holds(n))))
+}
+
+/// Whether `holds` is true of `args[0]`, which `who` needs to be a number.
+fn predicate(args: &[Object], who: &str, holds: fn(&Number) -> bool) -> Result<Object, Error> {
+    Ok(Object::Boolean(holds(&numbers(&args[..1], who)?[0])))
+}
+
+/// Whether the integer `args[0]` is odd, or even if `odd` is false.
+fn parity(args: &[Object], who: &str, odd: bool) -> Result<Object, Error> {
+    let n = numbers(&args[..1], who)?.remove(0);
+    let is_odd = match n {
+        Number::Integer(i) => i % 2 != 0,
+        Number::Big(i) => !i.is_even(),
+        Number::Real(x) if n.is_integer() => x % 2.0 != 0.0,
+        _ => return Err(Error::wrong_type(who, "an integer", &args[0])),
+    };
+    Ok(Object::Boolean(is_odd == odd))
+}
+
+/// `args` as exact integers, which `who` needs them to be.
+fn exact_integers(args: &[Object], who: &str) -> Result<Vec<Number>, Error> {
+    let ns = numbers(args, who)?;
+    match ns.iter().position(|n| !matches!(n, Number::Integer(_) | Number::Big(_))) {
+        Some(i) => Err(Error::wrong_type(who, "an exact integer", &args[i])),
+        None => Ok(ns),
+    }
+}
+
+/// Folds `args` with `small` if they're all `Integer`s, or with `big` if
+/// not, starting from `identity` either way.
+fn bitwise(
+    args: &[Object],
+    who: &str,
+    identity: i64,
+    small: fn(i64, i64) -> i64,
+    big: fn(&BigInt, &BigInt) -> BigInt,
+) -> Result<Object, Error> {
+    let ns = exact_integers(args, who)?;
+    let smalls: Option<Vec<i64>> = ns
+        .iter()
+        .map(|n| match n {
+            Number::Integer(i) => Some(*i),
+            _ => None,
+        })
+        .collect();
+    Ok(Object::Number(match smalls {
+        Some(is) => Number::Integer(is.into_iter().fold(identity, small)),
+        None => Number::integer(ns.iter().fold(BigInt::from(identity), |acc, n| {
+            big(&acc, &n.to_bigint().unwrap())
+        })),
+    }))
+}
+
+/// The most bits that `expt` and `arithmetic-shift` will give a result,
+/// since asking either for a huge one is all too easy, and it would take
+/// forever to work out, if there was ever the memory for it.
+const MAX_BITS: u64 = 1 << 20;
+
+/// How many bits the exact `n` takes up, in its numerator or denominator,
+/// whichever is longer.
+fn bits(n: &Number) -> u64 {
+    match n {
+        Number::Integer(i) => (64 - i.unsigned_abs().leading_zeros()).into(),
+        Number::Big(i) => i.bits() as u64,
+        Number::Rational(r) => r.num.bits().max(r.den.bits()) as u64,
+        Number::Real(_) => 64,
+    }
+}
+
+fn too_big(who: &str, args: &[Object]) -> Error {
+    Error::new(format!("{who}: result too big"), args.to_vec())
+}
+
+/// `args[0]` times 2^`args[1]`, rounded down when that's a fraction.
+fn arithmetic_shift(args: &[Object]) -> Result<Object, Error> {
+    let n = exact_integers(&args[..1], "arithmetic-shift")?.remove(0);
+    let k = match args[1] {
+        Object::Number(Number::Integer(k)) => k,
+        _ => return Err(Error::wrong_type("arithmetic-shift", "a small exact integer", &args[1])),
+    };
+    if k > 0 && !n.is_zero() && bits(&n).saturating_add(k as u64) > MAX_BITS {
+        return Err(too_big("arithmetic-shift", args));
+    }
+    Ok(Object::Number(match n {
+        Number::Integer(i) if k < 0 => Number::Integer(i >> k.unsigned_abs().min(63)),
+        Number::Integer(i) if k < 64 && (i << k) >> k == i => Number::Integer(i << k),
+        n => Number::integer(n.to_bigint().unwrap().arithmetic_shift(k)),
+    }))
+}
+
+/// How many bits of the exact integer `args[0]` are 1, or 0 if it's
+/// negative, since then there are endlessly many 1s in two's complement.
+fn bit_count(args: &[Object]) -> Result<Object, Error> {
+    let n = exact_integers(args, "bit-count")?.remove(0);
+    // the 0s of a negative n are the 1s of its complement, -n - 1
+    let n = match n < Number::Integer(0) {
+        true => Number::Integer(-1) - n,
+        false => n,
+    };
+    let count = match n {
+        Number::Integer(i) => i.count_ones().into(),
+        n => n.to_bigint().unwrap().count_ones(),
+    };
+    Ok(Object::Number(Number::from_i128(count.into())))
+}
+
+/// `base` to the power `exponent`, by repeated squaring, so that it takes
+/// only as many multiplications as `exponent` has bits.
+fn power(mut base: Number, mut exponent: u64) -> Number {
+    let mut result = Number::Integer(1);
+    while exponent > 0 {
+        if exponent % 2 == 1 {
+            result = result * base.clone();
+        }
+        exponent /= 2;
+        if exponent > 0 {
+            base = base.clone() * base;
+        }
+    }
+    result
+}
+
+/// `args[0]` to the power `args[1]`, which stays exact for an exact base
+/// and an exact integer exponent, but is otherwise worked out in floats.
+fn expt(args: &[Object]) -> Result<Object, Error> {
+    let mut ns = numbers(args, "expt")?;
+    let (base, exponent) = (ns.remove(0), ns.remove(0));
+    // every bit of the base past the first makes for at least one more
+    if let Number::Integer(k) = exponent {
+        let at_least = bits(&base).saturating_sub(1).saturating_mul(k.unsigned_abs());
+        if base.is_exact() && at_least > MAX_BITS {
+            return Err(too_big("expt", args));
+        }
+    }
+    Ok(Object::Number(match exponent {
+        Number::Integer(k) if base.is_exact() && k < 0 => {
+            if base.is_zero() {
+                return Err(Error::new("expt: division by zero", args.to_vec()));
+            }
+            Number::Integer(1) / power(base, k.unsigned_abs())
+        }
+        Number::Integer(k) if base.is_exact() => power(base, k.unsigned_abs()),
+        exponent => Number::Real(float_power(base.to_f64(), exponent.to_f64(), args)?),
+    }))
+}
+
+#[cfg(feature = "std")]
+fn float_power(base: f64, exponent: f64, _args: &[Object]) -> Result<f64, Error> {
+    Ok(base.powf(exponent))
+}
+
+/// Without std there's nothing to raise a float to a fractional power
+/// with, so only whole powers can be had.
+#[cfg(not(feature = "std"))]
+fn float_power(base: f64, exponent: f64, args: &[Object]) -> Result<f64, Error> {
+    match exponent.fract() == 0.0 && exponent.abs() <= i32::MAX as f64 {
+        true => Ok(base.powi(exponent as i32)),
+        false => Err(Error::new(
+            "expt: can't raise to a fractional power without the std feature",
+            args.to_vec(),
+        )),
+    }
+}
+
+/// The floor of the square root of the exact integer `args[0]`, and what's
+/// left over after subtracting its square.
+fn integer_sqrt(args: &[Object], who: &str) -> Result<(Number, Number), Error> {
+    let n = exact_integers(args, who)?.remove(0);
+    if n < Number::Integer(0) {
+        return Err(Error::wrong_type(who, "a non-negative integer", &args[0]));
+    }
+    let root = match n {
+        Number::Integer(i) => Number::Integer(i.isqrt()),
+        ref n => Number::integer(n.to_bigint().unwrap().isqrt()),
+    };
+    let rest = n - root.clone() * root.clone();
+    Ok((root, rest))
+}
+
+fn floor_div(args: &[Object]) -> Result<Object, Error> {
+    let (q, r) = integer_division(args, "floor/", true)?;
+    Ok(values(vec![Object::Number(q), Object::Number(r)]))
+}
+
+fn truncate_div(args: &[Object]) -> Result<Object, Error> {
+    let (q, r) = integer_division(args, "truncate/", false)?;
+    Ok(values(vec![Object::Number(q), Object::Number(r)]))
+}
+
+pub const PRIMITIVES: &[Primitive] = &[
+    Primitive::fixed("number?", 1, |args| classify(args, |_| true)),
+    Primitive::fixed("complex?", 1, |args| classify(args, |_| true)),
+    Primitive::fixed("real?", 1, |args| classify(args, |_| true)),
+    // every finite real is some fraction, if only one with a huge denominator
+    Primitive::fixed("rational?", 1, |args| {
+        classify(args, |n| n.is_exact() || n.to_f64().is_finite())
+    }),
+    Primitive::fixed("integer?", 1, |args| classify(args, Number::is_integer)),
+    Primitive::fixed("exact-integer?", 1, |args| {
+        classify(args, |n| matches!(n, Number::Integer(_) | Number::Big(_)))
+    }),
+    Primitive::fixed("exact-rational?", 1, |args| classify(args, Number::is_exact)),
+    Primitive::fixed("exact?", 1, |args| predicate(args, "exact?", Number::is_exact)),
+    Primitive::fixed("inexact?", 1, |args| predicate(args, "inexact?", |n| !n.is_exact())),
+    Primitive::fixed("zero?", 1, |args| predicate(args, "zero?", Number::is_zero)),
+    Primitive::fixed("positive?", 1, |args| {
+        predicate(args, "positive?", |n| *n > Number::Integer(0))
+    }),
+    Primitive::fixed("negative?", 1, |args| {
+        predicate(args, "negative?", |n| *n < Number::Integer(0))
+    }),
+    Primitive::fixed("odd?", 1, |args| parity(args, "odd?", true)),
+    Primitive::fixed("even?", 1, |args| parity(args, "even?", false)),
+    Primitive::variadic("+", 0, add),
+    Primitive::variadic("*", 0, mul),
+    Primitive::variadic("-", 1, sub),
+    Primitive::variadic("/", 1, div),
+    Primitive::variadic("=", 2, |args| chain(args, "=", Number::eq)),
+    Primitive::variadic("<", 2, |args| chain(args, "<", Number::lt)),
+    Primitive::variadic(">", 2, |args| chain(args, ">", Number::gt)),
+    Primitive::variadic("<=", 2, |args| chain(args, "<=", Number::le)),
+    Primitive::variadic(">=", 2, |args| chain(args, ">=", Number::ge)),
+    Primitive::fixed("abs", 1, abs),
+    Primitive::variadic("min", 1, |args| extremum(args, "min", false)),
+    Primitive::variadic("max", 1, |args| extremum(args, "max", true)),
+    Primitive::fixed("square", 1, |args| {
+        let n = numbers(args, "square")?.remove(0);
+        Ok(Object::Number(n.clone() * n))
+    }),
+    Primitive::fixed("floor/", 2, floor_div),
+    Primitive::fixed("floor-quotient", 2, |args| {
+        Ok(Object::Number(integer_division(args, "floor-quotient", true)?.0))
+    }),
+    Primitive::fixed("floor-remainder", 2, |args| {
+        Ok(Object::Number(integer_division(args, "floor-remainder", true)?.1))
+    }),
+    Primitive::fixed("truncate/", 2, truncate_div),
+    Primitive::fixed("truncate-quotient", 2, |args| {
+        Ok(Object::Number(integer_division(args, "truncate-quotient", false)?.0))
+    }),
+    Primitive::fixed("truncate-remainder", 2, |args| {
+        Ok(Object::Number(integer_division(args, "truncate-remainder", false)?.1))
+    }),
+    Primitive::fixed("quotient", 2, |args| {
+        Ok(Object::Number(integer_division(args, "quotient", false)?.0))
+    }),
+    Primitive::fixed("remainder", 2, |args| {
+        Ok(Object::Number(integer_division(args, "remainder", false)?.1))
+    }),
+    Primitive::fixed("modulo", 2, |args| {
+        Ok(Object::Number(integer_division(args, "modulo", true)?.1))
+    }),
+    Primitive::fixed("expt", 2, expt),
+    Primitive::fixed("exact-integer-sqrt", 1, |args| {
+        let (root, rest) = integer_sqrt(args, "exact-integer-sqrt")?;
+        Ok(values(vec![Object::Number(root), Object::Number(rest)]))
+    }),
+    Primitive::fixed("integer-sqrt", 1, |args| {
+        Ok(Object::Number(integer_sqrt(args, "integer-sqrt")?.0))
+    }),
+    Primitive::variadic("bitwise-and", 0, |args| {
+        bitwise(args, "bitwise-and", -1, |a, b| a & b, |a, b| a & b)
+    }),
+    Primitive::variadic("bitwise-ior", 0, |args| {
+        bitwise(args, "bitwise-ior", 0, |a, b| a | b, |a, b| a | b)
+    }),
+    Primitive::variadic("bitwise-xor", 0, |args| {
+        bitwise(args, "bitwise-xor", 0, |a, b| a ^ b, |a, b| a ^ b)
+    }),
+    // in two's complement, flipping every bit of n makes -n - 1
+    Primitive::fixed("bitwise-not", 1, |args| {
+        let n = exact_integers(args, "bitwise-not")?.remove(0);
+        Ok(Object::Number(Number::Integer(-1) - n))
+    }),
+    Primitive::fixed("arithmetic-shift", 2, arithmetic_shift),
+    Primitive::fixed("bit-count", 1, bit_count),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{check, check_errors};
+
+    #[test]
+    fn numeric_type_predicates() {
+        let predicates = "(define (kinds n)
+                            (map (lambda (pred) (pred n))
+                                 (list number? complex? real? rational? integer? exact? inexact?
+                                       exact-integer? exact-rational?)))";
+        check(&[
+            (&format!("{predicates} (kinds 1)"), "(#t #t #t #t #t #t #f #t #t)"),
+            (&format!("{predicates} (kinds 1.0)"), "(#t #t #t #t #t #f #t #f #f)"),
+            (&format!("{predicates} (kinds 2.5)"), "(#t #t #t #t #f #f #t #f #f)"),
+            (&format!("{predicates} (kinds 1/2)"), "(#t #t #t #t #f #t #f #f #t)"),
+            (&format!("{predicates} (kinds -0.0)"), "(#t #t #t #t #t #f #t #f #f)"),
+            (&format!("{predicates} (kinds +inf.0)"), "(#t #t #t #f #f #f #t #f #f)"),
+            (&format!("{predicates} (kinds +nan.0)"), "(#t #t #t #f #f #f #t #f #f)"),
+            (
+                &format!("{predicates} (kinds 100000000000000000000)"),
+                "(#t #t #t #t #t #t #f #t #t)",
+            ),
+            ("(list (number? 'a) (integer? \"1\") (rational? #\\1))", "(#f #f #f)"),
+        ]);
+        check_errors(&[("(exact? 'a)", "exact?: expected a number a")]);
+    }
+
+    #[test]
+    fn sign_and_parity() {
+        check(&[
+            ("(map zero? (list 0 0.0 -0.0 1 1/2))", "(#t #t #t #f #f)"),
+            ("(map positive? (list 1 0 -1 1/2 0.5 +inf.0))", "(#t #f #f #t #t #t)"),
+            ("(map negative? (list -1/2 0 -0.0 -inf.0 3))", "(#t #f #f #t #f)"),
+            ("(map odd? (list 1 2 -3 3.0 0))", "(#t #f #t #t #f)"),
+            ("(map even? (list 0 2.0 -4 1 100000000000000000000))", "(#t #t #t #f #t)"),
+        ]);
+        check_errors(&[
+            ("(zero? 'a)", "zero?: expected a number a"),
+            ("(odd? 1.5)", "odd?: expected an integer 1.5"),
+            ("(even? 1/2)", "even?: expected an integer 1/2"),
+        ]);
+    }
+
+    #[test]
+    fn abs_min_max_square() {
+        check(&[
+            ("(abs -3/4)", "3/4"),
+            ("(abs -7)", "7"),
+            ("(abs -2.5)", "2.5"),
+            ("(abs -100000000000000000000)", "100000000000000000000"),
+            ("(max 1 2.0)", "2."),
+            ("(max 3 2.0)", "3."),
+            ("(min 1 2.0)", "1."),
+            ("(max 1 2 3)", "3"),
+            ("(min 1/2 1/3)", "1/3"),
+            ("(square 5)", "25"),
+            ("(square 1/2)", "1/4"),
+            ("(square -1.5)", "2.25"),
+        ]);
+        check_errors(&[("(max 1 'a)", "max: expected a number a")]);
+    }
+
+    #[test]
+    fn rationals_stay_exact() {
+        let harmonic = "(define (harmonic n)
+                          (let loop ((k 1) (sum 0))
+                            (if (> k n) sum (loop (+ k 1) (+ sum (/ 1 k))))))";
+        check(&[
+            ("(+ 1/3 1/7)", "10/21"),
+            ("(- 1/6 1/3)", "-1/6"),
+            ("(* 2/3 3/2)", "1"),
+            ("(/ 4 -6)", "-2/3"),
+            (&format!("{harmonic} (harmonic 10)"), "7381/2520"),
+            (
+                &format!("{harmonic} (harmonic 100)"),
+                concat!(
+                    "14466636279520351160221518043104131447711",
+                    "/2788815009188499086581352357412492142272"
+                ),
+            ),
+            (&format!("{harmonic} (exact? (harmonic 100))"), "#t"),
+            // past what fits in 64 bits, on either side of the bar
+            (
+                "(+ (/ 1 (expt 2 70)) (/ 1 (expt 3 45)))",
+                "4134904327268245002067/3487836826332890698160249998717337450053632",
+            ),
+            ("(* (/ (expt 2 70) (expt 3 45)) (/ (expt 3 44) (expt 2 69)))", "2/3"),
+            ("(/ (expt 10 30) (expt 10 32))", "1/100"),
+        ]);
+    }
+
+    #[test]
+    fn bitwise_operations() {
+        check(&[
+            ("(bitwise-and 12 10)", "8"),
+            ("(bitwise-ior 12 10)", "14"),
+            ("(bitwise-xor 12 10)", "6"),
+            ("(list (bitwise-and) (bitwise-ior) (bitwise-xor))", "(-1 0 0)"),
+            ("(bitwise-and 7 14 28)", "4"),
+            // negatives are in two's complement, with infinitely many 1s
+            ("(list (bitwise-and -12 10) (bitwise-ior -12 10) (bitwise-xor -12 10))", "(0 -2 -2)"),
+            ("(bitwise-not 12)", "-13"),
+            ("(bitwise-not -1)", "0"),
+            ("(arithmetic-shift 1 10)", "1024"),
+            ("(arithmetic-shift 1024 -3)", "128"),
+            // a right shift rounds down, toward negative infinity
+            ("(arithmetic-shift -1000 -3)", "-125"),
+            ("(arithmetic-shift -1 -10)", "-1"),
+            ("(arithmetic-shift 1 100)", "1267650600228229401496703205376"),
+            ("(arithmetic-shift (- (expt 2 80)) -70)", "-1024"),
+            ("(bitwise-and (+ (expt 2 70) 5) (- (expt 2 65)))", "1180591620717411303424"),
+            ("(bitwise-not (expt 2 64))", "-18446744073709551617"),
+            ("(list (bit-count 0) (bit-count 7) (bit-count -1) (bit-count -12))", "(0 3 0 3)"),
+            ("(bit-count (- (expt 2 100) 1))", "100"),
+        ]);
+        check_errors(&[
+            ("(bitwise-and 1.5 1)", "bitwise-and: expected an exact integer 1.5"),
+            ("(arithmetic-shift 1 1/2)", "arithmetic-shift: expected a small exact integer 1/2"),
+        ]);
+    }
+
+    #[test]
+    fn integer_square_roots() {
+        check(&[
+            ("(exact-integer? 2.0)", "#f"),
+            ("(exact-integer? 3)", "#t"),
+            ("(exact-integer? (expt 10 30))", "#t"),
+            ("(exact-integer? 1/2)", "#f"),
+            ("(integer-sqrt 17)", "4"),
+            ("(integer-sqrt 16)", "4"),
+            ("(integer-sqrt 0)", "0"),
+            ("(integer-sqrt 9223372036854775807)", "3037000499"),
+            ("(integer-sqrt (+ (expt 10 40) 1))", "100000000000000000000"),
+            ("(integer-sqrt (expt 2 201))", "1792728671193156477399422023278"),
+            ("(call-with-values (lambda () (exact-integer-sqrt 17)) list)", "(4 1)"),
+        ]);
+        check_errors(&[
+            ("(integer-sqrt -1)", "integer-sqrt: expected a non-negative integer -1"),
+            ("(integer-sqrt 4.0)", "integer-sqrt: expected an exact integer 4."),
+        ]);
+    }
+
+    #[test]
+    fn exact_powers() {
+        check(&[
+            ("(expt 2 64)", "18446744073709551616"),
+            ("(exact? (expt 2 64))", "#t"),
+            ("(expt 7 77)", "118181386580595879976868414312001964434038548836769923458287039207"),
+            ("(expt -3 3)", "-27"),
+            ("(expt 5 0)", "1"),
+            ("(expt 2 -3)", "1/8"),
+            ("(expt -2/3 -3)", "-27/8"),
+            ("(expt 2. 3)", "8."),
+            ("(expt 2. -2)", "0.25"),
+        ]);
+        check_errors(&[("(expt 0 -1)", "expt: division by zero")]);
+        #[cfg(feature = "std")]
+        check(&[("(expt 4 1/2)", "2.")]);
+        #[cfg(not(feature = "std"))]
+        check_errors(&[(
+            "(expt 4 1/2)",
+            "expt: can't raise to a fractional power without the std feature",
+        )]);
+    }
+
+    #[test]
+    fn big_powers_are_quick() {
+        use std::time::{Duration, Instant};
+
+        // by repeated multiplication this would take 100000 of them, each
+        // of a number as big as the result
+        let start = Instant::now();
+        check(&[
+            ("(modulo (expt 3 100000) 1000)", "1"),
+            ("(= (expt 3 100000) (* (expt 3 50000) (expt 3 50000)))", "#t"),
+            ("(bit-count (expt 2 100000))", "1"),
+        ]);
+        assert!(start.elapsed() < Duration::from_secs(5), "took {:?}", start.elapsed());
+    }
+
+    #[test]
+    fn exact_and_inexact_compare_exactly() {
+        check(&[
+            ("(= (expt 10 400) +inf.0)", "#f"),
+            ("(< (expt 10 400) +inf.0)", "#t"),
+            ("(> (- (expt 10 400)) -inf.0)", "#t"),
+            ("(> (expt 10 400) 1e308)", "#t"),
+            // 2^53 + 1 rounds to 2^53 as a float, but isn't equal to it
+            (
+                "(define big (+ (expt 2 53) 1))
+                 (define near (* 1. (expt 2 53)))
+                 (list (= big near) (< big near) (> big near) (< near big))",
+                "(#f #f #t #t)",
+            ),
+            ("(= (expt 2 53) (* 1. (expt 2 53)))", "#t"),
+            // 0.1 is a little more than 1/10
+            ("(list (= 1/10 0.1) (< 1/10 0.1) (> 0.1 1/10))", "(#f #t #t)"),
+            ("(list (= 1/2 0.5) (<= 0.5 1/2 0.5))", "(#t #t)"),
+            ("(= 12345678901234567890123 12345678901234567890123.)", "#f"),
+            ("(list (= 3 +nan.0) (< 3 +nan.0) (> 3 +nan.0))", "(#f #f #f)"),
+            ("(list (= 0 -0.) (< -1/2 -0.) (max 1/3 0.3))", "(#t #t 0.3333333333333333)"),
+        ]);
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{
+    error::Error,
+    eval::{apply, Primitive},
+    is_procedure,
+    sync::{Rc, RefCell},
+    Object,
+};
+
+/// A parameter object, as created by `make-parameter`.
+///
+/// Calling it yields its current value; `parameterize` rebinds it for a
+/// dynamic extent and puts the old value back afterwards. Every value it
+/// is given, the initial one included, goes through its converter first.
+pub struct Parameter {
+    value: RefCell<Object>,
+    converter: Option<Object>,
+}
+
+impl Parameter {
+    pub fn new(value: Object) -> Parameter {
+        Parameter {
+            value: RefCell::new(value),
+            converter: None,
+        }
+    }
+
+    /// A parameter whose values are whatever `converter` makes of the
+    /// ones it is given, starting with `value`.
+    pub fn with_converter(value: Object, converter: Object) -> Result<Parameter, Error> {
+        let value = apply(&converter, vec![value])?;
+        Ok(Parameter {
+            value: RefCell::new(value),
+            converter: Some(converter),
+        })
+    }
+
+    pub fn get(&self) -> Object {
+        self.value.borrow().clone()
+    }
+
+    /// What `value` is converted to when the parameter is given it.
+    pub fn convert(&self, value: Object) -> Result<Object, Error> {
+        match &self.converter {
+            Some(converter) => apply(converter, vec![value]),
+            None => Ok(value),
+        }
+    }
+
+    /// Changes the current value, until the innermost `parameterize` of the
+    /// parameter, if there is one, ends.
+    pub fn set(&self, value: Object) {
+        self.value.replace(value);
+    }
+
+    /// Runs `body` with the parameter bound to `value`.
+    ///
+    /// The previous value is restored even if `body` unwinds.
+    pub fn parameterize<T>(&self, value: Object, body: impl FnOnce() -> T) -> T {
+        struct Restore<'a>(&'a Parameter, Option<Object>);
+        impl Drop for Restore<'_> {
+            fn drop(&mut self) {
+                if let Some(old) = self.1.take() {
+                    self.0.value.replace(old);
+                }
+            }
+        }
+
+        let old = self.value.replace(value);
+        let _restore = Restore(self, Some(old));
+        body()
+    }
+}
+
+/// `(make-parameter value [converter])`.
+fn make_parameter(args: &[Object]) -> Result<Object, Error> {
+    let parameter = match args.get(1) {
+        None => Parameter::new(args[0].clone()),
+        Some(converter) if is_procedure(converter) => {
+            Parameter::with_converter(args[0].clone(), converter.clone())?
+        }
+        Some(other) => return Err(Error::wrong_type("make-parameter", "a procedure", other)),
+    };
+    Ok(Object::Parameter(Rc::new(parameter)))
+}
+
+pub const PRIMITIVES: &[Primitive] = &[Primitive::range("make-parameter", 1, 2, make_parameter)];
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{check, check_errors};
+
+    #[test]
+    fn parameterize_restores_the_old_value() {
+        let p = "(define p (make-parameter 1))";
+        check(&[
+            (
+                &format!(
+                    "{p} (list (p)
+                               (parameterize ((p 2)) (list (p) (parameterize ((p 3)) (p)) (p)))
+                               (p))"
+                ),
+                "(1 (2 3 2) 1)",
+            ),
+            (
+                &format!("{p} (list (call/cc (lambda (k) (parameterize ((p 2)) (k (p))))) (p))"),
+                "(2 1)",
+            ),
+            (
+                &format!(
+                    "{p} (list (guard (e (#t (list e (p)))) (parameterize ((p 2)) (raise 'oops)))
+                               (p))"
+                ),
+                "((oops 1) 1)",
+            ),
+            (
+                "(define p (make-parameter 10 (lambda (x) (* x 2))))
+                 (list (p) (parameterize ((p 3)) (p)) (p))",
+                "(20 6 20)",
+            ),
+            (
+                "(define p (make-parameter 1))
+                 (define (get) (p))
+                 (parameterize ((p 2)) (get))",
+                "2",
+            ),
+            (
+                "(define a (make-parameter 1))
+                 (define b (make-parameter 2))
+                 (parameterize ((a (b)) (b (a))) (list (a) (b)))",
+                "(2 1)",
+            ),
+        ]);
+        check_errors(&[
+            (
+                "(parameterize ((car 1)) 2)",
+                "parameterize: expected a parameter",
+            ),
+            (
+                "(define p (make-parameter 1 (lambda (x) (if (number? x) x (raise 'bad)))))
+                 (parameterize ((p 'x)) (p))",
+                "uncaught exception: bad",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn converters_run_at_creation_and_each_rebinding() {
+        let p = "(define calls 0)
+                 (define p (make-parameter 1 (lambda (x) (set! calls (+ calls 1)) (* x 1.))))";
+        check(&[
+            (&format!("{p} (list (p) calls)"), "(1. 1)"),
+            (
+                &format!("{p} (list (parameterize ((p 2)) (list (p) calls)) (p) calls)"),
+                "((2. 2) 1. 2)",
+            ),
+            (&format!("{p} (p) (p) calls"), "1"),
+            (&format!("{p} (p 5) (list (p) calls)"), "(5. 2)"),
+            (
+                "(define q (make-parameter 'x)) (parameterize ((q 'y)) (q))",
+                "y",
+            ),
+        ]);
+        check_errors(&[(
+            "(make-parameter 1 (lambda (x) (car x)))",
+            "car: expected a pair 1",
+        )]);
+    }
+}
@@ -1,48 +1,1755 @@
-use crate::Object;
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use crate::{
+    bytevector,
+    collections::VecDeque,
+    error::Error,
+    eval::{apply, Primitive},
+    io::{self, Read, Write},
+    number::{self, Number},
+    parameter::Parameter,
+    string,
+    sync::{Rc, RefCell, Threadsafe},
+    Object,
+};
+#[cfg(feature = "std")]
 use std::{
+    cell::Cell,
     fs::File,
-    io::{self, Stdin, Stdout, Write},
+    io::{stderr, stdin, stdout, BufReader, BufWriter, Seek, SeekFrom, Stderr, Stdin, Stdout},
+    path::PathBuf,
 };
 
+/// Output to stdout and files is buffered, so it only reaches the OS on
+/// `flush-output-port`, `close-port`, or when the port is dropped.
+/// Stderr is left unbuffered so diagnostics show up right away.
+///
+/// The ports onto the standard streams and files are only there with the
+/// `std` feature; without it, the host supplies any others as custom ports.
 pub enum Port {
-    Stdin(Stdin),
-    Stdout(Stdout),
-    File(File),
+    #[cfg(feature = "std")]
+    Stdin(CharReader<Stdin>),
+    #[cfg(feature = "std")]
+    Stdout(BufWriter<Stdout>),
+    #[cfg(feature = "std")]
+    Stderr(Stderr),
+    #[cfg(feature = "std")]
+    InputFile(CharReader<BufReader<File>>),
+    #[cfg(feature = "std")]
+    OutputFile(BufWriter<File>),
+    StringInput { chars: Vec<char>, pos: usize },
+    StringOutput(Vec<u8>),
+    BytevectorInput { bytes: Vec<u8>, pos: usize },
+    BytevectorOutput(Vec<u8>),
+    Custom(CharReader<Backend>),
+    /// A port made by `make-custom-input-port`, with the text its `read`
+    /// procedure has handed over that hasn't been read yet, and whether it
+    /// last said there was no more. The procedure is only ever called by
+    /// [`fill`], once nothing has the port borrowed, so that it can do
+    /// what it likes, even with the port itself.
+    CustomInput {
+        read: Object,
+        close: Option<Object>,
+        chars: VecDeque<char>,
+        eof: bool,
+    },
+    /// A port made by `make-custom-output-port`, with what's been written
+    /// to it that hasn't been handed to its `write` procedure yet, which
+    /// [`deliver`] does the same way.
+    CustomOutput {
+        write: Object,
+        close: Option<Object>,
+        pending: Vec<u8>,
+    },
+    /// A port after `close-port`. It remembers what kind of port it was
+    /// so that the type predicates keep giving the same answers.
+    Closed { input: bool, binary: bool },
 }
 
-impl Clone for Port {
-    fn clone(&self) -> Self {
+impl Port {
+    pub fn input_string(s: &str) -> Port {
+        Port::StringInput {
+            chars: s.chars().collect(),
+            pos: 0,
+        }
+    }
+
+    pub fn output_string() -> Port {
+        Port::StringOutput(Vec::new())
+    }
+
+    pub fn input_bytevector(bytes: &[u8]) -> Port {
+        Port::BytevectorInput {
+            bytes: bytes.to_vec(),
+            pos: 0,
+        }
+    }
+
+    pub fn output_bytevector() -> Port {
+        Port::BytevectorOutput(Vec::new())
+    }
+
+    /// Wraps a host-provided backend. The host can keep its own handle to
+    /// `backend` to inspect or drive it while Scheme code uses the port.
+    pub fn custom(backend: Rc<RefCell<dyn PortBackend>>) -> Port {
+        Port::Custom(CharReader::new(Backend(backend)))
+    }
+
+    pub fn is_input(&self) -> bool {
+        if let Port::Custom(r) = self {
+            return r.inner.is_input();
+        }
+        match self {
+            #[cfg(feature = "std")]
+            Port::Stdin(_) | Port::InputFile(_) => true,
+            Port::StringInput { .. }
+            | Port::BytevectorInput { .. }
+            | Port::CustomInput { .. }
+            | Port::Closed { input: true, .. } => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_output(&self) -> bool {
+        if let Port::Custom(r) = self {
+            return r.inner.is_output();
+        }
+        match self {
+            #[cfg(feature = "std")]
+            Port::Stdout(_) | Port::Stderr(_) | Port::OutputFile(_) => true,
+            Port::StringOutput(_)
+            | Port::BytevectorOutput(_)
+            | Port::CustomOutput { .. }
+            | Port::Closed { input: false, .. } => true,
+            _ => false,
+        }
+    }
+
+    pub fn is_binary(&self) -> bool {
+        if let Port::Custom(r) = self {
+            return r.inner.is_binary();
+        }
+        matches!(
+            self,
+            Port::BytevectorInput { .. }
+                | Port::BytevectorOutput(_)
+                | Port::Closed { binary: true, .. }
+        )
+    }
+
+    pub fn is_open(&self) -> bool {
+        !matches!(self, Port::Closed { .. })
+    }
+
+    /// Flushes any pending output and releases the underlying resource.
+    /// Closing an already closed port has no effect.
+    pub fn close(&mut self) -> io::Result<()> {
+        if !self.is_open() {
+            return Ok(());
+        }
+        let mut result = if self.is_output() { self.flush() } else { Ok(()) };
+        if let Port::Custom(r) = self {
+            result = result.and(r.inner.0.borrow_mut().close());
+        }
+        *self = Port::Closed {
+            input: self.is_input(),
+            binary: self.is_binary(),
+        };
+        result
+    }
+
+    pub fn is_textual(&self) -> bool {
+        !self.is_binary()
+    }
+
+    pub fn read_char(&mut self) -> io::Result<Option<char>> {
+        match self {
+            #[cfg(feature = "std")]
+            Port::Stdin(r) => {
+                flush_stdout();
+                r.read_char()
+            }
+            #[cfg(feature = "std")]
+            Port::InputFile(r) => r.read_char(),
+            Port::Custom(r) if !r.inner.is_binary() => r.read_char(),
+            // it's only the end of the text once, and the procedure is
+            // asked again after that
+            Port::CustomInput { chars, eof, .. } => Ok(chars.pop_front().or_else(|| {
+                *eof = false;
+                None
+            })),
+            Port::StringInput { chars, pos } => {
+                let c = chars.get(*pos).copied();
+                if c.is_some() {
+                    *pos += 1;
+                }
+                Ok(c)
+            }
+            Port::Closed { .. } => Err(closed()),
+            _ => Err(not_textual_input()),
+        }
+    }
+
+    pub fn peek_char(&mut self) -> io::Result<Option<char>> {
+        match self {
+            #[cfg(feature = "std")]
+            Port::Stdin(r) => {
+                flush_stdout();
+                r.peek_char()
+            }
+            #[cfg(feature = "std")]
+            Port::InputFile(r) => r.peek_char(),
+            Port::Custom(r) if !r.inner.is_binary() => r.peek_char(),
+            Port::CustomInput { chars, .. } => Ok(chars.front().copied()),
+            Port::StringInput { chars, pos } => Ok(chars.get(*pos).copied()),
+            Port::Closed { .. } => Err(closed()),
+            _ => Err(not_textual_input()),
+        }
+    }
+
+    /// Whether `read_char` is guaranteed not to block.
+    ///
+    /// For stdin we can only tell when a char has already been peeked,
+    /// so this errs on the side of `false`.
+    pub fn char_ready(&self) -> io::Result<bool> {
+        match self {
+            #[cfg(feature = "std")]
+            Port::Stdin(r) => Ok(r.peeked.is_some()),
+            // reading a regular file never blocks
+            #[cfg(feature = "std")]
+            Port::InputFile(_) => Ok(true),
+            // and nor does asking a procedure for more
+            Port::StringInput { .. } | Port::CustomInput { .. } => Ok(true),
+            Port::Custom(r) if !r.inner.is_binary() => {
+                Ok(r.peeked.is_some() || r.inner.is_ready())
+            }
+            Port::Closed { .. } => Err(closed()),
+            _ => Err(not_textual_input()),
+        }
+    }
+
+    pub fn read_u8(&mut self) -> io::Result<Option<u8>> {
+        match self {
+            Port::Custom(r) if r.inner.is_binary() => r.read_u8(),
+            Port::BytevectorInput { bytes, pos } => {
+                let b = bytes.get(*pos).copied();
+                if b.is_some() {
+                    *pos += 1;
+                }
+                Ok(b)
+            }
+            Port::Closed { .. } => Err(closed()),
+            _ => Err(not_binary_input()),
+        }
+    }
+
+    pub fn peek_u8(&mut self) -> io::Result<Option<u8>> {
         match self {
-            Self::Stdin(_) => Port::Stdin(std::io::stdin()),
-            Self::Stdout(_) => Port::Stdout(std::io::stdout()),
-            Self::File(f) => Port::File(f.try_clone().expect("couldn't clone fd")),
+            Port::Custom(r) if r.inner.is_binary() => r.peek_u8(),
+            Port::BytevectorInput { bytes, pos } => Ok(bytes.get(*pos).copied()),
+            Port::Closed { .. } => Err(closed()),
+            _ => Err(not_binary_input()),
         }
     }
+
+    pub fn u8_ready(&self) -> io::Result<bool> {
+        match self {
+            Port::Custom(r) if r.inner.is_binary() => {
+                Ok(r.peeked_u8.is_some() || r.inner.is_ready())
+            }
+            Port::BytevectorInput { .. } => Ok(true),
+            Port::Closed { .. } => Err(closed()),
+            _ => Err(not_binary_input()),
+        }
+    }
+
+    /// Whether the port can say where it's got to, for `port-position`:
+    /// the string and bytevector ports and the file ports can, and the
+    /// standard streams and custom ports can't.
+    pub fn has_position(&self) -> bool {
+        match self {
+            #[cfg(feature = "std")]
+            Port::InputFile(_) | Port::OutputFile(_) => true,
+            Port::StringInput { .. }
+            | Port::StringOutput(_)
+            | Port::BytevectorInput { .. }
+            | Port::BytevectorOutput(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the port can be moved to another position, which output
+    /// string and bytevector ports can't, only ever being added to.
+    pub fn has_set_position(&self) -> bool {
+        match self {
+            #[cfg(feature = "std")]
+            Port::InputFile(_) | Port::OutputFile(_) => true,
+            Port::StringInput { .. } | Port::BytevectorInput { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Where the port has got to: how many characters into its text a
+    /// string port is, and how many bytes into them the others are.
+    pub fn position(&mut self) -> io::Result<u64> {
+        match self {
+            #[cfg(feature = "std")]
+            Port::InputFile(r) => {
+                // what's been peeked at is still to come
+                let peeked = r.peeked.map_or(0, char::len_utf8) as u64;
+                let peeked_u8 = u64::from(r.peeked_u8.is_some());
+                Ok(r.inner.stream_position()? - peeked - peeked_u8)
+            }
+            #[cfg(feature = "std")]
+            Port::OutputFile(f) => f.stream_position(),
+            Port::StringInput { pos, .. } | Port::BytevectorInput { pos, .. } => Ok(*pos as u64),
+            // counting the characters of what's been written as UTF-8 by
+            // the bytes that start them
+            Port::StringOutput(v) => Ok(v.iter().filter(|&&b| b & 0xC0 != 0x80).count() as u64),
+            Port::BytevectorOutput(v) => Ok(v.len() as u64),
+            Port::Closed { .. } => Err(closed()),
+            _ => Err(unsupported("port has no position")),
+        }
+    }
+
+    /// Moves the port to `position`, as [`Port::position`] counts them.
+    pub fn set_position(&mut self, position: u64) -> io::Result<()> {
+        match self {
+            #[cfg(feature = "std")]
+            Port::InputFile(r) => {
+                r.inner.seek(SeekFrom::Start(position))?;
+                r.peeked = None;
+                r.peeked_u8 = None;
+                Ok(())
+            }
+            #[cfg(feature = "std")]
+            Port::OutputFile(f) => f.seek(SeekFrom::Start(position)).map(drop),
+            Port::StringInput { chars, pos } => set_pos(pos, position, chars.len()),
+            Port::BytevectorInput { bytes, pos } => set_pos(pos, position, bytes.len()),
+            Port::Closed { .. } => Err(closed()),
+            _ => Err(unsupported("port's position can't be set")),
+        }
+    }
+
+    /// The procedure a custom port made in Scheme calls when it's closed,
+    /// if it has one, taken from it so that it's only called the once.
+    pub fn take_close_procedure(&mut self) -> Option<Object> {
+        match self {
+            Port::CustomInput { close, .. } | Port::CustomOutput { close, .. } => close.take(),
+            _ => None,
+        }
+    }
+
+    /// The text written to a string output port so far.
+    pub fn output_contents(&self) -> Option<String> {
+        match self {
+            Port::StringOutput(buf) => Some(String::from_utf8_lossy(buf).into_owned()),
+            _ => None,
+        }
+    }
+
+    /// The bytes written to a bytevector output port so far.
+    pub fn output_bytes(&self) -> Option<Vec<u8>> {
+        match self {
+            Port::BytevectorOutput(buf) => Some(buf.clone()),
+            _ => None,
+        }
+    }
+}
+
+fn set_pos(pos: &mut usize, position: u64, len: usize) -> io::Result<()> {
+    match usize::try_from(position) {
+        Ok(position) if position <= len => {
+            *pos = position;
+            Ok(())
+        }
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "position past the end of the port",
+        )),
+    }
+}
+
+fn not_textual_input() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, "not a textual input port")
+}
+
+fn not_binary_input() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, "not a binary input port")
+}
+
+/// Decodes UTF-8 chars from a byte stream one at a time,
+/// keeping one char of lookahead for `peek-char`.
+pub struct CharReader<R> {
+    inner: R,
+    peeked: Option<char>,
+    peeked_u8: Option<u8>,
+}
+
+impl<R: Read> CharReader<R> {
+    pub fn new(inner: R) -> Self {
+        CharReader {
+            inner,
+            peeked: None,
+            peeked_u8: None,
+        }
+    }
+
+    /// Byte-level reads for binary ports. These don't share lookahead with
+    /// `read_char`, but then no port is both textual and binary.
+    pub fn read_u8(&mut self) -> io::Result<Option<u8>> {
+        if let Some(b) = self.peeked_u8.take() {
+            return Ok(Some(b));
+        }
+        let mut buf = [0u8; 1];
+        Ok(match self.inner.read(&mut buf)? {
+            0 => None,
+            _ => Some(buf[0]),
+        })
+    }
+
+    pub fn peek_u8(&mut self) -> io::Result<Option<u8>> {
+        if self.peeked_u8.is_none() {
+            self.peeked_u8 = self.read_u8()?;
+        }
+        Ok(self.peeked_u8)
+    }
+
+    pub fn read_char(&mut self) -> io::Result<Option<char>> {
+        if let Some(c) = self.peeked.take() {
+            return Ok(Some(c));
+        }
+        let mut buf = [0u8; 4];
+        if self.inner.read(&mut buf[..1])? == 0 {
+            return Ok(None);
+        }
+        let len = match buf[0] {
+            0x00..=0x7F => 1,
+            0xC0..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xF7 => 4,
+            _ => return Err(invalid_utf8()),
+        };
+        self.inner.read_exact(&mut buf[1..len])?;
+        core::str::from_utf8(&buf[..len])
+            .map(|s| s.chars().next())
+            .map_err(|_| invalid_utf8())
+    }
+
+    pub fn peek_char(&mut self) -> io::Result<Option<char>> {
+        if self.peeked.is_none() {
+            self.peeked = self.read_char()?;
+        }
+        Ok(self.peeked)
+    }
+}
+
+/// A source and/or sink supplied by host code, for use with [`Port::custom`].
+///
+/// Backends only deal in bytes; textual ports decode them as UTF-8.
+/// A backend that is neither input nor output is not much use.
+pub trait PortBackend: Threadsafe {
+    fn is_input(&self) -> bool;
+    fn is_output(&self) -> bool;
+    fn is_binary(&self) -> bool {
+        false
+    }
+
+    /// Whether `read` can be called without blocking.
+    fn is_ready(&self) -> bool {
+        true
+    }
+
+    fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+        Err(unsupported("can't read from this port"))
+    }
+
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        Err(unsupported("can't write to this port"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn close(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn unsupported(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::Unsupported, msg)
+}
+
+#[cfg(not(feature = "sync"))]
+mod callbacks {
+    use alloc::{boxed::Box, string::String};
+
+    use crate::io;
+
+    pub type ReadCallback = Box<dyn FnMut() -> io::Result<Option<String>>>;
+    pub type WriteCallback = Box<dyn FnMut(&str) -> io::Result<()>>;
+    pub type CloseCallback = Box<dyn FnMut() -> io::Result<()>>;
+}
+
+#[cfg(feature = "sync")]
+mod callbacks {
+    use std::io;
+
+    pub type ReadCallback = Box<dyn FnMut() -> io::Result<Option<String>> + Send + Sync>;
+    pub type WriteCallback = Box<dyn FnMut(&str) -> io::Result<()> + Send + Sync>;
+    pub type CloseCallback = Box<dyn FnMut() -> io::Result<()> + Send + Sync>;
+}
+
+use callbacks::*;
+
+/// A textual port backend driven by callbacks, for hosts that only have
+/// a closure to read or write with. Ports made in Scheme by
+/// `make-custom-input-port` and `make-custom-output-port` don't use it,
+/// since their procedures can raise things that aren't `io::Error`s.
+///
+/// An input port's callback hands out the text in chunks, with `None`
+/// meaning end of file. An output port's callback is given the text of
+/// each write as it happens.
+pub struct CallbackPort {
+    read: Option<ReadCallback>,
+    write: Option<WriteCallback>,
+    close: Option<CloseCallback>,
+    /// Bytes of a chunk that haven't been consumed yet (for input), or
+    /// of a char that was split across writes (for output).
+    pending: Vec<u8>,
+}
+
+impl CallbackPort {
+    pub fn input(
+        read: impl FnMut() -> io::Result<Option<String>> + Threadsafe + 'static,
+        close: Option<CloseCallback>,
+    ) -> CallbackPort {
+        CallbackPort {
+            read: Some(Box::new(read)),
+            write: None,
+            close,
+            pending: Vec::new(),
+        }
+    }
+
+    pub fn output(
+        write: impl FnMut(&str) -> io::Result<()> + Threadsafe + 'static,
+        close: Option<CloseCallback>,
+    ) -> CallbackPort {
+        CallbackPort {
+            read: None,
+            write: Some(Box::new(write)),
+            close,
+            pending: Vec::new(),
+        }
+    }
+}
+
+impl PortBackend for CallbackPort {
+    fn is_input(&self) -> bool {
+        self.read.is_some()
+    }
+
+    fn is_output(&self) -> bool {
+        self.write.is_some()
+    }
+
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let Some(read) = &mut self.read else {
+            return Err(unsupported("can't read from this port"));
+        };
+        while self.pending.is_empty() {
+            match read()? {
+                Some(chunk) => self.pending = chunk.into_bytes(),
+                None => return Ok(0),
+            }
+        }
+        let n = buf.len().min(self.pending.len());
+        buf[..n].copy_from_slice(&self.pending[..n]);
+        self.pending.drain(..n);
+        Ok(n)
+    }
+
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let Some(write) = &mut self.write else {
+            return Err(unsupported("can't write to this port"));
+        };
+        self.pending.extend_from_slice(buf);
+        let valid = match core::str::from_utf8(&self.pending) {
+            Ok(s) => s.len(),
+            // keep the start of a char that the next write will complete
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            Err(_) => return Err(invalid_utf8()),
+        };
+        if valid > 0 {
+            let text: Vec<u8> = self.pending.drain(..valid).collect();
+            write(core::str::from_utf8(&text).expect("checked above"))?;
+        }
+        Ok(buf.len())
+    }
+
+    fn close(&mut self) -> io::Result<()> {
+        match &mut self.close {
+            Some(close) => close(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Shared handle to a [`PortBackend`], adapting it to `Read`/`Write`.
+pub struct Backend(Rc<RefCell<dyn PortBackend>>);
+
+impl Backend {
+    fn is_input(&self) -> bool {
+        self.0.borrow().is_input()
+    }
+
+    fn is_output(&self) -> bool {
+        self.0.borrow().is_output()
+    }
+
+    fn is_binary(&self) -> bool {
+        self.0.borrow().is_binary()
+    }
+
+    fn is_ready(&self) -> bool {
+        self.0.borrow().is_ready()
+    }
+}
+
+impl Read for Backend {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.borrow_mut().read(buf)
+    }
+}
+
+impl Write for Backend {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.borrow_mut().flush()
+    }
+}
+
+fn invalid_utf8() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "stream did not contain valid UTF-8")
+}
+
+impl From<Port> for Object {
+    fn from(p: Port) -> Object {
+        Object::Port(Rc::new(RefCell::new(p)))
+    }
+}
+
+fn closed() -> io::Error {
+    io::Error::new(io::ErrorKind::BrokenPipe, "port is closed")
+}
+
+/// Why an input port can't be written to or flushed, as `doing` says.
+fn not_output(doing: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, format!("can't {doing} an input port"))
 }
 
 impl Write for Port {
-    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
         match self {
-            Port::Stdin(_) => Err(io::Error::new(
-                io::ErrorKind::InvalidInput,
-                "can't write to stdin",
-            )),
-            Port::Stdout(s) => s.write(buf),
-            Port::File(f) => f.write(buf),
+            Port::Closed { .. } => Err(closed()),
+            #[cfg(feature = "std")]
+            Port::Stdin(_) | Port::InputFile(_) => Err(not_output("write to")),
+            Port::StringInput { .. } | Port::BytevectorInput { .. } | Port::CustomInput { .. } => {
+                Err(not_output("write to"))
+            }
+            #[cfg(feature = "std")]
+            Port::Stdout(s) => s.write(buf).inspect_err(note_stdout_gone),
+            #[cfg(feature = "std")]
+            Port::Stderr(s) => s.write(buf),
+            #[cfg(feature = "std")]
+            Port::OutputFile(f) => f.write(buf),
+            Port::Custom(r) => r.inner.write(buf),
+            Port::StringOutput(v)
+            | Port::BytevectorOutput(v)
+            | Port::CustomOutput { pending: v, .. } => v.write(buf),
         }
     }
-    fn flush(&mut self) -> std::io::Result<()> {
+    fn flush(&mut self) -> io::Result<()> {
         match self {
-            Port::Stdin(_) => Err(io::Error::new(
-                io::ErrorKind::InvalidInput,
-                "can't flush stdin",
-            )),
-            Port::Stdout(s) => s.flush(),
-            Port::File(f) => f.flush(),
+            Port::Closed { .. } => Err(closed()),
+            #[cfg(feature = "std")]
+            Port::Stdin(_) | Port::InputFile(_) => Err(not_output("flush")),
+            Port::StringInput { .. } | Port::BytevectorInput { .. } | Port::CustomInput { .. } => {
+                Err(not_output("flush"))
+            }
+            #[cfg(feature = "std")]
+            Port::Stdout(s) => s.flush().inspect_err(note_stdout_gone),
+            #[cfg(feature = "std")]
+            Port::Stderr(s) => s.flush(),
+            #[cfg(feature = "std")]
+            Port::OutputFile(f) => f.flush(),
+            Port::Custom(r) => r.inner.flush(),
+            // what's pending for a custom port is delivered once the port
+            // isn't borrowed any more
+            Port::StringOutput(_) | Port::BytevectorOutput(_) | Port::CustomOutput { .. } => {
+                Ok(())
+            }
         }
     }
 }
 
+#[cfg(feature = "std")]
+thread_local! {
+    /// The ports for the standard streams, made once so that everything
+    /// writing to standard output shares the one buffer.
+    static STDIN: Object = Port::Stdin(CharReader::new(stdin())).into();
+    static STDOUT: Object = Port::Stdout(BufWriter::new(stdout())).into();
+    static STDERR: Object = Port::Stderr(stderr()).into();
+    /// Whether writing to standard output has failed because nothing is
+    /// reading it any more.
+    static STDOUT_GONE: Cell<bool> = const { Cell::new(false) };
+}
+
+thread_local! {
+    static CURRENT_INPUT_PORT: Rc<Parameter> = Rc::new(Parameter::new(standard_port(0)));
+    static CURRENT_OUTPUT_PORT: Rc<Parameter> = Rc::new(Parameter::new(standard_port(1)));
+    static CURRENT_ERROR_PORT: Rc<Parameter> = Rc::new(Parameter::new(standard_port(2)));
+}
+
+/// What the current port for the standard stream numbered `fd` starts out
+/// as: the port onto that stream.
+#[cfg(feature = "std")]
+fn standard_port(fd: u8) -> Object {
+    match fd {
+        0 => STDIN.with(Object::clone),
+        1 => STDOUT.with(Object::clone),
+        _ => STDERR.with(Object::clone),
+    }
+}
+
+/// Without the standard streams, the current ports start out as string
+/// ports, with nothing to read and keeping what's written, until the host
+/// parameterizes them with its own.
+#[cfg(not(feature = "std"))]
+fn standard_port(fd: u8) -> Object {
+    match fd {
+        0 => Port::input_string("").into(),
+        _ => Port::output_string().into(),
+    }
+}
+
+#[cfg(feature = "std")]
+fn note_stdout_gone(e: &io::Error) {
+    if e.kind() == io::ErrorKind::BrokenPipe {
+        STDOUT_GONE.set(true);
+    }
+}
+
+/// Whether standard output has gone away, as it does when it's piped into
+/// a program that exits before reading everything. Writes to it will only
+/// go on failing.
+#[cfg(feature = "std")]
+pub fn stdout_gone() -> bool {
+    STDOUT_GONE.get()
+}
+
+/// Pushes out what's buffered for standard output before standard input
+/// is read, so that a prompt written without a newline has been seen by
+/// whoever is about to answer it. The port may be busy already, if it's
+/// what is being read from, and then there's nothing to do anyway.
+#[cfg(feature = "std")]
+fn flush_stdout() {
+    STDOUT.with(|port| {
+        if let Object::Port(p) = port {
+            if let Ok(mut p) = p.try_borrow_mut() {
+                p.flush().ok();
+            }
+        }
+    });
+}
+
+/// The parameter object bound to `current-input-port`.
+pub fn current_input_port_parameter() -> Rc<Parameter> {
+    CURRENT_INPUT_PORT.with(Rc::clone)
+}
+
+/// The parameter object bound to `current-output-port`.
+pub fn current_output_port_parameter() -> Rc<Parameter> {
+    CURRENT_OUTPUT_PORT.with(Rc::clone)
+}
+
+/// The parameter object bound to `current-error-port`.
+pub fn current_error_port_parameter() -> Rc<Parameter> {
+    CURRENT_ERROR_PORT.with(Rc::clone)
+}
+
+pub fn current_input_port() -> Object {
+    CURRENT_INPUT_PORT.with(|p| p.get())
+}
+
 pub fn current_output_port() -> Object {
-    Object::Port(Port::Stdout(std::io::stdout()))
+    CURRENT_OUTPUT_PORT.with(|p| p.get())
+}
+
+#[cfg(feature = "std")]
+pub fn current_error_port() -> Object {
+    CURRENT_ERROR_PORT.with(|p| p.get())
+}
+
+/// Pushes out whatever the current output and error ports are holding,
+/// e.g. before showing a prompt or exiting.
+///
+/// This matters because thread-locals aren't reliably dropped at process
+/// exit, so buffered output would otherwise be lost.
+#[cfg(feature = "std")]
+pub fn flush_current_ports() -> io::Result<()> {
+    for port in [current_output_port(), current_error_port()] {
+        if let Object::Port(p) = port {
+            let mut p = p.borrow_mut();
+            if p.is_open() {
+                p.flush()?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The port `obj`, which `who` needs to be a port.
+pub fn port_arg<'a>(obj: &'a Object, who: &str) -> Result<&'a Rc<RefCell<Port>>, Error> {
+    match obj {
+        Object::Port(p) => Ok(p),
+        _ => Err(Error::wrong_type(who, "a port", obj)),
+    }
+}
+
+/// `who` couldn't use `port` because of `e`, e.g. because it was closed.
+pub fn port_error(who: &str, port: &Object, e: io::Error) -> Error {
+    Error::new(format!("{who}: {e}"), vec![port.clone()])
+}
+
+/// Writes `text` to `port` for `who`, and then hands it on to the port's
+/// procedure if it's a custom one.
+pub fn write_text(port: &Object, text: &[u8], who: &str) -> Result<(), Error> {
+    let p = port_arg(port, who)?;
+    let written = p.borrow_mut().write_all(text);
+    written.map_err(|e| port_error(who, port, e))?;
+    deliver(p)
+}
+
+/// Hands what's been written to a custom output port made in Scheme on to
+/// its `write` procedure, if anything has. Other ports are left alone.
+pub fn deliver(p: &RefCell<Port>) -> Result<(), Error> {
+    let (write, text) = match &mut *p.borrow_mut() {
+        Port::CustomOutput { write, pending, .. } => {
+            // keeping the start of a character that the next write will
+            // finish
+            let valid = match core::str::from_utf8(pending) {
+                Ok(text) => text.len(),
+                Err(e) => e.valid_up_to(),
+            };
+            let text: Vec<u8> = pending.drain(..valid).collect();
+            (write.clone(), String::from_utf8(text).expect("checked above"))
+        }
+        _ => return Ok(()),
+    };
+    if text.is_empty() {
+        return Ok(());
+    }
+    apply(&write, vec![string::make(text.chars())]).map(drop)
+}
+
+/// Asks a custom input port made in Scheme for more text, if it's read all
+/// it had and hasn't just said there's no more. Other ports are left
+/// alone.
+pub fn fill(p: &RefCell<Port>) -> Result<(), Error> {
+    loop {
+        let read = match &*p.borrow() {
+            Port::CustomInput {
+                read,
+                chars,
+                eof: false,
+                ..
+            } if chars.is_empty() => read.clone(),
+            _ => return Ok(()),
+        };
+        let chunk = apply(&read, vec![])?;
+        // the procedure might have closed the port, or read from it itself
+        let mut port = p.borrow_mut();
+        let Port::CustomInput { chars, eof, .. } = &mut *port else {
+            return Ok(());
+        };
+        match chunk {
+            Object::String(s) => chars.extend(s.borrow().iter()),
+            Object::EofObject => *eof = true,
+            other => {
+                return Err(Error::wrong_type(
+                    "make-custom-input-port",
+                    "a read procedure that returns a string or an eof object",
+                    &other,
+                ))
+            }
+        }
+    }
+}
+
+fn read_char0() -> Result<Object, Error> {
+    read_char1(current_input_port())
+}
+
+fn read_char1(port: Object) -> Result<Object, Error> {
+    let p = port_arg(&port, "read-char")?;
+    fill(p)?;
+    match p.borrow_mut().read_char() {
+        Ok(Some(c)) => Ok(Object::Char(c)),
+        Ok(None) => Ok(Object::EofObject),
+        Err(e) => Err(port_error("read-char", &port, e)),
+    }
+}
+
+fn peek_char0() -> Result<Object, Error> {
+    peek_char1(current_input_port())
+}
+
+fn peek_char1(port: Object) -> Result<Object, Error> {
+    let p = port_arg(&port, "peek-char")?;
+    fill(p)?;
+    match p.borrow_mut().peek_char() {
+        Ok(Some(c)) => Ok(Object::Char(c)),
+        Ok(None) => Ok(Object::EofObject),
+        Err(e) => Err(port_error("peek-char", &port, e)),
+    }
+}
+
+fn char_ready0() -> Result<Object, Error> {
+    char_ready1(current_input_port())
+}
+
+fn char_ready1(port: Object) -> Result<Object, Error> {
+    let p = port_arg(&port, "char-ready?")?;
+    match p.borrow().char_ready() {
+        Ok(ready) => Ok(Object::Boolean(ready)),
+        Err(e) => Err(port_error("char-ready?", &port, e)),
+    }
+}
+
+fn read_u8_0() -> Result<Object, Error> {
+    read_u8_1(current_input_port())
+}
+
+fn read_u8_1(port: Object) -> Result<Object, Error> {
+    let p = port_arg(&port, "read-u8")?;
+    match p.borrow_mut().read_u8() {
+        Ok(Some(b)) => Ok(Object::Number(Number::Integer(b.into()))),
+        Ok(None) => Ok(Object::EofObject),
+        Err(e) => Err(port_error("read-u8", &port, e)),
+    }
+}
+
+fn peek_u8_0() -> Result<Object, Error> {
+    peek_u8_1(current_input_port())
+}
+
+fn peek_u8_1(port: Object) -> Result<Object, Error> {
+    let p = port_arg(&port, "peek-u8")?;
+    match p.borrow_mut().peek_u8() {
+        Ok(Some(b)) => Ok(Object::Number(Number::Integer(b.into()))),
+        Ok(None) => Ok(Object::EofObject),
+        Err(e) => Err(port_error("peek-u8", &port, e)),
+    }
+}
+
+fn u8_ready0() -> Result<Object, Error> {
+    u8_ready1(current_input_port())
+}
+
+fn u8_ready1(port: Object) -> Result<Object, Error> {
+    let p = port_arg(&port, "u8-ready?")?;
+    match p.borrow().u8_ready() {
+        Ok(ready) => Ok(Object::Boolean(ready)),
+        Err(e) => Err(port_error("u8-ready?", &port, e)),
+    }
+}
+
+fn is_port(obj: Object) -> Object {
+    Object::Boolean(matches!(obj, Object::Port(_)))
+}
+
+fn is_input_port(obj: Object) -> Object {
+    Object::Boolean(matches!(obj, Object::Port(p) if p.borrow().is_input()))
+}
+
+fn is_output_port(obj: Object) -> Object {
+    Object::Boolean(matches!(obj, Object::Port(p) if p.borrow().is_output()))
+}
+
+fn is_textual_port(obj: Object) -> Object {
+    Object::Boolean(matches!(obj, Object::Port(p) if p.borrow().is_textual()))
+}
+
+fn is_binary_port(obj: Object) -> Object {
+    Object::Boolean(matches!(obj, Object::Port(p) if p.borrow().is_binary()))
+}
+
+/// `input-port-open?` answers `#f` for output ports rather than
+/// complaining, since such a port can't perform input either way.
+fn is_input_port_open(port: Object) -> Result<Object, Error> {
+    let p = port_arg(&port, "input-port-open?")?.borrow();
+    Ok(Object::Boolean(p.is_input() && p.is_open()))
+}
+
+fn is_output_port_open(port: Object) -> Result<Object, Error> {
+    let p = port_arg(&port, "output-port-open?")?.borrow();
+    Ok(Object::Boolean(p.is_output() && p.is_open()))
+}
+
+fn close(port: Object, who: &str) -> Result<Object, Error> {
+    let p = port_arg(&port, who)?;
+    deliver(p)?;
+    let (closed, procedure) = {
+        let mut p = p.borrow_mut();
+        let procedure = p.take_close_procedure();
+        (p.close(), procedure)
+    };
+    closed.map_err(|e| port_error(who, &port, e))?;
+    if let Some(procedure) = procedure {
+        apply(&procedure, vec![])?;
+    }
+    Ok(Object::Unspecified)
+}
+
+fn close_port(port: Object) -> Result<Object, Error> {
+    close(port, "close-port")
+}
+
+fn close_input_port(port: Object) -> Result<Object, Error> {
+    if !port_arg(&port, "close-input-port")?.borrow().is_input() {
+        return Err(Error::wrong_type("close-input-port", "an input port", &port));
+    }
+    close(port, "close-input-port")
+}
+
+fn close_output_port(port: Object) -> Result<Object, Error> {
+    if !port_arg(&port, "close-output-port")?.borrow().is_output() {
+        return Err(Error::wrong_type("close-output-port", "an output port", &port));
+    }
+    close(port, "close-output-port")
+}
+
+/// Calls `proc` with `port` and closes the port once `proc` is done,
+/// whether it returns normally or unwinds past us.
+fn call_with_port<T>(port: Object, proc: impl FnOnce(Object) -> T) -> Result<T, Error> {
+    struct Close(Object);
+    impl Drop for Close {
+        fn drop(&mut self) {
+            // a failure has nowhere to be reported from a destructor
+            let _ = close(self.0.clone(), "call-with-port");
+        }
+    }
+
+    port_arg(&port, "call-with-port")?;
+    let _close = Close(port.clone());
+    Ok(proc(port))
+}
+
+#[cfg(feature = "std")]
+fn file_name(obj: &Object, who: &str) -> Result<PathBuf, Error> {
+    match obj {
+        Object::String(s) => Ok(s.borrow().iter().collect::<String>().into()),
+        _ => Err(Error::wrong_type(who, "a file name", obj)),
+    }
+}
+
+#[cfg(feature = "std")]
+fn open_file_error(name: Object, e: io::Error) -> Error {
+    Error::file(format!("can't open file: {e}"), vec![name])
+}
+
+#[cfg(feature = "std")]
+fn open_input_file(name: Object) -> Result<Object, Error> {
+    match File::open(file_name(&name, "open-input-file")?) {
+        Ok(f) => Ok(Port::InputFile(CharReader::new(BufReader::new(f))).into()),
+        Err(e) => Err(open_file_error(name, e)),
+    }
+}
+
+#[cfg(feature = "std")]
+fn open_output_file(name: Object) -> Result<Object, Error> {
+    match File::create(file_name(&name, "open-output-file")?) {
+        Ok(f) => Ok(Port::OutputFile(BufWriter::new(f)).into()),
+        Err(e) => Err(open_file_error(name, e)),
+    }
+}
+
+#[cfg(feature = "std")]
+fn call_with_input_file<T>(name: Object, proc: impl FnOnce(Object) -> T) -> Result<T, Error> {
+    call_with_port(open_input_file(name)?, proc)
+}
+
+#[cfg(feature = "std")]
+fn call_with_output_file<T>(name: Object, proc: impl FnOnce(Object) -> T) -> Result<T, Error> {
+    call_with_port(open_output_file(name)?, proc)
+}
+
+/// Runs `thunk` with `current-output-port` bound to a fresh string port
+/// and returns everything it wrote.
+fn with_output_to_string<T, E>(thunk: impl FnOnce() -> Result<T, E>) -> Result<Object, E> {
+    let port = Rc::new(RefCell::new(Port::output_string()));
+    current_output_port_parameter().parameterize(Object::Port(port.clone()), thunk)?;
+    let text = port.borrow().output_contents().unwrap_or_default();
+    Ok(string::make(text.chars()))
+}
+
+/// Runs `thunk` with `current-input-port` reading from the string `s`.
+fn with_input_from_string<T>(s: Object, thunk: impl FnOnce() -> T) -> Result<T, Error> {
+    let port = match &s {
+        Object::String(s) => Port::StringInput {
+            chars: s.borrow().clone(),
+            pos: 0,
+        },
+        _ => return Err(Error::wrong_type("with-input-from-string", "a string", &s)),
+    };
+    Ok(current_input_port_parameter().parameterize(port.into(), thunk))
+}
+
+fn open_input_string(s: &Object) -> Result<Object, Error> {
+    match s {
+        Object::String(s) => Ok(Port::StringInput {
+            chars: s.borrow().clone(),
+            pos: 0,
+        }
+        .into()),
+        _ => Err(Error::wrong_type("open-input-string", "a string", s)),
+    }
+}
+
+fn open_input_bytevector(v: &Object) -> Result<Object, Error> {
+    match v {
+        Object::Bytevector(v) => Ok(Port::input_bytevector(&v.borrow()).into()),
+        _ => Err(Error::wrong_type("open-input-bytevector", "a bytevector", v)),
+    }
+}
+
+fn get_output_string(port: &Object) -> Result<Object, Error> {
+    let text = port_arg(port, "get-output-string")?.borrow().output_contents();
+    match text {
+        Some(text) => Ok(string::make(text.chars())),
+        None => Err(Error::wrong_type("get-output-string", "a string output port", port)),
+    }
+}
+
+fn get_output_bytevector(port: &Object) -> Result<Object, Error> {
+    let bytes = port_arg(port, "get-output-bytevector")?.borrow().output_bytes();
+    match bytes {
+        Some(bytes) => Ok(bytevector::make(bytes)),
+        None => Err(Error::wrong_type("get-output-bytevector", "a bytevector output port", port)),
+    }
+}
+
+/// The optional port argument at `i` of `args`, if it's there, or the
+/// current output port. Either way `who` needs it to be textual, or binary
+/// if `binary` says so.
+fn output_port_arg(args: &[Object], i: usize, binary: bool, who: &str) -> Result<Object, Error> {
+    let port = args.get(i).cloned().unwrap_or_else(current_output_port);
+    let p = port_arg(&port, who)?.borrow();
+    match (p.is_binary(), binary) {
+        (true, false) => Err(Error::wrong_type(who, "a textual output port", &port)),
+        (false, true) => Err(Error::wrong_type(who, "a binary output port", &port)),
+        _ => {
+            drop(p);
+            Ok(port)
+        }
+    }
+}
+
+fn write_char(args: &[Object]) -> Result<Object, Error> {
+    let Object::Char(c) = args[0] else {
+        return Err(Error::wrong_type("write-char", "a character", &args[0]));
+    };
+    let port = output_port_arg(args, 1, false, "write-char")?;
+    write_text(&port, c.encode_utf8(&mut [0; 4]).as_bytes(), "write-char")?;
+    Ok(Object::Unspecified)
+}
+
+/// `(write-string string [port [start [end]]])`.
+fn write_string(args: &[Object]) -> Result<Object, Error> {
+    let Object::String(s) = &args[0] else {
+        return Err(Error::wrong_type("write-string", "a string", &args[0]));
+    };
+    let port = output_port_arg(args, 1, false, "write-string")?;
+    let text: String = {
+        let s = s.borrow();
+        let range = number::range(args.get(2..).unwrap_or_default(), s.len(), "write-string")?;
+        s[range].iter().collect()
+    };
+    write_text(&port, text.as_bytes(), "write-string")?;
+    Ok(Object::Unspecified)
+}
+
+fn newline(args: &[Object]) -> Result<Object, Error> {
+    let port = output_port_arg(args, 0, false, "newline")?;
+    write_text(&port, b"\n", "newline")?;
+    Ok(Object::Unspecified)
+}
+
+fn write_u8(args: &[Object]) -> Result<Object, Error> {
+    let byte = bytevector::byte(&args[0], "write-u8")?;
+    let port = output_port_arg(args, 1, true, "write-u8")?;
+    write_text(&port, &[byte], "write-u8")?;
+    Ok(Object::Unspecified)
+}
+
+fn flush_output_port0() -> Result<Object, Error> {
+    flush_output_port1(current_output_port())
+}
+
+fn flush_output_port1(port: Object) -> Result<Object, Error> {
+    let p = port_arg(&port, "flush-output-port")?;
+    let flushed = p.borrow_mut().flush();
+    flushed.map_err(|e| port_error("flush-output-port", &port, e))?;
+    deliver(p)?;
+    Ok(Object::Unspecified)
+}
+
+fn port_position(port: Object) -> Result<Object, Error> {
+    let position = port_arg(&port, "port-position")?.borrow_mut().position();
+    match position {
+        Ok(position) => Ok(Object::Number(Number::integer(position.into()))),
+        Err(e) => Err(port_error("port-position", &port, e)),
+    }
+}
+
+fn set_port_position(port: Object, position: &Object) -> Result<Object, Error> {
+    let p = port_arg(&port, "set-port-position!")?;
+    let position = number::index(position, "set-port-position!")?;
+    let set = p.borrow_mut().set_position(position as u64);
+    match set {
+        Ok(()) => Ok(Object::Unspecified),
+        Err(e) => Err(port_error("set-port-position!", &port, e)),
+    }
+}
+
+#[cfg(feature = "std")]
+fn file_exists(name: Object) -> Result<Object, Error> {
+    Ok(Object::Boolean(file_name(&name, "file-exists?")?.exists()))
+}
+
+#[cfg(feature = "std")]
+fn delete_file(name: Object) -> Result<Object, Error> {
+    match std::fs::remove_file(file_name(&name, "delete-file")?) {
+        Ok(()) => Ok(Object::Unspecified),
+        Err(e) => Err(Error::file(format!("can't delete file: {e}"), vec![name])),
+    }
+}
+
+/// `(make-custom-input-port read [close])` calls `read` for more text
+/// whenever the port runs dry; it returns a string, or an eof object
+/// once there is nothing more to read. Whatever `read` and `close` raise
+/// is raised by what was reading or closing the port.
+fn make_custom_input_port(args: &[Object]) -> Result<Object, Error> {
+    Ok(Port::CustomInput {
+        read: args[0].clone(),
+        close: args.get(1).cloned(),
+        chars: VecDeque::new(),
+        eof: false,
+    }
+    .into())
+}
+
+/// `(make-custom-output-port write [close])` calls `write` with the text
+/// of each write to the port.
+fn make_custom_output_port(args: &[Object]) -> Result<Object, Error> {
+    Ok(Port::CustomOutput {
+        write: args[0].clone(),
+        close: args.get(1).cloned(),
+        pending: Vec::new(),
+    }
+    .into())
+}
+
+/// Calls `f0` or `f1` depending on whether the optional port was given.
+fn optional_port(
+    args: &[Object],
+    f0: fn() -> Result<Object, Error>,
+    f1: fn(Object) -> Result<Object, Error>,
+) -> Result<Object, Error> {
+    match args {
+        [] => f0(),
+        _ => f1(args[0].clone()),
+    }
+}
+
+pub const PRIMITIVES: &[Primitive] = &[
+    Primitive::range("read-char", 0, 1, |args| optional_port(args, read_char0, read_char1)),
+    Primitive::range("peek-char", 0, 1, |args| optional_port(args, peek_char0, peek_char1)),
+    Primitive::range("char-ready?", 0, 1, |args| optional_port(args, char_ready0, char_ready1)),
+    Primitive::range("read-u8", 0, 1, |args| optional_port(args, read_u8_0, read_u8_1)),
+    Primitive::range("peek-u8", 0, 1, |args| optional_port(args, peek_u8_0, peek_u8_1)),
+    Primitive::range("u8-ready?", 0, 1, |args| optional_port(args, u8_ready0, u8_ready1)),
+    Primitive::fixed("port?", 1, |args| Ok(is_port(args[0].clone()))),
+    Primitive::fixed("input-port?", 1, |args| Ok(is_input_port(args[0].clone()))),
+    Primitive::fixed("output-port?", 1, |args| Ok(is_output_port(args[0].clone()))),
+    Primitive::fixed("textual-port?", 1, |args| Ok(is_textual_port(args[0].clone()))),
+    Primitive::fixed("binary-port?", 1, |args| Ok(is_binary_port(args[0].clone()))),
+    Primitive::fixed("input-port-open?", 1, |args| is_input_port_open(args[0].clone())),
+    Primitive::fixed("output-port-open?", 1, |args| is_output_port_open(args[0].clone())),
+    Primitive::fixed("close-port", 1, |args| close_port(args[0].clone())),
+    Primitive::fixed("close-input-port", 1, |args| close_input_port(args[0].clone())),
+    Primitive::fixed("close-output-port", 1, |args| close_output_port(args[0].clone())),
+    Primitive::fixed("call-with-port", 2, |args| {
+        call_with_port(args[0].clone(), |port| apply(&args[1], vec![port]))?
+    }),
+    #[cfg(feature = "std")]
+    Primitive::fixed("open-input-file", 1, |args| open_input_file(args[0].clone())),
+    #[cfg(feature = "std")]
+    Primitive::fixed("open-output-file", 1, |args| open_output_file(args[0].clone())),
+    #[cfg(feature = "std")]
+    Primitive::fixed("call-with-input-file", 2, |args| {
+        call_with_input_file(args[0].clone(), |port| apply(&args[1], vec![port]))?
+    }),
+    #[cfg(feature = "std")]
+    Primitive::fixed("call-with-output-file", 2, |args| {
+        call_with_output_file(args[0].clone(), |port| apply(&args[1], vec![port]))?
+    }),
+    Primitive::fixed("with-output-to-string", 1, |args| {
+        with_output_to_string(|| apply(&args[0], vec![]))
+    }),
+    Primitive::fixed("with-input-from-string", 2, |args| {
+        with_input_from_string(args[0].clone(), || apply(&args[1], vec![]))?
+    }),
+    Primitive::fixed("open-input-string", 1, |args| open_input_string(&args[0])),
+    Primitive::fixed("open-output-string", 0, |_| Ok(Port::output_string().into())),
+    Primitive::fixed("get-output-string", 1, |args| get_output_string(&args[0])),
+    Primitive::fixed("open-input-bytevector", 1, |args| open_input_bytevector(&args[0])),
+    Primitive::fixed("open-output-bytevector", 0, |_| Ok(Port::output_bytevector().into())),
+    Primitive::fixed("get-output-bytevector", 1, |args| get_output_bytevector(&args[0])),
+    Primitive::range("write-char", 1, 2, write_char),
+    Primitive::range("write-string", 1, 4, write_string),
+    Primitive::range("newline", 0, 1, newline),
+    Primitive::range("write-u8", 1, 2, write_u8),
+    Primitive::range("flush-output-port", 0, 1, |args| {
+        optional_port(args, flush_output_port0, flush_output_port1)
+    }),
+    // after R6RS
+    Primitive::fixed("port-has-port-position?", 1, |args| {
+        let p = port_arg(&args[0], "port-has-port-position?")?;
+        Ok(Object::Boolean(p.borrow().has_position()))
+    }),
+    Primitive::fixed("port-has-set-port-position!?", 1, |args| {
+        let p = port_arg(&args[0], "port-has-set-port-position!?")?;
+        Ok(Object::Boolean(p.borrow().has_set_position()))
+    }),
+    Primitive::fixed("port-position", 1, |args| port_position(args[0].clone())),
+    Primitive::fixed("set-port-position!", 2, |args| {
+        set_port_position(args[0].clone(), &args[1])
+    }),
+    #[cfg(feature = "std")]
+    Primitive::fixed("file-exists?", 1, |args| file_exists(args[0].clone())),
+    #[cfg(feature = "std")]
+    Primitive::fixed("delete-file", 1, |args| delete_file(args[0].clone())),
+    Primitive::range("make-custom-input-port", 1, 2, make_custom_input_port),
+    Primitive::range("make-custom-output-port", 1, 2, make_custom_output_port),
+];
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+    #[cfg(feature = "std")]
+    use crate::testing::temp_path;
+    use crate::{
+        read::read_port,
+        testing::{check, check_errors, output},
+    };
+
+    #[test]
+    fn parameterizing_the_output_port_captures_display() {
+        check(&[
+            (
+                "(define p (open-output-string))
+                 (parameterize ((current-output-port p)) (display \"hi\") (newline))
+                 (get-output-string p)",
+                "\"hi\\n\"",
+            ),
+            (
+                "(define p (open-output-string))
+                 (parameterize ((current-error-port p)) (display 1 (current-error-port)))
+                 (get-output-string p)",
+                "\"1\"",
+            ),
+            (
+                "(parameterize ((current-input-port (open-input-string \"(a b)\"))) (read))",
+                "(a b)",
+            ),
+        ]);
+        assert_eq!(output("(display \"out\")"), "out");
+    }
+
+    #[test]
+    fn string_and_bytevector_ports() {
+        check(&[
+            ("(read-char (open-input-string \"xy\"))", "#\\x"),
+            ("(read-u8 (open-input-bytevector (bytevector 7 8)))", "7"),
+            (
+                "(define p (open-output-bytevector)) (write-u8 1 p) (write-u8 2 p)
+                 (get-output-bytevector p)",
+                "#u8(1 2)",
+            ),
+            (
+                "(define p (open-output-string)) (write-char #\\λ p)
+                 (write-string \"abcde\" p 1 3) (get-output-string p)",
+                "\"λbc\"",
+            ),
+        ]);
+        check_errors(&[
+            (
+                "(get-output-string (open-input-string \"\"))",
+                "get-output-string: expected a string output port",
+            ),
+            ("(write-u8 1 (open-output-string))", "write-u8: expected a binary output port"),
+            (
+                "(write-char #\\a (open-output-bytevector))",
+                "write-char: expected a textual output port",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn chars_are_always_ready_on_string_ports() {
+        check(&[
+            ("(char-ready? (open-input-string \"a\"))", "#t"),
+            (
+                "(define p (open-input-string \"\"))
+                 (list (char-ready? p) (eof-object? (read-char p)))",
+                "(#t #t)",
+            ),
+            (
+                "(define p (open-input-string \"a\")) (read-char p)
+                 (list (char-ready? p) (eof-object? (read-char p)))",
+                "(#t #t)",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn port_predicates_classify_each_kind_of_port() {
+        let kinds = "(define (kinds p)
+                       (map (lambda (pred) (pred p))
+                            (list port? input-port? output-port? textual-port? binary-port?)))";
+        check(&[
+            (&format!("{kinds} (kinds (current-input-port))"), "(#t #t #f #t #f)"),
+            (&format!("{kinds} (kinds (current-error-port))"), "(#t #f #t #t #f)"),
+            (&format!("{kinds} (kinds (open-input-string \"\"))"), "(#t #t #f #t #f)"),
+            (&format!("{kinds} (kinds (open-output-string))"), "(#t #f #t #t #f)"),
+            (&format!("{kinds} (kinds (open-input-bytevector (bytevector)))"), "(#t #t #f #f #t)"),
+            (&format!("{kinds} (kinds (open-output-bytevector))"), "(#t #f #t #f #t)"),
+            (
+                &format!("{kinds} (kinds (make-custom-input-port (lambda () (eof-object))))"),
+                "(#t #t #f #t #f)",
+            ),
+            (
+                &format!("{kinds} (kinds (make-custom-output-port (lambda (s) #t)))"),
+                "(#t #f #t #t #f)",
+            ),
+            (&format!("{kinds} (kinds 'port)"), "(#f #f #f #f #f)"),
+        ]);
+    }
+
+    #[test]
+    fn ports_are_open_until_closed() {
+        check(&[
+            (
+                "(define p (open-input-string \"\"))
+                 (define before (list (input-port-open? p) (output-port-open? p)))
+                 (close-port p)
+                 (list before (input-port-open? p) (output-port-open? p))",
+                "((#t #f) #f #f)",
+            ),
+            (
+                "(define p (open-output-string))
+                 (define before (list (input-port-open? p) (output-port-open? p)))
+                 (close-output-port p)
+                 (list before (input-port-open? p) (output-port-open? p))",
+                "((#f #t) #f #f)",
+            ),
+        ]);
+        check_errors(&[
+            ("(define p (open-input-string \"a\")) (close-port p) (read-char p)", "read-char:"),
+            ("(close-input-port (open-output-string))", "close-input-port: expected an input port"),
+        ]);
+    }
+
+    #[test]
+    fn call_with_port_closes_the_port_however_the_body_ends() {
+        check(&[
+            (
+                "(define p (open-input-string \"abc\"))
+                 (list (call-with-port p read-char) (input-port-open? p))",
+                "(#\\a #f)",
+            ),
+            (
+                "(define p (open-input-string \"abc\"))
+                 (guard (e (#t (input-port-open? p)))
+                   (call-with-port p (lambda (p) (error \"boom\"))))",
+                "#f",
+            ),
+            (
+                "(define p (open-output-string))
+                 (call/cc (lambda (k) (call-with-port p (lambda (p) (k 1)))))
+                 (output-port-open? p)",
+                "#f",
+            ),
+        ]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn call_with_file_ports() {
+        let path = temp_path("call-with-file");
+        check(&[
+            (
+                &format!(
+                    "(define q #f)
+                     (define r (call-with-output-file {path:?}
+                                 (lambda (p) (set! q p) (write '(1 \"two\") p) 'done)))
+                     (list r (output-port-open? q))"
+                ),
+                "(done #f)",
+            ),
+            (
+                &format!(
+                    "(define q #f)
+                     (define r (call-with-input-file {path:?} (lambda (p) (set! q p) (read p))))
+                     (list r (input-port-open? q))"
+                ),
+                "((1 \"two\") #f)",
+            ),
+        ]);
+        std::fs::remove_file(&path).unwrap();
+        check(&[(
+            &format!("(guard (e ((file-error? e) 'missing)) (call-with-input-file {path:?} read))"),
+            "missing",
+        )]);
+    }
+
+    #[test]
+    fn with_output_to_string_and_with_input_from_string() {
+        check(&[
+            ("(with-output-to-string (lambda () (display \"hi\")))", "\"hi\""),
+            ("(with-output-to-string (lambda () #f))", "\"\""),
+            ("(with-input-from-string \"(x) y\" (lambda () (list (read) (read))))", "((x) y)"),
+        ]);
+        assert_eq!(
+            output("(define s (with-output-to-string (lambda () (display 1)))) (display 2)"),
+            "2"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn file_output_is_buffered_until_flushed() {
+        let path = temp_path("buffered");
+        check(&[(
+            &format!(
+                "(define (size)
+                   (let ((written (call-with-input-file {path:?} read)))
+                     (if (eof-object? written) 0 (length written))))
+                 (define p (open-output-file {path:?}))
+                 (write (make-list 1000 'x) p)
+                 (define before (size))
+                 (flush-output-port p)
+                 (define after (size))
+                 (close-port p)
+                 (list before after)"
+            ),
+            "(0 1000)",
+        )]);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    /// A backend that reads from and writes to memory the host can see.
+    #[derive(Default)]
+    struct Memory {
+        input: VecDeque<u8>,
+        output: Vec<u8>,
+    }
+
+    impl PortBackend for Memory {
+        fn is_input(&self) -> bool {
+            true
+        }
+
+        fn is_output(&self) -> bool {
+            true
+        }
+
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = buf.len().min(self.input.len());
+            for (b, byte) in buf.iter_mut().zip(self.input.drain(..n)) {
+                *b = byte;
+            }
+            Ok(n)
+        }
+
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.output.write(buf)
+        }
+    }
+
+    #[test]
+    fn custom_backends_can_be_read_and_written() {
+        let memory = Rc::new(RefCell::new(Memory::default()));
+        memory.borrow_mut().input.extend("(a \"λ\") b".bytes());
+        let port = Object::from(Port::custom(memory.clone()));
+        let Object::Port(p) = &port else { unreachable!() };
+        assert_eq!(read_port(p).unwrap().unwrap().to_string(), "(a \"λ\")");
+        assert_eq!(read_port(p).unwrap().unwrap().to_string(), "b");
+        assert!(read_port(p).unwrap().is_none());
+        write_text(&port, "ünï".as_bytes(), "test").unwrap();
+        assert_eq!(memory.borrow().output, "ünï".as_bytes());
+    }
+
+    #[test]
+    fn custom_ports_made_in_scheme() {
+        check(&[
+            (
+                "(define written '())
+                 (define closed 0)
+                 (define p (make-custom-output-port
+                             (lambda (s) (set! written (cons s written)))
+                             (lambda () (set! closed (+ closed 1)))))
+                 (display \"one\" p)
+                 (write 'two p)
+                 (close-port p)
+                 (close-port p)
+                 (list (reverse written) closed)",
+                "((\"one\" \"two\") 1)",
+            ),
+            (
+                "(define chunks '(\"(a \" \"b)\" \" c\"))
+                 (define p (make-custom-input-port
+                             (lambda ()
+                               (if (null? chunks)
+                                   (eof-object)
+                                   (let ((chunk (car chunks)))
+                                     (set! chunks (cdr chunks))
+                                     chunk)))))
+                 (list (read p) (read p) (eof-object? (read p)))",
+                "((a b) c #t)",
+            ),
+            (
+                "(guard (e ((symbol? e) e))
+                   (display 1 (make-custom-output-port (lambda (s) (raise 'boom)))))",
+                "boom",
+            ),
+            (
+                "(call/cc (lambda (k) (read-char (make-custom-input-port (lambda () (k 42))))))",
+                "42",
+            ),
+        ]);
+        check_errors(&[(
+            "(read-char (make-custom-input-port (lambda () 1)))",
+            "make-custom-input-port: expected a read procedure",
+        )]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn file_exists_and_delete_file() {
+        let path = temp_path("delete-me");
+        check(&[(
+            &format!(
+                "(call-with-output-file {path:?} (lambda (p) (write 1 p)))
+                 (define before (file-exists? {path:?}))
+                 (delete-file {path:?})
+                 (list before (file-exists? {path:?}))"
+            ),
+            "(#t #f)",
+        )]);
+        check(&[(
+            &format!("(guard (e ((file-error? e) 'file)) (delete-file {path:?}))"),
+            "file",
+        )]);
+    }
+
+    #[test]
+    fn seeking_and_reading_again() {
+        check(&[
+            (
+                "(define p (open-input-string \"(a b) \\\"λc\\\"\"))
+                 (define first (read p))
+                 (define after (port-position p))
+                 (set-port-position! p 0)
+                 (define again (read p))
+                 (list first again after (read-char p) (port-position p) (read p))",
+                "((a b) (a b) 5 #\\space 6 \"λc\")",
+            ),
+            // what's been peeked at is still to come
+            (
+                "(define p (open-input-string \"xyz\"))
+                 (read-char p)
+                 (peek-char p)
+                 (list (port-position p)
+                       (begin (set-port-position! p 3) (eof-object? (read-char p)))
+                       (begin (set-port-position! p 1) (read-char p)))",
+                "(1 #t #\\y)",
+            ),
+            (
+                "(define p (open-input-bytevector #u8(1 2 3)))
+                 (read-u8 p)
+                 (read-u8 p)
+                 (set-port-position! p 1)
+                 (list (read-u8 p) (port-position p))",
+                "(2 2)",
+            ),
+            // output to strings and bytevectors can only be added to
+            (
+                "(define p (open-output-string))
+                 (write-string \"λx\" p)
+                 (list (port-has-port-position? p) (port-has-set-port-position!? p)
+                       (port-position p))",
+                "(#t #f 2)",
+            ),
+            (
+                "(define p (make-custom-input-port (lambda () (eof-object))))
+                 (list (port-has-port-position? p) (port-has-set-port-position!? p))",
+                "(#f #f)",
+            ),
+        ]);
+        check_errors(&[
+            (
+                "(set-port-position! (open-input-string \"ab\") 3)",
+                "set-port-position!: position past the end of the port",
+            ),
+            (
+                "(set-port-position! (open-output-string) 0)",
+                "set-port-position!: port's position can't be set",
+            ),
+            (
+                "(port-position (make-custom-input-port (lambda () (eof-object))))",
+                "port-position: port has no position",
+            ),
+        ]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn seeking_in_files() {
+        let path = temp_path("seeking");
+        std::fs::write(&path, "one \"λ\" two").unwrap();
+        check(&[(
+            &format!(
+                "(call-with-input-file {path:?}
+                   (lambda (p)
+                     (define first (read p))
+                     (define at (port-position p))
+                     (define second (read p))
+                     (set-port-position! p at)
+                     (list first second (read p) at
+                           (port-has-set-port-position!? p))))"
+            ),
+            "(one \"λ\" \"λ\" 3 #t)",
+        )]);
+        std::fs::remove_file(&path).unwrap();
+    }
 }
@@ -0,0 +1,798 @@
+use alloc::{string::String, vec::Vec};
+use core::iter::Peekable;
+
+use crate::{
+    bytevector,
+    collections::{HashMap, HashSet, VecDeque},
+    error::Error,
+    eval::Primitive,
+    lex::{self, Span, Token},
+    list::Pair,
+    number::Number,
+    port::{self, current_input_port, Port},
+    string, symbol,
+    sync::{Rc, RefCell, Weak},
+    vec_to_list, vector, Object,
+};
+
+/// How deeply data can nest inside each other before the reader gives up
+/// on them, rather than running out of stack. Reading, writing and
+/// evaluating all recurse on nested data, and this leaves them plenty of
+/// room even in a debug build.
+const MAX_DEPTH: usize = 1000;
+
+thread_local! {
+    /// Where each list read by the last [`read_str_spans`] was, by the
+    /// address of its first pair, so that an error in evaluating it can
+    /// say where it happened. The pairs are held weakly, and being held
+    /// keeps their addresses from going to other pairs.
+    static SPANS: RefCell<HashMap<usize, (Weak<Pair>, Span)>> = RefCell::new(HashMap::new());
+
+    /// The datum labels each port has read so far, by the port's address,
+    /// so that a `#n#` in one `read` refers to the very `#n=` datum an
+    /// earlier `read` from the same port gave back. The ports are held
+    /// weakly, for the same reason as the pairs in [`SPANS`].
+    static PORT_LABELS: RefCell<HashMap<usize, PortLabels>> = RefCell::new(HashMap::new());
+}
+
+type Labels = HashMap<u64, Object>;
+type PortLabels = (Weak<RefCell<Port>>, Labels);
+
+/// Where the list whose first pair is at `address` was, if it was read by
+/// the last [`read_str_spans`] and is still around.
+pub fn span_of(address: usize) -> Option<Span> {
+    SPANS.with(|spans| match spans.borrow().get(&address) {
+        Some((pair, span)) if pair.strong_count() > 0 => Some(*span),
+        _ => None,
+    })
+}
+
+/// Builds data out of a stream of tokens, each with where it came from.
+pub struct Reader<I: Iterator<Item = (Token, Span)>> {
+    tokens: Peekable<I>,
+    /// How many data the one being read is inside of.
+    depth: usize,
+    /// Where the last token taken ended.
+    end: usize,
+    /// The data labelled so far. A label reaches past the end of the
+    /// datum it's in, where R7RS stops, to the rest of what's read, and
+    /// for a port to the rest of what's read from it.
+    labels: Labels,
+    /// Whether to note where each list is, in [`SPANS`].
+    keep_spans: bool,
+}
+
+impl<I: Iterator<Item = (Token, Span)>> Reader<I> {
+    pub fn new(tokens: I) -> Self {
+        Reader {
+            tokens: tokens.peekable(),
+            depth: 0,
+            end: 0,
+            labels: HashMap::new(),
+            keep_spans: false,
+        }
+    }
+
+    /// Reads the next datum, or `None` once the tokens have run out.
+    pub fn read(&mut self) -> Result<Option<Object>, Error> {
+        Ok(self.read_spanned()?.map(|(datum, _)| datum))
+    }
+
+    /// Like [`Reader::read`], with where the datum was, from its first
+    /// token to its last. Errors say where they were too.
+    pub fn read_spanned(&mut self) -> Result<Option<(Object, Span)>, Error> {
+        let Some((t, span)) = self.tokens.next() else {
+            return Ok(None);
+        };
+        self.end = span.end;
+        let datum = self.datum(t, span)?;
+        Ok(Some((datum, self.since(span))))
+    }
+
+    /// The next token of a datum that started at `open`, which is where
+    /// running out of tokens is blamed on.
+    fn next_token(&mut self, open: Span) -> Result<(Token, Span), Error> {
+        let (t, span) = self
+            .tokens
+            .next()
+            .ok_or_else(|| Error::read("unexpected end of input", vec![]).at(open))?;
+        self.end = span.end;
+        Ok((t, span))
+    }
+
+    /// From `start` up to the end of the last token taken.
+    fn since(&self, start: Span) -> Span {
+        Span {
+            start: start.start,
+            end: self.end,
+        }
+    }
+
+    fn datum(&mut self, t: Token, span: Span) -> Result<Object, Error> {
+        if self.depth == MAX_DEPTH {
+            return Err(Error::read("data nested too deeply", vec![]).at(span));
+        }
+        self.depth += 1;
+        let datum = self.compound(t, span);
+        self.depth -= 1;
+        if let (true, Ok(Object::Pair(pair))) = (self.keep_spans, &datum) {
+            // a list referred to by a label is where it was labelled
+            let span = self.since(span);
+            SPANS.with(|spans| {
+                let mut spans = spans.borrow_mut();
+                let entry = spans.entry(Rc::as_ptr(pair) as usize);
+                entry.or_insert_with(|| (Rc::downgrade(pair), span));
+            });
+        }
+        datum
+    }
+
+    fn compound(&mut self, t: Token, span: Span) -> Result<Object, Error> {
+        use Token::*;
+        Ok(match t {
+            Identifier(name) => Object::Symbol(Rc::from(name)),
+            Boolean(b) => Object::Boolean(b),
+            Number(n) => Object::Number(n),
+            Character(c) => Object::Char(c),
+            String(s) => string::make(s.chars()),
+            OpenParen => self.list(span)?,
+            OpenVec => vector::make(self.sequence(span)?),
+            OpenByteVec => {
+                let items = self.sequence(span)?;
+                let bytes = items.into_iter().map(byte).collect::<Result<_, _>>();
+                bytevector::make(bytes.map_err(|e| e.at(self.since(span)))?)
+            }
+            Quote => self.abbreviation("quote", span)?,
+            BackQuote => self.abbreviation("quasiquote", span)?,
+            Comma => self.abbreviation("unquote", span)?,
+            CommaAt => self.abbreviation("unquote-splicing", span)?,
+            Label(n) => self.labelled(n, span)?,
+            LabelRef(n) => match self.labels.get(&n) {
+                Some(datum) => datum.clone(),
+                None => {
+                    let n = Object::Number(crate::number::Number::Integer(n as i64));
+                    return Err(Error::read("undefined datum label", vec![n]).at(span));
+                }
+            },
+            CloseParen => return Err(Error::read("unexpected `)`", vec![]).at(span)),
+            Period => return Err(Error::read("unexpected `.`", vec![]).at(span)),
+        })
+    }
+
+    /// The rest of a list whose `(` was at `open`.
+    fn list(&mut self, open: Span) -> Result<Object, Error> {
+        let mut items = Vec::new();
+        let tail = loop {
+            match self.next_token(open)? {
+                (Token::CloseParen, _) => break Object::Null,
+                (Token::Period, _) if !items.is_empty() => {
+                    let (t, span) = self.next_token(open)?;
+                    let tail = self.datum(t, span)?;
+                    match self.next_token(open)? {
+                        (Token::CloseParen, _) => break tail,
+                        (_, span) => {
+                            return Err(Error::read(
+                                "expected `)` after the tail of a dotted list",
+                                vec![],
+                            )
+                            .at(span))
+                        }
+                    }
+                }
+                (t, span) => items.push(self.datum(t, span)?),
+            }
+        };
+        Ok(vec_to_list(items, tail))
+    }
+
+    /// The elements of a vector or bytevector whose opening token was at
+    /// `open`.
+    fn sequence(&mut self, open: Span) -> Result<Vec<Object>, Error> {
+        let mut items = Vec::new();
+        loop {
+            match self.next_token(open)? {
+                (Token::CloseParen, _) => return Ok(items),
+                (t, span) => items.push(self.datum(t, span)?),
+            }
+        }
+    }
+
+    fn abbreviation(&mut self, name: &str, open: Span) -> Result<Object, Error> {
+        let (t, span) = self.next_token(open)?;
+        let datum = self.datum(t, span)?;
+        Ok(list![symbol(name), datum])
+    }
+
+    /// The datum after a `#n=` at `open`. References to it from inside
+    /// itself are read as a stand-in at first, which is then swapped for
+    /// the datum itself, so that they're all the very same object and not
+    /// copies.
+    fn labelled(&mut self, n: u64, open: Span) -> Result<Object, Error> {
+        let stand_in = Rc::new(RefCell::new(Vec::new()));
+        self.labels.insert(n, Object::Vector(stand_in.clone()));
+        let (t, span) = self.next_token(open)?;
+        let datum = self.datum(t, span)?;
+        let is_stand_in =
+            |obj: &Object| matches!(obj, Object::Vector(v) if Rc::ptr_eq(v, &stand_in));
+        if is_stand_in(&datum) {
+            return Err(
+                Error::read("a datum label can't label only itself", vec![]).at(self.since(open))
+            );
+        }
+        // one reference here and one in `labels`: any more are in `datum`
+        if Rc::strong_count(&stand_in) > 2 {
+            replace(&datum, &is_stand_in, &datum, &mut HashSet::new());
+        }
+        self.labels.insert(n, datum.clone());
+        Ok(datum)
+    }
+}
+
+/// Replaces whatever `is_old` is true of in `obj`'s pairs and vectors with
+/// `new`, given the ones it's already been through in `seen`.
+fn replace(
+    obj: &Object,
+    is_old: &impl Fn(&Object) -> bool,
+    new: &Object,
+    seen: &mut HashSet<*const ()>,
+) {
+    match obj {
+        Object::Pair(_) => {
+            // the cdrs of a list are gone through in a loop rather than by
+            // recursion, so that long lists don't run out of stack
+            let mut obj = obj.clone();
+            while let Object::Pair(pair) = &obj {
+                if !seen.insert(Rc::as_ptr(pair).cast()) {
+                    return;
+                }
+                let car = pair.car();
+                if is_old(&car) {
+                    pair.set_car(new.clone());
+                } else {
+                    replace(&car, is_old, new, seen);
+                }
+                let cdr = pair.cdr();
+                if is_old(&cdr) {
+                    pair.set_cdr(new.clone());
+                    return;
+                }
+                obj = cdr;
+            }
+            replace(&obj, is_old, new, seen);
+        }
+        Object::Vector(v) => {
+            if !seen.insert(Rc::as_ptr(v).cast()) {
+                return;
+            }
+            let len = v.borrow().len();
+            for i in 0..len {
+                let item = v.borrow()[i].clone();
+                if is_old(&item) {
+                    v.borrow_mut()[i] = new.clone();
+                } else {
+                    replace(&item, is_old, new, seen);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn byte(obj: Object) -> Result<u8, Error> {
+    match obj {
+        Object::Number(Number::Integer(i)) if (0..=255).contains(&i) => Ok(i as u8),
+        _ => Err(Error::read(
+            "bytevector elements must be exact integers between 0 and 255",
+            vec![obj],
+        )),
+    }
+}
+
+/// Reads every datum in `i`.
+pub fn read_str(i: &str) -> Result<Vec<Object>, Error> {
+    Ok(read_spans(i, false)?
+        .into_iter()
+        .map(|(datum, _)| datum)
+        .collect())
+}
+
+/// Like [`read_str`], with where in `i` each datum is. Errors say where
+/// they are in `i` too, and so do errors in evaluating the lists read,
+/// until the next time this is called, which forgets them.
+pub fn read_str_spans(i: &str) -> Result<Vec<(Object, Span)>, Error> {
+    SPANS.with(|spans| spans.borrow_mut().clear());
+    read_spans(i, true)
+}
+
+/// Every datum in `i` with its span, noting the spans of the lists in
+/// [`SPANS`] too if `keep_spans`.
+fn read_spans(i: &str, keep_spans: bool) -> Result<Vec<(Object, Span)>, Error> {
+    let tokens = match lex::lex_spans(i) {
+        Ok(("", tokens)) => tokens,
+        Ok((rest, _)) => {
+            let span = Span {
+                start: i.len() - rest.len(),
+                end: i.len(),
+            };
+            if is_unclosed(rest) {
+                return Err(Error::read("unexpected end of input", vec![]).at(span));
+            }
+            let rest: Vec<char> = rest.chars().take(20).collect();
+            return Err(
+                Error::read("can't make sense of the input", vec![string::make(rest)]).at(span),
+            );
+        }
+        Err(e) => return Err(Error::read(format!("can't lex the input: {e}"), vec![])),
+    };
+    let mut reader = Reader::new(tokens.into_iter());
+    reader.keep_spans = keep_spans;
+    core::iter::from_fn(|| reader.read_spanned().transpose()).collect()
+}
+
+/// The tokens of what's read from a port, lexed as they're read, taking
+/// no more from the port than it takes to be sure where the last of them
+/// ends. That's the first delimiter after it, for most tokens, which is
+/// only peeked at, so the next datum read from the port starts there.
+///
+/// Where R7RS wants a delimiter between tokens and there isn't one, as in
+/// `a#t`, the tokens are only found out together, and any that the datum
+/// being read doesn't need are lost.
+struct PortTokens<'a> {
+    port: &'a RefCell<Port>,
+    /// What's been read of the tokens being lexed.
+    text: String,
+    /// Tokens lexed but not asked for yet.
+    lexed: VecDeque<(Token, Span)>,
+    /// What went wrong, if reading or lexing did, which ends the tokens.
+    error: Option<Error>,
+}
+
+impl<'a> PortTokens<'a> {
+    fn new(port: &'a RefCell<Port>) -> Self {
+        PortTokens {
+            port,
+            text: String::new(),
+            lexed: VecDeque::new(),
+            error: None,
+        }
+    }
+
+    /// Whether the tokens lexed from `text` are sure to be the ones there
+    /// are, with `next` the character to come after it.
+    fn is_settled(&self, tokens: &[(Token, Span)], next: Option<char>) -> bool {
+        let after = tokens.last().map_or(0, |(_, span)| span.end);
+        let last_line = self.text[after..].rsplit('\n').next().unwrap_or("");
+        if last_line.contains(';') {
+            // what might be a line comment isn't over until the line is
+            return next.is_none();
+        }
+        let closed = match tokens.last() {
+            // these can't be the start of some longer token
+            Some((
+                Token::OpenParen
+                | Token::CloseParen
+                | Token::OpenVec
+                | Token::OpenByteVec
+                | Token::Quote
+                | Token::BackQuote
+                | Token::CommaAt
+                | Token::String(_)
+                | Token::Label(_)
+                | Token::LabelRef(_),
+                _,
+            )) => true,
+            Some((Token::Comma, _)) => next != Some('@'),
+            _ => false,
+        };
+        // numbers, characters and booleans only lex with a delimiter after
+        // them, while nothing runs on into a quote, so that `x,@y` reads as
+        // it would from a string
+        let ends = |c| match tokens.last() {
+            Some((Token::Number(_) | Token::Character(_) | Token::Boolean(_), _)) => {
+                is_delimiter(c)
+            }
+            _ => is_delimiter(c) || matches!(c, '\'' | '`' | ','),
+        };
+        closed || next.is_none_or(ends)
+    }
+
+    fn fail(&mut self, error: Error) -> Option<(Token, Span)> {
+        self.error = Some(error);
+        None
+    }
+}
+
+impl Iterator for PortTokens<'_> {
+    type Item = (Token, Span);
+
+    fn next(&mut self) -> Option<(Token, Span)> {
+        loop {
+            if let Some(token) = self.lexed.pop_front() {
+                return Some(token);
+            }
+            if let Err(e) = port::fill(self.port) {
+                return self.fail(e);
+            }
+            let next = match self.port.borrow_mut().peek_char() {
+                Ok(next) => next,
+                Err(e) => return self.fail(Error::read(format!("read: {e}"), vec![])),
+            };
+            let mut tokens = lex::Tokens::new(&self.text);
+            let lexed: Vec<_> = tokens.by_ref().collect();
+            let lexed_all = !self.text.is_empty() && tokens.rest().is_empty();
+            if lexed_all && self.is_settled(&lexed, next) {
+                self.lexed.extend(lexed);
+                self.text.clear();
+                continue;
+            }
+            let Some(c) = next else {
+                if self.text.is_empty() {
+                    // taking the end of the text, which for a custom port
+                    // is only the end of it for now
+                    self.port.borrow_mut().read_char().ok();
+                    return None;
+                }
+                let rest: Vec<char> = tokens.rest().chars().take(20).collect();
+                let message = match is_incomplete(&self.text) {
+                    true => "unexpected end of input",
+                    false => "can't make sense of the input",
+                };
+                self.text.clear();
+                return self.fail(Error::read(message, vec![string::make(rest)]));
+            };
+            if let Err(e) = self.port.borrow_mut().read_char() {
+                return self.fail(Error::read(format!("read: {e}"), vec![]));
+            }
+            self.text.push(c);
+        }
+    }
+}
+
+/// Whether `c` is one of R7RS's delimiters, which any token has to end
+/// before.
+fn is_delimiter(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\r' | '|' | '(' | ')' | '"' | ';')
+}
+
+/// The next datum read from `port`, or `None` at the end of it.
+pub fn read_port(port: &Rc<RefCell<Port>>) -> Result<Option<Object>, Error> {
+    let address = Rc::as_ptr(port) as usize;
+    let labels = PORT_LABELS.with(|labels| match labels.borrow().get(&address) {
+        Some((p, labels)) if p.strong_count() > 0 => labels.clone(),
+        _ => Labels::new(),
+    });
+    let mut tokens = PortTokens::new(port);
+    let mut reader = Reader::new(&mut tokens);
+    reader.labels = labels;
+    let datum = reader.read();
+    if matches!(datum, Ok(Some(_))) && !reader.labels.is_empty() {
+        // the labels of a datum that couldn't be read might still be
+        // stand-ins, so only those of one that could are kept
+        let labels = core::mem::take(&mut reader.labels);
+        PORT_LABELS.with(|all| {
+            let mut all = all.borrow_mut();
+            all.retain(|_, (p, _)| p.strong_count() > 0);
+            all.insert(address, (Rc::downgrade(port), labels));
+        });
+    }
+    drop(reader);
+    let error = match (tokens.error, datum) {
+        (Some(error), _) | (None, Err(error)) => error,
+        (None, datum) => return datum,
+    };
+    // where a read error happened in the port's text would be taken for
+    // where it happened in the program's
+    if let Error::Raise(Object::Condition(c)) = &error {
+        c.span.set(None);
+    }
+    Err(error)
+}
+
+fn read(port: Object) -> Result<Object, Error> {
+    let p = port::port_arg(&port, "read")?;
+    Ok(read_port(p)?.unwrap_or(Object::EofObject))
+}
+
+/// `(read-all [port])`: a list of every datum left to read from `port`.
+fn read_all(port: Object) -> Result<Object, Error> {
+    let p = port::port_arg(&port, "read-all")?;
+    let mut data = Vec::new();
+    while let Some(datum) = read_port(p)? {
+        data.push(datum);
+    }
+    Ok(vec_to_list(data, Object::Null))
+}
+
+pub const PRIMITIVES: &[Primitive] = &[
+    Primitive::range("read", 0, 1, |args| match args {
+        [] => read(current_input_port()),
+        _ => read(args[0].clone()),
+    }),
+    // beyond R7RS
+    Primitive::range("read-all", 0, 1, |args| match args {
+        [] => read_all(current_input_port()),
+        _ => read_all(args[0].clone()),
+    }),
+];
+
+/// Whether `i` stops partway through a datum, so that more text could
+/// finish it: inside a list or vector, a string, a `|`-quoted symbol or a
+/// comment, or right after an abbreviation like `'`. Text that's wrong in
+/// some other way isn't incomplete, since nothing could be added to fix it.
+pub fn is_incomplete(i: &str) -> bool {
+    let Ok((rest, tokens)) = lex::lex(i) else {
+        return false;
+    };
+    if rest.starts_with("#;") || is_unclosed(rest) {
+        return true;
+    }
+    let mut depth = 0usize;
+    for token in &tokens {
+        match token {
+            Token::OpenParen | Token::OpenVec | Token::OpenByteVec => depth += 1,
+            Token::CloseParen if depth == 0 => return false,
+            Token::CloseParen => depth -= 1,
+            _ => {}
+        }
+    }
+    let dangling = matches!(
+        tokens.last(),
+        Some(Token::Quote | Token::BackQuote | Token::Comma | Token::CommaAt | Token::Label(_))
+    );
+    rest.is_empty() && (depth > 0 || dangling)
+}
+
+/// Whether `rest`, where lexing stopped, is a string, a `|`-quoted symbol
+/// or a block comment that the text ends before closing, rather than one
+/// with something wrong inside it.
+fn is_unclosed(rest: &str) -> bool {
+    if let Some(body) = rest.strip_prefix("#|") {
+        let mut depth = 1;
+        let mut chars = body.chars().peekable();
+        while let Some(c) = chars.next() {
+            match (c, chars.peek()) {
+                ('|', Some('#')) if depth == 1 => return false,
+                ('|', Some('#')) => depth -= 1,
+                ('#', Some('|')) => depth += 1,
+                _ => continue,
+            }
+            chars.next();
+        }
+        return true;
+    }
+    let Some(quote) = rest.chars().next().filter(|c| matches!(c, '"' | '|')) else {
+        return false;
+    };
+    let mut chars = rest[1..].chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            c if c == quote => return false,
+            _ => {}
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::{
+        bytevector, equal,
+        number::Number,
+        testing::{check, check_errors, Rng},
+        vector,
+    };
+
+    /// Characters that need escaping or quoting in strings and symbols,
+    /// among some that don't.
+    const CHARS: &str = "aZ09 \t\n\r\0\x07\x7f\u{a0}λ日\u{1f600}()#;\"\\|'`,.+-@";
+
+    fn character(rng: &mut Rng) -> char {
+        let chars: Vec<char> = CHARS.chars().collect();
+        *rng.pick(&chars)
+    }
+
+    fn text(rng: &mut Rng) -> String {
+        (0..rng.below(6)).map(|_| character(rng)).collect()
+    }
+
+    fn number(rng: &mut Rng) -> Number {
+        match rng.below(5) {
+            0 => Number::Integer(rng.next() as i64 >> rng.below(64)),
+            1 => {
+                let digits: String = (0..20 + rng.below(30))
+                    .map(|_| char::from(b'1' + rng.below(9) as u8))
+                    .collect();
+                let n = Number::from_digits(&digits, 10).unwrap();
+                match rng.below(2) {
+                    0 => n,
+                    _ => -n,
+                }
+            }
+            2 => {
+                let num = Number::Integer(rng.below(2001) as i64 - 1000);
+                num / Number::Integer(rng.below(1000) as i64 + 1)
+            }
+            3 => Number::Real(*rng.pick(&[0.0, -0.0, 0.5, -1.5, 1e21, 1e-7, 123.456, 1e300])),
+            _ => loop {
+                let x = f64::from_bits(rng.next());
+                if x.is_finite() {
+                    break Number::Real(x);
+                }
+            },
+        }
+    }
+
+    /// An object that `write` can write and `read` can read back, nested at
+    /// most `depth` deep, and without any cycles.
+    fn arbitrary(rng: &mut Rng, depth: usize) -> Object {
+        let leaves = 7;
+        match rng.below(if depth == 0 { leaves } else { leaves + 4 }) {
+            0 => Object::Boolean(rng.below(2) == 0),
+            1 => Object::Number(number(rng)),
+            2 => Object::Char(character(rng)),
+            3 => Object::str(&text(rng)),
+            4 => Object::sym(&text(rng)),
+            5 => Object::Null,
+            6 => bytevector::make((0..rng.below(4)).map(|_| rng.below(256) as u8).collect()),
+            7 | 8 => Object::list((0..rng.below(4)).map(|_| arbitrary(rng, depth - 1))),
+            9 => crate::cons(arbitrary(rng, depth - 1), arbitrary(rng, depth - 1)),
+            _ => vector::make(
+                (0..rng.below(4))
+                    .map(|_| arbitrary(rng, depth - 1))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Whether `obj` comes back from being written and read in again as
+    /// something `equal?` to it.
+    fn round_trips(obj: &Object) -> bool {
+        match read_str(&obj.to_string()) {
+            Ok(data) => data.len() == 1 && equal(obj, &data[0]),
+            Err(_) => false,
+        }
+    }
+
+    /// The smallest part of `obj` that doesn't round-trip, given that `obj`
+    /// itself doesn't.
+    fn shrink(obj: Object) -> Object {
+        let parts = match &obj {
+            Object::Pair(pair) => vec![pair.car(), pair.cdr()],
+            Object::Vector(items) => items.borrow().clone(),
+            _ => vec![],
+        };
+        match parts.into_iter().find(|part| !round_trips(part)) {
+            Some(part) => shrink(part),
+            None => obj,
+        }
+    }
+
+    #[test]
+    fn written_data_read_back_equal() {
+        let mut rng = Rng::new(0x5eed);
+        for _ in 0..2000 {
+            let obj = arbitrary(&mut rng, 4);
+            if !round_trips(&obj) {
+                let smallest = shrink(obj);
+                panic!("{smallest} doesn't read back as itself");
+            }
+        }
+    }
+
+    #[test]
+    fn errors_say_where_they_are() {
+        let span = |i: &str| read_str_spans(i).unwrap_err().span();
+        // an unclosed `(` is blamed for running out
+        assert_eq!(span("(a\n (b"), Some(Span { start: 4, end: 5 }));
+        assert_eq!(span("(a) )"), Some(Span { start: 4, end: 5 }));
+        assert_eq!(span("#(1 #1#)"), Some(Span { start: 4, end: 7 }));
+
+        let (data, spans): (Vec<_>, Vec<_>) =
+            read_str_spans(" (a (b)) 'c").unwrap().into_iter().unzip();
+        assert_eq!(
+            spans,
+            [Span { start: 1, end: 8 }, Span { start: 9, end: 11 }]
+        );
+        let items = crate::list_to_vec(&data[0]);
+        let Some([_, Object::Pair(inner)]) = items.as_deref() else {
+            panic!("{} isn't two long", data[0]);
+        };
+        assert_eq!(
+            span_of(Rc::as_ptr(inner) as usize),
+            Some(Span { start: 4, end: 7 })
+        );
+        // and they're forgotten the next time
+        read_str_spans("x").unwrap();
+        assert_eq!(span_of(Rc::as_ptr(inner) as usize), None);
+    }
+
+    #[test]
+    fn labels_are_shared_not_copied() {
+        check(&[
+            // within one datum, mutating through one reference shows
+            // through the other
+            (
+                "(define x (car (read-all (open-input-string \"(#0=(a) #0#)\"))))
+                 (set-car! (car x) 'b)
+                 (list x (eq? (car x) (cadr x)))",
+                "(((b) (b)) #t)",
+            ),
+            (
+                "(define x (car (read-all (open-input-string \"#0=#(1 #0#)\"))))
+                 (eq? x (vector-ref x 1))",
+                "#t",
+            ),
+            // and from one `read` of a port to the next
+            (
+                "(define port (open-input-string \"#0=(a) #0#\"))
+                 (define first (read port))
+                 (define second (read port))
+                 (set-car! first 'b)
+                 (list second (eq? first second))",
+                "((b) #t)",
+            ),
+        ]);
+        // but not from one port to another
+        check_errors(&[(
+            "(read (open-input-string \"#0=(a)\"))
+             (read (open-input-string \"#0#\"))",
+            "undefined datum label 0",
+        )]);
+    }
+
+    #[test]
+    fn reading_from_ports() {
+        check(&[
+            (
+                "(define p (open-input-string \"(1 2) foo \\\"three\\\"\"))
+                 (define a (read p))
+                 (define b (read p))
+                 (define c (read p))
+                 (list a b c (eof-object? (read p)) (eof-object? (read p)))",
+                "((1 2) foo \"three\" #t #t)",
+            ),
+            // only as much is taken as it takes to read the datum
+            (
+                "(define p (open-input-string \"abc(d) ; rest\\n e\"))
+                 (list (read p) (read p) (read-char p) (read p) (eof-object? (read p)))",
+                "(abc (d) #\\space e #t)",
+            ),
+            (
+                "(read-all (open-input-string \"1 #;2 (3 . 4) #| 5 |# #(6)\"))",
+                "(1 (3 . 4) #(6))",
+            ),
+            ("(read-all (open-input-string \"  ; nothing\"))", "()"),
+            ("(with-input-from-string \"x y\" read-all)", "(x y)"),
+            // a datum can come in over several reads of the port it's on
+            (
+                "(define chunks '(\"(a (b\" \" c)\" \")\" \" \\\"d\" \"e\\\"\"))
+                 (define p (make-custom-input-port
+                             (lambda ()
+                               (if (null? chunks)
+                                   (eof-object)
+                                   (let ((chunk (car chunks)))
+                                     (set! chunks (cdr chunks))
+                                     chunk)))))
+                 (read-all p)",
+                "((a (b c)) \"de\")",
+            ),
+        ]);
+        check_errors(&[
+            (
+                "(read (open-input-string \"(1 2\"))",
+                "unexpected end of input",
+            ),
+            (
+                "(read (open-input-string \"abc#t\"))",
+                "can't make sense of the input \"abc#t\"",
+            ),
+            ("(read 'not-a-port)", "read: expected a port not-a-port"),
+        ]);
+    }
+}
@@ -0,0 +1,406 @@
+use std::io::{self, BufRead, IsTerminal};
+
+use crate::{
+    editor::Editor,
+    env::interaction_environment,
+    error::Error,
+    eval::{eval, with_stack},
+    lex,
+    port::{self, current_error_port, current_output_port},
+    read, symbol, write_to, Object, Sharing, Style,
+};
+
+/// What the REPL does with its input, as switched between by entering
+/// `:lex`, `:read` or `:eval`.
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    /// Shows the tokens of each line.
+    Lex,
+    /// Shows each datum as it was read.
+    Read,
+    /// Evaluates each datum and shows its value.
+    Eval,
+}
+
+impl Mode {
+    fn named(command: &str) -> Option<Mode> {
+        match command {
+            "lex" => Some(Mode::Lex),
+            "read" => Some(Mode::Read),
+            "eval" => Some(Mode::Eval),
+            _ => None,
+        }
+    }
+
+    fn prompt(self) -> &'static str {
+        match self {
+            Mode::Lex => "lex> ",
+            Mode::Read => "read> ",
+            Mode::Eval => "> ",
+        }
+    }
+}
+
+/// How many times in a row the input can fail to be read before the REPL
+/// gives up on it, taking it to be broken for good.
+const MAX_INPUT_ERRORS: usize = 10;
+
+/// Where the REPL gets its input from, a line at a time.
+pub trait LineSource {
+    /// The next line, with its line ending, or `None` at the end of the
+    /// input. `prompt` is for whoever is typing it, if anyone is.
+    fn read_line(&mut self, prompt: &str) -> io::Result<Option<String>>;
+}
+
+/// Any buffered reader, without prompts, the way piped input is read.
+impl<R: BufRead> LineSource for R {
+    fn read_line(&mut self, _prompt: &str) -> io::Result<Option<String>> {
+        let mut line = String::new();
+        match BufRead::read_line(self, &mut line)? {
+            0 => Ok(None),
+            _ => Ok(Some(line)),
+        }
+    }
+}
+
+impl LineSource for Editor {
+    fn read_line(&mut self, prompt: &str) -> io::Result<Option<String>> {
+        Editor::read_line(self, prompt)
+    }
+}
+
+/// Standard input, with the prompts written out before each line if it's a
+/// terminal the line editor couldn't take over.
+struct Stdin {
+    interactive: bool,
+}
+
+impl LineSource for Stdin {
+    fn read_line(&mut self, prompt: &str) -> io::Result<Option<String>> {
+        if self.interactive {
+            write_prompt(prompt);
+        }
+        let line = LineSource::read_line(&mut io::stdin().lock(), prompt)?;
+        // so that whatever comes next starts on a line of its own
+        if line.is_none() && self.interactive {
+            write_prompt("\n");
+        }
+        Ok(line)
+    }
+}
+
+/// Why the REPL stopped before the end of its input.
+enum Stop {
+    /// The program called `exit`, and wants to end with this status.
+    Exit(i32),
+    /// The input kept failing to be read.
+    Input(io::Error),
+    /// Standard output has gone away, as when the program it was piped into
+    /// has exited, so there's no one left to show anything to.
+    OutputGone,
+}
+
+/// Reads from standard input, evaluates what it reads in the interaction
+/// environment and writes out the values, until the input runs out or
+/// the program exits. Returns the status to exit with. It's the one
+/// environment all along, so what's defined stays defined for later input.
+///
+/// Input is taken a line at a time, and a line that leaves a datum open is
+/// held on to until later lines finish it. On a terminal the lines can be
+/// edited as they're typed, and Ctrl-C throws away what's been held on to.
+///
+/// A line starting with a command like `:read` switches the mode, and the
+/// rest of it is dealt with in the new one.
+///
+/// Errors in what's evaluated, and in reading the input, are reported and
+/// the REPL carries on. Only input that keeps failing, or standard output
+/// going away, ends it early.
+pub fn run() -> i32 {
+    let interactive = io::stdin().is_terminal();
+    match if interactive { Editor::new() } else { None } {
+        Some(editor) => run_with(editor),
+        None => run_with(Stdin { interactive }),
+    }
+}
+
+/// Like [`run`], with the input taken from `lines` instead.
+pub fn run_with(mut lines: impl LineSource + Send) -> i32 {
+    // on a stack of its own, so that programs can recurse deeply
+    with_stack(move || status(repl(&mut lines)))
+}
+
+/// The status to exit with after the REPL has stopped for `reason`.
+fn status(reason: Result<(), Stop>) -> i32 {
+    match reason {
+        Ok(()) | Err(Stop::OutputGone) => 0,
+        Err(Stop::Exit(status)) => status,
+        Err(Stop::Input(e)) => {
+            report(input_error(e));
+            1
+        }
+    }
+}
+
+fn input_error(e: io::Error) -> Error {
+    Error::file(format!("can't read input: {e}"), vec![])
+}
+
+fn repl(lines: &mut impl LineSource) -> Result<(), Stop> {
+    let env = interaction_environment();
+    let mut mode = Mode::Eval;
+    let mut input = String::new();
+    // how many lines have been entered, and which of them `input` started on
+    let mut lines_read = 0;
+    let mut first_line = 1;
+    let mut failures = 0;
+    loop {
+        let text = if input.is_empty() {
+            mode.prompt()
+        } else {
+            "... "
+        };
+        match lines.read_line(text) {
+            Ok(None) => break,
+            Ok(Some(line)) => {
+                failures = 0;
+                lines_read += 1;
+                if input.is_empty() {
+                    first_line = lines_read;
+                }
+                match line.strip_prefix(':') {
+                    Some(command) if input.is_empty() => {
+                        let (command, rest) = command
+                            .split_once(char::is_whitespace)
+                            .unwrap_or((command, ""));
+                        match Mode::named(command) {
+                            Some(new) => mode = new,
+                            None => {
+                                report(Error::new("unknown REPL command", vec![symbol(command)]));
+                                continue;
+                            }
+                        }
+                        input.push_str(rest);
+                    }
+                    _ => input.push_str(&line),
+                }
+            }
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {
+                input.clear();
+                continue;
+            }
+            Err(e) => {
+                failures += 1;
+                if failures == MAX_INPUT_ERRORS {
+                    return Err(Stop::Input(e));
+                }
+                // whatever is held on to stays held, for the lines after
+                report(input_error(e));
+                continue;
+            }
+        }
+        if input.trim().is_empty() {
+            input.clear();
+            continue;
+        }
+        if mode != Mode::Lex && read::is_incomplete(&input) {
+            continue;
+        }
+        let result = match mode {
+            Mode::Lex => show_tokens(&input),
+            Mode::Read => {
+                read::read_str(&input).and_then(|data| data.into_iter().try_for_each(show))
+            }
+            Mode::Eval => read::read_str_spans(&input).and_then(|data| {
+                for (datum, span) in data {
+                    show(eval(datum, &env).map_err(|e| e.at(span))?)?;
+                }
+                Ok(())
+            }),
+        };
+        match result {
+            Ok(()) => {}
+            Err(Error::Exit { status, emergency }) => {
+                if !emergency {
+                    port::flush_current_ports().ok();
+                }
+                return Err(Stop::Exit(status));
+            }
+            // it was most likely a write that failed because of it
+            Err(_) if port::stdout_gone() => return Err(Stop::OutputGone),
+            Err(error) => report_in(error, &input, first_line),
+        }
+        input.clear();
+        port::flush_current_ports().ok();
+        if port::stdout_gone() {
+            return Err(Stop::OutputGone);
+        }
+    }
+    // whatever was left open is as finished as it's going to get, and
+    // reading it says what's wrong with it
+    if !input.trim().is_empty() {
+        if let Err(error) = read::read_str(&input) {
+            report_in(error, &input, first_line);
+        }
+    }
+    port::flush_current_ports().ok();
+    Ok(())
+}
+
+fn write_prompt(text: &str) {
+    port::write_text(&current_output_port(), text.as_bytes(), "write").ok();
+    port::flush_current_ports().ok();
+}
+
+/// Writes out `value` on a line of its own, or each of several values on
+/// lines of their own. Forms with no useful value don't show anything.
+fn show(value: Object) -> Result<(), Error> {
+    let values = match value {
+        Object::Unspecified => return Ok(()),
+        Object::Values(values) => values.to_vec(),
+        value => vec![value],
+    };
+    let port = current_output_port();
+    let newline = Object::Char('\n');
+    for value in values {
+        write_to(&value, &port, Style::Write, Sharing::Cycles, "write")?;
+        write_to(&newline, &port, Style::Display, Sharing::None, "write")?;
+    }
+    Ok(())
+}
+
+/// Writes out the tokens of `input`, and whatever is left over that isn't
+/// made of tokens.
+fn show_tokens(input: &str) -> Result<(), Error> {
+    let text = match lex::lex(input) {
+        Ok(("", tokens)) => format!("tokens:\n{tokens:?}\n"),
+        Ok((rest, tokens)) => format!("tokens:\n{tokens:?}\n followed by garbage: {rest:?}\n"),
+        Err(e) => format!("not tokens! {e}\n"),
+    };
+    port::write_text(&current_output_port(), text.as_bytes(), "write")
+}
+
+/// Tells the user about an error that nothing handled.
+fn report(error: Error) {
+    report_at(error, None)
+}
+
+/// Like [`report`], saying where the error happened if it knows where in
+/// `input` that was. `input` starts on line `first_line` of what's been
+/// entered.
+fn report_in(error: Error, input: &str, first_line: usize) {
+    let place = error.span().map(|span| {
+        let (line, column) = span.line_and_column(input);
+        (first_line + line - 1, column)
+    });
+    report_at(error, place)
+}
+
+fn report_at(error: Error, place: Option<(usize, usize)>) {
+    // so that it comes after any output that led up to it
+    port::flush_current_ports().ok();
+    let message = match &error {
+        Error::Exit { .. } => return,
+        Error::Raise(Object::Condition(_))
+        | Error::Reraise(_, Object::Condition(_))
+        | Error::Throw(..) => format!("error: {error}\n"),
+        _ => format!("{error}\n"),
+    };
+    let port = current_error_port();
+    let write = |text: &str| port::write_text(&port, text.as_bytes(), "write").ok();
+    write(&message);
+    if let Some((line, column)) = place {
+        write(&format!("  at line {line}, column {column}\n"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Port;
+
+    /// A source of lines that fails to read each of them that's `None`.
+    struct Flaky(Vec<Option<&'static str>>);
+
+    impl LineSource for Flaky {
+        fn read_line(&mut self, _prompt: &str) -> io::Result<Option<String>> {
+            if self.0.is_empty() {
+                return Ok(None);
+            }
+            match self.0.remove(0) {
+                Some(line) => Ok(Some(line.to_owned())),
+                None => Err(io::Error::other("the disk is on fire")),
+            }
+        }
+    }
+
+    /// The status the REPL ends with, reading `lines`, and what it writes
+    /// to its output and error ports.
+    fn session(mut lines: impl LineSource + Send) -> (i32, String, String) {
+        with_stack(move || {
+            let out = Object::from(Port::output_string());
+            let err = Object::from(Port::output_string());
+            let status = port::current_output_port_parameter().parameterize(out.clone(), || {
+                port::current_error_port_parameter()
+                    .parameterize(err.clone(), || status(repl(&mut lines)))
+            });
+            let contents = |port: Object| match port {
+                Object::Port(p) => p.borrow().output_contents().unwrap(),
+                _ => unreachable!(),
+            };
+            (status, contents(out), contents(err))
+        })
+    }
+
+    #[test]
+    fn input_errors_are_reported_and_the_repl_carries_on() {
+        let (status, out, err) = session(Flaky(vec![
+            Some("(define x 1)\n"),
+            None,
+            Some("(+ x\n"),
+            None,
+            Some("2)\n"),
+        ]));
+        assert_eq!(status, 0);
+        assert_eq!(out, "3\n");
+        assert_eq!(
+            err,
+            "error: can't read input: the disk is on fire\n".repeat(2)
+        );
+
+        // a line that isn't UTF-8 is skipped
+        let (status, out, err) = session(&b"(+ 1 2)\n\xff\n(+ 3 4)\n"[..]);
+        assert_eq!(status, 0);
+        assert_eq!(out, "3\n7\n");
+        assert!(err.contains("can't read input"), "{err}");
+    }
+
+    #[test]
+    fn input_that_keeps_failing_ends_the_repl() {
+        let (status, out, err) = session(Flaky(vec![None; 100]));
+        assert_eq!(status, 1);
+        assert_eq!(out, "");
+        assert_eq!(err.lines().count(), MAX_INPUT_ERRORS);
+    }
+
+    #[test]
+    fn errors_say_which_form_they_happened_in() {
+        let place = |input: &'static str| {
+            let (_, _, err) = session(input.as_bytes());
+            err.lines().nth(1).unwrap_or_default().to_owned()
+        };
+        // the innermost form
+        assert_eq!(place("(list 1\n  (car 2))\n"), "  at line 2, column 3");
+        assert_eq!(
+            place("(define (f) 1)\n(+ 1\n   (f 2))\n"),
+            "  at line 3, column 4"
+        );
+        assert_eq!(place("(+ 1 (* 2\n        y))\n"), "  at line 1, column 6");
+        // a procedure from an earlier input, at the call
+        assert_eq!(
+            place("(define (g x)\n (car x))\n(+ 1\n   (g 2))\n"),
+            "  at line 4, column 4"
+        );
+        // past an unclosed `(`, at the `(`
+        assert_eq!(place("(+ 1\n  (- 2\n"), "  at line 2, column 3");
+    }
+}
@@ -0,0 +1,825 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    bytevector, character, constant,
+    error::Error,
+    eval::{apply, Primitive},
+    list,
+    normalize::{self, Form},
+    number::{self, Number},
+    sync::{Rc, Ref, RefCell},
+    vec_to_list, vector, Object,
+};
+
+/// A new string of `chars`.
+pub fn make(chars: impl IntoIterator<Item = char>) -> Object {
+    Object::String(Rc::new(RefCell::new(chars.into_iter().collect())))
+}
+
+/// The characters of `obj`, which `who` needs to be a string.
+fn string<'a>(obj: &'a Object, who: &str) -> Result<&'a RefCell<Vec<char>>, Error> {
+    match obj {
+        Object::String(s) => Ok(s),
+        _ => Err(Error::wrong_type(who, "a string", obj)),
+    }
+}
+
+/// The characters of `obj`, which `who` needs to be a string it can change.
+fn string_mut<'a>(obj: &'a Object, who: &str) -> Result<&'a RefCell<Vec<char>>, Error> {
+    let s = string(obj, who)?;
+    constant::mutable(obj, who)?;
+    Ok(s)
+}
+
+fn char_arg(obj: &Object, who: &str) -> Result<char, Error> {
+    match obj {
+        Object::Char(c) => Ok(*c),
+        _ => Err(Error::wrong_type(who, "a character", obj)),
+    }
+}
+
+fn string_ref(args: &[Object]) -> Result<Object, Error> {
+    let s = string(&args[0], "string-ref")?.borrow();
+    let k = number::index_below(&args[1], s.len(), "string-ref")?;
+    Ok(Object::Char(s[k]))
+}
+
+fn string_set(args: &[Object]) -> Result<Object, Error> {
+    let mut s = string_mut(&args[0], "string-set!")?.borrow_mut();
+    let k = number::index_below(&args[1], s.len(), "string-set!")?;
+    s[k] = char_arg(&args[2], "string-set!")?;
+    Ok(Object::Unspecified)
+}
+
+/// `(substring s start end)`, and `(string-copy s [start [end]])` as `who`.
+fn copy(args: &[Object], who: &str) -> Result<Object, Error> {
+    let s = string(&args[0], who)?.borrow();
+    let range = number::range(&args[1..], s.len(), who)?;
+    Ok(make(s[range].iter().copied()))
+}
+
+fn string_append(args: &[Object]) -> Result<Object, Error> {
+    let mut chars = Vec::new();
+    for arg in args {
+        chars.extend_from_slice(&string(arg, "string-append")?.borrow());
+    }
+    Ok(make(chars))
+}
+
+/// `(string-copy! to at from [start [end]])`. The characters are taken
+/// out of `from` before any go into `to`, so it doesn't matter if the two
+/// are the same string and the ranges overlap.
+fn string_copy_to(args: &[Object]) -> Result<Object, Error> {
+    let to = string_mut(&args[0], "string-copy!")?;
+    let from = string(&args[2], "string-copy!")?.borrow();
+    let range = number::range(&args[3..], from.len(), "string-copy!")?;
+    let chars = from[range].to_vec();
+    drop(from);
+    let mut to = to.borrow_mut();
+    let at = number::index(&args[1], "string-copy!")?;
+    if at > to.len() || to.len() - at < chars.len() {
+        return Err(number::out_of_range("string-copy!", &args[1]));
+    }
+    to[at..at + chars.len()].copy_from_slice(&chars);
+    Ok(Object::Unspecified)
+}
+
+fn string_fill(args: &[Object]) -> Result<Object, Error> {
+    let mut s = string_mut(&args[0], "string-fill!")?.borrow_mut();
+    let fill = char_arg(&args[1], "string-fill!")?;
+    let range = number::range(&args[2..], s.len(), "string-fill!")?;
+    s[range].fill(fill);
+    Ok(Object::Unspecified)
+}
+
+fn string_to_list(args: &[Object]) -> Result<Object, Error> {
+    let s = string(&args[0], "string->list")?.borrow();
+    let range = number::range(&args[1..], s.len(), "string->list")?;
+    let chars = s[range].iter().map(|&c| Object::Char(c)).collect();
+    Ok(vec_to_list(chars, Object::Null))
+}
+
+fn string_to_vector(args: &[Object]) -> Result<Object, Error> {
+    let s = string(&args[0], "string->vector")?.borrow();
+    let range = number::range(&args[1..], s.len(), "string->vector")?;
+    Ok(vector::make(
+        s[range].iter().map(|&c| Object::Char(c)).collect(),
+    ))
+}
+
+fn string_to_utf8(args: &[Object]) -> Result<Object, Error> {
+    let s = string(&args[0], "string->utf8")?.borrow();
+    let range = number::range(&args[1..], s.len(), "string->utf8")?;
+    Ok(bytevector::make(
+        s[range].iter().collect::<String>().into_bytes(),
+    ))
+}
+
+fn list_to_string(args: &[Object]) -> Result<Object, Error> {
+    let chars = list::elements("list->string", &args[0])?
+        .iter()
+        .map(|c| char_arg(c, "list->string"))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(make(chars))
+}
+
+/// Whether `holds` is true of every adjacent pair of `args`, which compare
+/// lexicographically by code point.
+fn chain(args: &[Object], who: &str, holds: fn(&[char], &[char]) -> bool) -> Result<Object, Error> {
+    let strings = args
+        .iter()
+        .map(|arg| Ok(string(arg, who)?.borrow()))
+        .collect::<Result<Vec<Ref<Vec<char>>>, Error>>()?;
+    Ok(Object::Boolean(
+        strings.windows(2).all(|w| holds(&w[0], &w[1])),
+    ))
+}
+
+/// Like [`chain`], but comparing the strings' full case foldings, which
+/// can be longer than the strings: `"Straße"` and `"STRASSE"` are the same.
+fn chain_ci(
+    args: &[Object],
+    who: &str,
+    holds: fn(&[char], &[char]) -> bool,
+) -> Result<Object, Error> {
+    let folded = args
+        .iter()
+        .map(|arg| {
+            let s: String = string(arg, who)?.borrow().iter().collect();
+            Ok(foldcase(&s).chars().collect())
+        })
+        .collect::<Result<Vec<Vec<char>>, Error>>()?;
+    Ok(Object::Boolean(
+        folded.windows(2).all(|w| holds(&w[0], &w[1])),
+    ))
+}
+
+/// `s` run through `map`, which works on whole strings so that it can map
+/// characters differently depending on their neighbours, like lowercasing
+/// a sigma at the end of a word to `ς`.
+fn map_case(args: &[Object], who: &str, map: fn(&str) -> String) -> Result<Object, Error> {
+    let s: String = string(&args[0], who)?.borrow().iter().collect();
+    Ok(make(map(&s).chars()))
+}
+
+/// The full case folding of `s`, which can be longer than `s`: `ß` folds to
+/// `ss`. Folding one character is much like lowercasing its uppercase.
+fn foldcase(s: &str) -> String {
+    let mut folded = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            // ẞ uppercases to itself and lowercases to ß, rather than
+            // the ss that ß folds to
+            'ẞ' => folded.push_str("ss"),
+            'ı' | '\u{13A0}'..='\u{13FD}' | '\u{AB70}'..='\u{ABBF}' => {
+                folded.push(character::foldcase(c))
+            }
+            _ => folded.extend(c.to_uppercase().flat_map(char::to_lowercase)),
+        }
+    }
+    folded
+}
+
+fn normalize(args: &[Object], who: &str, form: Form) -> Result<Object, Error> {
+    let s = string(&args[0], who)?.borrow();
+    Ok(make(normalize::normalize(&s, form)))
+}
+
+/// Calls `f` with the characters at the same index in each of `strings`,
+/// up to the end of the shortest of them.
+fn lockstep(
+    who: &str,
+    strings: &[Object],
+    mut f: impl FnMut(Vec<Object>) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let strings = strings
+        .iter()
+        .map(|s| string(s, who))
+        .collect::<Result<Vec<_>, _>>()?;
+    let len = strings.iter().map(|s| s.borrow().len()).min().unwrap_or(0);
+    for i in 0..len {
+        // the characters are looked up afresh each time round, since `f`
+        // can change them
+        f(strings
+            .iter()
+            .map(|s| Object::Char(s.borrow()[i]))
+            .collect())?;
+    }
+    Ok(())
+}
+
+fn string_map(args: &[Object]) -> Result<Object, Error> {
+    let mut results = Vec::new();
+    lockstep("string-map", &args[1..], |chars| {
+        results.push(char_arg(&apply(&args[0], chars)?, "string-map")?);
+        Ok(())
+    })?;
+    Ok(make(results))
+}
+
+fn string_for_each(args: &[Object]) -> Result<Object, Error> {
+    lockstep("string-for-each", &args[1..], |chars| {
+        apply(&args[0], chars).map(drop)
+    })?;
+    Ok(Object::Unspecified)
+}
+
+/// The index of the first place `args[1]` appears in `args[0]`, or `#f` if
+/// it's nowhere in it.
+fn string_contains(args: &[Object]) -> Result<Object, Error> {
+    let haystack: String = string(&args[0], "string-contains")?
+        .borrow()
+        .iter()
+        .collect();
+    let needle: String = string(&args[1], "string-contains")?
+        .borrow()
+        .iter()
+        .collect();
+    // `str::find` takes linear time however the needle repeats itself, and
+    // the byte offset it finds is turned back into a character index
+    Ok(match haystack.find(&needle) {
+        Some(at) => Object::Number(Number::Integer(haystack[..at].chars().count() as i64)),
+        None => Object::Boolean(false),
+    })
+}
+
+/// Whether the string `args[1]` starts with the string `args[0]`, or ends
+/// with it if `end`.
+fn affix(args: &[Object], who: &str, end: bool) -> Result<Object, Error> {
+    let affix = string(&args[0], who)?.borrow();
+    let s = string(&args[1], who)?.borrow();
+    Ok(Object::Boolean(match end {
+        true => s.ends_with(&affix),
+        false => s.starts_with(&affix),
+    }))
+}
+
+/// The pieces of `args[0]` between each occurrence of `args[1]`, a
+/// character or a non-empty string. Every delimiter separates two fields,
+/// so there's one more field than delimiters: consecutive delimiters have
+/// an empty string between them, as does one at either end with the end.
+fn string_split(args: &[Object]) -> Result<Object, Error> {
+    let s: String = string(&args[0], "string-split")?.borrow().iter().collect();
+    let delimiter: String = match &args[1] {
+        Object::Char(c) => c.to_string(),
+        Object::String(d) if !d.borrow().is_empty() => d.borrow().iter().collect(),
+        other => {
+            return Err(Error::wrong_type(
+                "string-split",
+                "a character or a non-empty string",
+                other,
+            ))
+        }
+    };
+    let fields = s
+        .split(delimiter.as_str())
+        .map(|field| make(field.chars()))
+        .collect();
+    Ok(vec_to_list(fields, Object::Null))
+}
+
+/// `(string-pad s len [char [start [end]]])` as `who`: that range of `s`
+/// padded out with `char` to `len` characters, or cut down to them. That
+/// happens at the start of it, so that numbers line up on the right, or at
+/// the end if `right`.
+fn pad(args: &[Object], who: &str, right: bool) -> Result<Object, Error> {
+    let s = string(&args[0], who)?.borrow();
+    let len = number::index(&args[1], who)?;
+    let fill = match args.get(2) {
+        Some(c) => char_arg(c, who)?,
+        None => ' ',
+    };
+    let range = number::range(args.get(3..).unwrap_or_default(), s.len(), who)?;
+    let chars = &s[range];
+    let mut padded = vector::filled(len.saturating_sub(chars.len()), fill, who, &args[1])?;
+    Ok(match right {
+        true => make(chars[..len.min(chars.len())].iter().copied().chain(padded)),
+        false => {
+            padded.extend_from_slice(&chars[chars.len().saturating_sub(len)..]);
+            make(padded)
+        }
+    })
+}
+
+/// Whether `criterion` picks out `c` to be trimmed: `c` is whitespace if
+/// there's no criterion, or the criterion is `c`, or it's a predicate
+/// that's true of `c`.
+fn is_trimmed(c: char, criterion: Option<&Object>) -> Result<bool, Error> {
+    match criterion {
+        None => Ok(c.is_whitespace()),
+        Some(Object::Char(d)) => Ok(c == *d),
+        Some(predicate) => Ok(apply(predicate, vec![Object::Char(c)])?.is_truthy()),
+    }
+}
+
+/// `(string-trim s [criterion [start [end]]])` as `who`: that range of `s`
+/// without the characters `criterion` picks out at its start if `start`,
+/// and at its end if `end`.
+fn trim(args: &[Object], who: &str, start: bool, end: bool) -> Result<Object, Error> {
+    // a copy, since the criterion could change the string
+    let chars = string(&args[0], who)?.borrow().clone();
+    let range = number::range(args.get(2..).unwrap_or_default(), chars.len(), who)?;
+    let (mut from, mut to) = (range.start, range.end);
+    while start && from < to && is_trimmed(chars[from], args.get(1))? {
+        from += 1;
+    }
+    while end && from < to && is_trimmed(chars[to - 1], args.get(1))? {
+        to -= 1;
+    }
+    Ok(make(chars[from..to].iter().copied()))
+}
+
+fn make_string(args: &[Object]) -> Result<Object, Error> {
+    let k = number::index(&args[0], "make-string")?;
+    let fill = match args.get(1) {
+        Some(c) => char_arg(c, "make-string")?,
+        None => ' ',
+    };
+    Ok(make(vector::filled(k, fill, "make-string", &args[0])?))
+}
+
+pub const PRIMITIVES: &[Primitive] = &[
+    Primitive::fixed("string-length", 1, |args| {
+        let len = string(&args[0], "string-length")?.borrow().len();
+        Ok(Object::Number(Number::Integer(len as i64)))
+    }),
+    Primitive::fixed("string-ref", 2, string_ref),
+    Primitive::fixed("string-set!", 3, string_set),
+    Primitive::fixed("substring", 3, |args| copy(args, "substring")),
+    Primitive::variadic("string-append", 0, string_append),
+    Primitive::range("string-copy", 1, 3, |args| copy(args, "string-copy")),
+    Primitive::range("string-copy!", 3, 5, string_copy_to),
+    Primitive::range("string-fill!", 2, 4, string_fill),
+    Primitive::variadic("string=?", 2, |args| chain(args, "string=?", |a, b| a == b)),
+    Primitive::variadic("string<?", 2, |args| chain(args, "string<?", |a, b| a < b)),
+    Primitive::variadic("string>?", 2, |args| chain(args, "string>?", |a, b| a > b)),
+    Primitive::variadic("string<=?", 2, |args| {
+        chain(args, "string<=?", |a, b| a <= b)
+    }),
+    Primitive::variadic("string>=?", 2, |args| {
+        chain(args, "string>=?", |a, b| a >= b)
+    }),
+    Primitive::variadic("string-ci=?", 2, |args| {
+        chain_ci(args, "string-ci=?", |a, b| a == b)
+    }),
+    Primitive::variadic("string-ci<?", 2, |args| {
+        chain_ci(args, "string-ci<?", |a, b| a < b)
+    }),
+    Primitive::variadic("string-ci>?", 2, |args| {
+        chain_ci(args, "string-ci>?", |a, b| a > b)
+    }),
+    Primitive::variadic("string-ci<=?", 2, |args| {
+        chain_ci(args, "string-ci<=?", |a, b| a <= b)
+    }),
+    Primitive::variadic("string-ci>=?", 2, |args| {
+        chain_ci(args, "string-ci>=?", |a, b| a >= b)
+    }),
+    Primitive::fixed("string-upcase", 1, |args| {
+        map_case(args, "string-upcase", str::to_uppercase)
+    }),
+    Primitive::fixed("string-downcase", 1, |args| {
+        map_case(args, "string-downcase", str::to_lowercase)
+    }),
+    Primitive::fixed("string-foldcase", 1, |args| {
+        map_case(args, "string-foldcase", foldcase)
+    }),
+    Primitive::range("make-string", 1, 2, make_string),
+    // a fresh copy every time, and not a constant, so that it can be
+    // changed without changing the symbol
+    Primitive::fixed("symbol->string", 1, |args| match &args[0] {
+        Object::Symbol(name) => Ok(make(name.chars())),
+        other => Err(Error::wrong_type("symbol->string", "a symbol", other)),
+    }),
+    Primitive::fixed("string->symbol", 1, |args| {
+        let name: String = string(&args[0], "string->symbol")?
+            .borrow()
+            .iter()
+            .collect();
+        Ok(Object::Symbol(Rc::from(name)))
+    }),
+    Primitive::variadic("string-map", 2, string_map),
+    Primitive::variadic("string-for-each", 2, string_for_each),
+    // beyond R7RS, after SRFI 13
+    Primitive::fixed("string-contains", 2, string_contains),
+    Primitive::fixed("string-prefix?", 2, |args| {
+        affix(args, "string-prefix?", false)
+    }),
+    Primitive::fixed("string-suffix?", 2, |args| {
+        affix(args, "string-suffix?", true)
+    }),
+    Primitive::fixed("string-split", 2, string_split),
+    Primitive::range("string-pad", 2, 5, |args| pad(args, "string-pad", false)),
+    Primitive::range("string-pad-right", 2, 5, |args| {
+        pad(args, "string-pad-right", true)
+    }),
+    // unlike SRFI 13's, where `string-trim` only trims the start, this one
+    // trims both ends and `string-trim-left` is the one for the start
+    Primitive::range("string-trim", 1, 4, |args| {
+        trim(args, "string-trim", true, true)
+    }),
+    Primitive::range("string-trim-left", 1, 4, |args| {
+        trim(args, "string-trim-left", true, false)
+    }),
+    Primitive::range("string-trim-right", 1, 4, |args| {
+        trim(args, "string-trim-right", false, true)
+    }),
+    // beyond R7RS too, after R6RS
+    Primitive::fixed("string-normalize-nfc", 1, |args| {
+        normalize(args, "string-normalize-nfc", Form::Nfc)
+    }),
+    Primitive::fixed("string-normalize-nfd", 1, |args| {
+        normalize(args, "string-normalize-nfd", Form::Nfd)
+    }),
+    Primitive::fixed("string-normalize-nfkc", 1, |args| {
+        normalize(args, "string-normalize-nfkc", Form::Nfkc)
+    }),
+    Primitive::fixed("string-normalize-nfkd", 1, |args| {
+        normalize(args, "string-normalize-nfkd", Form::Nfkd)
+    }),
+    Primitive::range("string->list", 1, 3, string_to_list),
+    Primitive::fixed("list->string", 1, list_to_string),
+    Primitive::range("string->vector", 1, 3, string_to_vector),
+    Primitive::range("string->utf8", 1, 3, string_to_utf8),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{check, check_errors};
+
+    #[test]
+    fn length_ref_and_substring() {
+        check(&[
+            ("(string-length \"\")", "0"),
+            ("(string-length \"λx\")", "2"),
+            ("(string-ref \"λx\" 1)", "#\\x"),
+            ("(substring \"hello\" 1 3)", "\"el\""),
+            ("(substring \"hello\" 5 5)", "\"\""),
+        ]);
+        check_errors(&[
+            ("(string-ref \"abc\" 3)", "string-ref: index out of range 3"),
+            ("(string-ref \"abc\" -1)", "string-ref:"),
+            ("(substring \"hello\" 3 2)", "substring: index out of range"),
+            (
+                "(substring \"hello\" 1 9)",
+                "substring: index out of range 9",
+            ),
+            ("(string-length 'a)", "string-length: expected a string a"),
+        ]);
+    }
+
+    #[test]
+    fn appending_copying_and_filling() {
+        check(&[
+            ("(string-append \"ab\" \"\" \"cλ\")", "\"abcλ\""),
+            ("(string-append)", "\"\""),
+            ("(string-copy \"hello\" 1)", "\"ello\""),
+            ("(string-copy \"hello\" 1 3)", "\"el\""),
+            ("(define s \"abc\") (eq? s (string-copy s))", "#f"),
+            (
+                "(define s (string-copy \"abcde\")) (string-copy! s 1 s 0 3) s",
+                "\"aabce\"",
+            ),
+            (
+                "(define s (string-copy \"abcde\")) (string-copy! s 0 s 2) s",
+                "\"cdede\"",
+            ),
+            (
+                "(define s (make-string 3 #\\-)) (string-copy! s 1 \"xy\") s",
+                "\"-xy\"",
+            ),
+            (
+                "(define s (string-copy \"abcde\")) (string-fill! s #\\z 1 3) s",
+                "\"azzde\"",
+            ),
+            (
+                "(define s (string-copy \"abc\")) (string-fill! s #\\z) s",
+                "\"zzz\"",
+            ),
+        ]);
+        check_errors(&[
+            (
+                "(string-copy! (make-string 2) 1 \"abc\")",
+                "string-copy!: index out of range 1",
+            ),
+            (
+                "(string-fill! (make-string 2) 1)",
+                "string-fill!: expected a character 1",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn strings_and_lists() {
+        check(&[
+            ("(string->list \"abc\")", "(#\\a #\\b #\\c)"),
+            ("(string->list \"abcd\" 1)", "(#\\b #\\c #\\d)"),
+            ("(string->list \"abcd\" 1 3)", "(#\\b #\\c)"),
+            ("(string->list \"\")", "()"),
+            ("(list->string (list #\\a #\\λ))", "\"aλ\""),
+            ("(list->string '())", "\"\""),
+        ]);
+        check_errors(&[
+            (
+                "(list->string (list #\\a 1))",
+                "list->string: expected a character 1",
+            ),
+            (
+                "(list->string '(#\\a . #\\b))",
+                "list->string: expected a list",
+            ),
+            (
+                "(string->list \"abc\" 2 1)",
+                "string->list: index out of range",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn comparisons() {
+        check(&[
+            (
+                "(string=? \"abc\" (list->string (list #\\a #\\b #\\c)) (string-copy \"abc\"))",
+                "#t",
+            ),
+            ("(string=? \"abc\" \"abd\")", "#f"),
+            ("(string<? \"a\" \"ab\" \"b\")", "#t"),
+            ("(string<? \"a\" \"b\" \"b\")", "#f"),
+            ("(string<=? \"a\" \"b\" \"b\")", "#t"),
+            ("(string>? \"c\" \"b\" \"a\")", "#t"),
+            ("(string>=? \"b\" \"b\" \"c\")", "#f"),
+            ("(string<? \"Z\" \"a\")", "#t"),
+            ("(string<? \"\" \"a\")", "#t"),
+        ]);
+        check_errors(&[("(string<? \"a\" 1)", "string<?: expected a string 1")]);
+    }
+
+    #[test]
+    fn case_and_make_string() {
+        check(&[
+            ("(string-upcase \"straße\")", "\"STRASSE\""),
+            ("(string-length (string-upcase \"ß\"))", "2"),
+            ("(string-downcase \"HeLLo\")", "\"hello\""),
+            ("(string-downcase \"ΣΑΣ\")", "\"σας\""),
+            ("(string-foldcase \"Straße\")", "\"strasse\""),
+            ("(make-string 3 #\\x)", "\"xxx\""),
+            ("(string-length (make-string 4))", "4"),
+            ("(make-string 0 #\\x)", "\"\""),
+        ]);
+        check_errors(&[
+            (
+                "(make-string -1)",
+                "make-string: expected an exact non-negative integer -1",
+            ),
+            ("(make-string 2 \"x\")", "make-string: expected a character"),
+        ]);
+    }
+
+    #[test]
+    fn symbols_and_strings() {
+        check(&[
+            ("(symbol->string 'abc)", "\"abc\""),
+            ("(string->symbol \"hello world\")", "|hello world|"),
+            (
+                "(symbol->string (string->symbol \"hello world\"))",
+                "\"hello world\"",
+            ),
+            (
+                "(eq? (string->symbol \"hello world\") '|hello world|)",
+                "#t",
+            ),
+            ("(eq? (string->symbol (symbol->string 'x)) 'x)", "#t"),
+        ]);
+        check_errors(&[
+            (
+                "(symbol->string \"abc\")",
+                "symbol->string: expected a symbol",
+            ),
+            (
+                "(string->symbol 'abc)",
+                "string->symbol: expected a string abc",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn setting_characters() {
+        check(&[
+            (
+                "(define s (make-string 3 #\\a)) (string-set! s 1 #\\λ) s",
+                "\"aλa\"",
+            ),
+            (
+                "(define s (string-copy \"日本語\"))
+                 (string-set! s 0 #\\x)
+                 (list s (string-ref s 1) (string-length s))",
+                "(\"x本語\" #\\本 3)",
+            ),
+            (
+                "(list (string-set! (string-copy \"a\") 0 #\\b))",
+                "(<unspecified>)",
+            ),
+        ]);
+        check_errors(&[
+            (
+                "(string-set! (string-copy \"abc\") 3 #\\z)",
+                "string-set!: index out of range 3",
+            ),
+            (
+                "(string-set! (string-copy \"abc\") 0 \"z\")",
+                "string-set!: expected a character \"z\"",
+            ),
+            (
+                "(string-set! 'abc 0 #\\z)",
+                "string-set!: expected a string abc",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn mapping_over_strings() {
+        check(&[
+            ("(string-map char-upcase \"abc\")", "\"ABC\""),
+            ("(string-map char-upcase \"\")", "\"\""),
+            // in lockstep, for as long as the shortest string lasts
+            (
+                "(string-map (lambda (a b) (if (char<? a b) a b)) \"adcz\" \"bbbb\")",
+                "\"abbb\"",
+            ),
+            ("(string-map (lambda (a b) b) \"abc\" \"xy\")", "\"xy\""),
+            (
+                "(let ((out '()))
+                   (string-for-each (lambda (a b) (set! out (cons (list a b) out))) \"ab\" \"xyz\")
+                   out)",
+                "((#\\b #\\y) (#\\a #\\x))",
+            ),
+        ]);
+        check_errors(&[
+            (
+                "(string-map char->integer \"a\")",
+                "string-map: expected a character 97",
+            ),
+            (
+                "(string-map char-upcase 'a)",
+                "string-map: expected a string a",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn searching_strings() {
+        check(&[
+            ("(string-contains \"hello world\" \"o w\")", "4"),
+            ("(string-contains \"λλx\" \"x\")", "2"),
+            ("(string-contains \"hello\" \"xyz\")", "#f"),
+            ("(string-contains \"hello\" \"hello!\")", "#f"),
+            ("(string-contains \"hello\" \"\")", "0"),
+            ("(string-contains \"\" \"\")", "0"),
+            (
+                "(string-contains (string-append (make-string 100000 #\\a) \"b\")
+                                  (string-append (make-string 1000 #\\a) \"b\"))",
+                "99000",
+            ),
+            ("(string-prefix? \"he\" \"hello\")", "#t"),
+            ("(string-prefix? \"lo\" \"hello\")", "#f"),
+            ("(string-suffix? \"lo\" \"hello\")", "#t"),
+            ("(string-suffix? \"hello!\" \"hello\")", "#f"),
+            (
+                "(list (string-prefix? \"\" \"a\") (string-suffix? \"\" \"\"))",
+                "(#t #t)",
+            ),
+        ]);
+        check_errors(&[(
+            "(string-contains \"abc\" #\\a)",
+            "string-contains: expected a string #\\a",
+        )]);
+    }
+
+    #[test]
+    fn splitting() {
+        check(&[
+            // every delimiter separates two fields, empty or not
+            ("(string-split \"a,b,,c\" #\\,)", "(\"a\" \"b\" \"\" \"c\")"),
+            ("(string-split \",a,\" #\\,)", "(\"\" \"a\" \"\")"),
+            ("(string-split \"abc\" #\\,)", "(\"abc\")"),
+            ("(string-split \"\" #\\,)", "(\"\")"),
+            ("(string-split \"a::b:c\" \"::\")", "(\"a\" \"b:c\")"),
+            ("(string-split \"λ→μ→\" #\\→)", "(\"λ\" \"μ\" \"\")"),
+        ]);
+        check_errors(&[
+            (
+                "(string-split \"abc\" \"\")",
+                "string-split: expected a character or a non-empty string \"\"",
+            ),
+            (
+                "(string-split 'abc #\\,)",
+                "string-split: expected a string abc",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn padding_and_trimming() {
+        check(&[
+            // padded on the left, or cut down to the end
+            ("(string-pad \"42\" 5)", "\"   42\""),
+            ("(string-pad \"42\" 5 #\\0)", "\"00042\""),
+            ("(string-pad \"12345\" 3)", "\"345\""),
+            ("(string-pad \"abc\" 3)", "\"abc\""),
+            ("(string-pad \"abcdef\" 4 #\\. 1 4)", "\".bcd\""),
+            // padded on the right, or cut down to the start
+            ("(string-pad-right \"ab\" 4)", "\"ab  \""),
+            ("(string-pad-right \"ab\" 4 #\\*)", "\"ab**\""),
+            ("(string-pad-right \"abcdef\" 2)", "\"ab\""),
+            ("(string-pad-right \"abc\" 0)", "\"\""),
+            ("(string-trim \"  a b \\n\")", "\"a b\""),
+            ("(string-trim-left \"  a b \")", "\"a b \""),
+            ("(string-trim-right \"  a b \")", "\"  a b\""),
+            ("(string-trim \"   \")", "\"\""),
+            ("(string-trim \"xxaxx\" #\\x)", "\"a\""),
+            ("(string-trim-left \"123abc\" char-numeric?)", "\"abc\""),
+            ("(string-trim \"  a  b  \" #\\space 1 7)", "\"a  b\""),
+        ]);
+        check_errors(&[
+            (
+                "(string-pad \"a\" -1)",
+                "string-pad: expected an exact non-negative integer -1",
+            ),
+            (
+                "(string-pad \"a\" 3 \"x\")",
+                "string-pad: expected a character \"x\"",
+            ),
+            ("(string-trim 'a)", "string-trim: expected a string a"),
+        ]);
+    }
+
+    #[test]
+    fn case_insensitive_comparisons() {
+        check(&[
+            ("(string-ci=? \"Hello\" \"hELLO\")", "#t"),
+            ("(string-ci=? \"abc\" \"ABC\" \"aBd\")", "#f"),
+            ("(string-ci<? \"apple\" \"Banana\" \"cherry\")", "#t"),
+            ("(string-ci<? \"apple\" \"Banana\" \"banana\")", "#f"),
+            (
+                "(list (string<? \"a\" \"B\") (string-ci<? \"a\" \"B\"))",
+                "(#f #t)",
+            ),
+            ("(string-ci>? \"c\" \"B\" \"a\")", "#t"),
+            ("(string-ci<=? \"a\" \"A\" \"b\")", "#t"),
+            ("(string-ci>=? \"b\" \"B\" \"c\")", "#f"),
+            // folding ß makes it two characters long
+            ("(string-ci=? \"Straße\" \"STRASSE\" \"strasse\")", "#t"),
+            ("(string-ci<? \"ß\" \"sst\")", "#t"),
+            ("(string-ci>? \"ßa\" \"ss\")", "#t"),
+            ("(string-ci=? \"ΣΑΣ\" \"σας\")", "#t"),
+        ]);
+        check_errors(&[(
+            "(string-ci=? \"a\" #\\a)",
+            "string-ci=?: expected a string #\\a",
+        )]);
+    }
+
+    #[test]
+    fn normalization() {
+        let precomposed = "(list->string (list #\\xe9))";
+        let decomposed = "(list->string (list #\\e #\\x301))";
+        check(&[
+            (&format!("(string=? {precomposed} {decomposed})"), "#f"),
+            (
+                &format!("(string=? (string-normalize-nfc {decomposed}) {precomposed})"),
+                "#t",
+            ),
+            (
+                &format!("(string=? (string-normalize-nfd {precomposed}) {decomposed})"),
+                "#t",
+            ),
+            (
+                &format!("(string-length (string-normalize-nfd {precomposed}))"),
+                "2",
+            ),
+            // combining marks are put in a canonical order
+            (
+                "(string=? (string-normalize-nfc (list->string (list #\\a #\\x323 #\\x301)))
+                           (string-normalize-nfc (list->string (list #\\a #\\x301 #\\x323))))",
+                "#t",
+            ),
+            // Hangul syllables come apart into their jamo and back
+            ("(string-length (string-normalize-nfd \"한\"))", "3"),
+            (
+                "(string-normalize-nfc (string-normalize-nfd \"한국어\"))",
+                "\"한국어\"",
+            ),
+            // only the compatibility forms fold ligatures and the like
+            ("(string-normalize-nfc \"ﬁ\")", "\"ﬁ\""),
+            ("(string-normalize-nfkc \"ﬁ²\")", "\"fi2\""),
+            ("(string-length (string-normalize-nfkd \"ǆ\"))", "3"),
+            ("(string-normalize-nfc \"\")", "\"\""),
+        ]);
+        check_errors(&[(
+            "(string-normalize-nfc 'e)",
+            "string-normalize-nfc: expected a string e",
+        )]);
+    }
+}
@@ -0,0 +1,140 @@
+//! The containers that objects are built out of: `Rc`, `RefCell` and
+//! `Cell` ordinarily, or with the `sync` feature, stand-ins with the same
+//! interface that are safe to share between threads, so that `Object` is
+//! `Send` and `Sync`. Those cost atomic reference counts and a lock for
+//! every borrow, which is why they aren't the default.
+//!
+//! Where a `RefCell` would panic at a conflicting borrow, the stand-in
+//! blocks instead, so the same care is needed not to hold a borrow across
+//! code that might want to mutate the same thing.
+//!
+//! Without the `std` feature there are no threads to keep state apart for,
+//! and [`Local`] is what `thread_local!` makes instead, except in the
+//! tests, which have std anyway and run on threads of their own.
+
+#[cfg(not(feature = "sync"))]
+mod imp {
+    pub use alloc::rc::{Rc, Weak};
+    pub use core::cell::{Cell, Ref, RefCell};
+
+    /// What everything shared inside objects has to be: nothing, without
+    /// the `sync` feature.
+    pub trait Threadsafe {}
+
+    impl<T: ?Sized> Threadsafe for T {}
+}
+
+#[cfg(feature = "sync")]
+mod imp {
+    use std::sync::{Mutex, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError};
+
+    pub use std::sync::{Arc as Rc, Weak};
+
+    /// What everything shared inside objects has to be: safe to send to
+    /// and share with other threads.
+    pub trait Threadsafe: Send + Sync {}
+
+    impl<T: Send + Sync + ?Sized> Threadsafe for T {}
+
+    pub type Ref<'a, T> = RwLockReadGuard<'a, T>;
+
+    pub type RefMut<'a, T> = RwLockWriteGuard<'a, T>;
+
+    /// Why [`RefCell::try_borrow_mut`] failed: something else has it
+    /// borrowed.
+    #[derive(Debug)]
+    pub struct BorrowMutError;
+
+    /// `std::cell::RefCell`'s interface over a read-write lock. A panic
+    /// while it was borrowed doesn't stop it being borrowed again, just as
+    /// with a `RefCell`.
+    #[derive(Default)]
+    pub struct RefCell<T: ?Sized>(RwLock<T>);
+
+    impl<T> RefCell<T> {
+        pub const fn new(value: T) -> RefCell<T> {
+            RefCell(RwLock::new(value))
+        }
+
+        pub fn replace(&self, value: T) -> T {
+            std::mem::replace(&mut self.borrow_mut(), value)
+        }
+    }
+
+    impl<T: ?Sized> RefCell<T> {
+        pub fn borrow(&self) -> Ref<'_, T> {
+            self.0.read().unwrap_or_else(PoisonError::into_inner)
+        }
+
+        pub fn borrow_mut(&self) -> RefMut<'_, T> {
+            self.0.write().unwrap_or_else(PoisonError::into_inner)
+        }
+
+        pub fn try_borrow_mut(&self) -> Result<RefMut<'_, T>, BorrowMutError> {
+            match self.0.try_write() {
+                Ok(guard) => Ok(guard),
+                Err(TryLockError::Poisoned(poisoned)) => Ok(poisoned.into_inner()),
+                Err(TryLockError::WouldBlock) => Err(BorrowMutError),
+            }
+        }
+    }
+
+    /// `std::cell::Cell`'s interface over a mutex.
+    #[derive(Default)]
+    pub struct Cell<T>(Mutex<T>);
+
+    impl<T> Cell<T> {
+        pub const fn new(value: T) -> Cell<T> {
+            Cell(Mutex::new(value))
+        }
+
+        pub fn set(&self, value: T) {
+            *self.0.lock().unwrap_or_else(PoisonError::into_inner) = value;
+        }
+    }
+
+    impl<T: Copy> Cell<T> {
+        pub fn get(&self) -> T {
+            *self.0.lock().unwrap_or_else(PoisonError::into_inner)
+        }
+    }
+}
+
+pub use imp::*;
+
+/// What would be per thread, kept once for the whole program, and made the
+/// first time it's used. It's shared without any locking, which is only
+/// sound because a build without std must only be used from one thread.
+#[cfg(not(any(feature = "std", test)))]
+pub struct Local<T: 'static> {
+    init: fn() -> T,
+    value: core::cell::OnceCell<T>,
+}
+
+// SAFETY: a build without std is only ever used from the one thread, as
+// the `std` feature says, so nothing is really shared between threads
+#[cfg(not(any(feature = "std", test)))]
+unsafe impl<T> Sync for Local<T> {}
+
+#[cfg(not(any(feature = "std", test)))]
+impl<T> Local<T> {
+    pub const fn new(init: fn() -> T) -> Local<T> {
+        Local {
+            init,
+            value: core::cell::OnceCell::new(),
+        }
+    }
+
+    pub fn with<R>(&'static self, f: impl FnOnce(&T) -> R) -> R {
+        f(self.value.get_or_init(self.init))
+    }
+
+    /// Like `LocalKey::try_with`, which only fails while a thread exits.
+    #[cfg(feature = "pair-pool")]
+    pub fn try_with<R>(
+        &'static self,
+        f: impl FnOnce(&T) -> R,
+    ) -> Result<R, core::convert::Infallible> {
+        Ok(self.with(f))
+    }
+}
@@ -0,0 +1,667 @@
+use alloc::{borrow::ToOwned, string::ToString, vec::Vec};
+
+use crate::{
+    collections::HashMap,
+    cons,
+    env::Env,
+    equal,
+    error::Error,
+    eval::Primitive,
+    list_to_vec,
+    sync::{Cell, Rc, RefCell, Weak},
+    vec_to_list, vector, Object,
+};
+
+/// A macro, as made by `syntax-rules`.
+pub struct Macro {
+    name: Rc<str>,
+    ellipsis: Rc<str>,
+    literals: Vec<Rc<str>>,
+    /// Each rule's pattern, without the keyword it starts with, and its
+    /// template.
+    rules: Vec<(Object, Object)>,
+    /// Where the macro was defined, and so where the identifiers that its
+    /// templates introduce mean something.
+    env: Rc<Env>,
+}
+
+/// What an alias stands for: the identifier it renames, as it is in the
+/// environment of the macro that introduced it.
+struct Alias {
+    symbol: Weak<str>,
+    name: Rc<str>,
+    env: Rc<Env>,
+}
+
+thread_local! {
+    static COUNTER: Cell<u64> = const { Cell::new(0) };
+    /// Every fresh name that might still be around, by its address. Holding
+    /// on to them weakly keeps that address from being reused by another.
+    static FRESH_NAMES: RefCell<HashMap<usize, Weak<str>>> = RefCell::new(HashMap::new());
+    /// How big `FRESH_NAMES` can get before the names nothing refers to any
+    /// more are cleared out of it.
+    static FRESH_LIMIT: Cell<usize> = const { Cell::new(64) };
+    /// Every alias that might still be around, by the address of its name.
+    static ALIASES: RefCell<HashMap<usize, Alias>> = RefCell::new(HashMap::new());
+    /// How big `ALIASES` can get before the aliases nothing refers to any
+    /// more are cleared out of it.
+    static LIMIT: Cell<usize> = const { Cell::new(64) };
+}
+
+/// The prefix of names that the reader can't produce.
+const FRESH: &str = "#:";
+
+/// A new name, unlike any other, that starts with `stem`.
+///
+/// It isn't interned: a symbol that's only spelt the same, as `|#:g.1|`
+/// reads as, is a different symbol, since [`is_fresh`] can tell them apart.
+pub fn fresh(stem: &str) -> Rc<str> {
+    let n = COUNTER.with(|counter| {
+        counter.set(counter.get() + 1);
+        counter.get()
+    });
+    let name: Rc<str> = Rc::from(format!("{FRESH}{stem}.{n}"));
+    FRESH_NAMES.with(|names| {
+        let mut names = names.borrow_mut();
+        if names.len() >= FRESH_LIMIT.with(Cell::get) {
+            names.retain(|_, name| name.strong_count() > 0);
+            FRESH_LIMIT.with(|limit| limit.set((2 * names.len()).max(64)));
+        }
+        names.insert(address(&name), Rc::downgrade(&name));
+    });
+    name
+}
+
+fn address(name: &Rc<str>) -> usize {
+    Rc::as_ptr(name).cast::<u8>() as usize
+}
+
+/// Whether `name` was made by [`fresh`], rather than just spelt like it.
+pub fn is_fresh(name: &Rc<str>) -> bool {
+    name.starts_with(FRESH) && FRESH_NAMES.with(|names| names.borrow().contains_key(&address(name)))
+}
+
+/// A fresh identifier that means whatever `name` means in `env`, at least
+/// until something binds it.
+///
+/// This is how expansion stays hygienic: an identifier a template
+/// introduces is replaced by an alias, so it can't capture or be captured
+/// by the identifiers around the place the macro was used, and looking
+/// the alias up when nothing has bound it gets to the original in `env`.
+fn alias(name: &Rc<str>, env: &Rc<Env>) -> Rc<str> {
+    let symbol = fresh(&unalias(name));
+    ALIASES.with(|aliases| {
+        let mut aliases = aliases.borrow_mut();
+        if aliases.len() >= LIMIT.with(Cell::get) {
+            aliases.retain(|_, alias| alias.symbol.strong_count() > 0);
+            LIMIT.with(|limit| limit.set((2 * aliases.len()).max(64)));
+        }
+        let alias = Alias {
+            symbol: Rc::downgrade(&symbol),
+            name: name.clone(),
+            env: env.clone(),
+        };
+        aliases.insert(address(&symbol), alias);
+    });
+    symbol
+}
+
+/// The identifier that `name` is an alias of and the environment it
+/// belongs to, if `name` is an alias.
+pub fn resolve(name: &Rc<str>) -> Option<(Rc<str>, Rc<Env>)> {
+    if !name.starts_with(FRESH) {
+        return None;
+    }
+    ALIASES.with(|aliases| {
+        let aliases = aliases.borrow();
+        let alias = aliases.get(&address(name))?;
+        Some((alias.name.clone(), alias.env.clone()))
+    })
+}
+
+/// The name that `name` is an alias of, through any number of aliases.
+pub fn unalias(name: &Rc<str>) -> Rc<str> {
+    match resolve(name) {
+        Some((name, _)) => unalias(&name),
+        None => name.clone(),
+    }
+}
+
+/// The syntactic keyword that `name` stands for in `env`. That's the name
+/// itself, unless it's an alias that nothing has bound.
+pub fn keyword(name: &Rc<str>, env: &Env) -> Rc<str> {
+    match resolve(name) {
+        Some((original, original_env)) if !env.binds(name) => keyword(&original, &original_env),
+        _ => name.clone(),
+    }
+}
+
+/// `datum` with every alias in it replaced by the name it stands for, as
+/// it should be when it's quoted.
+pub fn strip(datum: &Object) -> Object {
+    match datum {
+        Object::Symbol(name) => Object::Symbol(unalias(name)),
+        Object::Pair(_) => {
+            let mut items = Vec::new();
+            let mut rest = datum.clone();
+            while let Object::Pair(pair) = rest {
+                items.push(strip(&pair.car()));
+                rest = pair.cdr();
+            }
+            vec_to_list(items, strip(&rest))
+        }
+        Object::Vector(items) => vector::make(items.borrow().iter().map(strip).collect()),
+        _ => datum.clone(),
+    }
+}
+
+/// What a pattern variable matched: a form, or for one followed by an
+/// ellipsis, whatever it matched each time.
+#[derive(Clone)]
+enum Binding {
+    One(Object),
+    Many(Rc<Vec<Binding>>),
+}
+
+type Bindings = HashMap<Rc<str>, Binding>;
+
+/// How a template is being expanded.
+#[derive(Clone, Copy)]
+enum Context {
+    /// As code, so that the identifiers it introduces get renamed.
+    Code,
+    /// Inside a quotation, where identifiers are just symbols.
+    Quoted,
+    /// Inside `depth` levels of quasiquotation.
+    Quasi(usize),
+}
+
+impl Macro {
+    /// The macro that `(syntax-rules [ellipsis] (literal...) (pattern
+    /// template)...)` specifies, given `operands` after `syntax-rules`.
+    pub fn new(
+        name: Rc<str>,
+        spec: &Object,
+        operands: &[Object],
+        env: &Rc<Env>,
+    ) -> Result<Macro, Error> {
+        let bad = || Error::new("bad syntax", vec![spec.clone()]);
+        let (ellipsis, operands) = match operands {
+            [Object::Symbol(ellipsis), rest @ ..] => (ellipsis.clone(), rest),
+            _ => (Rc::from("..."), operands),
+        };
+        let [literals, rules @ ..] = operands else {
+            return Err(bad());
+        };
+        let literals = list_to_vec(literals)
+            .ok_or_else(bad)?
+            .into_iter()
+            .map(|literal| match literal {
+                Object::Symbol(name) => Ok(name),
+                _ => Err(bad()),
+            })
+            .collect::<Result<_, _>>()?;
+        let rules = rules
+            .iter()
+            .map(|rule| match &list_to_vec(rule).unwrap_or_default()[..] {
+                [Object::Pair(pattern), template] => Ok((pattern.cdr(), template.clone())),
+                _ => Err(bad()),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Macro {
+            name,
+            ellipsis,
+            literals,
+            rules,
+            env: env.clone(),
+        })
+    }
+
+    /// The expansion of `form`, a use of this macro.
+    pub fn expand(&self, form: &Object) -> Result<Object, Error> {
+        let Object::Pair(pair) = form else {
+            return Err(Error::new("bad syntax", vec![form.clone()]));
+        };
+        for (pattern, template) in &self.rules {
+            let mut bindings = Bindings::new();
+            if self.matches(pattern, &pair.cdr(), &mut bindings) {
+                let mut renames = HashMap::new();
+                return self.instantiate(template, &bindings, Context::Code, &mut renames);
+            }
+        }
+        Err(Error::new(
+            format!("{}: no pattern matches", unalias(&self.name)),
+            vec![form.clone()],
+        ))
+    }
+
+    fn is_ellipsis(&self, obj: &Object) -> bool {
+        matches!(obj, Object::Symbol(s) if *s == self.ellipsis)
+    }
+
+    /// What follows `pattern`'s first element, if that's an ellipsis.
+    fn after_ellipsis(&self, pattern: &Object) -> Option<Object> {
+        match pattern {
+            Object::Pair(pair) => match pair.cdr() {
+                Object::Pair(next) if self.is_ellipsis(&next.car()) => Some(next.cdr()),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Whether `form` matches `pattern`, adding what its pattern variables
+    /// matched to `bindings` if so.
+    fn matches(&self, pattern: &Object, form: &Object, bindings: &mut Bindings) -> bool {
+        match pattern {
+            Object::Symbol(literal) if self.literals.contains(literal) => {
+                matches!(form, Object::Symbol(s) if unalias(s) == unalias(literal))
+            }
+            Object::Symbol(s) if &**s == "_" => true,
+            Object::Symbol(var) => {
+                bindings.insert(var.clone(), Binding::One(form.clone()));
+                true
+            }
+            Object::Pair(pair) => match self.after_ellipsis(pattern) {
+                Some(after) => self.matches_repeated(&pair.car(), &after, form, bindings),
+                None => match form {
+                    Object::Pair(form) => {
+                        self.matches(&pair.car(), &form.car(), bindings)
+                            && self.matches(&pair.cdr(), &form.cdr(), bindings)
+                    }
+                    _ => false,
+                },
+            },
+            Object::Vector(items) => match form {
+                Object::Vector(form) => {
+                    let pattern = vec_to_list(items.borrow().clone(), Object::Null);
+                    let form = vec_to_list(form.borrow().clone(), Object::Null);
+                    self.matches(&pattern, &form, bindings)
+                }
+                _ => false,
+            },
+            _ => equal(pattern, form),
+        }
+    }
+
+    /// Whether `form` matches `repeated ... . after`: as many of its
+    /// elements as `after` leaves over each match `repeated`.
+    fn matches_repeated(
+        &self,
+        repeated: &Object,
+        after: &Object,
+        form: &Object,
+        bindings: &mut Bindings,
+    ) -> bool {
+        let mut needed = 0;
+        let mut rest = after.clone();
+        while let Object::Pair(pair) = rest {
+            needed += 1;
+            rest = pair.cdr();
+        }
+        let mut items = Vec::new();
+        let mut tail = form.clone();
+        while let Object::Pair(pair) = tail {
+            items.push(pair.car());
+            tail = pair.cdr();
+        }
+        let Some(count) = items.len().checked_sub(needed) else {
+            return false;
+        };
+        let mut matched = Vec::with_capacity(count);
+        for item in &items[..count] {
+            let mut each = Bindings::new();
+            if !self.matches(repeated, item, &mut each) {
+                return false;
+            }
+            matched.push(each);
+        }
+        for var in self.pattern_vars(repeated) {
+            let each = matched.iter().map(|each| each[&var].clone()).collect();
+            bindings.insert(var, Binding::Many(Rc::new(each)));
+        }
+        let rest = vec_to_list(items[count..].to_vec(), tail);
+        self.matches(after, &rest, bindings)
+    }
+
+    fn pattern_vars(&self, pattern: &Object) -> Vec<Rc<str>> {
+        let mut vars = Vec::new();
+        let mut pending = vec![pattern.clone()];
+        while let Some(pattern) = pending.pop() {
+            match pattern {
+                Object::Symbol(s)
+                    if !self.literals.contains(&s) && s != self.ellipsis && &*s != "_" =>
+                {
+                    vars.push(s)
+                }
+                Object::Pair(pair) => pending.extend([pair.car(), pair.cdr()]),
+                Object::Vector(items) => pending.extend(items.borrow().iter().cloned()),
+                _ => {}
+            }
+        }
+        vars
+    }
+
+    /// `template` with the pattern variables in it replaced by what they
+    /// matched, and the identifiers it introduces renamed.
+    fn instantiate(
+        &self,
+        template: &Object,
+        bindings: &Bindings,
+        context: Context,
+        renames: &mut HashMap<Rc<str>, Rc<str>>,
+    ) -> Result<Object, Error> {
+        match template {
+            Object::Symbol(s) => match (bindings.get(s), context) {
+                (Some(Binding::One(form)), Context::Code) => Ok(form.clone()),
+                (Some(Binding::One(form)), _) => Ok(strip(form)),
+                (Some(Binding::Many(_)), _) => Err(Error::new(
+                    format!(
+                        "{}: pattern variable used without an ellipsis",
+                        unalias(&self.name)
+                    ),
+                    vec![template.clone()],
+                )),
+                (None, Context::Code) => {
+                    let renamed = renames
+                        .entry(s.clone())
+                        .or_insert_with(|| alias(s, &self.env));
+                    Ok(Object::Symbol(renamed.clone()))
+                }
+                (None, _) => Ok(Object::Symbol(unalias(s))),
+            },
+            Object::Pair(pair) => {
+                let head = pair.car();
+                // `(... template)` stands for `template` with ellipses taken
+                // literally
+                if self.is_ellipsis(&head) {
+                    if let Some([escaped]) = list_to_vec(&pair.cdr()).as_deref() {
+                        let literal = Macro {
+                            name: self.name.clone(),
+                            ellipsis: fresh("ellipsis"),
+                            literals: Vec::new(),
+                            rules: Vec::new(),
+                            env: self.env.clone(),
+                        };
+                        return literal.instantiate(escaped, bindings, context, renames);
+                    }
+                }
+                let inner = match (&head, context) {
+                    (Object::Symbol(s), Context::Code) if !bindings.contains_key(s) => {
+                        match &*unalias(s) {
+                            "quote" => Context::Quoted,
+                            "quasiquote" => Context::Quasi(1),
+                            _ => Context::Code,
+                        }
+                    }
+                    (Object::Symbol(s), Context::Quasi(depth)) => match &**s {
+                        "quasiquote" => Context::Quasi(depth + 1),
+                        "unquote" | "unquote-splicing" if depth == 1 => Context::Code,
+                        "unquote" | "unquote-splicing" => Context::Quasi(depth - 1),
+                        _ => context,
+                    },
+                    _ => context,
+                };
+                let mut items = Vec::new();
+                let mut rest = template.clone();
+                // the head itself is in the outer context
+                let mut each = context;
+                while let Object::Pair(pair) = rest.clone() {
+                    let element = pair.car();
+                    let mut depth = 0;
+                    rest = pair.cdr();
+                    while let Object::Pair(next) = rest.clone() {
+                        if !self.is_ellipsis(&next.car()) {
+                            break;
+                        }
+                        depth += 1;
+                        rest = next.cdr();
+                    }
+                    if depth == 0 {
+                        items.push(self.instantiate(&element, bindings, each, renames)?);
+                    } else {
+                        self.repeat(&element, depth, bindings, each, renames, &mut items)?;
+                    }
+                    each = inner;
+                }
+                let tail = self.instantiate(&rest, bindings, inner, renames)?;
+                Ok(vec_to_list(items, tail))
+            }
+            Object::Vector(items) => {
+                let list = vec_to_list(items.borrow().clone(), Object::Null);
+                let list =
+                    self.instantiate(&cons(Object::Null, list), bindings, context, renames)?;
+                let items = list_to_vec(&list).unwrap_or_default();
+                Ok(vector::make(items[1..].to_vec()))
+            }
+            _ => Ok(template.clone()),
+        }
+    }
+
+    /// Instantiates `template ...`, with `depth` ellipses, into `out`: once
+    /// for each of the forms matched by its pattern variables that were
+    /// followed by ellipses.
+    fn repeat(
+        &self,
+        template: &Object,
+        depth: usize,
+        bindings: &Bindings,
+        context: Context,
+        renames: &mut HashMap<Rc<str>, Rc<str>>,
+        out: &mut Vec<Object>,
+    ) -> Result<(), Error> {
+        let bad = |message: &str| {
+            Error::new(
+                format!("{}: {message}", unalias(&self.name)),
+                vec![template.clone()],
+            )
+        };
+        let repeated = self
+            .pattern_vars(template)
+            .into_iter()
+            .filter_map(|var| match bindings.get(&var) {
+                Some(Binding::Many(each)) => Some((var, each.clone())),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let Some((_, first)) = repeated.first() else {
+            return Err(bad("nothing to repeat before an ellipsis"));
+        };
+        let count = first.len();
+        if repeated.iter().any(|(_, each)| each.len() != count) {
+            return Err(bad("pattern variables repeat different numbers of times"));
+        }
+        for i in 0..count {
+            let mut inner = bindings.clone();
+            for (var, each) in &repeated {
+                inner.insert(var.clone(), each[i].clone());
+            }
+            if depth > 1 {
+                self.repeat(template, depth - 1, &inner, context, renames, out)?;
+            } else {
+                out.push(self.instantiate(template, &inner, context, renames)?);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `(gensym [prefix])`: a symbol that no other symbol is equal to, and
+/// that can't be read back in, named after `prefix` if it's given.
+fn gensym(args: &[Object]) -> Result<Object, Error> {
+    let stem = match args.first() {
+        None => "g".to_owned(),
+        Some(Object::Symbol(s)) => s.to_string(),
+        Some(Object::String(s)) => s.borrow().iter().collect(),
+        Some(other) => return Err(Error::wrong_type("gensym", "a string or symbol", other)),
+    };
+    Ok(Object::Symbol(fresh(&stem)))
+}
+
+pub const PRIMITIVES: &[Primitive] = &[Primitive::range("gensym", 0, 1, gensym)];
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{check, check_errors};
+
+    #[test]
+    fn syntax_rules_macros() {
+        let swap = "(define-syntax swap!
+                      (syntax-rules ()
+                        ((_ a b) (let ((tmp a)) (set! a b) (set! b tmp)))))";
+        let my_list = "(define-syntax my-list
+                         (syntax-rules ()
+                           ((_) '())
+                           ((_ x rest ...) (cons x (my-list rest ...)))))";
+        check(&[
+            (
+                &format!("{swap} (define x 1) (define y 2) (swap! x y) (list x y)"),
+                "(2 1)",
+            ),
+            // the macro's `tmp` doesn't capture the user's
+            (
+                &format!("{swap} (define tmp 1) (define y 2) (swap! tmp y) (list tmp y)"),
+                "(2 1)",
+            ),
+            (&format!("{my_list} (my-list)"), "()"),
+            (&format!("{my_list} (my-list 1 (+ 1 1) 3)"), "(1 2 3)"),
+            // nor does a user's `if` change what the macro's `if` means
+            (
+                "(define-syntax my-or
+                   (syntax-rules ()
+                     ((_) #f)
+                     ((_ e) e)
+                     ((_ e r ...) (let ((t e)) (if t t (my-or r ...))))))
+                 (define t 5)
+                 (let ((if list)) (my-or #f t))",
+                "5",
+            ),
+            (
+                "(define-syntax my-let*
+                   (syntax-rules ()
+                     ((_ () body ...) (let () body ...))
+                     ((_ ((x v) rest ...) body ...) (let ((x v)) (my-let* (rest ...) body ...)))))
+                 (my-let* ((a 1) (b (+ a 1))) (* a b))",
+                "2",
+            ),
+            (
+                "(define-syntax flatten
+                   (syntax-rules ()
+                     ((_ (a ...) ...) '(a ... ...))))
+                 (flatten (1 2) () (3))",
+                "(1 2 3)",
+            ),
+            (
+                "(define-syntax pairs
+                   (syntax-rules ()
+                     ((_ (k v ...) ...) '((k . (v ...)) ...))))
+                 (pairs (a 1 2) (b) (c 3))",
+                "((a 1 2) (b) (c 3))",
+            ),
+            (
+                "(define-syntax arrow
+                   (syntax-rules (=>)
+                     ((_ a => b) (list 'arrow a b))
+                     ((_ a b c) (list 'plain a b c))))
+                 (list (arrow 1 => 2) (arrow 1 2 3))",
+                "((arrow 1 2) (plain 1 2 3))",
+            ),
+            (
+                "(define-syntax vec
+                   (syntax-rules ()
+                     ((_ #(a ...)) (list a ...))))
+                 (vec #(1 2 3))",
+                "(1 2 3)",
+            ),
+            (
+                "(define-syntax my-ellipsis
+                   (syntax-rules ::: ()
+                     ((_ x :::) (list x ::: '...))))
+                 (my-ellipsis 1 2)",
+                "(1 2 ...)",
+            ),
+        ]);
+        check_errors(&[(
+            &format!("{swap} (swap! 1)"),
+            "swap!: no pattern matches (swap! 1)",
+        )]);
+    }
+
+    #[test]
+    fn local_macros() {
+        check(&[
+            (
+                "(let-syntax ((twice (syntax-rules () ((_ e) (begin e e)))))
+                   (define n 0)
+                   (twice (set! n (+ n 1)))
+                   n)",
+                "2",
+            ),
+            (
+                "(letrec-syntax
+                     ((my-even? (syntax-rules ()
+                                  ((_) #t)
+                                  ((_ x rest ...) (my-odd? rest ...))))
+                      (my-odd? (syntax-rules ()
+                                 ((_) #f)
+                                 ((_ x rest ...) (my-even? rest ...)))))
+                   (list (my-even? a b c d) (my-odd? a b c)))",
+                "(#t #t)",
+            ),
+            // let-syntax macros see the outer binding of their own keyword
+            (
+                "(define-syntax m (syntax-rules () ((_) 'outer)))
+                 (let-syntax ((m (syntax-rules () ((_) (list 'inner (m))))))
+                   (m))",
+                "(inner outer)",
+            ),
+            (
+                "(define (f) 'procedure)
+                 (let-syntax ((f (syntax-rules () ((_) 'macro)))) (f))",
+                "macro",
+            ),
+            (
+                "(define (f) 'procedure)
+                 (let-syntax ((f (syntax-rules () ((_) 'macro)))) (f))
+                 (f)",
+                "procedure",
+            ),
+        ]);
+        check_errors(&[(
+            "(let-syntax ((local (syntax-rules () ((_) 1)))) (local)) (local)",
+            "unbound variable local",
+        )]);
+    }
+
+    #[test]
+    fn gensyms_are_uninterned() {
+        let spelt_the_same = "(define g (gensym))
+                              (define same
+                                (read (open-input-string
+                                        (string-append \"|\" (symbol->string g) \"|\"))))";
+        check(&[
+            (
+                "(let ((a (gensym)) (b (gensym))) (list (eq? a b) (eq? a a)))",
+                "(#f #t)",
+            ),
+            ("(symbol? (gensym 'tmp))", "#t"),
+            (&format!("{spelt_the_same} (symbol->string g)"), "\"#:g.1\""),
+            (
+                &format!(
+                    "{spelt_the_same}
+                     (list (string=? (symbol->string g) (symbol->string same))
+                           (eq? g same)
+                           (equal? g same)
+                           (eq? '|#:g.1| '|#:g.1|))"
+                ),
+                "(#t #f #f #t)",
+            ),
+            (
+                "(define g (gensym)) (eq? g (string->symbol (symbol->string g)))",
+                "#f",
+            ),
+            ("(define g (gensym)) (memq g (list 'a g))", "(|#:g.1|)"),
+        ]);
+        check_errors(&[("(gensym 1)", "gensym: expected a string or symbol 1")]);
+    }
+}
@@ -0,0 +1,116 @@
+//! Helpers for the tests, most of which run a little Scheme program and
+//! look at what it evaluated to, raised or wrote out.
+//!
+//! Each program runs on its own thread, with a fresh interaction
+//! environment and current ports, and with a stack big enough for deep
+//! recursion.
+
+use alloc::{
+    borrow::ToOwned,
+    string::{String, ToString},
+};
+
+use crate::{
+    eval, eval::with_stack, interaction_environment, port, read_str, Error, Object, Port,
+};
+
+/// The value of the last datum of `src`, with all of them evaluated in
+/// order, and what they wrote to the current output port along the way.
+fn run(src: &str) -> (Result<Object, Error>, String) {
+    let out = Object::from(Port::output_string());
+    let value = port::current_output_port_parameter().parameterize(out.clone(), || {
+        let env = interaction_environment();
+        let mut value = Object::Unspecified;
+        for datum in read_str(src)? {
+            value = eval(datum, &env)?;
+        }
+        Ok(value)
+    });
+    let Object::Port(p) = out else { unreachable!() };
+    let text = p.borrow().output_contents().unwrap_or_default();
+    (value, text)
+}
+
+/// What `src` evaluates to, as `write` would write it.
+pub fn show(src: &str) -> String {
+    let src = src.to_owned();
+    with_stack(move || match run(&src).0 {
+        Ok(value) => value.to_string(),
+        Err(e) => panic!("{src} raised {e}"),
+    })
+}
+
+/// What `src` writes to the current output port.
+pub fn output(src: &str) -> String {
+    let src = src.to_owned();
+    with_stack(move || match run(&src) {
+        (Ok(_), text) => text,
+        (Err(e), _) => panic!("{src} raised {e}"),
+    })
+}
+
+/// What `src` raises, as the REPL would report it, that nothing caught.
+pub fn error(src: &str) -> String {
+    let src = src.to_owned();
+    with_stack(move || match run(&src).0 {
+        Ok(value) => panic!("{src} evaluated to {value} instead of raising"),
+        Err(e) => e.to_string(),
+    })
+}
+
+/// Checks that each of `cases` evaluates to what it's paired with, as
+/// `write` would write it.
+pub fn check(cases: &[(&str, &str)]) {
+    for (src, expected) in cases {
+        assert_eq!(show(src), *expected, "evaluating {src}");
+    }
+}
+
+/// Checks that each of `cases` raises an error whose report starts with
+/// what it's paired with.
+pub fn check_errors(cases: &[(&str, &str)]) {
+    for (src, expected) in cases {
+        let error = error(src);
+        assert!(
+            error.starts_with(expected),
+            "{src} raised {error:?}, not {expected:?}"
+        );
+    }
+}
+
+/// A path in the temporary directory for a test to use, named after the
+/// test and this process so that concurrent runs don't collide.
+#[cfg(feature = "std")]
+pub fn temp_path(name: &str) -> String {
+    let name = format!("mibph-{}-{name}", std::process::id());
+    std::env::temp_dir().join(name).to_string_lossy().into_owned()
+}
+
+/// A small, seeded source of pseudo-random numbers, so that generated test
+/// cases are the same on every run and a failure can be reproduced.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        // xorshift gets stuck at zero
+        Rng(seed.max(1))
+    }
+
+    /// The next number, by xorshift64.
+    pub fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// A number below `n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    /// One of `items`.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+}
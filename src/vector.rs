@@ -0,0 +1,470 @@
+use alloc::vec::Vec;
+
+use crate::{
+    constant,
+    error::Error,
+    eval::{apply, Primitive},
+    list,
+    number::{self, Number},
+    string,
+    sync::{Rc, RefCell},
+    vec_to_list, Object,
+};
+
+/// A new vector of `items`.
+pub fn make(items: Vec<Object>) -> Object {
+    Object::Vector(Rc::new(RefCell::new(items)))
+}
+
+/// The elements of `obj`, which `who` needs to be a vector.
+fn vector<'a>(obj: &'a Object, who: &str) -> Result<&'a RefCell<Vec<Object>>, Error> {
+    match obj {
+        Object::Vector(v) => Ok(v),
+        _ => Err(Error::wrong_type(who, "a vector", obj)),
+    }
+}
+
+/// The elements of `obj`, which `who` needs to be a vector it can change.
+fn vector_mut<'a>(obj: &'a Object, who: &str) -> Result<&'a RefCell<Vec<Object>>, Error> {
+    let v = vector(obj, who)?;
+    constant::mutable(obj, who)?;
+    Ok(v)
+}
+
+/// `len` copies of `fill` for `who`, which `obj` asked for, or an error if
+/// there isn't the memory for them.
+pub fn filled<T: Clone>(len: usize, fill: T, who: &str, obj: &Object) -> Result<Vec<T>, Error> {
+    let mut items = Vec::new();
+    items
+        .try_reserve_exact(len)
+        .map_err(|_| Error::out_of_memory(who, obj))?;
+    items.resize(len, fill);
+    Ok(items)
+}
+
+fn make_vector(args: &[Object]) -> Result<Object, Error> {
+    let k = number::index(&args[0], "make-vector")?;
+    let fill = args.get(1).cloned().unwrap_or(Object::Unspecified);
+    Ok(make(filled(k, fill, "make-vector", &args[0])?))
+}
+
+fn vector_ref(args: &[Object]) -> Result<Object, Error> {
+    let v = vector(&args[0], "vector-ref")?.borrow();
+    let k = number::index_below(&args[1], v.len(), "vector-ref")?;
+    Ok(v[k].clone())
+}
+
+fn vector_set(args: &[Object]) -> Result<Object, Error> {
+    let mut v = vector_mut(&args[0], "vector-set!")?.borrow_mut();
+    let k = number::index_below(&args[1], v.len(), "vector-set!")?;
+    v[k] = args[2].clone();
+    Ok(Object::Unspecified)
+}
+
+fn vector_to_list(args: &[Object]) -> Result<Object, Error> {
+    let v = vector(&args[0], "vector->list")?.borrow();
+    let range = number::range(&args[1..], v.len(), "vector->list")?;
+    Ok(vec_to_list(v[range].to_vec(), Object::Null))
+}
+
+fn vector_to_string(args: &[Object]) -> Result<Object, Error> {
+    let v = vector(&args[0], "vector->string")?.borrow();
+    let range = number::range(&args[1..], v.len(), "vector->string")?;
+    let chars = v[range]
+        .iter()
+        .map(|c| match c {
+            Object::Char(c) => Ok(*c),
+            _ => Err(Error::wrong_type("vector->string", "a character", c)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(string::make(chars))
+}
+
+fn vector_fill(args: &[Object]) -> Result<Object, Error> {
+    let mut v = vector_mut(&args[0], "vector-fill!")?.borrow_mut();
+    let range = number::range(&args[2..], v.len(), "vector-fill!")?;
+    v[range].fill(args[1].clone());
+    Ok(Object::Unspecified)
+}
+
+fn vector_copy(args: &[Object]) -> Result<Object, Error> {
+    let v = vector(&args[0], "vector-copy")?.borrow();
+    let range = number::range(&args[1..], v.len(), "vector-copy")?;
+    Ok(make(v[range].to_vec()))
+}
+
+/// `(vector-copy! to at from [start [end]])`. The elements are taken out
+/// of `from` before any go into `to`, so it doesn't matter if the two are
+/// the same vector and the ranges overlap.
+fn vector_copy_to(args: &[Object]) -> Result<Object, Error> {
+    let to = vector_mut(&args[0], "vector-copy!")?;
+    let from = vector(&args[2], "vector-copy!")?.borrow();
+    let range = number::range(&args[3..], from.len(), "vector-copy!")?;
+    let items = from[range].to_vec();
+    drop(from);
+    let mut to = to.borrow_mut();
+    let at = number::index(&args[1], "vector-copy!")?;
+    if at > to.len() || to.len() - at < items.len() {
+        return Err(number::out_of_range("vector-copy!", &args[1]));
+    }
+    to[at..at + items.len()].clone_from_slice(&items);
+    Ok(Object::Unspecified)
+}
+
+fn vector_append(args: &[Object]) -> Result<Object, Error> {
+    let mut items = Vec::new();
+    for arg in args {
+        items.extend_from_slice(&vector(arg, "vector-append")?.borrow());
+    }
+    Ok(make(items))
+}
+
+/// Calls `f` with the elements at the same index in each of `vectors`, up
+/// to the end of the shortest of them.
+fn lockstep(
+    who: &str,
+    vectors: &[Object],
+    mut f: impl FnMut(Vec<Object>) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let vectors = vectors
+        .iter()
+        .map(|v| vector(v, who))
+        .collect::<Result<Vec<_>, _>>()?;
+    let len = vectors.iter().map(|v| v.borrow().len()).min().unwrap_or(0);
+    for i in 0..len {
+        // the elements are looked up afresh each time round, since `f` can
+        // change them
+        f(vectors.iter().map(|v| v.borrow()[i].clone()).collect())?;
+    }
+    Ok(())
+}
+
+fn vector_map(args: &[Object]) -> Result<Object, Error> {
+    let mut results = Vec::new();
+    lockstep("vector-map", &args[1..], |elements| {
+        results.push(apply(&args[0], elements)?);
+        Ok(())
+    })?;
+    Ok(make(results))
+}
+
+fn vector_for_each(args: &[Object]) -> Result<Object, Error> {
+    lockstep("vector-for-each", &args[1..], |elements| {
+        apply(&args[0], elements).map(drop)
+    })?;
+    Ok(Object::Unspecified)
+}
+
+/// `items` in the order `less` says, a procedure that's true of two items
+/// when the first must go before the second. It's a merge sort, and so
+/// stable: items that must go neither before nor after each other stay in
+/// the order they were in.
+pub fn sort(mut items: Vec<Object>, less: &Object) -> Result<Vec<Object>, Error> {
+    if items.len() < 2 {
+        return Ok(items);
+    }
+    let back = items.split_off(items.len() / 2);
+    let mut front = sort(items, less)?.into_iter().peekable();
+    let mut back = sort(back, less)?.into_iter().peekable();
+    let mut merged = Vec::with_capacity(front.len() + back.len());
+    while let (Some(f), Some(b)) = (front.peek(), back.peek()) {
+        // taking from the back only when it must go first is what keeps
+        // the sort stable
+        let next = match apply(less, vec![b.clone(), f.clone()])?.is_truthy() {
+            true => back.next(),
+            false => front.next(),
+        };
+        merged.extend(next);
+    }
+    merged.extend(front.chain(back));
+    Ok(merged)
+}
+
+/// `(vector-sort less? v)`: a sorted copy of `v`.
+fn vector_sort(args: &[Object]) -> Result<Object, Error> {
+    let items = vector(&args[1], "vector-sort")?.borrow().clone();
+    Ok(make(sort(items, &args[0])?))
+}
+
+/// `(sort! less? v)`: sorts `v` in place. It's sorted apart from `v` and
+/// then put back, so `less?` sees `v` unchanged until it's done.
+fn vector_sort_in_place(args: &[Object]) -> Result<Object, Error> {
+    let v = vector_mut(&args[1], "sort!")?;
+    let items = v.borrow().clone();
+    let sorted = sort(items, &args[0])?;
+    *v.borrow_mut() = sorted;
+    Ok(Object::Unspecified)
+}
+
+/// `(vector-binary-search v value cmp)`: the index of an element of `v`
+/// that `(cmp element value)` says is equal to `value` by returning zero,
+/// or `#f` if there isn't one. `v` has to be sorted the way `cmp` sees it,
+/// negative for elements before `value` and positive for those after.
+fn vector_binary_search(args: &[Object]) -> Result<Object, Error> {
+    let v = vector(&args[0], "vector-binary-search")?;
+    let (mut low, mut high) = (0, v.borrow().len());
+    while low < high {
+        let middle = low + (high - low) / 2;
+        // looked up afresh each time, in case `cmp` changes `v`
+        let element = v.borrow()[middle].clone();
+        match apply(&args[2], vec![element, args[1].clone()])? {
+            Object::Number(n) if n < Number::Integer(0) => low = middle + 1,
+            Object::Number(n) if n > Number::Integer(0) => high = middle,
+            Object::Number(n) if n.is_zero() => {
+                return Ok(Object::Number(Number::Integer(middle as i64)))
+            }
+            other => {
+                return Err(Error::wrong_type(
+                    "vector-binary-search",
+                    "a comparison that returns a number",
+                    &other,
+                ))
+            }
+        }
+    }
+    Ok(Object::Boolean(false))
+}
+
+pub const PRIMITIVES: &[Primitive] = &[
+    Primitive::range("make-vector", 1, 2, make_vector),
+    Primitive::variadic("vector", 0, |args| Ok(make(args.to_vec()))),
+    Primitive::fixed("vector-length", 1, |args| {
+        let len = vector(&args[0], "vector-length")?.borrow().len();
+        Ok(Object::Number(Number::Integer(len as i64)))
+    }),
+    Primitive::fixed("vector-ref", 2, vector_ref),
+    Primitive::fixed("vector-set!", 3, vector_set),
+    Primitive::range("vector->list", 1, 3, vector_to_list),
+    Primitive::range("vector->string", 1, 3, vector_to_string),
+    Primitive::range("vector-fill!", 2, 4, vector_fill),
+    Primitive::range("vector-copy", 1, 3, vector_copy),
+    Primitive::range("vector-copy!", 3, 5, vector_copy_to),
+    Primitive::variadic("vector-append", 0, vector_append),
+    Primitive::variadic("vector-map", 2, vector_map),
+    Primitive::variadic("vector-for-each", 2, vector_for_each),
+    Primitive::fixed("vector-sort", 2, vector_sort),
+    Primitive::fixed("sort!", 2, vector_sort_in_place),
+    Primitive::fixed("vector-binary-search", 3, vector_binary_search),
+    Primitive::fixed("list->vector", 1, |args| {
+        Ok(make(list::elements("list->vector", &args[0])?))
+    }),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::{check, check_errors};
+
+    #[test]
+    fn making_and_indexing() {
+        check(&[
+            ("(make-vector 3 'x)", "#(x x x)"),
+            ("(vector-length (make-vector 2))", "2"),
+            ("(make-vector 0)", "#()"),
+            ("(vector 1 \"two\" #\\3)", "#(1 \"two\" #\\3)"),
+            ("(vector)", "#()"),
+            ("(vector-ref #(a b c) 2)", "c"),
+            ("(vector-length #(a b c))", "3"),
+            (
+                "(define v (make-vector 2 0)) (vector-set! v 1 'y) v",
+                "#(0 y)",
+            ),
+            (
+                "(guard (e ((error-object? e) 'caught)) (vector-ref #(1 2) 2))",
+                "caught",
+            ),
+        ]);
+        check_errors(&[
+            ("(vector-ref #(1 2) 2)", "vector-ref: index out of range 2"),
+            ("(vector-ref #(1 2) -1)", "vector-ref:"),
+            ("(vector-ref '(1 2) 0)", "vector-ref: expected a vector"),
+            (
+                "(make-vector -1)",
+                "make-vector: expected an exact non-negative integer -1",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn vectors_and_lists() {
+        check(&[
+            ("(vector->list #(1 2 3) 1)", "(2 3)"),
+            ("(vector->list #(1 2 3))", "(1 2 3)"),
+            ("(vector->list #(1 2 3) 1 2)", "(2)"),
+            ("(vector->list #())", "()"),
+            ("(list->vector '(a b))", "#(a b)"),
+            ("(list->vector '())", "#()"),
+        ]);
+        check_errors(&[
+            (
+                "(vector->list #(1 2 3) 4)",
+                "vector->list: index out of range 4",
+            ),
+            ("(list->vector '(a . b))", "list->vector: expected a list"),
+        ]);
+    }
+
+    #[test]
+    fn filling_copying_and_appending() {
+        check(&[
+            (
+                "(define v (vector 1 2 3 4)) (vector-fill! v 'x 1 3) v",
+                "#(1 x x 4)",
+            ),
+            ("(define v (vector 1 2)) (vector-fill! v 0) v", "#(0 0)"),
+            ("(vector-copy #(1 2 3) 1)", "#(2 3)"),
+            ("(define v (vector 1 2)) (eq? v (vector-copy v))", "#f"),
+            (
+                "(define v (vector 1 2 3 4 5)) (vector-copy! v 1 v 0 3) v",
+                "#(1 1 2 3 5)",
+            ),
+            (
+                "(define v (vector 1 2 3 4 5)) (vector-copy! v 0 v 2) v",
+                "#(3 4 5 4 5)",
+            ),
+            (
+                "(define v (make-vector 3 0)) (vector-copy! v 1 #(a b)) v",
+                "#(0 a b)",
+            ),
+            ("(vector-append #(1) #() #(2 3))", "#(1 2 3)"),
+            ("(vector-append)", "#()"),
+        ]);
+        check_errors(&[
+            (
+                "(vector-copy! (make-vector 1) 0 #(1 2))",
+                "vector-copy!: index out of range 0",
+            ),
+            (
+                "(vector-append #(1) '(2))",
+                "vector-append: expected a vector",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn mapping() {
+        check(&[
+            ("(vector-map (lambda (x) (* x x)) #(1 2 3))", "#(1 4 9)"),
+            ("(vector-map + #(1 2 3) #(10 20))", "#(11 22)"),
+            ("(vector-map car #())", "#()"),
+            (
+                "(define seen '())
+                 (vector-for-each (lambda (x i) (set! seen (cons (list i x) seen))) #(a b c) #(0 1 2))
+                 (reverse seen)",
+                "((0 a) (1 b) (2 c))",
+            ),
+        ]);
+        check_errors(&[("(vector-map car '(1))", "vector-map: expected a vector")]);
+    }
+
+    #[test]
+    fn vectors_and_strings() {
+        check(&[
+            ("(vector->string #(#\\a #\\b))", "\"ab\""),
+            ("(string->vector \"ab\")", "#(#\\a #\\b)"),
+            ("(vector->string (string->vector \"λx\"))", "\"λx\""),
+            ("(vector->string #(#\\a #\\b #\\c) 1)", "\"bc\""),
+            ("(string->vector \"abc\" 1 2)", "#(#\\b)"),
+        ]);
+        check_errors(&[
+            (
+                "(vector->string #(#\\a 1))",
+                "vector->string: expected a character 1",
+            ),
+            (
+                "(string->vector \"abc\" 4)",
+                "string->vector: index out of range 4",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn sorting_is_stable() {
+        let records = "(define records
+                         (vector '(3 . a) '(1 . b) '(2 . c) '(1 . d) '(3 . e) '(2 . f) '(1 . g)))
+                       (define (by-key a b) (< (car a) (car b)))";
+        check(&[
+            (
+                "(list-sort < '(3 1 4 1 5 9 2 6 5 3 5))",
+                "(1 1 2 3 3 4 5 5 5 6 9)",
+            ),
+            ("(sort > '(3 1 2))", "(3 2 1)"),
+            ("(list-sort < '())", "()"),
+            ("(let ((l (list 2 1))) (list-sort < l) l)", "(2 1)"),
+            (
+                &format!("{records} (vector-sort by-key records)"),
+                "#((1 . b) (1 . d) (1 . g) (2 . c) (2 . f) (3 . a) (3 . e))",
+            ),
+            (
+                &format!("{records} (list-sort by-key (vector->list records))"),
+                "((1 . b) (1 . d) (1 . g) (2 . c) (2 . f) (3 . a) (3 . e))",
+            ),
+            // a copy, leaving the vector as it was
+            (
+                &format!("{records} (vector-sort by-key records) records"),
+                "#((3 . a) (1 . b) (2 . c) (1 . d) (3 . e) (2 . f) (1 . g))",
+            ),
+            (
+                &format!("{records} (sort! by-key records) records"),
+                "#((1 . b) (1 . d) (1 . g) (2 . c) (2 . f) (3 . a) (3 . e))",
+            ),
+            // the elements with equal keys stay in the order they were in,
+            // through all the merging of a longer sort
+            (
+                "(define v (make-vector 500 0))
+                 (do ((i 0 (+ i 1))) ((= i 500)) (vector-set! v i (cons (modulo (* i 7) 3) i)))
+                 (define sorted (vector->list (vector-sort (lambda (a b) (< (car a) (car b))) v)))
+                 (let loop ((l sorted))
+                   (cond ((null? (cdr l)) 'stable)
+                         ((and (= (caar l) (caadr l)) (> (cdar l) (cdadr l))) (car l))
+                         (else (loop (cdr l)))))",
+                "stable",
+            ),
+        ]);
+        check_errors(&[
+            (
+                "(vector-sort < '(1 2))",
+                "vector-sort: expected a vector (1 2)",
+            ),
+            ("(list-sort < '(1 a))", "<: expected a number a"),
+        ]);
+    }
+
+    #[test]
+    fn binary_search() {
+        let search = "(define (search v x) (vector-binary-search v x -))";
+        let searches = [
+            ("(search #(1 3 5 7 9) 7)", "3"),
+            ("(search #(1 3 5 7 9) 4)", "#f"),
+            // at either end, and past them
+            ("(search #(1 3 5 7 9) 1)", "0"),
+            ("(search #(1 3 5 7 9) 9)", "4"),
+            ("(search #(1 3 5 7 9) 0)", "#f"),
+            ("(search #(1 3 5 7 9) 10)", "#f"),
+            ("(search #() 1)", "#f"),
+            ("(search #(5) 5)", "0"),
+            ("(search #(1 3) 3)", "1"),
+        ];
+        for (src, expected) in searches {
+            check(&[(&format!("{search} {src}"), expected)]);
+        }
+        // every element of a long vector is found, in a logarithmic number
+        // of comparisons
+        check(&[(
+            "(define v (make-vector 1000 0))
+             (do ((i 0 (+ i 1))) ((= i 1000)) (vector-set! v i (* 2 i)))
+             (define comparisons 0)
+             (define (cmp a b) (set! comparisons (+ comparisons 1)) (- a b))
+             (define (found-all? i)
+               (or (= i 1000)
+                   (and (= (vector-binary-search v (* 2 i) cmp) i)
+                        (not (vector-binary-search v (+ (* 2 i) 1) cmp))
+                        (found-all? (+ i 1)))))
+             (list (found-all? 0) (<= comparisons (* 2000 11)))",
+            "(#t #t)",
+        )]);
+        check_errors(&[(
+            "(vector-binary-search '(1) 1 -)",
+            "vector-binary-search: expected a vector (1)",
+        )]);
+    }
+}
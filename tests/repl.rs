@@ -0,0 +1,152 @@
+//! Runs the REPL as a program, the way it's used, with its input piped in.
+
+#![cfg(feature = "std")]
+
+use std::{
+    fs,
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+/// What the REPL wrote to standard output and standard error with `input`
+/// as its input, and the status it exited with.
+fn repl(input: &str) -> (String, String, Option<i32>) {
+    run(Command::new(env!("CARGO_BIN_EXE_mibph")), input)
+}
+
+/// What `command` wrote with `input` as its input, and how it exited.
+fn run(mut command: Command, input: &str) -> (String, String, Option<i32>) {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("can't start the REPL");
+    let mut stdin = child.stdin.take().unwrap();
+    stdin.write_all(input.as_bytes()).unwrap();
+    drop(stdin);
+    let Output {
+        stdout,
+        stderr,
+        status,
+    } = child.wait_with_output().unwrap();
+    (
+        String::from_utf8(stdout).unwrap(),
+        String::from_utf8(stderr).unwrap(),
+        status.code(),
+    )
+}
+
+#[test]
+fn runaway_recursion_is_reported_and_the_repl_carries_on() {
+    let (out, err, status) = repl("(define (f n) (+ 1 (f n)))\n(f 0)\n(+ 1 2)\n");
+    assert_eq!(out, "3\n");
+    assert!(err.starts_with("error: recursion too deep"), "{err}");
+    assert_eq!(status, Some(0));
+}
+
+#[test]
+fn exit_runs_the_afters_and_ends_with_its_status() {
+    let (out, _, status) = repl(
+        "(dynamic-wind (lambda () #f) (lambda () (exit 3)) (lambda () (display \"after\")))\n\
+         (display \"never\")\n",
+    );
+    assert_eq!(out, "after");
+    assert_eq!(status, Some(3));
+    let (out, _, status) = repl(
+        "(dynamic-wind (lambda () #f) (lambda () (emergency-exit #f)) (lambda () (display \"after\")))\n",
+    );
+    assert_eq!(out, "");
+    assert_eq!(status, Some(1));
+}
+
+#[test]
+fn an_unfinished_datum_is_continued_on_later_lines() {
+    let (out, err, status) =
+        repl("(+ 1\n2)\n(string-length \"a\nb\")\n#| a\n comment |# (list 'done\n)\n");
+    assert_eq!(out, "3\n3\n(done)\n");
+    assert_eq!(err, "");
+    assert_eq!(status, Some(0));
+}
+
+#[test]
+fn piped_input_is_read_without_a_terminal() {
+    let home = std::env::temp_dir().join(format!("mibph-{}-home", std::process::id()));
+    fs::create_dir_all(&home).unwrap();
+    let mut command = Command::new(env!("CARGO_BIN_EXE_mibph"));
+    command.env("HOME", &home);
+    let (out, err, status) = run(command, "(define x 20)\n(+ x 1)\n(* x 2)\n");
+    // no prompts, no escape sequences, and nothing saved as history
+    assert_eq!(out, "21\n40\n");
+    assert_eq!(err, "");
+    assert_eq!(status, Some(0));
+    assert!(!home.join(".mibph_history").exists());
+    fs::remove_dir_all(&home).unwrap();
+}
+
+#[test]
+fn commands_switch_between_lexing_reading_and_evaluating() {
+    let (out, err, status) = repl(":read (1 . 2)\n'x (+ 1 2)\n:eval (+ 1 2)\n:lex (a\n:nope\n");
+    let mut lines = out.lines();
+    assert_eq!(lines.next(), Some("(1 . 2)"));
+    assert_eq!(lines.next(), Some("(quote x)"));
+    assert_eq!(lines.next(), Some("(+ 1 2)"));
+    assert_eq!(lines.next(), Some("3"));
+    assert_eq!(lines.next(), Some("tokens:"));
+    assert!(
+        lines.next().is_some_and(|tokens| tokens.starts_with('[')),
+        "{out}"
+    );
+    assert_eq!(lines.next(), None);
+    assert!(err.starts_with("error: unknown REPL command nope"), "{err}");
+    assert_eq!(status, Some(0));
+}
+
+#[test]
+fn definitions_last_from_one_input_to_the_next() {
+    let (out, err, status) = repl(
+        "(define x 1)\n\
+         x\n\
+         (set! x (+ x 1))\n\
+         x\n\
+         (define (f) (* x 10))\n\
+         (f)\n\
+         (define-syntax twice (syntax-rules () ((_ e) (begin e e))))\n\
+         (twice (set! x (+ x 1)))\n\
+         (f)\n\
+         (define x 'redefined)\n\
+         x\n",
+    );
+    assert_eq!(out, "1\n2\n20\n40\nredefined\n");
+    assert_eq!(err, "");
+    assert_eq!(status, Some(0));
+}
+
+#[test]
+fn the_end_of_the_input_ends_the_repl_cleanly() {
+    let (out, err, status) = repl("(+ 1 2)\n");
+    assert_eq!((out.as_str(), err.as_str(), status), ("3\n", "", Some(0)));
+    let (out, err, status) = repl("");
+    assert_eq!((out.as_str(), err.as_str(), status), ("", "", Some(0)));
+    // without a newline at the end
+    let (out, _, status) = repl("(+ 1 2)");
+    assert_eq!((out.as_str(), status), ("3\n", Some(0)));
+}
+
+#[test]
+fn a_datum_left_open_at_the_end_is_reported() {
+    let (out, err, status) = repl("(display \"before\")\n(+ 1\n");
+    assert_eq!(out, "before");
+    assert!(err.starts_with("error: unexpected end of input"), "{err}");
+    assert!(err.contains("line 2, column 1"), "{err}");
+    assert_eq!(status, Some(0));
+    for unclosed in ["\"abc\n", "|sym\n", "#| comment\n", "'\n", "#(1 2\n"] {
+        let (out, err, status) = repl(unclosed);
+        assert_eq!(out, "", "{unclosed:?}");
+        assert!(
+            err.starts_with("error: unexpected end of input"),
+            "{unclosed:?}: {err}"
+        );
+        assert_eq!(status, Some(0), "{unclosed:?}");
+    }
+}